@@ -1,52 +1,47 @@
 datealgo::asm::next_date:
-	movabs rcx, 1099511627776
-	movabs rax, 281474976710655
-	and rax, rdi
-	mov rdx, rax
-	shr rdx, 32
-	mov rsi, rdi
-	shr rsi, 40
-	cmp sil, 28
-	jae .LBB4_1
-.LBB4_3:
-	add rdi, rcx
-	movabs rcx, 280375465082880
-	and rcx, rdi
-.LBB4_8:
-	movzx edx, dl
-	shl rdx, 32
-	mov eax, eax
-	or rax, rcx
+	mov rax, rdi
+	mov rcx, rdi
+	shr rcx, 32
+	cmp cl, 2
+	jne .LBB19_2
+	imul edx, eax, -1030792151
+	add edx, 85899345
+	cmp edx, 171798691
+	mov edx, 15
+	mov esi, 3
+	cmovb esi, edx
+	test esi, eax
+	sete sil
+	or sil, 28
+	jmp .LBB19_3
+.LBB19_2:
+	mov esi, ecx
+	shr sil, 3
+	xor sil, cl
+	or sil, 30
+.LBB19_3:
+	mov rdi, rax
+	shr rdi, 40
+	movabs r8, 1099511627776
+	lea r9, [rax + r8]
+	movabs rdx, 280375465082880
+	and rdx, r9
+	xor r9d, r9d
+	cmp sil, dil
+	setbe r9b
+	cmovbe rdx, r8
+	cmp cl, 12
+	sete sil
+	and sil, r9b
+	lea edi, [rcx + r9]
+	add ecx, r9d
+	add ecx, 244
+	test sil, sil
+	cmove ecx, edi
+	movzx esi, sil
+	add eax, esi
+	movzx ecx, cl
+	shl rcx, 32
 	or rax, rdx
+	or rax, rcx
 	ret
-.LBB4_1:
-	cmp dl, 2
-	jne .LBB4_2
-	imul r8d, edi, -1030792151
-	add r8d, 85899345
-	cmp r8d, 171798691
-	mov r8d, 15
-	mov r9d, 3
-	cmovb r9d, r8d
-	test r9d, edi
-	sete r8b
-	or r8b, 28
-	cmp r8b, sil
-	ja .LBB4_3
-	jmp .LBB4_7
-.LBB4_2:
-	mov r8d, edx
-	shr r8b, 3
-	xor r8b, dl
-	or r8b, 30
-	cmp r8b, sil
-	ja .LBB4_3
-	cmp dl, 12
-	jae .LBB4_5
-.LBB4_7:
-	inc rdx
-	jmp .LBB4_8
-.LBB4_5:
-	inc rax
-	mov edx, 1
-	jmp .LBB4_8
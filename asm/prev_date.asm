@@ -1,20 +1,24 @@
 datealgo::asm::prev_date:
-	movabs rax, 281474976710655
-	and rax, rdi
-	mov rcx, rdi
-	shr rcx, 32
 	mov rdx, rdi
-	shr rdx, 40
-	cmp dl, 1
-	jbe .LBB5_1
-	dec dl
-	jmp .LBB5_6
-.LBB5_1:
-	cmp cl, 1
-	jbe .LBB5_2
-	dec cl
+	shr rdx, 32
+	mov rcx, rdi
+	shr rcx, 40
+	mov eax, ecx
+	or eax, edx
+	xor esi, esi
+	xor r8d, r8d
+	test al, -2
+	sete r8b
+	mov eax, 12
+	cmovne eax, esi
+	sub edi, r8d
+	cmp cl, 2
+	sbb dl, 0
+	add al, dl
 	cmp cl, 2
-	jne .LBB5_5
+	jae .LBB20_1
+	cmp al, 2
+	jne .LBB20_4
 	imul ecx, edi, -1030792151
 	add ecx, 85899345
 	cmp ecx, 171798691
@@ -22,27 +26,23 @@ datealgo::asm::prev_date:
 	mov edx, 3
 	cmovb edx, ecx
 	test edx, edi
-	sete dl
-	or dl, 28
-	mov cl, 2
-	jmp .LBB5_6
-.LBB5_2:
-	mov ecx, 4294967295
-	add rax, rcx
-	mov dl, 31
-	mov cl, 12
-	jmp .LBB5_6
-.LBB5_5:
-	mov edx, ecx
-	shr dl, 3
-	xor dl, cl
-	or dl, 30
-.LBB5_6:
-	movzx edx, dl
-	shl rdx, 40
+	sete cl
+	or cl, 28
+	jmp .LBB20_5
+.LBB20_1:
+	dec ecx
+	jmp .LBB20_5
+.LBB20_4:
+	mov ecx, eax
+	shr cl, 3
+	xor cl, al
+	or cl, 30
+.LBB20_5:
 	movzx ecx, cl
-	shl rcx, 32
-	or rcx, rdx
-	mov eax, eax
-	or rax, rcx
+	shl rcx, 40
+	movzx edx, al
+	shl rdx, 32
+	or rdx, rcx
+	mov eax, edi
+	or rax, rdx
 	ret
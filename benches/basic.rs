@@ -64,13 +64,17 @@ fn rand_dtn() -> (i32, u8, u8, u8, u8, u8, u32) {
     (y, m, d, hh, mm, ss, n)
 }
 
+fn rand_tm() -> datealgo::Tm {
+    datealgo::secs_to_tm(rand_secs())
+}
+
 fn rand_iwd() -> (i32, u8, u8) {
     datealgo::rd_to_isoweekdate(rand_rd())
 }
 
 fn bench_basic(c: &mut Criterion) {
     c.bench_function("overhead", |b| {
-        b.iter_custom(bencher(rand_date, |d| black_box(d)));
+        b.iter_custom(bencher(rand_date, black_box));
     });
     c.bench_function("rd_to_date", |b| {
         b.iter_custom(bencher(rand_rd, |rd| datealgo::rd_to_date(black_box(rd))))
@@ -102,12 +106,35 @@ fn bench_basic(c: &mut Criterion) {
     c.bench_function("datetime_to_secs", |b| {
         b.iter_custom(bencher(rand_dt, |dt| datealgo::datetime_to_secs(black_box(dt))))
     });
+    c.bench_function("datetime_to_secs_unchecked", |b| {
+        b.iter_custom(bencher(rand_dt, |dt| datealgo::datetime_to_secs_unchecked(black_box(dt))))
+    });
+    c.bench_function("secs_to_tm", |b| {
+        b.iter_custom(bencher(rand_secs, |s| datealgo::secs_to_tm(black_box(s))))
+    });
+    c.bench_function("tm_to_secs", |b| {
+        b.iter_custom(bencher(rand_tm, |tm| datealgo::tm_to_secs(black_box(&tm))))
+    });
     c.bench_function("is_leap_year", |b| {
         b.iter_custom(bencher(rand_year, |y| datealgo::is_leap_year(black_box(y))))
     });
     c.bench_function("days_in_month", |b| {
         b.iter_custom(bencher(rand_ym, |(y, m)| datealgo::days_in_month(black_box(y), black_box(m))))
     });
+    #[cfg(feature = "tables")]
+    c.bench_function("tables::rd_to_date", |b| {
+        b.iter_custom(bencher(rand_rd, |rd| datealgo::tables::rd_to_date(black_box(rd))))
+    });
+    #[cfg(feature = "tables")]
+    c.bench_function("tables::is_leap_year", |b| {
+        b.iter_custom(bencher(rand_year, |y| datealgo::tables::is_leap_year(black_box(y))))
+    });
+    #[cfg(feature = "tables")]
+    c.bench_function("tables::days_in_month", |b| {
+        b.iter_custom(bencher(rand_ym, |(y, m)| {
+            datealgo::tables::days_in_month(black_box(y), black_box(m))
+        }))
+    });
     c.bench_function("rd_to_isoweekdate", |b| {
         b.iter_custom(bencher(rand_rd, |rd| datealgo::rd_to_isoweekdate(black_box(rd))))
     });
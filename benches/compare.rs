@@ -1,3 +1,5 @@
+#![allow(clippy::unnecessary_cast, clippy::manual_is_multiple_of)]
+
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 use std::time::{Duration, SystemTime};
 
@@ -0,0 +1,57 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use std::time::Duration;
+
+mod util;
+use util::bencher;
+
+fn rand_rd() -> i32 {
+    fastrand::i32(datealgo::RD_MIN..=datealgo::RD_MAX)
+}
+
+fn rand_secs() -> i64 {
+    fastrand::i64(datealgo::RD_SECONDS_MIN..=datealgo::RD_SECONDS_MAX)
+}
+
+fn rand_rd_x8() -> [i32; 8] {
+    std::array::from_fn(|_| rand_rd())
+}
+
+fn rand_secs_x8() -> [i64; 8] {
+    std::array::from_fn(|_| rand_secs())
+}
+
+fn bench_simd(c: &mut Criterion) {
+    c.bench_function("rd_to_date x8 scalar", |b| {
+        b.iter_custom(bencher(rand_rd_x8, |ns| black_box(ns).map(datealgo::rd_to_date)))
+    });
+    c.bench_function("simd::rd_to_date_x8", |b| {
+        b.iter_custom(bencher(rand_rd_x8, |ns| datealgo::simd::rd_to_date_x8(black_box(ns))))
+    });
+    c.bench_function("secs_to_datetime x8 scalar", |b| {
+        b.iter_custom(bencher(rand_secs_x8, |secs| black_box(secs).map(datealgo::secs_to_datetime)))
+    });
+    c.bench_function("simd::secs_to_datetime_x8", |b| {
+        b.iter_custom(bencher(rand_secs_x8, |secs| datealgo::simd::secs_to_datetime_x8(black_box(secs))))
+    });
+    let ns: Vec<i32> = (0..4096).map(|_| rand_rd()).collect();
+    let mut years = vec![0; ns.len()];
+    let mut months = vec![0; ns.len()];
+    let mut days = vec![0; ns.len()];
+    c.bench_function("rd_to_date x4096 scalar", |b| {
+        b.iter(|| {
+            for (((&n, y), m), d) in black_box(&ns).iter().zip(&mut years).zip(&mut months).zip(&mut days) {
+                (*y, *m, *d) = datealgo::rd_to_date(n);
+            }
+        })
+    });
+    c.bench_function("batch::rd_to_date_slice x4096", |b| {
+        b.iter(|| datealgo::batch::rd_to_date_slice(black_box(&ns), &mut years, &mut months, &mut days))
+    });
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(5000).measurement_time(Duration::from_secs(10));
+    targets = bench_simd
+}
+criterion_main!(benches);
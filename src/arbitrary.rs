@@ -0,0 +1,71 @@
+//! Structured generators for fuzzing with `arbitrary`
+//!
+//! Fuzzing parsers and other code built on this crate needs inputs that are
+//! valid by construction. The wrapper types in this module implement
+//! [Arbitrary] so that they only ever yield valid values within the bounds
+//! supported by the crate, by sampling a Rata Die or Unix seconds value from
+//! the supported range and converting it.
+//!
+//! # Examples
+//!
+//! ```
+//! use arbitrary::{Arbitrary, Unstructured};
+//! use datealgo::arbitrary::ArbDateTime;
+//!
+//! let mut u = Unstructured::new(&[1, 2, 3, 4, 5, 6, 7, 8]);
+//! let ArbDateTime((y, m, d, hh, mm, ss)) = ArbDateTime::arbitrary(&mut u).unwrap();
+//! assert!(d <= datealgo::days_in_month(y, m));
+//! ```
+
+use ::arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::{rd_to_date, rd_to_isoweekdate, secs_to_datetime, RD_MAX, RD_MIN, RD_SECONDS_MAX, RD_SECONDS_MIN};
+
+/// Arbitrary valid `(year, month, day)` tuple
+///
+/// Every day between [RD_MIN] and [RD_MAX] may be generated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ArbDate(pub (i32, u8, u8));
+
+/// Arbitrary valid `(year, month, day, hours, minutes, seconds)` tuple
+///
+/// Every second between [RD_SECONDS_MIN] and [RD_SECONDS_MAX] may be
+/// generated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ArbDateTime(pub (i32, u8, u8, u8, u8, u8));
+
+/// Arbitrary valid ISO week date `(year, week, day)` tuple
+///
+/// Every day between [RD_MIN] and [RD_MAX] may be generated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ArbIsoWeekDate(pub (i32, u8, u8));
+
+impl<'a> Arbitrary<'a> for ArbDate {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(ArbDate(rd_to_date(u.int_in_range(RD_MIN..=RD_MAX)?)))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        i32::size_hint(depth)
+    }
+}
+
+impl<'a> Arbitrary<'a> for ArbDateTime {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(ArbDateTime(secs_to_datetime(u.int_in_range(RD_SECONDS_MIN..=RD_SECONDS_MAX)?)))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        i64::size_hint(depth)
+    }
+}
+
+impl<'a> Arbitrary<'a> for ArbIsoWeekDate {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(ArbIsoWeekDate(rd_to_isoweekdate(u.int_in_range(RD_MIN..=RD_MAX)?)))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        i32::size_hint(depth)
+    }
+}
@@ -0,0 +1,389 @@
+//! Conversions between Apache Arrow temporal arrays and component arrays
+//!
+//! Query engines built on [Apache Arrow](https://arrow.apache.org/) store
+//! dates as `Date32` (days from Unix epoch) or `Date64` (milliseconds from
+//! Unix epoch) arrays, and instants as `Timestamp` arrays in seconds,
+//! milliseconds, microseconds or nanoseconds. The functions in this module
+//! convert whole arrays between those and separate arrays of years, months,
+//! days, hours, minutes, seconds and nanoseconds, using the slice functions
+//! in the [batch](crate::batch) module.
+//!
+//! Nulls are propagated. Values in null slots are not converted, and are set
+//! to the Unix epoch in the output. When converting from components, the
+//! result is null wherever any of the components is null.
+//!
+//! The time zone of a `Timestamp` array is ignored, so components are always
+//! in UTC, and `Timestamp` arrays created from components have no time zone.
+
+use ::arrow_array::types::{ArrowPrimitiveType, ArrowTimestampType, Date32Type, Date64Type};
+use ::arrow_array::{Array, Date32Array, Date64Array, Int32Array, PrimitiveArray, UInt32Array, UInt8Array};
+use ::arrow_buffer::NullBuffer;
+use ::arrow_schema::TimeUnit;
+
+use crate::batch::{date_to_rd_slice, datetime_to_secs_slice, rd_to_date_slice};
+use crate::SECS_IN_DAY;
+
+/// Milliseconds in a day
+const MILLIS_IN_DAY: i64 = SECS_IN_DAY * 1000;
+
+/// Year, month and day arrays
+pub type DateArrays = (Int32Array, UInt8Array, UInt8Array);
+
+/// Year, month, day, hours, minutes, seconds and nanoseconds arrays
+pub type DateTimeArrays = (Int32Array, UInt8Array, UInt8Array, UInt8Array, UInt8Array, UInt8Array, UInt32Array);
+
+/// Number of ticks in a second for a time unit
+#[inline]
+const fn ticks_in_sec(unit: TimeUnit) -> i64 {
+    match unit {
+        TimeUnit::Second => 1,
+        TimeUnit::Millisecond => 1_000,
+        TimeUnit::Microsecond => 1_000_000,
+        TimeUnit::Nanosecond => 1_000_000_000,
+    }
+}
+
+/// Copy the values of an array, replacing values in null slots with `value`
+#[inline]
+fn values_or<T: ArrowPrimitiveType>(array: &PrimitiveArray<T>, nulls: Option<&NullBuffer>, value: T::Native) -> Vec<T::Native> {
+    let mut values = array.values().to_vec();
+    if let Some(nulls) = nulls {
+        for (v, valid) in values.iter_mut().zip(nulls.iter()) {
+            if !valid {
+                *v = value;
+            }
+        }
+    }
+    values
+}
+
+/// Split Rata Die values to year, month and day arrays
+#[inline]
+fn rd_to_date_arrays(ns: &[i32], nulls: Option<&NullBuffer>) -> DateArrays {
+    let mut years = vec![0; ns.len()];
+    let mut months = vec![0; ns.len()];
+    let mut days = vec![0; ns.len()];
+    rd_to_date_slice(ns, &mut years, &mut months, &mut days);
+    (
+        Int32Array::new(years.into(), nulls.cloned()),
+        UInt8Array::new(months.into(), nulls.cloned()),
+        UInt8Array::new(days.into(), nulls.cloned()),
+    )
+}
+
+/// Combine year, month and day arrays to Rata Die values
+#[inline]
+fn date_arrays_to_rd(years: &Int32Array, months: &UInt8Array, days: &UInt8Array) -> Option<(Vec<i32>, Option<NullBuffer>)> {
+    if years.len() != months.len() || years.len() != days.len() {
+        return None;
+    }
+    let nulls = NullBuffer::union_many([years.nulls(), months.nulls(), days.nulls()]);
+    let mut ns = vec![0; years.len()];
+    let n = nulls.as_ref();
+    date_to_rd_slice(
+        &values_or(years, n, 1970),
+        &values_or(months, n, 1),
+        &values_or(days, n, 1),
+        &mut ns,
+    );
+    Some((ns, nulls))
+}
+
+/// Convert a `Date32` array to year, month and day arrays
+///
+/// Given an array of days counting from Unix epoch (January 1st, 1970),
+/// returns arrays of the year, month and day of each.
+///
+/// # Panics
+///
+/// Every non-null element must be between [RD_MIN](crate::RD_MIN) and
+/// [RD_MAX](crate::RD_MAX) inclusive. Bounds are checked using `debug_assert`
+/// only, so that the checks are not present in release builds, similar to
+/// integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use arrow_array::{Array, Date32Array};
+/// use datealgo::arrow::date32_to_date_arrays;
+///
+/// let (years, months, days) = date32_to_date_arrays(&Date32Array::from(vec![Some(19489), None]));
+/// assert_eq!((years.value(0), months.value(0), days.value(0)), (2023, 5, 12));
+/// assert!(years.is_null(1));
+/// ```
+///
+/// # Algorithm
+///
+/// [rd_to_date_slice] over the values.
+#[inline]
+pub fn date32_to_date_arrays(array: &Date32Array) -> DateArrays {
+    rd_to_date_arrays(&values_or(array, array.nulls(), 0), array.nulls())
+}
+
+/// Convert year, month and day arrays to a `Date32` array
+///
+/// Given arrays of years, months and days, returns an array of days counting
+/// from Unix epoch (January 1st, 1970).
+///
+/// # Errors
+///
+/// Returns `None` if the arrays differ in length.
+///
+/// # Panics
+///
+/// For every non-null element, year must be between [YEAR_MIN](crate::YEAR_MIN)
+/// and [YEAR_MAX](crate::YEAR_MAX), month must be between `1` and `12` and day
+/// must be between `1` and the number of days in the month in question. Bounds
+/// are checked using `debug_assert` only, so that the checks are not present in
+/// release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use arrow_array::{Array, Int32Array, UInt8Array};
+/// use datealgo::arrow::date_arrays_to_date32;
+///
+/// let years = Int32Array::from(vec![2023, 1970]);
+/// let months = UInt8Array::from(vec![Some(5), None]);
+/// let days = UInt8Array::from(vec![12, 1]);
+/// let array = date_arrays_to_date32(&years, &months, &days).unwrap();
+/// assert_eq!(array.value(0), 19489);
+/// assert!(array.is_null(1));
+/// ```
+///
+/// # Algorithm
+///
+/// [date_to_rd_slice] over the values.
+#[inline]
+pub fn date_arrays_to_date32(years: &Int32Array, months: &UInt8Array, days: &UInt8Array) -> Option<Date32Array> {
+    let (ns, nulls) = date_arrays_to_rd(years, months, days)?;
+    Some(PrimitiveArray::<Date32Type>::new(ns.into(), nulls))
+}
+
+/// Convert a `Date64` array to year, month and day arrays
+///
+/// Given an array of milliseconds counting from Unix epoch (January 1st,
+/// 1970), returns arrays of the year, month and day of each. Any time of day
+/// is discarded.
+///
+/// # Panics
+///
+/// Every non-null element must be between [RD_SECONDS_MIN](crate::RD_SECONDS_MIN)
+/// and [RD_SECONDS_MAX](crate::RD_SECONDS_MAX) inclusive when converted to
+/// seconds. Bounds are checked using `debug_assert` only, so that the checks
+/// are not present in release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use arrow_array::Date64Array;
+/// use datealgo::arrow::date64_to_date_arrays;
+///
+/// let (years, months, days) = date64_to_date_arrays(&Date64Array::from(vec![1683849600000, -1]));
+/// assert_eq!((years.value(0), months.value(0), days.value(0)), (2023, 5, 12));
+/// assert_eq!((years.value(1), months.value(1), days.value(1)), (1969, 12, 31));
+/// ```
+///
+/// # Algorithm
+///
+/// Floor division followed by [rd_to_date_slice].
+#[inline]
+pub fn date64_to_date_arrays(array: &Date64Array) -> DateArrays {
+    let ns: Vec<i32> = values_or(array, array.nulls(), 0)
+        .iter()
+        .map(|ms| ms.div_euclid(MILLIS_IN_DAY) as i32)
+        .collect();
+    rd_to_date_arrays(&ns, array.nulls())
+}
+
+/// Convert year, month and day arrays to a `Date64` array
+///
+/// Given arrays of years, months and days, returns an array of milliseconds
+/// counting from Unix epoch (January 1st, 1970) at midnight of each day.
+///
+/// # Errors
+///
+/// Returns `None` if the arrays differ in length.
+///
+/// # Panics
+///
+/// Same as [date_arrays_to_date32].
+///
+/// # Examples
+///
+/// ```
+/// use arrow_array::{Int32Array, UInt8Array};
+/// use datealgo::arrow::date_arrays_to_date64;
+///
+/// let years = Int32Array::from(vec![2023]);
+/// let months = UInt8Array::from(vec![5]);
+/// let days = UInt8Array::from(vec![12]);
+/// assert_eq!(date_arrays_to_date64(&years, &months, &days).unwrap().value(0), 1683849600000);
+/// ```
+///
+/// # Algorithm
+///
+/// [date_to_rd_slice] followed by multiplication.
+#[inline]
+pub fn date_arrays_to_date64(years: &Int32Array, months: &UInt8Array, days: &UInt8Array) -> Option<Date64Array> {
+    let (ns, nulls) = date_arrays_to_rd(years, months, days)?;
+    let ms: Vec<i64> = ns.iter().map(|&n| n as i64 * MILLIS_IN_DAY).collect();
+    Some(PrimitiveArray::<Date64Type>::new(ms.into(), nulls))
+}
+
+/// Convert a `Timestamp` array to date and time component arrays
+///
+/// Given an array of timestamps in any unit, returns arrays of the year,
+/// month, day, hours, minutes, seconds and nanoseconds of each, in UTC.
+///
+/// # Panics
+///
+/// Every non-null element must be between [RD_SECONDS_MIN](crate::RD_SECONDS_MIN)
+/// and [RD_SECONDS_MAX](crate::RD_SECONDS_MAX) inclusive when converted to
+/// seconds. Bounds are checked using `debug_assert` only, so that the checks
+/// are not present in release builds, similar to integer overflow checks. Only
+/// arrays in seconds can contain values out of range.
+///
+/// # Examples
+///
+/// ```
+/// use arrow_array::TimestampMillisecondArray;
+/// use datealgo::arrow::timestamp_to_datetime_arrays;
+///
+/// let array = TimestampMillisecondArray::from(vec![1684574678123]);
+/// let (y, m, d, hh, mm, ss, nanos) = timestamp_to_datetime_arrays(&array);
+/// assert_eq!((y.value(0), m.value(0), d.value(0)), (2023, 5, 20));
+/// assert_eq!((hh.value(0), mm.value(0), ss.value(0), nanos.value(0)), (9, 24, 38, 123_000_000));
+/// ```
+///
+/// # Algorithm
+///
+/// Floor division of the values followed by [rd_to_date_slice] for the dates.
+#[inline]
+pub fn timestamp_to_datetime_arrays<T: ArrowTimestampType>(array: &PrimitiveArray<T>) -> DateTimeArrays {
+    let ticks = ticks_in_sec(T::UNIT);
+    let values = values_or(array, array.nulls(), 0);
+    let len = values.len();
+    let mut ns = vec![0; len];
+    let mut hours = vec![0; len];
+    let mut minutes = vec![0; len];
+    let mut seconds = vec![0; len];
+    let mut nanos = vec![0; len];
+    for (i, v) in values.iter().enumerate() {
+        let secs = v.div_euclid(ticks);
+        let ss = secs.rem_euclid(SECS_IN_DAY) as u32;
+        ns[i] = secs.div_euclid(SECS_IN_DAY) as i32;
+        hours[i] = (ss / 3600) as u8;
+        minutes[i] = (ss / 60 % 60) as u8;
+        seconds[i] = (ss % 60) as u8;
+        nanos[i] = (v.rem_euclid(ticks) * (1_000_000_000 / ticks)) as u32;
+    }
+    let nulls = array.nulls();
+    let (years, months, days) = rd_to_date_arrays(&ns, nulls);
+    (
+        years,
+        months,
+        days,
+        UInt8Array::new(hours.into(), nulls.cloned()),
+        UInt8Array::new(minutes.into(), nulls.cloned()),
+        UInt8Array::new(seconds.into(), nulls.cloned()),
+        UInt32Array::new(nanos.into(), nulls.cloned()),
+    )
+}
+
+/// Convert date and time component arrays to a `Timestamp` array
+///
+/// Given arrays of years, months, days, hours, minutes, seconds and
+/// nanoseconds in UTC, returns an array of timestamps in the unit of `T`,
+/// truncating the nanoseconds to whole units. The result has no time zone.
+///
+/// # Errors
+///
+/// Returns `None` if the arrays differ in length, or if a non-null value does
+/// not fit in the unit of `T`, which is only possible for nanoseconds outside
+/// years 1677 to 2262.
+///
+/// # Panics
+///
+/// For every non-null element, year must be between [YEAR_MIN](crate::YEAR_MIN)
+/// and [YEAR_MAX](crate::YEAR_MAX), month must be between `1` and `12`, day
+/// must be between `1` and the number of days in the month in question, hours
+/// must be between `0` and `23`, minutes must be between `0` and `59`, seconds
+/// must be between `0` and `59` and nanoseconds must be between `0` and
+/// `999_999_999`. Bounds are checked using `debug_assert` only, so that the
+/// checks are not present in release builds, similar to integer overflow
+/// checks.
+///
+/// # Examples
+///
+/// ```
+/// use arrow_array::types::TimestampMicrosecondType;
+/// use arrow_array::{Int32Array, UInt32Array, UInt8Array};
+/// use datealgo::arrow::datetime_arrays_to_timestamp;
+///
+/// let array = datetime_arrays_to_timestamp::<TimestampMicrosecondType>(
+///     &Int32Array::from(vec![2023]),
+///     &UInt8Array::from(vec![5]),
+///     &UInt8Array::from(vec![20]),
+///     &UInt8Array::from(vec![9]),
+///     &UInt8Array::from(vec![24]),
+///     &UInt8Array::from(vec![38]),
+///     &UInt32Array::from(vec![123_456_789]),
+/// )
+/// .unwrap();
+/// assert_eq!(array.value(0), 1684574678123456);
+/// ```
+///
+/// # Algorithm
+///
+/// [datetime_to_secs_slice] followed by checked multiplication.
+#[allow(clippy::too_many_arguments)]
+#[inline]
+pub fn datetime_arrays_to_timestamp<T: ArrowTimestampType>(
+    years: &Int32Array,
+    months: &UInt8Array,
+    days: &UInt8Array,
+    hours: &UInt8Array,
+    minutes: &UInt8Array,
+    seconds: &UInt8Array,
+    nanos: &UInt32Array,
+) -> Option<PrimitiveArray<T>> {
+    let len = years.len();
+    if [months.len(), days.len(), hours.len(), minutes.len(), seconds.len(), nanos.len()]
+        .iter()
+        .any(|&l| l != len)
+    {
+        return None;
+    }
+    let nulls = NullBuffer::union_many([
+        years.nulls(),
+        months.nulls(),
+        days.nulls(),
+        hours.nulls(),
+        minutes.nulls(),
+        seconds.nulls(),
+        nanos.nulls(),
+    ]);
+    let n = nulls.as_ref();
+    let mut secs = vec![0; len];
+    datetime_to_secs_slice(
+        &values_or(years, n, 1970),
+        &values_or(months, n, 1),
+        &values_or(days, n, 1),
+        &values_or(hours, n, 0),
+        &values_or(minutes, n, 0),
+        &values_or(seconds, n, 0),
+        &mut secs,
+    );
+    let ticks = ticks_in_sec(T::UNIT);
+    let nanos = values_or(nanos, n, 0);
+    debug_assert!(nanos.iter().all(|&n| n < 1_000_000_000), "given nanoseconds is out of range");
+    let mut values = Vec::with_capacity(len);
+    for (&s, &n) in secs.iter().zip(nanos.iter()) {
+        let sub = n as i64 / (1_000_000_000 / ticks);
+        // Borrow a second before the epoch, so that the minimum value does not overflow
+        let (s, sub) = if s < 0 && sub > 0 { (s + 1, sub - ticks) } else { (s, sub) };
+        values.push(s.checked_mul(ticks)?.checked_add(sub)?);
+    }
+    Some(PrimitiveArray::<T>::new(values.into(), nulls))
+}
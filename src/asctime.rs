@@ -0,0 +1,106 @@
+//! Formatting compatible with C `asctime` and `ctime`
+//!
+//! The C functions `asctime` and `ctime` produce a fixed layout such as
+//! `Sat May 20 09:24:38 2023\n`, which still appears in log files, mail
+//! headers and the test suites of C libraries. [format_asctime] produces the
+//! same bytes into a buffer of the size required by `asctime_r`. For `ctime`,
+//! convert the seconds with [secs_to_datetime](crate::secs_to_datetime) and
+//! [rd_to_weekday](crate::rd_to_weekday) first.
+
+use crate::iso::{format_2digits, format_digits};
+use crate::{consts, days_in_month, YEAR_MAX, YEAR_MIN};
+
+/// Size of the buffer for `asctime_r`, including the terminating NUL
+pub const ASCTIME_BUF_LEN: usize = 26;
+
+/// Abbreviated day names, starting from Monday
+const WEEKDAY_NAMES: [&[u8; 3]; 7] = [b"Mon", b"Tue", b"Wed", b"Thu", b"Fri", b"Sat", b"Sun"];
+
+/// Abbreviated month names
+const MONTH_NAMES: [&[u8; 3]; 12] = [
+    b"Jan", b"Feb", b"Mar", b"Apr", b"May", b"Jun", b"Jul", b"Aug", b"Sep", b"Oct", b"Nov", b"Dec",
+];
+
+/// Format date and time in the layout of C `asctime`
+///
+/// Given a `(year, month, day, hours, minutes, seconds)` tuple and the day of
+/// week as in [rd_to_weekday](crate::rd_to_weekday) writes the
+/// `Www Mmm dd hh:mm:ss yyyy\n` layout to the given buffer, followed by a
+/// terminating NUL, and returns the number of bytes written before the NUL.
+/// The day of month is padded with a space and the year is written without
+/// padding, as with the C format `%.3s %.3s%3d %.2d:%.2d:%.2d %d\n`.
+///
+/// # Errors
+///
+/// Returns `0` and leaves the buffer untouched if the year is after `9999` or
+/// before `-999`, as the result would not fit in the buffer. C libraries fail
+/// with `EOVERFLOW` in this case.
+///
+/// # Panics
+///
+/// Year must be between [YEAR_MIN] and [YEAR_MAX]. Month must be between `1`
+/// and `12`. Day must be between `1` and the number of days in the month in
+/// question. Hours must be between `0` and `23`. Minutes must be between `0`
+/// and `59`. Seconds must be between `0` and `59`. Day of week must be
+/// between `1` and `7`. Bounds are checked using `debug_assert` only, so that
+/// the checks are not present in release builds, similar to integer overflow
+/// checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::asctime::{format_asctime, ASCTIME_BUF_LEN};
+///
+/// let mut buf = [0; ASCTIME_BUF_LEN];
+/// let len = format_asctime((2023, 5, 20, 9, 24, 38), 6, &mut buf);
+/// assert_eq!(&buf[..len], b"Sat May 20 09:24:38 2023\n");
+/// let len = format_asctime((1970, 1, 1, 0, 0, 0), 4, &mut buf);
+/// assert_eq!(&buf[..len + 1], b"Thu Jan  1 00:00:00 1970\n\0");
+/// let len = format_asctime((-1, 12, 31, 23, 59, 59), 5, &mut buf);
+/// assert_eq!(&buf[..len], b"Fri Dec 31 23:59:59 -1\n");
+/// assert_eq!(format_asctime((10000, 1, 1, 0, 0, 0), 6, &mut buf), 0);
+/// ```
+///
+/// # Algorithm
+///
+/// Table lookup of the names and simple digit formatting.
+#[inline]
+pub fn format_asctime((y, m, d, hh, mm, ss): (i32, u8, u8, u8, u8, u8), wd: u8, buf: &mut [u8; ASCTIME_BUF_LEN]) -> usize {
+    debug_assert!(y >= YEAR_MIN && y <= YEAR_MAX, "given year is out of range");
+    debug_assert!(m >= consts::MONTH_MIN && m <= consts::MONTH_MAX, "given month is out of range");
+    debug_assert!(d >= consts::DAY_MIN && d <= days_in_month(y, m), "given day is out of range");
+    debug_assert!(hh >= consts::HOUR_MIN && hh <= consts::HOUR_MAX, "given hour is out of range");
+    debug_assert!(mm >= consts::MINUTE_MIN && mm <= consts::MINUTE_MAX, "given minute is out of range");
+    debug_assert!(ss >= consts::SECOND_MIN && ss <= consts::SECOND_MAX, "given second is out of range");
+    debug_assert!(
+        wd >= consts::WEEKDAY_MIN && wd <= consts::WEEKDAY_MAX,
+        "given weekday is out of range"
+    );
+    if !(-999..=9999).contains(&y) {
+        return 0;
+    }
+    buf[..3].copy_from_slice(WEEKDAY_NAMES[(wd.wrapping_sub(1) % 7) as usize]);
+    buf[3] = b' ';
+    buf[4..7].copy_from_slice(MONTH_NAMES[(m.wrapping_sub(1) % 12) as usize]);
+    buf[7] = b' ';
+    format_2digits(buf, 8, d as u32);
+    if d < 10 {
+        buf[8] = b' ';
+    }
+    buf[10] = b' ';
+    format_2digits(buf, 11, hh as u32);
+    buf[13] = b':';
+    format_2digits(buf, 14, mm as u32);
+    buf[16] = b':';
+    format_2digits(buf, 17, ss as u32);
+    buf[19] = b' ';
+    let mut i = 20;
+    if y < 0 {
+        buf[i] = b'-';
+        i += 1;
+    }
+    i = format_digits(buf, i, y.unsigned_abs() as u64);
+    buf[i] = b'\n';
+    buf[i + 1] = 0;
+    i + 1
+}
@@ -0,0 +1,298 @@
+//! Batch conversions over slices
+//!
+//! Analytics workloads often convert millions of values at a time. Calling the
+//! scalar functions in a loop works, but returning tuples per element tends to
+//! block vectorization. The functions in this module operate on whole slices
+//! at a time, in a form that the compiler can autovectorize.
+//!
+//! Dates and times are given as separate column slices, as is customary in
+//! columnar query engines. Conversions from Rata Die can produce output
+//! either as separate column slices (struct of arrays) or as a slice of
+//! tuples (array of structs). The struct of arrays form is usually faster, as
+//! it is directly amenable to vectorization.
+//!
+//! Slice lengths are checked once up front and the loops themselves contain no
+//! per-element bounds checks or branches.
+//!
+//! With the `simd` feature, the bulk of the data is converted with the vector
+//! implementations in the [simd](crate::simd) module where available. The
+//! implementation is selected from the enabled target features and, with the
+//! `std` feature, from the features of the CPU detected at run time, once per
+//! call rather than for each chunk.
+
+use crate::{date_to_rd, datetime_to_secs, rd_to_date, rd_to_weekday, secs_to_dhms};
+
+/// Convert a slice of Rata Die to Gregorian dates in separate columns
+///
+/// Given a slice of days counting from Unix epoch (January 1st, 1970), writes
+/// the year, month and day of each into the corresponding element of `years`,
+/// `months` and `days`.
+///
+/// # Panics
+///
+/// All slices must have the same length, and every element of `ns` must be
+/// between [RD_MIN](crate::RD_MIN) and [RD_MAX](crate::RD_MAX) inclusive.
+/// Bounds are checked using `debug_assert` only, so that the checks are not
+/// present in release builds, similar to integer overflow checks. In release
+/// builds, only as many elements as fit in the shortest slice are converted.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::batch::rd_to_date_slice;
+///
+/// let ns = [0, 19489, -719528];
+/// let mut years = [0; 3];
+/// let mut months = [0; 3];
+/// let mut days = [0; 3];
+/// rd_to_date_slice(&ns, &mut years, &mut months, &mut days);
+/// assert_eq!(years, [1970, 2023, 0]);
+/// assert_eq!(months, [1, 5, 1]);
+/// assert_eq!(days, [1, 12, 1]);
+/// ```
+///
+/// # Algorithm
+///
+/// Applies [rd_to_date] to each element in a branchless loop. With the `simd`
+/// feature, uses vector instructions for chunks of 16 elements on CPUs with
+/// AVX-512F and for chunks of 8 elements on CPUs with AVX2 or NEON.
+#[inline]
+pub fn rd_to_date_slice(ns: &[i32], years: &mut [i32], months: &mut [u8], days: &mut [u8]) {
+    debug_assert!(
+        ns.len() == years.len() && ns.len() == months.len() && ns.len() == days.len(),
+        "given slices differ in length"
+    );
+    #[cfg(feature = "simd")]
+    let start = crate::simd::rd_to_date_chunks(ns, years, months, days);
+    #[cfg(not(feature = "simd"))]
+    let start = 0;
+    let (ns, years, months, days) = (&ns[start..], &mut years[start..], &mut months[start..], &mut days[start..]);
+    for (((&n, y), m), d) in ns.iter().zip(years).zip(months).zip(days) {
+        (*y, *m, *d) = rd_to_date(n);
+    }
+}
+
+/// Convert a slice of Rata Die to Gregorian date tuples
+///
+/// Given a slice of days counting from Unix epoch (January 1st, 1970), writes
+/// a `(year, month, day)` tuple of each into the corresponding element of
+/// `dates`.
+///
+/// # Panics
+///
+/// Both slices must have the same length, and every element of `ns` must be
+/// between [RD_MIN](crate::RD_MIN) and [RD_MAX](crate::RD_MAX) inclusive.
+/// Bounds are checked using `debug_assert` only, so that the checks are not
+/// present in release builds, similar to integer overflow checks. In release
+/// builds, only as many elements as fit in the shorter slice are converted.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::batch::rd_to_date_slice_aos;
+///
+/// let ns = [0, 19489, -719528];
+/// let mut dates = [(0, 0, 0); 3];
+/// rd_to_date_slice_aos(&ns, &mut dates);
+/// assert_eq!(dates, [(1970, 1, 1), (2023, 5, 12), (0, 1, 1)]);
+/// ```
+///
+/// # Algorithm
+///
+/// Applies [rd_to_date] to each element in a branchless loop. With the `simd`
+/// feature, uses vector instructions for chunks of 16 elements on CPUs with
+/// AVX-512F and for chunks of 8 elements on CPUs with AVX2 or NEON.
+#[inline]
+pub fn rd_to_date_slice_aos(ns: &[i32], dates: &mut [(i32, u8, u8)]) {
+    debug_assert!(ns.len() == dates.len(), "given slices differ in length");
+    #[cfg(feature = "simd")]
+    let start = crate::simd::rd_to_date_chunks_aos(ns, dates);
+    #[cfg(not(feature = "simd"))]
+    let start = 0;
+    let (ns, dates) = (&ns[start..], &mut dates[start..]);
+    for (&n, date) in ns.iter().zip(dates) {
+        *date = rd_to_date(n);
+    }
+}
+
+/// Convert Gregorian dates in separate columns to a slice of Rata Die
+///
+/// Given slices of years, months and days, writes the days since Unix epoch
+/// (January 1st, 1970) of each date into the corresponding element of `ns`.
+///
+/// # Panics
+///
+/// All slices must have the same length. Every date must be valid, with year
+/// between [YEAR_MIN](crate::YEAR_MIN) and [YEAR_MAX](crate::YEAR_MAX), month
+/// between `1` and `12` and day between `1` and the number of days in the
+/// month in question. Bounds are checked using `debug_assert` only, so that
+/// the checks are not present in release builds, similar to integer overflow
+/// checks. In release builds, only as many elements as fit in the shortest
+/// slice are converted.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::batch::date_to_rd_slice;
+///
+/// let years = [1970, 2023, 0];
+/// let months = [1, 5, 1];
+/// let days = [1, 12, 1];
+/// let mut ns = [0; 3];
+/// date_to_rd_slice(&years, &months, &days, &mut ns);
+/// assert_eq!(ns, [0, 19489, -719528]);
+/// ```
+///
+/// # Algorithm
+///
+/// Applies [date_to_rd] to each element in a branchless loop.
+#[inline]
+pub fn date_to_rd_slice(years: &[i32], months: &[u8], days: &[u8], ns: &mut [i32]) {
+    debug_assert!(
+        years.len() == ns.len() && months.len() == ns.len() && days.len() == ns.len(),
+        "given slices differ in length"
+    );
+    let len = ns.len().min(years.len()).min(months.len()).min(days.len());
+    let (years, months, days, ns) = (&years[..len], &months[..len], &days[..len], &mut ns[..len]);
+    for i in 0..len {
+        ns[i] = date_to_rd((years[i], months[i], days[i]));
+    }
+}
+
+/// Convert date and time in separate columns to a slice of Unix seconds
+///
+/// Given slices of years, months, days, hours, minutes and seconds, writes the
+/// seconds since Unix epoch (January 1st, 1970) of each date and time into the
+/// corresponding element of `secs`.
+///
+/// # Panics
+///
+/// All slices must have the same length. Every date must be valid, with year
+/// between [YEAR_MIN](crate::YEAR_MIN) and [YEAR_MAX](crate::YEAR_MAX), month
+/// between `1` and `12` and day between `1` and the number of days in the
+/// month in question. Hours must be between `0` and `23`, minutes between `0`
+/// and `59` and seconds between `0` and `59`. Bounds are checked using
+/// `debug_assert` only, so that the checks are not present in release builds,
+/// similar to integer overflow checks. In release builds, only as many
+/// elements as fit in the shortest slice are converted.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::batch::datetime_to_secs_slice;
+///
+/// let years = [1970, 2023];
+/// let months = [1, 5];
+/// let days = [1, 20];
+/// let hours = [0, 9];
+/// let minutes = [0, 24];
+/// let seconds = [0, 38];
+/// let mut secs = [0; 2];
+/// datetime_to_secs_slice(&years, &months, &days, &hours, &minutes, &seconds, &mut secs);
+/// assert_eq!(secs, [0, 1684574678]);
+/// ```
+///
+/// # Algorithm
+///
+/// Applies [datetime_to_secs] to each element in a branchless loop.
+#[inline]
+pub fn datetime_to_secs_slice(years: &[i32], months: &[u8], days: &[u8], hours: &[u8], minutes: &[u8], seconds: &[u8], secs: &mut [i64]) {
+    debug_assert!(
+        years.len() == secs.len()
+            && months.len() == secs.len()
+            && days.len() == secs.len()
+            && hours.len() == secs.len()
+            && minutes.len() == secs.len()
+            && seconds.len() == secs.len(),
+        "given slices differ in length"
+    );
+    let len = secs
+        .len()
+        .min(years.len())
+        .min(months.len())
+        .min(days.len())
+        .min(hours.len())
+        .min(minutes.len())
+        .min(seconds.len());
+    let (years, months, days) = (&years[..len], &months[..len], &days[..len]);
+    let (hours, minutes, seconds) = (&hours[..len], &minutes[..len], &seconds[..len]);
+    let secs = &mut secs[..len];
+    for i in 0..len {
+        secs[i] = datetime_to_secs((years[i], months[i], days[i], hours[i], minutes[i], seconds[i]));
+    }
+}
+
+/// Convert a slice of Rata Die to days of week
+///
+/// Given a slice of days counting from Unix epoch (January 1st, 1970), writes
+/// the day of week of each into the corresponding element of `weekdays`, with
+/// `1` meaning Monday and `7` meaning Sunday.
+///
+/// # Panics
+///
+/// Both slices must have the same length, and every element of `ns` must be
+/// between [RD_MIN](crate::RD_MIN) and [RD_MAX](crate::RD_MAX) inclusive.
+/// Bounds are checked using `debug_assert` only, so that the checks are not
+/// present in release builds, similar to integer overflow checks. In release
+/// builds, only as many elements as fit in the shorter slice are converted.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::batch::rd_to_weekday_slice;
+///
+/// let ns = [0, 19489, 19358];
+/// let mut weekdays = [0; 3];
+/// rd_to_weekday_slice(&ns, &mut weekdays);
+/// assert_eq!(weekdays, [4, 5, 7]);
+/// ```
+///
+/// # Algorithm
+///
+/// Applies [rd_to_weekday] to each element in a branchless loop. The
+/// multiply and shift used by it vectorizes directly.
+#[inline]
+pub fn rd_to_weekday_slice(ns: &[i32], weekdays: &mut [u8]) {
+    debug_assert!(ns.len() == weekdays.len(), "given slices differ in length");
+    for (&n, w) in ns.iter().zip(weekdays) {
+        *w = rd_to_weekday(n);
+    }
+}
+
+/// Convert a slice of Unix seconds to days of week
+///
+/// Given a slice of seconds counting from Unix epoch (January 1st, 1970),
+/// writes the day of week of each into the corresponding element of
+/// `weekdays`, with `1` meaning Monday and `7` meaning Sunday.
+///
+/// # Panics
+///
+/// Both slices must have the same length, and every element of `secs` must be
+/// between [RD_SECONDS_MIN](crate::RD_SECONDS_MIN) and
+/// [RD_SECONDS_MAX](crate::RD_SECONDS_MAX) inclusive. Bounds are checked using
+/// `debug_assert` only, so that the checks are not present in release builds,
+/// similar to integer overflow checks. In release builds, only as many
+/// elements as fit in the shorter slice are converted.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::batch::secs_to_weekday_slice;
+///
+/// let secs = [0, -1, 1684574678];
+/// let mut weekdays = [0; 3];
+/// secs_to_weekday_slice(&secs, &mut weekdays);
+/// assert_eq!(weekdays, [4, 3, 6]);
+/// ```
+///
+/// # Algorithm
+///
+/// Applies [secs_to_dhms] followed by [rd_to_weekday] to each element in a
+/// branchless loop, with the time of day computation optimized out.
+#[inline]
+pub fn secs_to_weekday_slice(secs: &[i64], weekdays: &mut [u8]) {
+    debug_assert!(secs.len() == weekdays.len(), "given slices differ in length");
+    for (&s, w) in secs.iter().zip(weekdays) {
+        *w = rd_to_weekday(secs_to_dhms(s).0);
+    }
+}
@@ -0,0 +1,606 @@
+//! Business days and date adjustment conventions
+//!
+//! A business day is a day that is neither a weekend day nor a holiday.
+//! Weekend days are given as a weekend mask, such as
+//! [WEEKEND_SAT_SUN](crate::week::WEEKEND_SAT_SUN), where bit `wd - 1` is set
+//! when day of week `wd` as in [rd_to_weekday] is a weekend day. Holidays are
+//! given by any type implementing [Holidays], which include sorted slices of
+//! days counting from Unix epoch (January 1st, 1970) and [HolidayCalendar]
+//! bitmaps for constant time lookups.
+
+use crate::{consts, date_to_rd, days_in_month, rd_to_weekday, rd_to_year_month, RD_MAX, RD_MIN, YEAR_MAX, YEAR_MIN};
+
+/// Business day adjustment convention
+///
+/// Determines how a date that is not a business day, such as a payment date
+/// falling on a weekend, is moved to a business day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BusinessDayConvention {
+    /// Date is not adjusted
+    Unadjusted,
+    /// First business day after the date
+    Following,
+    /// First business day after the date, unless it is in the next month, in
+    /// which case the last business day before the date
+    ModifiedFollowing,
+    /// Last business day before the date
+    Preceding,
+    /// Last business day before the date, unless it is in the previous month,
+    /// in which case the first business day after the date
+    ModifiedPreceding,
+}
+
+/// Determine if the given day is a holiday
+///
+/// Given a day counting from Unix epoch (January 1st, 1970) and a sorted slice
+/// of holidays returns `true` if the day is in the slice.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{business::is_holiday, date_to_rd};
+///
+/// let holidays = [date_to_rd((2023, 1, 1)), date_to_rd((2023, 12, 25))];
+/// assert_eq!(is_holiday(date_to_rd((2023, 12, 25)), &holidays), true);
+/// assert_eq!(is_holiday(date_to_rd((2023, 12, 24)), &holidays), false);
+/// ```
+///
+/// # Algorithm
+///
+/// Binary search of the slice.
+#[inline]
+pub const fn is_holiday(rd: i32, holidays: &[i32]) -> bool {
+    let mut lo = 0;
+    let mut hi = holidays.len();
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        if holidays[mid] < rd {
+            lo = mid + 1;
+        } else if holidays[mid] > rd {
+            hi = mid;
+        } else {
+            return true;
+        }
+    }
+    false
+}
+
+/// Set of holidays
+///
+/// Implemented for sorted slices and arrays of days counting from Unix epoch
+/// (January 1st, 1970), which are searched with [is_holiday], and for
+/// [HolidayCalendar].
+pub trait Holidays {
+    /// Determine if the given day is a holiday
+    fn is_holiday(&self, rd: i32) -> bool;
+}
+
+impl Holidays for [i32] {
+    #[inline]
+    fn is_holiday(&self, rd: i32) -> bool {
+        is_holiday(rd, self)
+    }
+}
+
+impl<const N: usize> Holidays for [i32; N] {
+    #[inline]
+    fn is_holiday(&self, rd: i32) -> bool {
+        is_holiday(rd, self)
+    }
+}
+
+impl<const N: usize> Holidays for HolidayCalendar<N> {
+    #[inline]
+    fn is_holiday(&self, rd: i32) -> bool {
+        HolidayCalendar::is_holiday(self, rd)
+    }
+}
+
+/// Holiday calendar as a bitmap over a range of days
+///
+/// Holds one bit for each day of a range of `64 * N` days starting from a
+/// given day, counting from Unix epoch (January 1st, 1970), so that lookups
+/// take constant time. Days outside the range are not holidays. Calendars of
+/// several markets can be combined with [union](HolidayCalendar::union).
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{business::HolidayCalendar, date_to_rd, week::WEEKEND_SAT_SUN};
+///
+/// const START: i32 = date_to_rd((2023, 1, 1));
+/// const US: HolidayCalendar<6> = HolidayCalendar::from_sorted(START, &[date_to_rd((2023, 7, 4)), date_to_rd((2023, 12, 25))]);
+/// const UK: HolidayCalendar<6> = HolidayCalendar::from_sorted(START, &[date_to_rd((2023, 8, 28)), date_to_rd((2023, 12, 25))]);
+/// const BOTH: HolidayCalendar<6> = US.union(&UK);
+///
+/// assert_eq!(BOTH.count(), 3);
+/// assert_eq!(BOTH.is_holiday(date_to_rd((2023, 7, 4))), true);
+/// assert_eq!(BOTH.is_business_day(date_to_rd((2023, 7, 5)), WEEKEND_SAT_SUN), true);
+/// assert_eq!(US.intersection(&UK).count(), 1);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HolidayCalendar<const N: usize> {
+    start: i32,
+    words: [u64; N],
+}
+
+impl<const N: usize> HolidayCalendar<N> {
+    /// Create an empty calendar for the range starting from the given day
+    ///
+    /// # Panics
+    ///
+    /// The range must be between [RD_MIN] and [RD_MAX] inclusive. Bounds are
+    /// checked using `debug_assert` only, so that the checks are not present
+    /// in release builds, similar to integer overflow checks.
+    #[inline]
+    pub const fn new(start: i32) -> Self {
+        debug_assert!(start >= RD_MIN && start <= RD_MAX, "given rata die is out of range");
+        debug_assert!((start as i64 + 64 * N as i64 - 1) <= RD_MAX as i64, "given range is out of range");
+        HolidayCalendar { start, words: [0; N] }
+    }
+
+    /// Create a calendar for the range starting from the given day with the
+    /// given holidays
+    ///
+    /// Holidays outside the range of the calendar are skipped, so a holiday
+    /// list covering more years than the calendar can be given as is.
+    ///
+    /// # Panics
+    ///
+    /// The range must be between [RD_MIN] and [RD_MAX] inclusive. Holidays must
+    /// be sorted. Bounds are checked using `debug_assert` only, so that the
+    /// checks are not present in release builds, similar to integer overflow
+    /// checks.
+    #[inline]
+    pub const fn from_sorted(start: i32, holidays: &[i32]) -> Self {
+        let mut cal = Self::new(start);
+        let mut i = 0;
+        while i < holidays.len() {
+            debug_assert!(i == 0 || holidays[i - 1] <= holidays[i], "given holidays are not sorted");
+            cal = cal.with_holiday(holidays[i]);
+            i += 1;
+        }
+        cal
+    }
+
+    /// Return a copy of the calendar with the given day added as a holiday
+    ///
+    /// Days outside the range of the calendar are skipped, as they are never
+    /// holidays in [is_holiday](HolidayCalendar::is_holiday).
+    #[inline]
+    pub const fn with_holiday(mut self, rd: i32) -> Self {
+        if rd < self.start || rd > self.last() {
+            return self;
+        }
+        let i = (rd - self.start) as usize;
+        self.words[i / 64] |= 1 << (i % 64);
+        self
+    }
+
+    /// First day of the range of the calendar
+    #[inline]
+    pub const fn start(&self) -> i32 {
+        self.start
+    }
+
+    /// Last day of the range of the calendar
+    #[inline]
+    pub const fn last(&self) -> i32 {
+        self.start + (64 * N) as i32 - 1
+    }
+
+    /// Number of holidays in the calendar
+    #[inline]
+    pub const fn count(&self) -> u32 {
+        let mut count = 0;
+        let mut i = 0;
+        while i < N {
+            count += self.words[i].count_ones();
+            i += 1;
+        }
+        count
+    }
+
+    /// Determine if the given day is a holiday
+    ///
+    /// Days outside the range of the calendar are not holidays.
+    #[inline]
+    pub const fn is_holiday(&self, rd: i32) -> bool {
+        if rd < self.start || rd > self.last() {
+            return false;
+        }
+        let i = (rd - self.start) as usize;
+        self.words[i / 64] & (1 << (i % 64)) != 0
+    }
+
+    /// Determine if the given day is a business day
+    ///
+    /// Given a day and a weekend mask returns `true` if the day is neither a
+    /// weekend day nor a holiday, as in [is_business_day].
+    ///
+    /// # Panics
+    ///
+    /// Argument must be between [RD_MIN] and [RD_MAX] inclusive. Weekend mask
+    /// must only have bits 0 to 6 set. Bounds are checked using `debug_assert`
+    /// only, so that the checks are not present in release builds, similar to
+    /// integer overflow checks.
+    #[inline]
+    pub const fn is_business_day(&self, rd: i32, weekend_mask: u8) -> bool {
+        debug_assert!(rd >= RD_MIN && rd <= RD_MAX, "given rata die is out of range");
+        debug_assert!(weekend_mask < 1 << 7, "given weekend mask is out of range");
+        weekend_mask & (1 << (rd_to_weekday(rd) - 1)) == 0 && !self.is_holiday(rd)
+    }
+
+    /// Combine with another calendar, so that holidays in either are holidays
+    ///
+    /// # Panics
+    ///
+    /// Both calendars must start from the same day. Bounds are checked using
+    /// `debug_assert` only, so that the checks are not present in release
+    /// builds, similar to integer overflow checks.
+    #[inline]
+    pub const fn union(&self, other: &Self) -> Self {
+        debug_assert!(self.start == other.start, "given calendars have different ranges");
+        let mut words = self.words;
+        let mut i = 0;
+        while i < N {
+            words[i] |= other.words[i];
+            i += 1;
+        }
+        HolidayCalendar { start: self.start, words }
+    }
+
+    /// Combine with another calendar, so that only holidays in both are holidays
+    ///
+    /// # Panics
+    ///
+    /// Both calendars must start from the same day. Bounds are checked using
+    /// `debug_assert` only, so that the checks are not present in release
+    /// builds, similar to integer overflow checks.
+    #[inline]
+    pub const fn intersection(&self, other: &Self) -> Self {
+        debug_assert!(self.start == other.start, "given calendars have different ranges");
+        let mut words = self.words;
+        let mut i = 0;
+        while i < N {
+            words[i] &= other.words[i];
+            i += 1;
+        }
+        HolidayCalendar { start: self.start, words }
+    }
+
+    /// Remove the holidays of another calendar from this calendar
+    ///
+    /// # Panics
+    ///
+    /// Both calendars must start from the same day. Bounds are checked using
+    /// `debug_assert` only, so that the checks are not present in release
+    /// builds, similar to integer overflow checks.
+    #[inline]
+    pub const fn difference(&self, other: &Self) -> Self {
+        debug_assert!(self.start == other.start, "given calendars have different ranges");
+        let mut words = self.words;
+        let mut i = 0;
+        while i < N {
+            words[i] &= !other.words[i];
+            i += 1;
+        }
+        HolidayCalendar { start: self.start, words }
+    }
+}
+
+/// Determine if the given day is a business day
+///
+/// Given a day counting from Unix epoch (January 1st, 1970), a weekend mask
+/// and a set of holidays returns `true` if the day is neither a weekend day nor
+/// a holiday.
+///
+/// # Panics
+///
+/// Argument must be between [RD_MIN] and [RD_MAX] inclusive. Weekend mask must
+/// only have bits 0 to 6 set. Bounds are checked using `debug_assert` only, so
+/// that the checks are not present in release builds, similar to integer
+/// overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{business::is_business_day, date_to_rd, week::WEEKEND_SAT_SUN};
+///
+/// let holidays = [date_to_rd((2023, 12, 25))];
+/// assert_eq!(is_business_day(date_to_rd((2023, 12, 22)), WEEKEND_SAT_SUN, &holidays), true);
+/// assert_eq!(is_business_day(date_to_rd((2023, 12, 23)), WEEKEND_SAT_SUN, &holidays), false);
+/// assert_eq!(is_business_day(date_to_rd((2023, 12, 25)), WEEKEND_SAT_SUN, &holidays), false);
+/// ```
+///
+/// # Algorithm
+///
+/// Lookup of the day of week in the weekend mask, followed by
+/// [Holidays::is_holiday].
+#[inline]
+pub fn is_business_day<H: Holidays + ?Sized>(rd: i32, weekend_mask: u8, holidays: &H) -> bool {
+    debug_assert!(rd >= RD_MIN && rd <= RD_MAX, "given rata die is out of range");
+    debug_assert!(weekend_mask < 1 << 7, "given weekend mask is out of range");
+    weekend_mask & (1 << (rd_to_weekday(rd) - 1)) == 0 && !holidays.is_holiday(rd)
+}
+
+/// Adjust day to a business day according to a convention
+///
+/// Given a day counting from Unix epoch (January 1st, 1970), a business day
+/// adjustment convention, a weekend mask and a set of holidays returns the
+/// adjusted day. Business days are never adjusted.
+///
+/// # Panics
+///
+/// Argument and the adjusted day must be between [RD_MIN] and [RD_MAX]
+/// inclusive. Weekend mask must only have bits 0 to 6 set, and not all of
+/// them. Bounds are checked using `debug_assert` only, so that the checks are
+/// not present in release builds, similar to integer overflow checks. If all
+/// days of week are weekend days, the day is returned unadjusted in release
+/// builds.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{date_to_rd, week::WEEKEND_SAT_SUN};
+/// use datealgo::business::{adjust_business_day, BusinessDayConvention::*};
+///
+/// let holidays = [date_to_rd((2023, 12, 25)), date_to_rd((2023, 12, 26))];
+/// let rd = date_to_rd((2023, 12, 23));
+/// assert_eq!(adjust_business_day(rd, Following, WEEKEND_SAT_SUN, &holidays), date_to_rd((2023, 12, 27)));
+/// assert_eq!(adjust_business_day(rd, Preceding, WEEKEND_SAT_SUN, &holidays), date_to_rd((2023, 12, 22)));
+/// let rd = date_to_rd((2023, 9, 30));
+/// assert_eq!(adjust_business_day(rd, Following, WEEKEND_SAT_SUN, &holidays), date_to_rd((2023, 10, 2)));
+/// assert_eq!(adjust_business_day(rd, ModifiedFollowing, WEEKEND_SAT_SUN, &holidays), date_to_rd((2023, 9, 29)));
+/// ```
+///
+/// # Algorithm
+///
+/// Stepping a day at a time until a business day is found, and stepping in
+/// the other direction if a modified convention moves to another month.
+#[inline]
+pub fn adjust_business_day<H: Holidays + ?Sized>(rd: i32, convention: BusinessDayConvention, weekend_mask: u8, holidays: &H) -> i32 {
+    debug_assert!(weekend_mask < 0x7f, "given weekend mask is out of range");
+    let (step, modified) = match convention {
+        BusinessDayConvention::Unadjusted => return rd,
+        BusinessDayConvention::Following => (1, false),
+        BusinessDayConvention::ModifiedFollowing => (1, true),
+        BusinessDayConvention::Preceding => (-1, false),
+        BusinessDayConvention::ModifiedPreceding => (-1, true),
+    };
+    let adjusted = step_to_business_day(rd, step, weekend_mask, holidays);
+    if modified && !same_month(adjusted, rd) {
+        step_to_business_day(rd, -step, weekend_mask, holidays)
+    } else {
+        adjusted
+    }
+}
+
+/// Determine the nth business day of a month
+///
+/// Given a year, a month, a number `n` starting from `1`, a weekend mask and a
+/// set of holidays returns the nth business day of the month, counting from
+/// Unix epoch (January 1st, 1970).
+///
+/// # Errors
+///
+/// Returns `None` if the month has fewer than `n` business days.
+///
+/// # Panics
+///
+/// Year must be between [YEAR_MIN] and [YEAR_MAX]. Month must be between `1`
+/// and `12`. Number must be at least `1`. Weekend mask must only have bits 0 to
+/// 6 set. Bounds are checked using `debug_assert` only, so that the checks are
+/// not present in release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{business::nth_business_day_of_month, date_to_rd, week::WEEKEND_SAT_SUN};
+///
+/// let holidays = [date_to_rd((2024, 1, 1))];
+/// assert_eq!(nth_business_day_of_month(2024, 1, 1, WEEKEND_SAT_SUN, &holidays), Some(date_to_rd((2024, 1, 2))));
+/// assert_eq!(nth_business_day_of_month(2024, 1, 5, WEEKEND_SAT_SUN, &holidays), Some(date_to_rd((2024, 1, 8))));
+/// assert_eq!(nth_business_day_of_month(2024, 1, 23, WEEKEND_SAT_SUN, &holidays), None);
+/// ```
+///
+/// # Algorithm
+///
+/// Stepping a day at a time from the first day of the month.
+#[inline]
+pub fn nth_business_day_of_month<H: Holidays + ?Sized>(y: i32, m: u8, n: u8, weekend_mask: u8, holidays: &H) -> Option<i32> {
+    debug_assert!(y >= YEAR_MIN && y <= YEAR_MAX, "given year is out of range");
+    debug_assert!(m >= consts::MONTH_MIN && m <= consts::MONTH_MAX, "given month is out of range");
+    debug_assert!(n >= 1, "given number is out of range");
+    let first = date_to_rd((y, m, 1));
+    let mut count = 0;
+    let mut rd = first;
+    while rd < first + days_in_month(y, m) as i32 {
+        if is_business_day(rd, weekend_mask, holidays) {
+            count += 1;
+            if count == n {
+                return Some(rd);
+            }
+        }
+        rd += 1;
+    }
+    None
+}
+
+/// Determine the last business day of a month
+///
+/// Given a year, a month, a weekend mask and a set of holidays returns the
+/// last business day of the month, counting from Unix epoch (January 1st,
+/// 1970).
+///
+/// # Errors
+///
+/// Returns `None` if the month has no business days.
+///
+/// # Panics
+///
+/// Year must be between [YEAR_MIN] and [YEAR_MAX]. Month must be between `1`
+/// and `12`. Weekend mask must only have bits 0 to 6 set. Bounds are checked
+/// using `debug_assert` only, so that the checks are not present in release
+/// builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{business::last_business_day_of_month, date_to_rd, week::WEEKEND_SAT_SUN};
+///
+/// let holidays = [date_to_rd((2024, 3, 29))];
+/// assert_eq!(last_business_day_of_month(2024, 3, WEEKEND_SAT_SUN, &holidays), Some(date_to_rd((2024, 3, 28))));
+/// assert_eq!(last_business_day_of_month(2024, 4, WEEKEND_SAT_SUN, &holidays), Some(date_to_rd((2024, 4, 30))));
+/// ```
+///
+/// # Algorithm
+///
+/// Stepping a day at a time from the last day of the month.
+#[inline]
+pub fn last_business_day_of_month<H: Holidays + ?Sized>(y: i32, m: u8, weekend_mask: u8, holidays: &H) -> Option<i32> {
+    debug_assert!(y >= YEAR_MIN && y <= YEAR_MAX, "given year is out of range");
+    debug_assert!(m >= consts::MONTH_MIN && m <= consts::MONTH_MAX, "given month is out of range");
+    let first = date_to_rd((y, m, 1));
+    let mut rd = first + days_in_month(y, m) as i32 - 1;
+    while rd >= first {
+        if is_business_day(rd, weekend_mask, holidays) {
+            return Some(rd);
+        }
+        rd -= 1;
+    }
+    None
+}
+
+/// Determine the settlement date of a trade
+///
+/// Given a trade day counting from Unix epoch (January 1st, 1970), a number of
+/// business days `n`, a weekend mask and a set of holidays returns the day
+/// `n` business days after the trade day, as in `T+2` settlement. For `T+0`
+/// the trade day is returned if it is a business day, and otherwise the next
+/// business day.
+///
+/// # Panics
+///
+/// Argument and the result must be between [RD_MIN] and [RD_MAX] inclusive.
+/// Weekend mask must only have bits 0 to 6 set, and not all of them. Bounds
+/// are checked using `debug_assert` only, so that the checks are not present
+/// in release builds, similar to integer overflow checks. If all days of week
+/// are weekend days, the result is `n` days after the trade day in release
+/// builds.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{business::settlement_date, date_to_rd, week::WEEKEND_SAT_SUN};
+///
+/// let holidays = [date_to_rd((2023, 12, 25)), date_to_rd((2023, 12, 26))];
+/// assert_eq!(settlement_date(date_to_rd((2023, 12, 20)), 2, WEEKEND_SAT_SUN, &holidays), date_to_rd((2023, 12, 22)));
+/// assert_eq!(settlement_date(date_to_rd((2023, 12, 21)), 2, WEEKEND_SAT_SUN, &holidays), date_to_rd((2023, 12, 27)));
+/// assert_eq!(settlement_date(date_to_rd((2023, 12, 23)), 0, WEEKEND_SAT_SUN, &holidays), date_to_rd((2023, 12, 27)));
+/// ```
+///
+/// # Algorithm
+///
+/// Stepping a day at a time, counting the business days.
+#[inline]
+pub fn settlement_date<H: Holidays + ?Sized>(trade_rd: i32, n: u8, weekend_mask: u8, holidays: &H) -> i32 {
+    debug_assert!(weekend_mask < 0x7f, "given weekend mask is out of range");
+    let mut rd = trade_rd;
+    let mut i = 0;
+    while i < n {
+        rd = step_to_business_day(rd + 1, 1, weekend_mask, holidays);
+        i += 1;
+    }
+    step_to_business_day(rd, 1, weekend_mask, holidays)
+}
+
+/// Maximum number of days searched for a common business day of two calendars
+const JOINT_SEARCH_DAYS: i32 = 366;
+
+/// Determine the settlement date of a trade across two calendars
+///
+/// Same as [settlement_date], counting the business days in the first
+/// calendar, but additionally requiring the settlement date to be a business
+/// day in the second calendar, as for cross-currency trades. If the day found
+/// is not a business day in the second calendar, the next day that is a
+/// business day in both calendars is returned.
+///
+/// # Errors
+///
+/// Returns `None` if the calendars have no common business day, either
+/// because the weekend masks together cover every day of week or because no
+/// common business day is found within a year of the settlement date in the
+/// first calendar.
+///
+/// # Panics
+///
+/// Argument and the result must be between [RD_MIN] and [RD_MAX] inclusive.
+/// Weekend masks must only have bits 0 to 6 set, and not all of them. Bounds
+/// are checked using `debug_assert` only, so that the checks are not present
+/// in release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{business::settlement_date_joint, date_to_rd, week::{WEEKEND_FRI_SAT, WEEKEND_SAT_SUN}};
+///
+/// let holidays = [date_to_rd((2023, 12, 25))];
+/// let other: [i32; 0] = [];
+/// let rd = date_to_rd((2023, 12, 19));
+/// assert_eq!(settlement_date_joint(rd, 2, WEEKEND_SAT_SUN, &holidays, WEEKEND_SAT_SUN, &other), Some(date_to_rd((2023, 12, 21))));
+/// assert_eq!(settlement_date_joint(rd, 3, WEEKEND_SAT_SUN, &holidays, WEEKEND_FRI_SAT, &other), Some(date_to_rd((2023, 12, 26))));
+/// assert_eq!(settlement_date_joint(rd, 2, WEEKEND_SAT_SUN, &holidays, 0x1f, &other), None);
+/// ```
+///
+/// # Algorithm
+///
+/// Result of [settlement_date] followed by stepping a day at a time until a
+/// business day in both calendars is found, for at most a year.
+#[inline]
+pub fn settlement_date_joint<H: Holidays + ?Sized, J: Holidays + ?Sized>(
+    trade_rd: i32,
+    n: u8,
+    weekend_mask: u8,
+    holidays: &H,
+    other_weekend_mask: u8,
+    other_holidays: &J,
+) -> Option<i32> {
+    debug_assert!(other_weekend_mask < 0x7f, "given weekend mask is out of range");
+    if (weekend_mask | other_weekend_mask) & 0x7f == 0x7f {
+        return None;
+    }
+    let start = settlement_date(trade_rd, n, weekend_mask, holidays);
+    let end = start.saturating_add(JOINT_SEARCH_DAYS).min(RD_MAX);
+    let mut rd = start;
+    while rd <= end {
+        if is_business_day(rd, other_weekend_mask, other_holidays) && is_business_day(rd, weekend_mask, holidays) {
+            return Some(rd);
+        }
+        rd += 1;
+    }
+    None
+}
+
+/// First business day at or after the day in the given direction
+///
+/// Returns the day itself if every day of week is a weekend day, so that the
+/// loop always terminates.
+#[inline]
+fn step_to_business_day<H: Holidays + ?Sized>(mut rd: i32, step: i32, weekend_mask: u8, holidays: &H) -> i32 {
+    if weekend_mask & 0x7f == 0x7f {
+        return rd;
+    }
+    while !is_business_day(rd, weekend_mask, holidays) {
+        rd += step;
+    }
+    rd
+}
+
+/// Determine if the given days are in the same month
+#[inline]
+const fn same_month(a: i32, b: i32) -> bool {
+    let (ya, ma) = rd_to_year_month(a);
+    let (yb, mb) = rd_to_year_month(b);
+    ya == yb && ma == mb
+}
@@ -0,0 +1,172 @@
+//! French Republican calendar
+//!
+//! The French Republican calendar has twelve months of 30 days, followed by
+//! five or six complementary days, which are represented here as month `13`.
+//! Years are counted from the proclamation of the Republic, with 1 Vendémiaire
+//! of year I being September 22nd, 1792.
+//!
+//! Historically years started on the day of the autumnal equinox, making years
+//! III, VII and XI leap years, and XV the next one had the calendar remained
+//! in use. These leap years are used for years I to XVI. From year XVII onwards
+//! the continuation rule proposed by Gilbert Romme is used: years divisible by
+//! four are leap years, except for years divisible by 100 but not 400, and
+//! years divisible by 4000. Years before year I also use the Romme rule.
+
+use crate::{consts, RD_MAX, RD_MIN};
+
+/// Rata Die of 1 Vendémiaire I, September 22nd, 1792
+pub const FRENCH_EPOCH: i32 = -64748;
+
+/// Number of days before the given year, counting from the epoch
+#[inline]
+const fn days_before_year(y: i64) -> i64 {
+    let leaps = if y >= 1 && y <= 16 {
+        y / 4
+    } else {
+        let n = y - 1;
+        n.div_euclid(4) - n.div_euclid(100) + n.div_euclid(400) - n.div_euclid(4000)
+    };
+    365 * (y - 1) + leaps
+}
+
+/// Convert Rata Die to French Republican date
+///
+/// Given a day counting from Unix epoch (January 1st, 1970) returns a `(year,
+/// month, day)` tuple in the French Republican calendar. The complementary
+/// days at the end of the year are returned as month `13`.
+///
+/// # Panics
+///
+/// Argument must be between [RD_MIN] and [RD_MAX] inclusive. Bounds are checked
+/// using `debug_assert` only, so that the checks are not present in release
+/// builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{date_to_rd, calendar::french::rd_to_french_date};
+///
+/// assert_eq!(rd_to_french_date(date_to_rd((1792, 9, 22))), (1, 1, 1));
+/// assert_eq!(rd_to_french_date(date_to_rd((1794, 7, 27))), (2, 11, 9));
+/// assert_eq!(rd_to_french_date(date_to_rd((1799, 11, 9))), (8, 2, 18));
+/// assert_eq!(rd_to_french_date(date_to_rd((1795, 9, 22))), (3, 13, 6));
+/// ```
+///
+/// # Algorithm
+///
+/// Estimation of the year using the mean year length, corrected by at most a
+/// couple of steps, followed by simple division into 30 day months.
+#[inline]
+pub const fn rd_to_french_date(n: i32) -> (i32, u8, u8) {
+    debug_assert!(n >= RD_MIN && n <= RD_MAX, "given rata die is out of range");
+    let n = n as i64 - FRENCH_EPOCH as i64;
+    let mut y = (n * 10000).div_euclid(3652425) + 1;
+    while days_before_year(y + 1) <= n {
+        y += 1;
+    }
+    while days_before_year(y) > n {
+        y -= 1;
+    }
+    let r = n - days_before_year(y);
+    (y as i32, (r / 30 + 1) as u8, (r % 30 + 1) as u8)
+}
+
+/// Convert French Republican date to Rata Die
+///
+/// Given a `(year, month, day)` tuple in the French Republican calendar
+/// returns the days since Unix epoch (January 1st, 1970). The complementary
+/// days at the end of the year are given as month `13`.
+///
+/// # Panics
+///
+/// Month must be between `1` and `13`. Day must be between `1` and the number
+/// of days in the month in question. The result must be between [RD_MIN] and
+/// [RD_MAX] inclusive. Bounds are checked using `debug_assert` only, so that
+/// the checks are not present in release builds, similar to integer overflow
+/// checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{date_to_rd, calendar::french::french_date_to_rd};
+///
+/// assert_eq!(french_date_to_rd((1, 1, 1)), date_to_rd((1792, 9, 22)));
+/// assert_eq!(french_date_to_rd((2, 11, 9)), date_to_rd((1794, 7, 27)));
+/// assert_eq!(french_date_to_rd((14, 4, 11)), date_to_rd((1806, 1, 1)));
+/// assert_eq!(french_date_to_rd((3, 13, 6)), date_to_rd((1795, 9, 22)));
+/// ```
+///
+/// # Algorithm
+///
+/// Simple count of days in preceding years, months and days.
+#[inline]
+pub const fn french_date_to_rd((y, m, d): (i32, u8, u8)) -> i32 {
+    debug_assert!(m >= consts::MONTH_MIN && m <= 13, "given month is out of range");
+    debug_assert!(d >= consts::DAY_MIN && d <= french_days_in_month(y, m), "given day is out of range");
+    let n = days_before_year(y as i64) + 30 * (m as i64 - 1) + d as i64 - 1 + FRENCH_EPOCH as i64;
+    debug_assert!(n >= RD_MIN as i64 && n <= RD_MAX as i64, "given date is out of range");
+    n as i32
+}
+
+/// Determine if the given year is a leap year in the French Republican
+/// calendar
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::calendar::french::is_french_leap_year;
+///
+/// assert_eq!(is_french_leap_year(3), true);
+/// assert_eq!(is_french_leap_year(4), false);
+/// assert_eq!(is_french_leap_year(20), true);
+/// assert_eq!(is_french_leap_year(100), false);
+/// ```
+///
+/// # Algorithm
+///
+/// Historical leap years for years I to XVI, the Romme rule otherwise.
+#[inline]
+pub const fn is_french_leap_year(y: i32) -> bool {
+    if y >= 1 && y <= 16 {
+        y % 4 == 3
+    } else {
+        let y = y.rem_euclid(4000);
+        y % 4 == 0 && (y % 100 != 0 || y % 400 == 0) && y != 0
+    }
+}
+
+/// Determine the number of days in the given month in the given French
+/// Republican year
+///
+/// The complementary days at the end of the year are given as month `13`.
+///
+/// # Panics
+///
+/// Month must be between `1` and `13`. Bounds are checked using `debug_assert`
+/// only, so that the checks are not present in release builds, similar to
+/// integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::calendar::french::french_days_in_month;
+///
+/// assert_eq!(french_days_in_month(2, 1), 30);
+/// assert_eq!(french_days_in_month(2, 13), 5);
+/// assert_eq!(french_days_in_month(3, 13), 6);
+/// ```
+///
+/// # Algorithm
+///
+/// Fixed month lengths, except for the complementary days in leap years.
+#[inline]
+pub const fn french_days_in_month(y: i32, m: u8) -> u8 {
+    debug_assert!(m >= consts::MONTH_MIN && m <= 13, "given month is out of range");
+    if m <= 12 {
+        30
+    } else if is_french_leap_year(y) {
+        6
+    } else {
+        5
+    }
+}
@@ -0,0 +1,210 @@
+//! Tabular Islamic calendar
+//!
+//! The tabular Islamic calendar is an arithmetic approximation of the lunar
+//! Islamic calendar. Years have twelve months alternating between 30 and 29
+//! days, and 11 leap years in every 30 year cycle add a 30th day to the last
+//! month. Several leap year patterns and two epochs are in use, so both are
+//! given explicitly as arguments. Observational calendars, such as Umm al-Qura,
+//! differ from these and need separate adjustment.
+//!
+//! Years are numbered from 1 AH, with years before that continuing
+//! proleptically to 0 and negative years.
+
+use crate::{consts, RD_MAX, RD_MIN};
+
+/// Leap years 2, 5, 7, 10, 13, 15, 18, 21, 24, 26 and 29 of the 30 year cycle
+///
+/// Leap year pattern attributed to Kūshyār ibn Labbān. Bit `n - 1` is set when
+/// year `n` of the cycle is a leap year.
+pub const ISLAMIC_LEAP_PATTERN_I: u32 = 0x12925252;
+
+/// Leap years 2, 5, 7, 10, 13, 16, 18, 21, 24, 26 and 29 of the 30 year cycle
+///
+/// The most common leap year pattern, used for example by the "Kuwaiti
+/// algorithm". Bit `n - 1` is set when year `n` of the cycle is a leap year.
+pub const ISLAMIC_LEAP_PATTERN_II: u32 = 0x12929252;
+
+/// Leap years 2, 5, 8, 10, 13, 16, 19, 21, 24, 27 and 29 of the 30 year cycle
+///
+/// Leap year pattern used by the Fatimid and Bohra communities. Bit `n - 1` is
+/// set when year `n` of the cycle is a leap year.
+pub const ISLAMIC_LEAP_PATTERN_III: u32 = 0x14949292;
+
+/// Leap years 2, 5, 8, 11, 13, 16, 19, 21, 24, 27 and 30 of the 30 year cycle
+///
+/// Leap year pattern attributed to Habash al-Hasib. Bit `n - 1` is set when
+/// year `n` of the cycle is a leap year.
+pub const ISLAMIC_LEAP_PATTERN_IV: u32 = 0x24949492;
+
+/// Rata Die of the civil epoch, Friday July 16th, 622 (Julian)
+pub const ISLAMIC_EPOCH_CIVIL: i32 = -492148;
+
+/// Rata Die of the astronomical epoch, Thursday July 15th, 622 (Julian)
+pub const ISLAMIC_EPOCH_ASTRONOMICAL: i32 = -492149;
+
+/// Number of leap years before the given year of the 30 year cycle
+#[inline]
+const fn leaps_before(k: u32, pattern: u32) -> u32 {
+    (pattern & ((1 << k) - 1)).count_ones()
+}
+
+/// Convert Rata Die to tabular Islamic date
+///
+/// Given a day counting from Unix epoch (January 1st, 1970) returns a `(year,
+/// month, day)` tuple in the tabular Islamic calendar using the given leap
+/// year pattern, such as [ISLAMIC_LEAP_PATTERN_II], and epoch, either
+/// [ISLAMIC_EPOCH_CIVIL] or [ISLAMIC_EPOCH_ASTRONOMICAL].
+///
+/// # Panics
+///
+/// Argument and epoch must be between [RD_MIN] and [RD_MAX] inclusive. Leap
+/// year pattern must only have bits 0 to 29 set. Bounds are checked using
+/// `debug_assert` only, so that the checks are not present in release builds,
+/// similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::calendar::islamic::{rd_to_islamic_tabular, ISLAMIC_EPOCH_CIVIL, ISLAMIC_LEAP_PATTERN_II};
+///
+/// assert_eq!(rd_to_islamic_tabular(19557, ISLAMIC_LEAP_PATTERN_II, ISLAMIC_EPOCH_CIVIL), (1445, 1, 1));
+/// assert_eq!(rd_to_islamic_tabular(0, ISLAMIC_LEAP_PATTERN_II, ISLAMIC_EPOCH_CIVIL), (1389, 10, 22));
+/// ```
+///
+/// # Algorithm
+///
+/// Simple computation of 30 year cycles, with the year within the cycle
+/// determined from the leap year pattern.
+#[inline]
+pub const fn rd_to_islamic_tabular(n: i32, pattern: u32, epoch: i32) -> (i32, u8, u8) {
+    debug_assert!(n >= RD_MIN && n <= RD_MAX, "given rata die is out of range");
+    debug_assert!(epoch >= RD_MIN && epoch <= RD_MAX, "given epoch is out of range");
+    debug_assert!(pattern < 1 << 30, "given leap year pattern is out of range");
+    let cycle = 354 * 30 + pattern.count_ones() as i64;
+    let n = n as i64 - epoch as i64;
+    let c = n.div_euclid(cycle);
+    let r = n.rem_euclid(cycle) as u32;
+    // year within cycle
+    let mut k = r / 355;
+    while k < 29 && 354 * (k + 1) + leaps_before(k + 1, pattern) <= r {
+        k += 1;
+    }
+    let r = r - (354 * k + leaps_before(k, pattern));
+    // month and day
+    let m = 2 * r / 59 + 1;
+    let m = if m > 12 { 12 } else { m };
+    let d = r - (29 * (m - 1) + m / 2) + 1;
+    let y = c * 30 + k as i64 + 1;
+    (y as i32, m as u8, d as u8)
+}
+
+/// Convert tabular Islamic date to Rata Die
+///
+/// Given a `(year, month, day)` tuple in the tabular Islamic calendar using
+/// the given leap year pattern, such as [ISLAMIC_LEAP_PATTERN_II], and epoch,
+/// either [ISLAMIC_EPOCH_CIVIL] or [ISLAMIC_EPOCH_ASTRONOMICAL], returns the
+/// days since Unix epoch (January 1st, 1970).
+///
+/// # Panics
+///
+/// Month must be between `1` and `12`. Day must be between `1` and the number
+/// of days in the month in question. Leap year pattern must only have bits 0
+/// to 29 set. The epoch and the result must be between [RD_MIN] and [RD_MAX]
+/// inclusive. Bounds are checked using `debug_assert` only, so that the checks
+/// are not present in release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::calendar::islamic::{islamic_tabular_to_rd, ISLAMIC_EPOCH_CIVIL, ISLAMIC_LEAP_PATTERN_II};
+///
+/// assert_eq!(islamic_tabular_to_rd((1445, 1, 1), ISLAMIC_LEAP_PATTERN_II, ISLAMIC_EPOCH_CIVIL), 19557);
+/// assert_eq!(islamic_tabular_to_rd((1389, 10, 22), ISLAMIC_LEAP_PATTERN_II, ISLAMIC_EPOCH_CIVIL), 0);
+/// ```
+///
+/// # Algorithm
+///
+/// Simple computation of 30 year cycles, with the leap years within the cycle
+/// counted from the leap year pattern.
+#[inline]
+pub const fn islamic_tabular_to_rd((y, m, d): (i32, u8, u8), pattern: u32, epoch: i32) -> i32 {
+    debug_assert!(m >= consts::MONTH_MIN && m <= consts::MONTH_MAX, "given month is out of range");
+    debug_assert!(
+        d >= consts::DAY_MIN && d <= islamic_tabular_days_in_month(y, m, pattern),
+        "given day is out of range"
+    );
+    debug_assert!(epoch >= RD_MIN && epoch <= RD_MAX, "given epoch is out of range");
+    debug_assert!(pattern < 1 << 30, "given leap year pattern is out of range");
+    let cycle = 354 * 30 + pattern.count_ones() as i64;
+    let y = y as i64 - 1;
+    let c = y.div_euclid(30);
+    let k = y.rem_euclid(30) as u32;
+    let m = m as u32;
+    let r = 354 * k + leaps_before(k, pattern) + 29 * (m - 1) + m / 2 + d as u32 - 1;
+    let n = c * cycle + r as i64 + epoch as i64;
+    debug_assert!(n >= RD_MIN as i64 && n <= RD_MAX as i64, "given date is out of range");
+    n as i32
+}
+
+/// Determine if the given year is a leap year in the tabular Islamic calendar
+///
+/// # Panics
+///
+/// Leap year pattern must only have bits 0 to 29 set. Bounds are checked using
+/// `debug_assert` only, so that the checks are not present in release builds,
+/// similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::calendar::islamic::{is_islamic_tabular_leap_year, ISLAMIC_LEAP_PATTERN_I, ISLAMIC_LEAP_PATTERN_II};
+///
+/// assert_eq!(is_islamic_tabular_leap_year(1445, ISLAMIC_LEAP_PATTERN_II), true);
+/// assert_eq!(is_islamic_tabular_leap_year(1446, ISLAMIC_LEAP_PATTERN_II), false);
+/// assert_eq!(is_islamic_tabular_leap_year(1455, ISLAMIC_LEAP_PATTERN_I), true);
+/// assert_eq!(is_islamic_tabular_leap_year(1455, ISLAMIC_LEAP_PATTERN_II), false);
+/// ```
+///
+/// # Algorithm
+///
+/// Lookup of the year within the 30 year cycle in the leap year pattern.
+#[inline]
+pub const fn is_islamic_tabular_leap_year(y: i32, pattern: u32) -> bool {
+    debug_assert!(pattern < 1 << 30, "given leap year pattern is out of range");
+    let k = (y as i64 - 1).rem_euclid(30) as u32;
+    pattern & (1 << k) != 0
+}
+
+/// Determine the number of days in the given month in the given tabular
+/// Islamic year
+///
+/// # Panics
+///
+/// Month must be between `1` and `12`. Leap year pattern must only have bits 0
+/// to 29 set. Bounds are checked using `debug_assert` only, so that the checks
+/// are not present in release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::calendar::islamic::{islamic_tabular_days_in_month, ISLAMIC_LEAP_PATTERN_II};
+///
+/// assert_eq!(islamic_tabular_days_in_month(1445, 1, ISLAMIC_LEAP_PATTERN_II), 30);
+/// assert_eq!(islamic_tabular_days_in_month(1445, 2, ISLAMIC_LEAP_PATTERN_II), 29);
+/// assert_eq!(islamic_tabular_days_in_month(1445, 12, ISLAMIC_LEAP_PATTERN_II), 30);
+/// assert_eq!(islamic_tabular_days_in_month(1446, 12, ISLAMIC_LEAP_PATTERN_II), 29);
+/// ```
+///
+/// # Algorithm
+///
+/// Odd months have 30 days and even months 29 days, except for the last month
+/// in leap years.
+#[inline]
+pub const fn islamic_tabular_days_in_month(y: i32, m: u8, pattern: u32) -> u8 {
+    debug_assert!(m >= consts::MONTH_MIN && m <= consts::MONTH_MAX, "given month is out of range");
+    if m == 12 && is_islamic_tabular_leap_year(y, pattern) {
+        30
+    } else {
+        29 + (m & 1)
+    }
+}
@@ -0,0 +1,284 @@
+//! Julian calendar and hybrid Julian/Gregorian calendar
+//!
+//! The Julian calendar has a leap year every four years without exception.
+//! Years use astronomical year numbering, like the rest of the crate. Dates
+//! are proleptic, so the calendar is extended backwards and forwards
+//! indefinitely.
+
+use crate::{consts, date_to_rd, days_in_month, rd_to_date, RD_MAX, RD_MIN, YEAR_MAX, YEAR_MIN};
+
+/// Adjustment from Unix epoch to internal epoch, March 1st, -1500000 Julian
+const DAY_OFFSET: i32 = 548594470;
+
+/// Adjustment from astronomical years to internal years
+const YEAR_OFFSET: i32 = 1500000;
+
+/// Minimum supported Julian year for conversion
+///
+/// This is the Julian year of [RD_MIN], so only part of the year falls within
+/// the supported range of Rata Die.
+pub const JULIAN_YEAR_MIN: i32 = -1467969;
+
+/// Maximum supported Julian year for conversion
+///
+/// This is the Julian year of [RD_MAX], so only part of the year falls within
+/// the supported range of Rata Die.
+pub const JULIAN_YEAR_MAX: i32 = 1471714;
+
+/// Rata Die of the first day of the Gregorian calendar as originally adopted
+///
+/// Thursday, October 4th, 1582 (Julian) was followed by Friday, October 15th,
+/// 1582 (Gregorian).
+pub const GREGORIAN_REFORM_RD: i32 = -141427;
+
+/// Rata Die of the first day of the Gregorian calendar in Great Britain
+///
+/// Wednesday, September 2nd, 1752 (Julian) was followed by Thursday,
+/// September 14th, 1752 (Gregorian).
+pub const BRITISH_REFORM_RD: i32 = -79366;
+
+/// Convert Rata Die to Julian date
+///
+/// Given a day counting from Unix epoch (January 1st, 1970) returns a `(year,
+/// month, day)` tuple in the proleptic Julian calendar.
+///
+/// # Panics
+///
+/// Argument must be between [RD_MIN] and [RD_MAX] inclusive. Bounds are checked
+/// using `debug_assert` only, so that the checks are not present in release
+/// builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::calendar::julian::rd_to_julian_date;
+///
+/// assert_eq!(rd_to_julian_date(0), (1969, 12, 19));
+/// assert_eq!(rd_to_julian_date(-141428), (1582, 10, 4));
+/// assert_eq!(rd_to_julian_date(19497), (2023, 5, 7));
+/// ```
+///
+/// # Algorithm
+///
+/// Simple four year cycle computation, using the Neri-Schneider Euclidean
+/// affine function for month and day.
+#[inline]
+pub const fn rd_to_julian_date(n: i32) -> (i32, u8, u8) {
+    debug_assert!(n >= RD_MIN && n <= RD_MAX, "given rata die is out of range");
+    let n = (n + DAY_OFFSET) as u32;
+    // year
+    let y = ((4 * n as u64 + 3) / 1461) as u32;
+    let n = n - (365 * y + y / 4);
+    let j = n >= 306;
+    let y = y + j as u32;
+    // month and day
+    let n = 2141 * n + 197913;
+    let m = n / 2u32.pow(16);
+    let d = n % 2u32.pow(16) / 2141;
+    // map
+    let y = (y as i32) - YEAR_OFFSET;
+    let m = if j { m - 12 } else { m };
+    let d = d + 1;
+    (y, m as u8, d as u8)
+}
+
+/// Convert Julian date to Rata Die
+///
+/// Given a `(year, month, day)` tuple in the proleptic Julian calendar returns
+/// the days since Unix epoch (January 1st, 1970).
+///
+/// # Panics
+///
+/// Year must be between [JULIAN_YEAR_MIN] and [JULIAN_YEAR_MAX]. Month must be
+/// between `1` and `12`. Day must be between `1` and the number of days in the
+/// month in question. Bounds are checked using `debug_assert` only, so that
+/// the checks are not present in release builds, similar to integer overflow
+/// checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::calendar::julian::julian_date_to_rd;
+///
+/// assert_eq!(julian_date_to_rd((1969, 12, 19)), 0);
+/// assert_eq!(julian_date_to_rd((1582, 10, 4)), -141428);
+/// assert_eq!(julian_date_to_rd((2023, 5, 7)), 19497);
+/// ```
+///
+/// # Algorithm
+///
+/// Simple four year cycle computation, using the Neri-Schneider Euclidean
+/// affine function for month.
+#[inline]
+pub const fn julian_date_to_rd((y, m, d): (i32, u8, u8)) -> i32 {
+    debug_assert!(y >= JULIAN_YEAR_MIN && y <= JULIAN_YEAR_MAX, "given year is out of range");
+    debug_assert!(m >= consts::MONTH_MIN && m <= consts::MONTH_MAX, "given month is out of range");
+    debug_assert!(d >= consts::DAY_MIN && d <= julian_days_in_month(y, m), "given day is out of range");
+    let jf = (m < 3) as u32;
+    let y = (y + YEAR_OFFSET) as u32 - jf;
+    let m = m as u32 + 12 * jf;
+    let n = 365 * y + y / 4 + (979 * m - 2919) / 32 + d as u32 - 1;
+    (n as i32) - DAY_OFFSET
+}
+
+/// Determine if the given year is a leap year in the Julian calendar
+///
+/// # Panics
+///
+/// Year must be between [JULIAN_YEAR_MIN] and [JULIAN_YEAR_MAX] inclusive.
+/// Bounds are checked using `debug_assert` only, so that the checks are not
+/// present in release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::calendar::julian::is_julian_leap_year;
+///
+/// assert_eq!(is_julian_leap_year(2023), false);
+/// assert_eq!(is_julian_leap_year(2024), true);
+/// assert_eq!(is_julian_leap_year(1900), true);
+/// assert_eq!(is_julian_leap_year(-1), false);
+/// assert_eq!(is_julian_leap_year(-4), true);
+/// ```
+///
+/// # Algorithm
+///
+/// Every year divisible by four is a leap year.
+#[inline]
+pub const fn is_julian_leap_year(y: i32) -> bool {
+    debug_assert!(y >= JULIAN_YEAR_MIN && y <= JULIAN_YEAR_MAX, "given year is out of range");
+    y % 4 == 0
+}
+
+/// Determine the number of days in the given month in the given Julian year
+///
+/// # Panics
+///
+/// Year must be between [JULIAN_YEAR_MIN] and [JULIAN_YEAR_MAX]. Month must be
+/// between `1` and `12`. Bounds are checked using `debug_assert` only, so that
+/// the checks are not present in release builds, similar to integer overflow
+/// checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::calendar::julian::julian_days_in_month;
+///
+/// assert_eq!(julian_days_in_month(2023, 1), 31);
+/// assert_eq!(julian_days_in_month(2023, 2), 28);
+/// assert_eq!(julian_days_in_month(1900, 2), 29);
+/// assert_eq!(julian_days_in_month(2023, 4), 30);
+/// ```
+///
+/// # Algorithm
+///
+/// Same as [days_in_month] with the Julian leap year rule.
+#[inline]
+pub const fn julian_days_in_month(y: i32, m: u8) -> u8 {
+    debug_assert!(m >= consts::MONTH_MIN && m <= consts::MONTH_MAX, "given month is out of range");
+    if m != 2 {
+        30 | (m ^ (m >> 3))
+    } else if is_julian_leap_year(y) {
+        29
+    } else {
+        28
+    }
+}
+
+/// Convert Rata Die to hybrid Julian/Gregorian date
+///
+/// Given a day counting from Unix epoch (January 1st, 1970) returns a `(year,
+/// month, day)` tuple in a hybrid calendar which uses the Julian calendar for
+/// days before `switchover` and the Gregorian calendar from `switchover`
+/// onwards. This reproduces the behavior of `java.util.GregorianCalendar`,
+/// with [GREGORIAN_REFORM_RD] being the default switchover there, and
+/// [BRITISH_REFORM_RD] the switchover in Great Britain and its colonies.
+///
+/// # Panics
+///
+/// Argument must be between [RD_MIN] and [RD_MAX] inclusive. Bounds are checked
+/// using `debug_assert` only, so that the checks are not present in release
+/// builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::calendar::julian::{rd_to_hybrid_date, BRITISH_REFORM_RD};
+///
+/// assert_eq!(rd_to_hybrid_date(BRITISH_REFORM_RD - 1, BRITISH_REFORM_RD), (1752, 9, 2));
+/// assert_eq!(rd_to_hybrid_date(BRITISH_REFORM_RD, BRITISH_REFORM_RD), (1752, 9, 14));
+/// assert_eq!(rd_to_hybrid_date(0, BRITISH_REFORM_RD), (1970, 1, 1));
+/// ```
+///
+/// # Algorithm
+///
+/// Selects between [rd_to_julian_date] and [rd_to_date].
+#[inline]
+pub const fn rd_to_hybrid_date(n: i32, switchover: i32) -> (i32, u8, u8) {
+    if n < switchover {
+        rd_to_julian_date(n)
+    } else {
+        rd_to_date(n)
+    }
+}
+
+/// Convert hybrid Julian/Gregorian date to Rata Die
+///
+/// Given a `(year, month, day)` tuple in a hybrid calendar which uses the
+/// Julian calendar for days before `switchover` and the Gregorian calendar from
+/// `switchover` onwards, returns the days since Unix epoch (January 1st, 1970).
+///
+/// If the switchover is placed in an era where the Julian calendar runs behind
+/// the Gregorian calendar, before the 3rd century, some dates exist in both
+/// calendars; in such case the Gregorian interpretation is returned.
+///
+/// # Errors
+///
+/// Returns `None` if the date does not exist, either because the day exceeds
+/// the number of days in the month in the applicable calendar, or because the
+/// date falls in the days skipped at the switchover.
+///
+/// # Panics
+///
+/// Year must be between [YEAR_MIN] and [YEAR_MAX]. Month must be between `1`
+/// and `12`. Day must be between `1` and `31`. Bounds are checked using
+/// `debug_assert` only, so that the checks are not present in release builds,
+/// similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::calendar::julian::{hybrid_date_to_rd, BRITISH_REFORM_RD};
+///
+/// assert_eq!(hybrid_date_to_rd((1752, 9, 2), BRITISH_REFORM_RD), Some(BRITISH_REFORM_RD - 1));
+/// assert_eq!(hybrid_date_to_rd((1752, 9, 3), BRITISH_REFORM_RD), None);
+/// assert_eq!(hybrid_date_to_rd((1752, 9, 13), BRITISH_REFORM_RD), None);
+/// assert_eq!(hybrid_date_to_rd((1752, 9, 14), BRITISH_REFORM_RD), Some(BRITISH_REFORM_RD));
+/// assert_eq!(hybrid_date_to_rd((1700, 2, 29), BRITISH_REFORM_RD), Some(-98546));
+/// assert_eq!(hybrid_date_to_rd((1900, 2, 29), BRITISH_REFORM_RD), None);
+/// ```
+///
+/// # Algorithm
+///
+/// Tries the Gregorian interpretation using [date_to_rd] first, and the Julian
+/// interpretation using [julian_date_to_rd] if the former falls before the
+/// switchover.
+#[inline]
+pub const fn hybrid_date_to_rd((y, m, d): (i32, u8, u8), switchover: i32) -> Option<i32> {
+    debug_assert!(y >= YEAR_MIN && y <= YEAR_MAX, "given year is out of range");
+    debug_assert!(m >= consts::MONTH_MIN && m <= consts::MONTH_MAX, "given month is out of range");
+    debug_assert!(d >= consts::DAY_MIN && d <= consts::DAY_MAX, "given day is out of range");
+    if d <= days_in_month(y, m) {
+        let n = date_to_rd((y, m, d));
+        if n >= switchover {
+            return Some(n);
+        }
+    }
+    if y >= JULIAN_YEAR_MIN && y <= JULIAN_YEAR_MAX && d <= julian_days_in_month(y, m) {
+        let n = julian_date_to_rd((y, m, d));
+        if n < switchover {
+            return Some(n);
+        }
+    }
+    None
+}
@@ -0,0 +1,160 @@
+//! Leap week calendars based on ISO week dates
+//!
+//! A leap week calendar consists of years of whole weeks, so that every year
+//! and every month starts on the same day of the week. The calendars in this
+//! module use the [ISO week date](https://en.wikipedia.org/wiki/ISO_week_date)
+//! years, which have 52 or 53 weeks starting on Monday, and divide them into
+//! twelve months of four or five weeks according to a month pattern, such as
+//! the 4-5-4 pattern common in retail and manufacturing. The leap week of a 53
+//! week year is added to the last month.
+//!
+//! Note that Symmetry454 uses the same 4-5-4 month pattern, but its own leap
+//! year rule, so its years start on different days than ISO week years in some
+//! years.
+
+use crate::{consts, isoweekdate_to_rd, isoweeks_in_year, rd_to_isoweekdate, RD_MAX, RD_MIN, YEAR_MAX, YEAR_MIN};
+
+/// Month pattern with 4, 5 and 4 weeks in each quarter
+///
+/// Bit `m - 1` is set when month `m` has five weeks instead of four.
+pub const LEAPWEEK_PATTERN_454: u16 = 0x492;
+
+/// Month pattern with 4, 4 and 5 weeks in each quarter
+///
+/// Bit `m - 1` is set when month `m` has five weeks instead of four.
+pub const LEAPWEEK_PATTERN_445: u16 = 0x924;
+
+/// Month pattern with 5, 4 and 4 weeks in each quarter
+///
+/// Bit `m - 1` is set when month `m` has five weeks instead of four.
+pub const LEAPWEEK_PATTERN_544: u16 = 0x249;
+
+/// Number of weeks before the given month
+#[inline]
+pub(super) const fn weeks_before(m: u8, pattern: u16) -> u8 {
+    4 * (m - 1) + (pattern & ((1 << (m - 1)) - 1)).count_ones() as u8
+}
+
+/// Convert Rata Die to leap week date
+///
+/// Given a day counting from Unix epoch (January 1st, 1970) and a month
+/// pattern, such as [LEAPWEEK_PATTERN_454], returns a `(year, month, day)`
+/// tuple in the leap week calendar. Days of month are between `1` and `28` or
+/// `35`, or `42` for the last month of a 53 week year.
+///
+/// # Panics
+///
+/// Argument must be between [RD_MIN] and [RD_MAX] inclusive. Month pattern
+/// must have exactly four of the bits 0 to 11 set. Bounds are checked using
+/// `debug_assert` only, so that the checks are not present in release builds,
+/// similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::calendar::leapweek::{rd_to_leapweek_date, LEAPWEEK_PATTERN_454};
+///
+/// assert_eq!(rd_to_leapweek_date(0, LEAPWEEK_PATTERN_454), (1970, 1, 4));
+/// assert_eq!(rd_to_leapweek_date(19486, LEAPWEEK_PATTERN_454), (2023, 5, 9));
+/// assert_eq!(rd_to_leapweek_date(18630, LEAPWEEK_PATTERN_454), (2020, 12, 35));
+/// ```
+///
+/// # Algorithm
+///
+/// Conversion to ISO week date using [rd_to_isoweekdate], followed by
+/// division of the weeks into months according to the pattern.
+#[inline]
+pub const fn rd_to_leapweek_date(n: i32, pattern: u16) -> (i32, u8, u8) {
+    debug_assert!(n >= RD_MIN && n <= RD_MAX, "given rata die is out of range");
+    debug_assert!(
+        pattern < 1 << 12 && pattern.count_ones() == 4,
+        "given month pattern is out of range"
+    );
+    let (y, w, wd) = rd_to_isoweekdate(n);
+    let mut m = 1;
+    while m < 12 && weeks_before(m + 1, pattern) < w {
+        m += 1;
+    }
+    let d = (w - weeks_before(m, pattern) - 1) * 7 + wd;
+    (y, m, d)
+}
+
+/// Convert leap week date to Rata Die
+///
+/// Given a `(year, month, day)` tuple in the leap week calendar with the given
+/// month pattern, such as [LEAPWEEK_PATTERN_454], returns the days since Unix
+/// epoch (January 1st, 1970).
+///
+/// # Panics
+///
+/// Year must be between [YEAR_MIN] and [YEAR_MAX]. Month must be between `1`
+/// and `12`. Day must be between `1` and the number of days in the month in
+/// question. Month pattern must have exactly four of the bits 0 to 11 set.
+/// Bounds are checked using `debug_assert` only, so that the checks are not
+/// present in release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::calendar::leapweek::{leapweek_date_to_rd, LEAPWEEK_PATTERN_454};
+///
+/// assert_eq!(leapweek_date_to_rd((1970, 1, 4), LEAPWEEK_PATTERN_454), 0);
+/// assert_eq!(leapweek_date_to_rd((2023, 5, 9), LEAPWEEK_PATTERN_454), 19486);
+/// assert_eq!(leapweek_date_to_rd((2020, 12, 35), LEAPWEEK_PATTERN_454), 18630);
+/// ```
+///
+/// # Algorithm
+///
+/// Combination of the weeks of preceding months according to the pattern,
+/// followed by conversion from ISO week date using [isoweekdate_to_rd].
+#[inline]
+pub const fn leapweek_date_to_rd((y, m, d): (i32, u8, u8), pattern: u16) -> i32 {
+    debug_assert!(y >= YEAR_MIN && y <= YEAR_MAX, "given year is out of range");
+    debug_assert!(m >= consts::MONTH_MIN && m <= consts::MONTH_MAX, "given month is out of range");
+    debug_assert!(
+        d >= consts::DAY_MIN && d <= leapweek_days_in_month(y, m, pattern),
+        "given day is out of range"
+    );
+    let w = weeks_before(m, pattern) + (d - 1) / 7 + 1;
+    let wd = (d - 1) % 7 + 1;
+    isoweekdate_to_rd((y, w, wd))
+}
+
+/// Determine the number of days in the given month in the given leap week year
+///
+/// # Panics
+///
+/// Year must be between [YEAR_MIN] and [YEAR_MAX]. Month must be between `1`
+/// and `12`. Month pattern must have exactly four of the bits 0 to 11 set.
+/// Bounds are checked using `debug_assert` only, so that the checks are not
+/// present in release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::calendar::leapweek::{leapweek_days_in_month, LEAPWEEK_PATTERN_454};
+///
+/// assert_eq!(leapweek_days_in_month(2023, 1, LEAPWEEK_PATTERN_454), 28);
+/// assert_eq!(leapweek_days_in_month(2023, 2, LEAPWEEK_PATTERN_454), 35);
+/// assert_eq!(leapweek_days_in_month(2023, 12, LEAPWEEK_PATTERN_454), 28);
+/// assert_eq!(leapweek_days_in_month(2020, 12, LEAPWEEK_PATTERN_454), 35);
+/// ```
+///
+/// # Algorithm
+///
+/// Lookup of the month in the pattern, with the leap week added to the last
+/// month when the ISO week year has 53 weeks.
+#[inline]
+pub const fn leapweek_days_in_month(y: i32, m: u8, pattern: u16) -> u8 {
+    debug_assert!(m >= consts::MONTH_MIN && m <= consts::MONTH_MAX, "given month is out of range");
+    debug_assert!(
+        pattern < 1 << 12 && pattern.count_ones() == 4,
+        "given month pattern is out of range"
+    );
+    let weeks = 4 + ((pattern >> (m - 1)) & 1) as u8;
+    if m == 12 && isoweeks_in_year(y) == 53 {
+        (weeks + 1) * 7
+    } else {
+        weeks * 7
+    }
+}
@@ -0,0 +1,99 @@
+//! Maya Long Count
+//!
+//! The Long Count counts days from a mythological creation date in units of
+//! kin (1 day), uinal (20 kin), tun (18 uinal), katun (20 tun) and baktun (20
+//! katun). The creation date is tied to other calendars using a correlation
+//! constant, given as the Julian Day Number of the creation date. The most
+//! widely accepted correlation is [MAYA_GMT_CORRELATION], but others are in use,
+//! so the correlation is an explicit argument.
+//!
+//! Dates before the creation date have negative baktun values, with the other
+//! units being always non-negative.
+
+use crate::{RD_MAX, RD_MIN};
+
+/// Goodman-Martinez-Thompson correlation, Julian Day Number 584283
+pub const MAYA_GMT_CORRELATION: i32 = 584283;
+
+/// Lounsbury's modified correlation, Julian Day Number 584285
+pub const MAYA_LOUNSBURY_CORRELATION: i32 = 584285;
+
+/// Julian Day Number of Unix epoch (January 1st, 1970)
+const JDN_EPOCH: i32 = 2440588;
+
+/// Convert Rata Die to Maya Long Count
+///
+/// Given a day counting from Unix epoch (January 1st, 1970) and a correlation
+/// constant, such as [MAYA_GMT_CORRELATION], returns a `(baktun, katun, tun,
+/// uinal, kin)` tuple.
+///
+/// # Panics
+///
+/// Argument must be between [RD_MIN] and [RD_MAX] inclusive. Bounds are checked
+/// using `debug_assert` only, so that the checks are not present in release
+/// builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{date_to_rd, calendar::maya::{rd_to_longcount, MAYA_GMT_CORRELATION}};
+///
+/// assert_eq!(rd_to_longcount(date_to_rd((2012, 12, 21)), MAYA_GMT_CORRELATION), (13, 0, 0, 0, 0));
+/// assert_eq!(rd_to_longcount(0, MAYA_GMT_CORRELATION), (12, 17, 16, 7, 5));
+/// assert_eq!(rd_to_longcount(date_to_rd((-3113, 8, 11)), MAYA_GMT_CORRELATION), (0, 0, 0, 0, 0));
+/// ```
+///
+/// # Algorithm
+///
+/// Simple division into the units of the Long Count.
+#[inline]
+pub const fn rd_to_longcount(n: i32, correlation: i32) -> (i32, u8, u8, u8, u8) {
+    debug_assert!(n >= RD_MIN && n <= RD_MAX, "given rata die is out of range");
+    let n = n as i64 + JDN_EPOCH as i64 - correlation as i64;
+    let baktun = n.div_euclid(144000);
+    let n = n.rem_euclid(144000) as u32;
+    let katun = n / 7200;
+    let n = n % 7200;
+    let tun = n / 360;
+    let n = n % 360;
+    let uinal = n / 20;
+    let kin = n % 20;
+    (baktun as i32, katun as u8, tun as u8, uinal as u8, kin as u8)
+}
+
+/// Convert Maya Long Count to Rata Die
+///
+/// Given a `(baktun, katun, tun, uinal, kin)` tuple and a correlation constant,
+/// such as [MAYA_GMT_CORRELATION], returns the days since Unix epoch (January
+/// 1st, 1970).
+///
+/// # Panics
+///
+/// Katun and tun must be between `0` and `19`, uinal between `0` and `17`, and
+/// kin between `0` and `19`. The result must be between [RD_MIN] and [RD_MAX]
+/// inclusive. Bounds are checked using `debug_assert` only, so that the checks
+/// are not present in release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{date_to_rd, calendar::maya::{longcount_to_rd, MAYA_GMT_CORRELATION}};
+///
+/// assert_eq!(longcount_to_rd((13, 0, 0, 0, 0), MAYA_GMT_CORRELATION), date_to_rd((2012, 12, 21)));
+/// assert_eq!(longcount_to_rd((12, 17, 16, 7, 5), MAYA_GMT_CORRELATION), 0);
+/// ```
+///
+/// # Algorithm
+///
+/// Simple multiplication of the units of the Long Count.
+#[inline]
+pub const fn longcount_to_rd((baktun, katun, tun, uinal, kin): (i32, u8, u8, u8, u8), correlation: i32) -> i32 {
+    debug_assert!(katun < 20, "given katun is out of range");
+    debug_assert!(tun < 20, "given tun is out of range");
+    debug_assert!(uinal < 18, "given uinal is out of range");
+    debug_assert!(kin < 20, "given kin is out of range");
+    let n = baktun as i64 * 144000 + katun as i64 * 7200 + tun as i64 * 360 + uinal as i64 * 20 + kin as i64;
+    let n = n + correlation as i64 - JDN_EPOCH as i64;
+    debug_assert!(n >= RD_MIN as i64 && n <= RD_MAX as i64, "given long count is out of range");
+    n as i32
+}
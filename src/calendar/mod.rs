@@ -0,0 +1,253 @@
+//! Conversions between Rata Die and other calendars
+//!
+//! The functions in the crate root use the proleptic Gregorian calendar. The
+//! submodules of this module provide conversions between Rata Die, counting
+//! days from Unix epoch (January 1st, 1970), and dates in other calendars, so
+//! that dates can be converted between any two calendars via Rata Die.
+
+pub mod french;
+pub mod islamic;
+pub mod julian;
+pub mod leapweek;
+pub mod maya;
+pub mod persian;
+pub mod retail;
+pub mod sexagenary;
+
+/// Common interface for calendars with years, months and days
+///
+/// Each calendar converts between Rata Die, counting days from Unix epoch
+/// (January 1st, 1970), and `(year, month, day)` tuples, using the same
+/// functions as the calendar specific modules, with the same bounds and
+/// panics. This allows writing code that is generic over the calendar system.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::calendar::{Calendar, Gregorian, Julian};
+///
+/// fn convert<A: Calendar, B: Calendar>(a: &A, b: &B, date: (i32, u8, u8)) -> (i32, u8, u8) {
+///     b.rd_to_date(a.date_to_rd(date))
+/// }
+///
+/// assert_eq!(convert(&Julian, &Gregorian, (1582, 10, 4)), (1582, 10, 14));
+/// assert_eq!(Julian.days_in_month(1900, 2), 29);
+/// assert_eq!(Gregorian.days_in_month(1900, 2), 28);
+/// ```
+pub trait Calendar {
+    /// Convert Rata Die to date in this calendar
+    fn rd_to_date(&self, n: i32) -> (i32, u8, u8);
+
+    /// Convert date in this calendar to Rata Die
+    fn date_to_rd(&self, date: (i32, u8, u8)) -> i32;
+
+    /// Determine the number of days in the given month in the given year
+    fn days_in_month(&self, y: i32, m: u8) -> u8;
+
+    /// Determine if the given year is a leap year
+    fn is_leap_year(&self, y: i32) -> bool;
+
+    /// Determine the number of months in the given year
+    fn months_in_year(&self, y: i32) -> u8;
+}
+
+/// Proleptic Gregorian calendar, using the functions in the crate root
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Gregorian;
+
+impl Calendar for Gregorian {
+    #[inline]
+    fn rd_to_date(&self, n: i32) -> (i32, u8, u8) {
+        crate::rd_to_date(n)
+    }
+
+    #[inline]
+    fn date_to_rd(&self, date: (i32, u8, u8)) -> i32 {
+        crate::date_to_rd(date)
+    }
+
+    #[inline]
+    fn days_in_month(&self, y: i32, m: u8) -> u8 {
+        crate::days_in_month(y, m)
+    }
+
+    #[inline]
+    fn is_leap_year(&self, y: i32) -> bool {
+        crate::is_leap_year(y)
+    }
+
+    #[inline]
+    fn months_in_year(&self, _y: i32) -> u8 {
+        12
+    }
+}
+
+/// Proleptic Julian calendar, using the functions in [julian]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Julian;
+
+impl Calendar for Julian {
+    #[inline]
+    fn rd_to_date(&self, n: i32) -> (i32, u8, u8) {
+        julian::rd_to_julian_date(n)
+    }
+
+    #[inline]
+    fn date_to_rd(&self, date: (i32, u8, u8)) -> i32 {
+        julian::julian_date_to_rd(date)
+    }
+
+    #[inline]
+    fn days_in_month(&self, y: i32, m: u8) -> u8 {
+        julian::julian_days_in_month(y, m)
+    }
+
+    #[inline]
+    fn is_leap_year(&self, y: i32) -> bool {
+        julian::is_julian_leap_year(y)
+    }
+
+    #[inline]
+    fn months_in_year(&self, _y: i32) -> u8 {
+        12
+    }
+}
+
+/// Tabular Islamic calendar, using the functions in [islamic]
+///
+/// The leap year pattern and epoch are given as fields, see
+/// [islamic::rd_to_islamic_tabular] for details.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IslamicTabular {
+    /// Leap year pattern, such as [islamic::ISLAMIC_LEAP_PATTERN_II]
+    pub pattern: u32,
+    /// Epoch, such as [islamic::ISLAMIC_EPOCH_CIVIL]
+    pub epoch: i32,
+}
+
+impl Calendar for IslamicTabular {
+    #[inline]
+    fn rd_to_date(&self, n: i32) -> (i32, u8, u8) {
+        islamic::rd_to_islamic_tabular(n, self.pattern, self.epoch)
+    }
+
+    #[inline]
+    fn date_to_rd(&self, date: (i32, u8, u8)) -> i32 {
+        islamic::islamic_tabular_to_rd(date, self.pattern, self.epoch)
+    }
+
+    #[inline]
+    fn days_in_month(&self, y: i32, m: u8) -> u8 {
+        islamic::islamic_tabular_days_in_month(y, m, self.pattern)
+    }
+
+    #[inline]
+    fn is_leap_year(&self, y: i32) -> bool {
+        islamic::is_islamic_tabular_leap_year(y, self.pattern)
+    }
+
+    #[inline]
+    fn months_in_year(&self, _y: i32) -> u8 {
+        12
+    }
+}
+
+/// Persian calendar with the 33 year rule, using the functions in [persian]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Persian33;
+
+impl Calendar for Persian33 {
+    #[inline]
+    fn rd_to_date(&self, n: i32) -> (i32, u8, u8) {
+        persian::rd_to_persian33_date(n)
+    }
+
+    #[inline]
+    fn date_to_rd(&self, date: (i32, u8, u8)) -> i32 {
+        persian::persian33_date_to_rd(date)
+    }
+
+    #[inline]
+    fn days_in_month(&self, y: i32, m: u8) -> u8 {
+        persian::persian33_days_in_month(y, m)
+    }
+
+    #[inline]
+    fn is_leap_year(&self, y: i32) -> bool {
+        persian::is_persian33_leap_year(y)
+    }
+
+    #[inline]
+    fn months_in_year(&self, _y: i32) -> u8 {
+        12
+    }
+}
+
+/// French Republican calendar, using the functions in [french]
+///
+/// The complementary days are counted as the 13th month.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct FrenchRepublican;
+
+impl Calendar for FrenchRepublican {
+    #[inline]
+    fn rd_to_date(&self, n: i32) -> (i32, u8, u8) {
+        french::rd_to_french_date(n)
+    }
+
+    #[inline]
+    fn date_to_rd(&self, date: (i32, u8, u8)) -> i32 {
+        french::french_date_to_rd(date)
+    }
+
+    #[inline]
+    fn days_in_month(&self, y: i32, m: u8) -> u8 {
+        french::french_days_in_month(y, m)
+    }
+
+    #[inline]
+    fn is_leap_year(&self, y: i32) -> bool {
+        french::is_french_leap_year(y)
+    }
+
+    #[inline]
+    fn months_in_year(&self, _y: i32) -> u8 {
+        13
+    }
+}
+
+/// Leap week calendar, using the functions in [leapweek]
+///
+/// Leap years are the years with a leap week.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LeapWeek {
+    /// Month pattern, such as [leapweek::LEAPWEEK_PATTERN_454]
+    pub pattern: u16,
+}
+
+impl Calendar for LeapWeek {
+    #[inline]
+    fn rd_to_date(&self, n: i32) -> (i32, u8, u8) {
+        leapweek::rd_to_leapweek_date(n, self.pattern)
+    }
+
+    #[inline]
+    fn date_to_rd(&self, date: (i32, u8, u8)) -> i32 {
+        leapweek::leapweek_date_to_rd(date, self.pattern)
+    }
+
+    #[inline]
+    fn days_in_month(&self, y: i32, m: u8) -> u8 {
+        leapweek::leapweek_days_in_month(y, m, self.pattern)
+    }
+
+    #[inline]
+    fn is_leap_year(&self, y: i32) -> bool {
+        crate::isoweeks_in_year(y) == 53
+    }
+
+    #[inline]
+    fn months_in_year(&self, _y: i32) -> u8 {
+        12
+    }
+}
@@ -0,0 +1,180 @@
+//! Persian (Solar Hijri) calendar using the 33 year arithmetic rule
+//!
+//! The official Persian calendar starts each year at the vernal equinox as
+//! observed in Tehran. The arithmetic rule implemented here places 8 leap years
+//! in every 33 year cycle, on years whose remainder modulo 33 is 1, 5, 9, 13,
+//! 17, 22, 26 or 30. It agrees with the astronomical calendar for the years
+//! 1178 to 1634 AP (1799 to 2256), and is extended proleptically outside that
+//! range. The rule is part of the function names, so that other rules can be
+//! added alongside without ambiguity.
+//!
+//! The first six months have 31 days, the next five 30 days, and the last
+//! month 29 days, or 30 days in leap years.
+
+use crate::{consts, RD_MAX, RD_MIN};
+
+/// Rata Die of Farvardin 1st, 1 AP, according to the 33 year rule
+pub const PERSIAN33_EPOCH: i32 = -492268;
+
+/// Leap years within a 33 year cycle, bit `k` for year `33c + k + 1`
+const LEAP_PATTERN: u64 = 0x22211111;
+
+/// Number of days in the 33 year cycle
+const CYCLE_DAYS: i64 = 365 * 33 + 8;
+
+/// Number of leap years before the given year of the 33 year cycle
+#[inline]
+const fn leaps_before(k: u32) -> u32 {
+    (LEAP_PATTERN & ((1u64 << k) - 1)).count_ones()
+}
+
+/// Convert Rata Die to Persian date using the 33 year rule
+///
+/// Given a day counting from Unix epoch (January 1st, 1970) returns a `(year,
+/// month, day)` tuple in the Persian calendar using the 33 year arithmetic
+/// leap year rule.
+///
+/// # Panics
+///
+/// Argument must be between [RD_MIN] and [RD_MAX] inclusive. Bounds are checked
+/// using `debug_assert` only, so that the checks are not present in release
+/// builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::calendar::persian::rd_to_persian33_date;
+///
+/// assert_eq!(rd_to_persian33_date(0), (1348, 10, 11));
+/// assert_eq!(rd_to_persian33_date(19802), (1403, 1, 1));
+/// assert_eq!(rd_to_persian33_date(20167), (1403, 12, 30));
+/// ```
+///
+/// # Algorithm
+///
+/// Simple computation of 33 year cycles, with the year within the cycle
+/// determined from the leap year pattern.
+#[inline]
+pub const fn rd_to_persian33_date(n: i32) -> (i32, u8, u8) {
+    debug_assert!(n >= RD_MIN && n <= RD_MAX, "given rata die is out of range");
+    let n = n as i64 - PERSIAN33_EPOCH as i64;
+    let c = n.div_euclid(CYCLE_DAYS);
+    let r = n.rem_euclid(CYCLE_DAYS) as u32;
+    // year within cycle
+    let mut k = r / 366;
+    while k < 32 && 365 * (k + 1) + leaps_before(k + 1) <= r {
+        k += 1;
+    }
+    let r = r - (365 * k + leaps_before(k));
+    // month and day
+    let (m, d) = if r < 186 {
+        (r / 31 + 1, r % 31 + 1)
+    } else {
+        ((r - 6) / 30 + 1, (r - 6) % 30 + 1)
+    };
+    let y = c * 33 + k as i64 + 1;
+    (y as i32, m as u8, d as u8)
+}
+
+/// Convert Persian date to Rata Die using the 33 year rule
+///
+/// Given a `(year, month, day)` tuple in the Persian calendar using the 33
+/// year arithmetic leap year rule returns the days since Unix epoch (January
+/// 1st, 1970).
+///
+/// # Panics
+///
+/// Month must be between `1` and `12`. Day must be between `1` and the number
+/// of days in the month in question. The result must be between [RD_MIN] and
+/// [RD_MAX] inclusive. Bounds are checked using `debug_assert` only, so that
+/// the checks are not present in release builds, similar to integer overflow
+/// checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::calendar::persian::persian33_date_to_rd;
+///
+/// assert_eq!(persian33_date_to_rd((1348, 10, 11)), 0);
+/// assert_eq!(persian33_date_to_rd((1403, 1, 1)), 19802);
+/// assert_eq!(persian33_date_to_rd((1403, 12, 30)), 20167);
+/// ```
+///
+/// # Algorithm
+///
+/// Simple computation of 33 year cycles, with the leap years within the cycle
+/// counted from the leap year pattern.
+#[inline]
+pub const fn persian33_date_to_rd((y, m, d): (i32, u8, u8)) -> i32 {
+    debug_assert!(m >= consts::MONTH_MIN && m <= consts::MONTH_MAX, "given month is out of range");
+    debug_assert!(
+        d >= consts::DAY_MIN && d <= persian33_days_in_month(y, m),
+        "given day is out of range"
+    );
+    let y = y as i64 - 1;
+    let c = y.div_euclid(33);
+    let k = y.rem_euclid(33) as u32;
+    let m = m as u32;
+    let m = if m <= 7 { 31 * (m - 1) } else { 30 * (m - 1) + 6 };
+    let r = 365 * k + leaps_before(k) + m + d as u32 - 1;
+    let n = c * CYCLE_DAYS + r as i64 + PERSIAN33_EPOCH as i64;
+    debug_assert!(n >= RD_MIN as i64 && n <= RD_MAX as i64, "given date is out of range");
+    n as i32
+}
+
+/// Determine if the given year is a leap year in the Persian calendar using
+/// the 33 year rule
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::calendar::persian::is_persian33_leap_year;
+///
+/// assert_eq!(is_persian33_leap_year(1399), true);
+/// assert_eq!(is_persian33_leap_year(1402), false);
+/// assert_eq!(is_persian33_leap_year(1403), true);
+/// ```
+///
+/// # Algorithm
+///
+/// Lookup of the year within the 33 year cycle in the leap year pattern.
+#[inline]
+pub const fn is_persian33_leap_year(y: i32) -> bool {
+    let k = (y as i64 - 1).rem_euclid(33) as u32;
+    LEAP_PATTERN & (1u64 << k) != 0
+}
+
+/// Determine the number of days in the given month in the given Persian year
+/// using the 33 year rule
+///
+/// # Panics
+///
+/// Month must be between `1` and `12`. Bounds are checked using `debug_assert`
+/// only, so that the checks are not present in release builds, similar to
+/// integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::calendar::persian::persian33_days_in_month;
+///
+/// assert_eq!(persian33_days_in_month(1403, 1), 31);
+/// assert_eq!(persian33_days_in_month(1403, 7), 30);
+/// assert_eq!(persian33_days_in_month(1403, 12), 30);
+/// assert_eq!(persian33_days_in_month(1402, 12), 29);
+/// ```
+///
+/// # Algorithm
+///
+/// Fixed month lengths, except for the last month in leap years.
+#[inline]
+pub const fn persian33_days_in_month(y: i32, m: u8) -> u8 {
+    debug_assert!(m >= consts::MONTH_MIN && m <= consts::MONTH_MAX, "given month is out of range");
+    if m <= 6 {
+        31
+    } else if m <= 11 || is_persian33_leap_year(y) {
+        30
+    } else {
+        29
+    }
+}
@@ -0,0 +1,239 @@
+//! Retail calendars with configurable year end
+//!
+//! A retail calendar, also known as a 52-53 week fiscal calendar, consists of
+//! years of whole weeks that end on a fixed day of week close to the end of a
+//! given month, such as the Saturday nearest January 31st used by the National
+//! Retail Federation. Each year is divided into twelve periods of four or five
+//! weeks according to a month pattern from [leapweek], such as
+//! [LEAPWEEK_PATTERN_445], and the extra week of a 53 week year is added to
+//! the last period.
+//!
+//! Fiscal years are numbered by the calendar year in which they end. Some
+//! conventions, such as the NRF calendar, number the years by the calendar
+//! year in which they start instead, so that the NRF fiscal year 2023 is year
+//! `2024` here.
+//!
+//! [LEAPWEEK_PATTERN_445]: leapweek::LEAPWEEK_PATTERN_445
+
+use super::leapweek;
+use crate::{consts, date_to_rd, days_in_month, rd_to_weekday, rd_to_year, RD_MAX, RD_MIN, YEAR_MAX, YEAR_MIN};
+
+/// Rule for the last day of a retail year
+///
+/// Months and days of week are as in [date_to_rd] and [rd_to_weekday].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum YearEnd {
+    /// Last given day of week in the given month
+    Last(u8, u8),
+    /// Given day of week nearest to the last day of the given month, which may
+    /// fall in the next month
+    Nearest(u8, u8),
+}
+
+/// Year end of the National Retail Federation 4-5-4 calendar
+pub const YEAR_END_NRF: YearEnd = YearEnd::Nearest(1, consts::SATURDAY);
+
+/// Determine the last day of the given retail year
+///
+/// Given a year and a year end rule returns the last day of the retail year,
+/// counting from Unix epoch (January 1st, 1970).
+///
+/// # Panics
+///
+/// Year must be between [YEAR_MIN] and [YEAR_MAX]. Month of the year end rule
+/// must be between `1` and `12` and day of week between `1` and `7`. Bounds
+/// are checked using `debug_assert` only, so that the checks are not present
+/// in release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{consts::SATURDAY, date_to_rd};
+/// use datealgo::calendar::retail::{retail_year_end, YearEnd, YEAR_END_NRF};
+///
+/// assert_eq!(retail_year_end(2024, YEAR_END_NRF), date_to_rd((2024, 2, 3)));
+/// assert_eq!(retail_year_end(2023, YearEnd::Last(9, SATURDAY)), date_to_rd((2023, 9, 30)));
+/// ```
+///
+/// # Algorithm
+///
+/// Day of week of the last day of the month using [rd_to_weekday].
+#[inline]
+pub const fn retail_year_end(y: i32, year_end: YearEnd) -> i32 {
+    debug_assert!(y >= YEAR_MIN && y <= YEAR_MAX, "given year is out of range");
+    let (m, wd, nearest) = match year_end {
+        YearEnd::Last(m, wd) => (m, wd, false),
+        YearEnd::Nearest(m, wd) => (m, wd, true),
+    };
+    debug_assert!(m >= consts::MONTH_MIN && m <= consts::MONTH_MAX, "given month is out of range");
+    debug_assert!(
+        wd >= consts::WEEKDAY_MIN && wd <= consts::WEEKDAY_MAX,
+        "given weekday is out of range"
+    );
+    let last = date_to_rd((y, m, days_in_month(y, m)));
+    let back = ((rd_to_weekday(last) + 7 - wd) % 7) as i32;
+    if nearest && back > 3 {
+        last - back + 7
+    } else {
+        last - back
+    }
+}
+
+/// Convert Rata Die to retail calendar date
+///
+/// Given a day counting from Unix epoch (January 1st, 1970), a month pattern,
+/// such as [LEAPWEEK_PATTERN_454](leapweek::LEAPWEEK_PATTERN_454), and a year
+/// end rule returns a `(year, period, week)` tuple, where week is the week of
+/// the retail year between `1` and `53`.
+///
+/// # Panics
+///
+/// Argument must be between [RD_MIN] and [RD_MAX] inclusive, and the retail
+/// year and the years before and after it must be between [YEAR_MIN] and
+/// [YEAR_MAX]. Month pattern must have exactly four of the bits 0 to 11 set.
+/// Bounds are checked using `debug_assert` only, so that the checks are not
+/// present in release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::date_to_rd;
+/// use datealgo::calendar::leapweek::LEAPWEEK_PATTERN_454;
+/// use datealgo::calendar::retail::{rd_to_retail, YEAR_END_NRF};
+///
+/// assert_eq!(rd_to_retail(date_to_rd((2023, 1, 29)), LEAPWEEK_PATTERN_454, YEAR_END_NRF), (2024, 1, 1));
+/// assert_eq!(rd_to_retail(date_to_rd((2023, 5, 20)), LEAPWEEK_PATTERN_454, YEAR_END_NRF), (2024, 4, 16));
+/// assert_eq!(rd_to_retail(date_to_rd((2024, 2, 3)), LEAPWEEK_PATTERN_454, YEAR_END_NRF), (2024, 12, 53));
+/// ```
+///
+/// # Algorithm
+///
+/// The retail year is found by comparing to the year ends around the calendar
+/// year, after which the weeks are divided into periods as in
+/// [rd_to_leapweek_date](leapweek::rd_to_leapweek_date).
+#[inline]
+pub const fn rd_to_retail(n: i32, pattern: u16, year_end: YearEnd) -> (i32, u8, u8) {
+    debug_assert!(n >= RD_MIN && n <= RD_MAX, "given rata die is out of range");
+    debug_assert!(
+        pattern < 1 << 12 && pattern.count_ones() == 4,
+        "given month pattern is out of range"
+    );
+    let mut y = rd_to_year(n);
+    if n > retail_year_end(y, year_end) {
+        y += 1;
+    } else if n <= retail_year_end(y - 1, year_end) {
+        y -= 1;
+    }
+    let w = ((n - retail_year_end(y - 1, year_end) - 1) / 7 + 1) as u8;
+    let mut p = 1;
+    while p < 12 && leapweek::weeks_before(p + 1, pattern) < w {
+        p += 1;
+    }
+    (y, p, w)
+}
+
+/// Convert retail week to range of Rata Die
+///
+/// Given a `(year, week)` tuple and a year end rule returns the `(first,
+/// last)` days of the week, counting from Unix epoch (January 1st, 1970).
+///
+/// # Panics
+///
+/// Year and the year before it must be between [YEAR_MIN] and [YEAR_MAX].
+/// Week must be between `1` and the number of weeks in the given year (52 or
+/// 53). Bounds are checked using `debug_assert` only, so that the checks are
+/// not present in release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::date_to_rd;
+/// use datealgo::calendar::retail::{retail_week_to_rd_range, YEAR_END_NRF};
+///
+/// assert_eq!(retail_week_to_rd_range((2024, 16), YEAR_END_NRF), (date_to_rd((2023, 5, 14)), date_to_rd((2023, 5, 20))));
+/// ```
+///
+/// # Algorithm
+///
+/// Offset from the end of the previous year.
+#[inline]
+pub const fn retail_week_to_rd_range((y, w): (i32, u8), year_end: YearEnd) -> (i32, i32) {
+    debug_assert!(w >= 1 && w <= retail_weeks_in_year(y, year_end), "given week is out of range");
+    let first = retail_year_end(y - 1, year_end) + 1 + (w as i32 - 1) * 7;
+    (first, first + 6)
+}
+
+/// Convert retail period to range of Rata Die
+///
+/// Given a `(year, period)` tuple, a month pattern and a year end rule returns
+/// the `(first, last)` days of the period, counting from Unix epoch (January
+/// 1st, 1970).
+///
+/// # Panics
+///
+/// Year and the year before it must be between [YEAR_MIN] and [YEAR_MAX].
+/// Period must be between `1` and `12`. Month pattern must have exactly four
+/// of the bits 0 to 11 set. Bounds are checked using `debug_assert` only, so
+/// that the checks are not present in release builds, similar to integer
+/// overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::date_to_rd;
+/// use datealgo::calendar::leapweek::LEAPWEEK_PATTERN_454;
+/// use datealgo::calendar::retail::{retail_period_to_rd_range, YEAR_END_NRF};
+///
+/// assert_eq!(
+///     retail_period_to_rd_range((2024, 2), LEAPWEEK_PATTERN_454, YEAR_END_NRF),
+///     (date_to_rd((2023, 2, 26)), date_to_rd((2023, 4, 1)))
+/// );
+/// assert_eq!(
+///     retail_period_to_rd_range((2024, 12), LEAPWEEK_PATTERN_454, YEAR_END_NRF),
+///     (date_to_rd((2023, 12, 31)), date_to_rd((2024, 2, 3)))
+/// );
+/// ```
+///
+/// # Algorithm
+///
+/// Combination of the weeks of preceding periods according to the pattern.
+#[inline]
+pub const fn retail_period_to_rd_range((y, p): (i32, u8), pattern: u16, year_end: YearEnd) -> (i32, i32) {
+    debug_assert!(p >= consts::MONTH_MIN && p <= consts::MONTH_MAX, "given period is out of range");
+    debug_assert!(
+        pattern < 1 << 12 && pattern.count_ones() == 4,
+        "given month pattern is out of range"
+    );
+    let start = retail_year_end(y - 1, year_end) + 1;
+    let first = start + leapweek::weeks_before(p, pattern) as i32 * 7;
+    if p == 12 {
+        (first, retail_year_end(y, year_end))
+    } else {
+        (first, start + leapweek::weeks_before(p + 1, pattern) as i32 * 7 - 1)
+    }
+}
+
+/// Determine the number of weeks in the given retail year
+///
+/// # Panics
+///
+/// Year and the year before it must be between [YEAR_MIN] and [YEAR_MAX].
+/// Bounds are checked using `debug_assert` only, so that the checks are not
+/// present in release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::calendar::retail::{retail_weeks_in_year, YEAR_END_NRF};
+///
+/// assert_eq!(retail_weeks_in_year(2023, YEAR_END_NRF), 52);
+/// assert_eq!(retail_weeks_in_year(2024, YEAR_END_NRF), 53);
+/// ```
+///
+/// # Algorithm
+///
+/// Difference between the year ends.
+#[inline]
+pub const fn retail_weeks_in_year(y: i32, year_end: YearEnd) -> u8 {
+    ((retail_year_end(y, year_end) - retail_year_end(y - 1, year_end)) / 7) as u8
+}
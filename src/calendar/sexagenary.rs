@@ -0,0 +1,149 @@
+//! Sexagenary cycle of heavenly stems and earthly branches
+//!
+//! The sexagenary cycle combines ten heavenly stems and twelve earthly branches
+//! into a cycle of 60, used for counting both years and days in the Chinese
+//! and other East Asian calendars. Positions in the cycle are given as `u8`
+//! numbers between 1 and 60, with `1` being the first combination (jiǎzǐ),
+//! stems as numbers between 1 and 10, and branches as numbers between 1 and 12.
+//!
+//! The day cycle runs continuously and is purely arithmetic. The year cycle is
+//! arithmetic as well, but Chinese years begin at the lunar new year, so the
+//! year given to [year_to_sexagenary] must be the Chinese year, which for dates
+//! in January and February may be the previous Gregorian year. Determining the
+//! lunar new year is outside the scope of this module.
+
+use crate::{RD_MAX, RD_MIN, YEAR_MAX, YEAR_MIN};
+
+/// Convert year to position in the sexagenary cycle
+///
+/// Given a year returns its position in the sexagenary cycle, between `1` and
+/// `60`. Year 4 and every 60 years from it, such as 1984, is the first year of
+/// the cycle.
+///
+/// # Panics
+///
+/// Year must be between [YEAR_MIN] and [YEAR_MAX] inclusive. Bounds are checked
+/// using `debug_assert` only, so that the checks are not present in release
+/// builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::calendar::sexagenary::year_to_sexagenary;
+///
+/// assert_eq!(year_to_sexagenary(1984), 1);
+/// assert_eq!(year_to_sexagenary(2023), 40);
+/// assert_eq!(year_to_sexagenary(2043), 60);
+/// ```
+///
+/// # Algorithm
+///
+/// Simple modular arithmetic.
+#[inline]
+pub const fn year_to_sexagenary(y: i32) -> u8 {
+    debug_assert!(y >= YEAR_MIN && y <= YEAR_MAX, "given year is out of range");
+    ((y - 4).rem_euclid(60) + 1) as u8
+}
+
+/// Convert Rata Die to position in the sexagenary day cycle
+///
+/// Given a day counting from Unix epoch (January 1st, 1970) returns its
+/// position in the sexagenary day cycle, between `1` and `60`.
+///
+/// # Panics
+///
+/// Argument must be between [RD_MIN] and [RD_MAX] inclusive. Bounds are checked
+/// using `debug_assert` only, so that the checks are not present in release
+/// builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{date_to_rd, calendar::sexagenary::rd_to_sexagenary};
+///
+/// assert_eq!(rd_to_sexagenary(0), 18);
+/// assert_eq!(rd_to_sexagenary(date_to_rd((2000, 1, 1))), 55);
+/// assert_eq!(rd_to_sexagenary(date_to_rd((2023, 5, 20))), 15);
+/// ```
+///
+/// # Algorithm
+///
+/// Simple modular arithmetic.
+#[inline]
+pub const fn rd_to_sexagenary(n: i32) -> u8 {
+    debug_assert!(n >= RD_MIN && n <= RD_MAX, "given rata die is out of range");
+    ((n + 17).rem_euclid(60) + 1) as u8
+}
+
+/// Convert position in the sexagenary cycle to stem and branch
+///
+/// Given a position in the sexagenary cycle, between `1` and `60`, returns a
+/// `(stem, branch)` tuple, with stem between `1` and `10` and branch between
+/// `1` and `12`.
+///
+/// # Panics
+///
+/// Argument must be between `1` and `60` inclusive. Bounds are checked using
+/// `debug_assert` only, so that the checks are not present in release builds,
+/// similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::calendar::sexagenary::sexagenary_to_stem_branch;
+///
+/// assert_eq!(sexagenary_to_stem_branch(1), (1, 1));
+/// assert_eq!(sexagenary_to_stem_branch(40), (10, 4));
+/// assert_eq!(sexagenary_to_stem_branch(60), (10, 12));
+/// ```
+///
+/// # Algorithm
+///
+/// Simple modular arithmetic.
+#[inline]
+pub const fn sexagenary_to_stem_branch(n: u8) -> (u8, u8) {
+    debug_assert!(n >= 1 && n <= 60, "given sexagenary position is out of range");
+    let n = n - 1;
+    (n % 10 + 1, n % 12 + 1)
+}
+
+/// Convert stem and branch to position in the sexagenary cycle
+///
+/// Given a `(stem, branch)` tuple, with stem between `1` and `10` and branch
+/// between `1` and `12`, returns the position in the sexagenary cycle, between
+/// `1` and `60`.
+///
+/// # Errors
+///
+/// Returns `None` if the combination does not occur in the cycle, which is
+/// the case when one of stem and branch is odd and the other even.
+///
+/// # Panics
+///
+/// Stem must be between `1` and `10` and branch between `1` and `12`. Bounds
+/// are checked using `debug_assert` only, so that the checks are not present in
+/// release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::calendar::sexagenary::stem_branch_to_sexagenary;
+///
+/// assert_eq!(stem_branch_to_sexagenary((1, 1)), Some(1));
+/// assert_eq!(stem_branch_to_sexagenary((10, 4)), Some(40));
+/// assert_eq!(stem_branch_to_sexagenary((1, 2)), None);
+/// ```
+///
+/// # Algorithm
+///
+/// Chinese remainder theorem for the moduli 10 and 12.
+#[inline]
+pub const fn stem_branch_to_sexagenary((s, b): (u8, u8)) -> Option<u8> {
+    debug_assert!(s >= 1 && s <= 10, "given stem is out of range");
+    debug_assert!(b >= 1 && b <= 12, "given branch is out of range");
+    if (s ^ b) & 1 != 0 {
+        return None;
+    }
+    let n = (6 * (s as i32 - 1) - 5 * (b as i32 - 1)).rem_euclid(60);
+    Some(n as u8 + 1)
+}
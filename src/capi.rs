@@ -0,0 +1,169 @@
+//! C ABI exports of `gmtime_r`, `timegm` and `mktime` replacements
+//!
+//! This module exports `extern "C"` functions with unmangled names, operating
+//! on the platform `struct tm` from [`libc::tm`], so that C, C++ and other
+//! languages with a C FFI can use the algorithms of this crate directly. The
+//! crate is built as a library for C consumers with, for example:
+//!
+//! ```text
+//! cargo rustc --release --features capi --crate-type staticlib
+//! ```
+//!
+//! The corresponding C declarations are:
+//!
+//! ```c
+//! #include <time.h>
+//!
+//! struct tm *datealgo_gmtime_r(const time_t *timep, struct tm *result);
+//! time_t datealgo_timegm(struct tm *tm);
+//! time_t datealgo_mktime_utc(struct tm *tm);
+//! ```
+//!
+//! Null pointers are accepted and reported as errors. Unlike the C library
+//! functions, `errno` is never set. Only UTC is supported.
+//!
+//! Exporting unmangled symbols is not allowed under `forbid(unsafe_code)`, so
+//! enabling this feature relaxes the crate level lint to `deny(unsafe_code)`
+//! and allows it for the exported functions only. No `unsafe` blocks are used,
+//! as pointers are received as `Option<&T>`, which has the same ABI as a
+//! nullable pointer.
+
+use ::libc::{time_t, tm};
+
+use crate::libc::secs_to_tm;
+use crate::{date_to_rd, RD_SECONDS_MAX, RD_SECONDS_MIN, SECS_IN_DAY, YEAR_MAX, YEAR_MIN};
+
+/// Normalize out-of-range `struct tm` fields to Unix seconds
+#[inline]
+fn normalize(tm: &tm) -> Option<i64> {
+    let y = tm.tm_year as i64 + 1900 + (tm.tm_mon as i64).div_euclid(12);
+    let m = (tm.tm_mon as i64).rem_euclid(12) as u8 + 1;
+    if y < YEAR_MIN as i64 || y > YEAR_MAX as i64 {
+        return None;
+    }
+    let n = date_to_rd((y as i32, m, 1)) as i64 + tm.tm_mday as i64 - 1;
+    let secs = n * SECS_IN_DAY + tm.tm_hour as i64 * 3600 + tm.tm_min as i64 * 60 + tm.tm_sec as i64;
+    if secs < RD_SECONDS_MIN || secs > RD_SECONDS_MAX {
+        return None;
+    }
+    Some(secs)
+}
+
+/// Convert `time_t` to `struct tm` in UTC
+///
+/// Replacement for `gmtime_r`. Given a pointer to seconds from Unix epoch
+/// (January 1st, 1970), fills in all of the standard fields of `result`,
+/// including `tm_wday` and `tm_yday`, and returns `result`.
+///
+/// # Errors
+///
+/// Returns null if either pointer is null or the seconds are not between
+/// [RD_SECONDS_MIN] and [RD_SECONDS_MAX] inclusive, in which case `result` is
+/// left untouched.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::capi::datealgo_gmtime_r;
+/// use datealgo::libc::secs_to_tm;
+///
+/// let mut tm = secs_to_tm(0);
+/// let tm = datealgo_gmtime_r(Some(&1684574678), Some(&mut tm)).unwrap();
+/// assert_eq!((tm.tm_year, tm.tm_mon, tm.tm_mday, tm.tm_wday), (123, 4, 20, 6));
+/// ```
+///
+/// # Algorithm
+///
+/// Range check followed by [secs_to_tm].
+#[allow(unsafe_code)]
+#[no_mangle]
+pub extern "C" fn datealgo_gmtime_r<'a>(timep: Option<&time_t>, result: Option<&'a mut tm>) -> Option<&'a mut tm> {
+    // `time_t` is 32-bit on some targets
+    #[allow(clippy::unnecessary_cast)]
+    let secs = *timep? as i64;
+    let result = result?;
+    if secs < RD_SECONDS_MIN || secs > RD_SECONDS_MAX {
+        return None;
+    }
+    *result = secs_to_tm(secs);
+    Some(result)
+}
+
+/// Convert `struct tm` in UTC to `time_t`
+///
+/// Replacement for `timegm`. Given a pointer to a `struct tm` in UTC, returns
+/// the seconds from Unix epoch (January 1st, 1970). Fields outside their
+/// normal ranges are accepted and carried over, so that for example month
+/// `12` is January of the next year and day `0` is the last day of the
+/// previous month. The structure is then updated with the normalized fields,
+/// including `tm_wday` and `tm_yday`. The `tm_wday`, `tm_yday` and `tm_isdst`
+/// fields are ignored on input.
+///
+/// # Errors
+///
+/// Returns `-1` if the pointer is null, the result would not fit in `time_t`
+/// or the result is not between [RD_SECONDS_MIN] and [RD_SECONDS_MAX]
+/// inclusive, in which case the structure is left untouched.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::capi::datealgo_timegm;
+/// use datealgo::libc::secs_to_tm;
+///
+/// let mut tm = secs_to_tm(0);
+/// tm.tm_mon = 12;
+/// tm.tm_mday = 0;
+/// assert_eq!(datealgo_timegm(Some(&mut tm)), 31449600);
+/// assert_eq!((tm.tm_year, tm.tm_mon, tm.tm_mday, tm.tm_yday), (70, 11, 31, 364));
+/// ```
+///
+/// # Algorithm
+///
+/// Carrying of months to years, and of days, hours, minutes and seconds to
+/// seconds, followed by [secs_to_tm].
+#[allow(unsafe_code)]
+#[no_mangle]
+pub extern "C" fn datealgo_timegm(tm: Option<&mut tm>) -> time_t {
+    let Some(tm) = tm else {
+        return -1;
+    };
+    let Some(secs) = normalize(tm) else {
+        return -1;
+    };
+    match time_t::try_from(secs) {
+        Ok(t) => {
+            *tm = secs_to_tm(secs);
+            t
+        }
+        Err(_) => -1,
+    }
+}
+
+/// Convert `struct tm` in UTC to `time_t`
+///
+/// Replacement for `mktime` with the time zone set to UTC. Identical to
+/// [datealgo_timegm], provided for code written against `mktime`. The
+/// `tm_isdst` field is ignored on input and set to `0`.
+///
+/// # Errors
+///
+/// Same as [datealgo_timegm].
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::capi::datealgo_mktime_utc;
+/// use datealgo::libc::secs_to_tm;
+///
+/// let mut tm = secs_to_tm(0);
+/// tm.tm_isdst = 1;
+/// tm.tm_sec = 3600;
+/// assert_eq!(datealgo_mktime_utc(Some(&mut tm)), 3600);
+/// assert_eq!((tm.tm_hour, tm.tm_sec, tm.tm_isdst), (1, 0, 0));
+/// ```
+#[allow(unsafe_code)]
+#[no_mangle]
+pub extern "C" fn datealgo_mktime_utc(tm: Option<&mut tm>) -> time_t {
+    datealgo_timegm(tm)
+}
@@ -0,0 +1,291 @@
+//! Conversions for years 2000 to 2099 as used by RTC chips
+//!
+//! Hardware real-time clocks typically store the year as a two digit offset
+//! from 2000, and implement the leap year rule simply as divisibility by four.
+//! Within the years 2000 to 2099 this is exact, as 2000 is a leap year and
+//! there are no other century years. The functions in this module use the
+//! same representation, which allows for smaller code and faster math on
+//! microcontrollers.
+//!
+//! Days and seconds are counted from January 1st, 2000, instead of the Unix
+//! epoch, so that they fit in `u16` and `u32` respectively. Use [EPOCH_RD] and
+//! [EPOCH_SECS] to convert to and from the Unix epoch based values used in
+//! the rest of the crate.
+
+use crate::consts;
+
+/// Adjustment from internal epoch, March 1st, 1996, to January 1st, 2000
+const DAY_OFFSET: u32 = 1401;
+
+/// Rata Die of January 1st, 2000
+pub const EPOCH_RD: i32 = 10957;
+
+/// Unix seconds of January 1st, 2000
+pub const EPOCH_SECS: i64 = 946684800;
+
+/// Maximum supported year, as offset from 2000
+pub const YEAR_MAX: u8 = 99;
+
+/// Maximum supported day, December 31st, 2099
+pub const DAYS_MAX: u16 = 36524;
+
+/// Maximum supported seconds, December 31st, 2099 23:59:59
+pub const SECONDS_MAX: u32 = 3155759999;
+
+/// Convert days since 2000 to date
+///
+/// Given a day counting from January 1st, 2000 returns a `(year, month, day)`
+/// tuple, with year given as offset from 2000.
+///
+/// # Panics
+///
+/// Argument must be between `0` and [DAYS_MAX] inclusive. Bounds are checked
+/// using `debug_assert` only, so that the checks are not present in release
+/// builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::century21::days_to_date;
+///
+/// assert_eq!(days_to_date(0), (0, 1, 1));
+/// assert_eq!(days_to_date(59), (0, 2, 29));
+/// assert_eq!(days_to_date(8532), (23, 5, 12));
+/// assert_eq!(days_to_date(36524), (99, 12, 31));
+/// ```
+///
+/// # Algorithm
+///
+/// Neri-Schneider algorithm as in [rd_to_date](crate::rd_to_date), with the
+/// century computation omitted.
+#[inline]
+pub const fn days_to_date(n: u16) -> (u8, u8, u8) {
+    debug_assert!(n <= DAYS_MAX, "given day is out of range");
+    let n = n as u32 + DAY_OFFSET;
+    // year
+    let n = 4 * n + 3;
+    let y = n / 1461;
+    let n = n % 1461 / 4;
+    let j = n >= 306;
+    let y = y + j as u32;
+    // month and day
+    let n = 2141 * n + 197913;
+    let m = n / 2u32.pow(16);
+    let d = n % 2u32.pow(16) / 2141;
+    // map
+    let y = y.wrapping_sub(4);
+    let m = if j { m - 12 } else { m };
+    let d = d + 1;
+    (y as u8, m as u8, d as u8)
+}
+
+/// Convert date to days since 2000
+///
+/// Given a `(year, month, day)` tuple, with year given as offset from 2000,
+/// returns the days since January 1st, 2000.
+///
+/// # Panics
+///
+/// Year must be between `0` and [YEAR_MAX]. Month must be between `1` and `12`.
+/// Day must be between `1` and the number of days in the month in question.
+/// Bounds are checked using `debug_assert` only, so that the checks are not
+/// present in release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::century21::date_to_days;
+///
+/// assert_eq!(date_to_days((0, 1, 1)), 0);
+/// assert_eq!(date_to_days((0, 2, 29)), 59);
+/// assert_eq!(date_to_days((23, 5, 12)), 8532);
+/// assert_eq!(date_to_days((99, 12, 31)), 36524);
+/// ```
+///
+/// # Algorithm
+///
+/// Neri-Schneider algorithm as in [date_to_rd](crate::date_to_rd), with the
+/// century computation omitted.
+#[inline]
+pub const fn date_to_days((y, m, d): (u8, u8, u8)) -> u16 {
+    debug_assert!(y <= YEAR_MAX, "given year is out of range");
+    debug_assert!(m >= consts::MONTH_MIN && m <= consts::MONTH_MAX, "given month is out of range");
+    debug_assert!(d >= consts::DAY_MIN && d <= days_in_month(y, m), "given day is out of range");
+    let jf = (m < 3) as u32;
+    let y = y as u32 + 4 - jf;
+    let m = m as u32 + 12 * jf;
+    // year
+    let y = 1461 * y / 4;
+    // month
+    let m = (979 * m - 2919) / 32;
+    // result
+    let n = y + m + d as u32 - 1;
+    n.wrapping_sub(DAY_OFFSET) as u16
+}
+
+/// Convert days since 2000 to day of week
+///
+/// Given a day counting from January 1st, 2000 returns the day of week, with
+/// `1` meaning Monday and `7` meaning Sunday.
+///
+/// # Panics
+///
+/// Argument must be between `0` and [DAYS_MAX] inclusive. Bounds are checked
+/// using `debug_assert` only, so that the checks are not present in release
+/// builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::century21::days_to_weekday;
+///
+/// assert_eq!(days_to_weekday(0), 6);
+/// assert_eq!(days_to_weekday(8532), 5);
+/// ```
+///
+/// # Algorithm
+///
+/// Same as [rd_to_weekday](crate::rd_to_weekday), using the 32-bit reciprocal
+/// of `7`.
+#[inline]
+pub const fn days_to_weekday(n: u16) -> u8 {
+    debug_assert!(n <= DAYS_MAX, "given day is out of range");
+    const C: u32 = u32::MAX / 7;
+    // offset makes n = 0 yield 6, as January 1st, 2000 was a Saturday
+    let m = n as u32 + 5;
+    ((m + 1).wrapping_mul(C) >> 29) as u8
+}
+
+/// Determine if the given year is a leap year
+///
+/// # Panics
+///
+/// Year must be between `0` and [YEAR_MAX] inclusive. Bounds are checked using
+/// `debug_assert` only, so that the checks are not present in release builds,
+/// similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::century21::is_leap_year;
+///
+/// assert_eq!(is_leap_year(0), true);
+/// assert_eq!(is_leap_year(23), false);
+/// assert_eq!(is_leap_year(24), true);
+/// ```
+///
+/// # Algorithm
+///
+/// Every year divisible by four is a leap year within the supported range.
+#[inline]
+pub const fn is_leap_year(y: u8) -> bool {
+    debug_assert!(y <= YEAR_MAX, "given year is out of range");
+    y & 3 == 0
+}
+
+/// Determine the number of days in the given month in the given year
+///
+/// # Panics
+///
+/// Year must be between `0` and [YEAR_MAX]. Month must be between `1` and `12`.
+/// Bounds are checked using `debug_assert` only, so that the checks are not
+/// present in release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::century21::days_in_month;
+///
+/// assert_eq!(days_in_month(23, 1), 31);
+/// assert_eq!(days_in_month(23, 2), 28);
+/// assert_eq!(days_in_month(24, 2), 29);
+/// assert_eq!(days_in_month(23, 4), 30);
+/// ```
+///
+/// # Algorithm
+///
+/// Same as [days_in_month](crate::days_in_month).
+#[inline]
+pub const fn days_in_month(y: u8, m: u8) -> u8 {
+    debug_assert!(m >= consts::MONTH_MIN && m <= consts::MONTH_MAX, "given month is out of range");
+    if m != 2 {
+        30 | (m ^ (m >> 3))
+    } else if is_leap_year(y) {
+        29
+    } else {
+        28
+    }
+}
+
+/// Convert seconds since 2000 to year, month, day, hours, minutes and seconds
+///
+/// Given seconds counting from January 1st, 2000 returns a `(year, month, day,
+/// hours, minutes, seconds)` tuple, with year given as offset from 2000.
+///
+/// # Panics
+///
+/// Argument must be between `0` and [SECONDS_MAX] inclusive. Bounds are checked
+/// using `debug_assert` only, so that the checks are not present in release
+/// builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::century21::secs_to_datetime;
+///
+/// assert_eq!(secs_to_datetime(0), (0, 1, 1, 0, 0, 0));
+/// assert_eq!(secs_to_datetime(737889878), (23, 5, 20, 9, 24, 38));
+/// assert_eq!(secs_to_datetime(3155759999), (99, 12, 31, 23, 59, 59));
+/// ```
+///
+/// # Algorithm
+///
+/// Plain 32-bit division followed by [days_to_date].
+#[inline]
+pub const fn secs_to_datetime(secs: u32) -> (u8, u8, u8, u8, u8, u8) {
+    debug_assert!(secs <= SECONDS_MAX, "given seconds value is out of range");
+    let days = secs / 86400;
+    let secs = secs % 86400;
+    let mins = secs / 60;
+    let ss = secs % 60;
+    let hh = mins / 60;
+    let mm = mins % 60;
+    let (y, m, d) = days_to_date(days as u16);
+    (y, m, d, hh as u8, mm as u8, ss as u8)
+}
+
+/// Convert year, month, day, hours, minutes and seconds to seconds since 2000
+///
+/// Given a `(year, month, day, hours, minutes, seconds)` tuple, with year given
+/// as offset from 2000, returns the seconds since January 1st, 2000.
+///
+/// # Panics
+///
+/// Year must be between `0` and [YEAR_MAX]. Month must be between `1` and `12`.
+/// Day must be between `1` and the number of days in the month in question.
+/// Hours must be between `0` and `23`. Minutes must be between `0` and `59`.
+/// Seconds must be between `0` and `59`. Bounds are checked using
+/// `debug_assert` only, so that the checks are not present in release builds,
+/// similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::century21::datetime_to_secs;
+///
+/// assert_eq!(datetime_to_secs((0, 1, 1, 0, 0, 0)), 0);
+/// assert_eq!(datetime_to_secs((23, 5, 20, 9, 24, 38)), 737889878);
+/// assert_eq!(datetime_to_secs((99, 12, 31, 23, 59, 59)), 3155759999);
+/// ```
+///
+/// # Algorithm
+///
+/// Algorithm is simple multiplication after [date_to_days].
+#[inline]
+pub const fn datetime_to_secs((y, m, d, hh, mm, ss): (u8, u8, u8, u8, u8, u8)) -> u32 {
+    debug_assert!(hh >= consts::HOUR_MIN && hh <= consts::HOUR_MAX, "given hour is out of range");
+    debug_assert!(mm >= consts::MINUTE_MIN && mm <= consts::MINUTE_MAX, "given minute is out of range");
+    debug_assert!(ss >= consts::SECOND_MIN && ss <= consts::SECOND_MAX, "given second is out of range");
+    let days = date_to_days((y, m, d)) as u32;
+    days.wrapping_mul(86400).wrapping_add(hh as u32 * 3600 + mm as u32 * 60 + ss as u32)
+}
@@ -0,0 +1,354 @@
+//! Constant-time conversions
+//!
+//! Timestamps handled during certificate or token validation may be adjacent
+//! to secret data, in which case the time taken by a conversion should not
+//! depend on the value being converted. The functions in this module compute
+//! the same results as the corresponding functions in the crate root, but are
+//! written without data-dependent branches or table lookups. All conditions
+//! are turned into integer masks and flags, and all divisions are by
+//! constants.
+//!
+//! Rust does not offer a way to guarantee constant-time execution, so the
+//! guarantees depend on the compiler and the target:
+//!
+//! - On `x86_64` and `aarch64` the functions compile to straight-line code in
+//!   release builds, with divisions by constants lowered to multiplications
+//!   and shifts. Multiplication is constant-time on all common processors of
+//!   these architectures.
+//! - On 32-bit targets without a native 64-bit multiplier, 64-bit
+//!   multiplications are done by a runtime library routine, which is usually,
+//!   but not necessarily, constant-time.
+//! - On targets where the hardware multiplier terminates early for small
+//!   operands, such as some older ARM cores, timing may depend on the values.
+//!
+//! Debug builds contain `debug_assert` bound checks, which branch on the
+//! arguments. Callers with strict requirements should verify the generated
+//! code for their target.
+
+use crate::{
+    consts, DAY_OFFSET, RD_MAX, RD_MIN, RD_SECONDS_MAX, RD_SECONDS_MIN, SECS_IN_DAY, SECS_OFFSET, YEAR_MAX, YEAR_MIN, YEAR_OFFSET,
+};
+
+/// Determine if the given year is a leap year in constant time
+///
+/// Same as [is_leap_year](crate::is_leap_year).
+///
+/// # Panics
+///
+/// Year must be between [YEAR_MIN] and [YEAR_MAX] inclusive. Bounds are checked
+/// using `debug_assert` only, so that the checks are not present in release
+/// builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::ct::is_leap_year;
+///
+/// assert_eq!(is_leap_year(2023), false);
+/// assert_eq!(is_leap_year(2024), true);
+/// assert_eq!(is_leap_year(2100), false);
+/// assert_eq!(is_leap_year(2400), true);
+/// ```
+///
+/// # Algorithm
+///
+/// Same as [is_leap_year](crate::is_leap_year), with the century condition
+/// turned into a bit mask.
+#[inline]
+pub const fn is_leap_year(y: i32) -> bool {
+    debug_assert!(y >= YEAR_MIN && y <= YEAR_MAX, "given year is out of range");
+    let c = ((y % 25) == 0) as i32;
+    let mask = 3 | (12 & c.wrapping_neg());
+    y & mask == 0
+}
+
+/// Determine the number of days in the given month in the given year in
+/// constant time
+///
+/// Same as [days_in_month](crate::days_in_month).
+///
+/// # Panics
+///
+/// Year must be between [YEAR_MIN] and [YEAR_MAX]. Month must be between `1`
+/// and `12`. Bounds are checked using `debug_assert` only, so that the checks
+/// are not present in release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::ct::days_in_month;
+///
+/// assert_eq!(days_in_month(2023, 1), 31);
+/// assert_eq!(days_in_month(2023, 2), 28);
+/// assert_eq!(days_in_month(2024, 2), 29);
+/// assert_eq!(days_in_month(2024, 4), 30);
+/// ```
+///
+/// # Algorithm
+///
+/// Same as [days_in_month](crate::days_in_month), with February handled by
+/// subtracting a flag dependent correction.
+#[inline]
+pub const fn days_in_month(y: i32, m: u8) -> u8 {
+    debug_assert!(m >= consts::MONTH_MIN && m <= consts::MONTH_MAX, "given month is out of range");
+    let feb = (m == 2) as u8;
+    let leap = is_leap_year(y) as u8;
+    (30 | (m ^ (m >> 3))).wrapping_sub(feb * (2 - leap))
+}
+
+/// Convert Rata Die to Gregorian date in constant time
+///
+/// Same as [rd_to_date](crate::rd_to_date).
+///
+/// # Panics
+///
+/// Argument must be between [RD_MIN] and [RD_MAX] inclusive. Bounds are checked
+/// using `debug_assert` only, so that the checks are not present in release
+/// builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::ct::rd_to_date;
+///
+/// assert_eq!(rd_to_date(-719528), (0, 1, 1));
+/// assert_eq!(rd_to_date(0), (1970, 1, 1));
+/// assert_eq!(rd_to_date(19489), (2023, 5, 12));
+/// ```
+///
+/// # Algorithm
+///
+/// Same as [rd_to_date](crate::rd_to_date), with the final month adjustment
+/// done arithmetically.
+#[inline]
+pub const fn rd_to_date(n: i32) -> (i32, u8, u8) {
+    debug_assert!(n >= RD_MIN && n <= RD_MAX, "given rata die is out of range");
+    let n = n.wrapping_add(DAY_OFFSET) as u32;
+    // century
+    let n = n.wrapping_mul(4).wrapping_add(3);
+    let c = n / 146097;
+    let r = n % 146097;
+    // year
+    let n = r | 3;
+    let p = 2939745 * n as u64;
+    let z = (p >> 32) as u32;
+    let n = p as u32 / 2939745 / 4;
+    let j = (n >= 306) as u32;
+    let y = 100 * c + z + j;
+    // month and day
+    let n = 2141 * n + 197913;
+    let m = (n >> 16) - 12 * j;
+    let d = (n & 0xffff) / 2141 + 1;
+    // map
+    let y = (y as i32).wrapping_sub(YEAR_OFFSET);
+    (y, m as u8, d as u8)
+}
+
+/// Convert Gregorian date to Rata Die in constant time
+///
+/// Same as [date_to_rd](crate::date_to_rd).
+///
+/// # Panics
+///
+/// Year must be between [YEAR_MIN] and [YEAR_MAX]. Month must be between `1`
+/// and `12`. Day must be between `1` and the number of days in the month in
+/// question. Bounds are checked using `debug_assert` only, so that the checks
+/// are not present in release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::ct::date_to_rd;
+///
+/// assert_eq!(date_to_rd((0, 1, 1)), -719528);
+/// assert_eq!(date_to_rd((1970, 1, 1)), 0);
+/// assert_eq!(date_to_rd((2023, 5, 12)), 19489);
+/// ```
+///
+/// # Algorithm
+///
+/// Same as [date_to_rd](crate::date_to_rd).
+#[inline]
+pub const fn date_to_rd((y, m, d): (i32, u8, u8)) -> i32 {
+    debug_assert!(y >= YEAR_MIN && y <= YEAR_MAX, "given year is out of range");
+    debug_assert!(m >= consts::MONTH_MIN && m <= consts::MONTH_MAX, "given month is out of range");
+    debug_assert!(d >= consts::DAY_MIN && d <= days_in_month(y, m), "given day is out of range");
+    let jf = (m < 3) as u32;
+    let y = (y.wrapping_add(YEAR_OFFSET) as u32).wrapping_sub(jf);
+    let c = y / 100;
+    let m = m as u32 + 12 * jf;
+    // year
+    let y = 1461 * y / 4 - c + c / 4;
+    // month
+    let m = (979 * m - 2919) / 32;
+    // result
+    let n = y.wrapping_add(m).wrapping_add(d as u32).wrapping_sub(1);
+    (n as i32).wrapping_sub(DAY_OFFSET)
+}
+
+/// Convert Rata Die to day of week in constant time
+///
+/// Same as [rd_to_weekday](crate::rd_to_weekday).
+///
+/// # Panics
+///
+/// Argument must be between [RD_MIN] and [RD_MAX] inclusive. Bounds are checked
+/// using `debug_assert` only, so that the checks are not present in release
+/// builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::ct::rd_to_weekday;
+///
+/// assert_eq!(rd_to_weekday(0), 4);
+/// assert_eq!(rd_to_weekday(19489), 5);
+/// ```
+///
+/// # Algorithm
+///
+/// Same as [rd_to_weekday](crate::rd_to_weekday).
+#[inline]
+pub const fn rd_to_weekday(n: i32) -> u8 {
+    debug_assert!(n >= RD_MIN && n <= RD_MAX, "given rata die is out of range");
+    const P64_OVER_SEVEN: u64 = ((1 << 63) / 7) << 1; // = (1 << 64) / 7
+    (((n.wrapping_sub(RD_MIN) as u64 + 1).wrapping_mul(P64_OVER_SEVEN)) >> 61) as u8
+}
+
+/// Split total seconds to days, hours, minutes and seconds in constant time
+///
+/// Same as [secs_to_dhms](crate::secs_to_dhms).
+///
+/// # Panics
+///
+/// Argument must be between [RD_SECONDS_MIN] and [RD_SECONDS_MAX] inclusive.
+/// Bounds are checked using `debug_assert` only, so that the checks are not
+/// present in release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::ct::secs_to_dhms;
+///
+/// assert_eq!(secs_to_dhms(0), (0, 0, 0, 0));
+/// assert_eq!(secs_to_dhms(-1), (-1, 23, 59, 59));
+/// assert_eq!(secs_to_dhms(1684574678), (19497, 9, 24, 38));
+/// ```
+///
+/// # Algorithm
+///
+/// Same as [secs_to_dhms](crate::secs_to_dhms), without the range clamp.
+#[inline]
+pub const fn secs_to_dhms(secs: i64) -> (i32, u8, u8, u8) {
+    debug_assert!(
+        secs >= RD_SECONDS_MIN && secs <= RD_SECONDS_MAX,
+        "given seconds value is out of range"
+    );
+    let secs = secs.wrapping_add(SECS_OFFSET) as u64;
+    let days = (secs / SECS_IN_DAY as u64) as u32;
+    let secs = secs % SECS_IN_DAY as u64;
+    // see secs_to_dhms for the identities used
+    let prd = 71582789 * secs;
+    let mins = prd >> 32;
+    let ss = (prd as u32) / 71582789;
+    let prd = 71582789 * mins;
+    let hh = prd >> 32;
+    let mm = (prd as u32) / 71582789;
+    let days = (days as i32).wrapping_sub(DAY_OFFSET);
+    (days, hh as u8, mm as u8, ss as u8)
+}
+
+/// Combine days, hours, minutes and seconds to total seconds in constant time
+///
+/// Same as [dhms_to_secs](crate::dhms_to_secs).
+///
+/// # Panics
+///
+/// Days must be between [RD_MIN] and [RD_MAX] inclusive. Hours must be between
+/// `0` and `23`. Minutes must be between `0` and `59`. Seconds must be between
+/// `0` and `59`. Bounds are checked using `debug_assert` only, so that the
+/// checks are not present in release builds, similar to integer overflow
+/// checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::ct::dhms_to_secs;
+///
+/// assert_eq!(dhms_to_secs((0, 0, 0, 0)), 0);
+/// assert_eq!(dhms_to_secs((-1, 23, 59, 59)), -1);
+/// assert_eq!(dhms_to_secs((19497, 9, 24, 38)), 1684574678);
+/// ```
+///
+/// # Algorithm
+///
+/// Simple multiplication, without the range check.
+#[inline]
+pub const fn dhms_to_secs((d, h, m, s): (i32, u8, u8, u8)) -> i64 {
+    debug_assert!(d >= RD_MIN && d <= RD_MAX, "given rata die is out of range");
+    debug_assert!(h >= consts::HOUR_MIN && h <= consts::HOUR_MAX, "given hour is out of range");
+    debug_assert!(m >= consts::MINUTE_MIN && m <= consts::MINUTE_MAX, "given minute is out of range");
+    debug_assert!(s >= consts::SECOND_MIN && s <= consts::SECOND_MAX, "given second is out of range");
+    d as i64 * SECS_IN_DAY + h as i64 * 3600 + m as i64 * 60 + s as i64
+}
+
+/// Convert total seconds to year, month, day, hours, minutes and seconds in
+/// constant time
+///
+/// Same as [secs_to_datetime](crate::secs_to_datetime).
+///
+/// # Panics
+///
+/// Argument must be between [RD_SECONDS_MIN] and [RD_SECONDS_MAX] inclusive.
+/// Bounds are checked using `debug_assert` only, so that the checks are not
+/// present in release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::ct::secs_to_datetime;
+///
+/// assert_eq!(secs_to_datetime(0), (1970, 1, 1, 0, 0, 0));
+/// assert_eq!(secs_to_datetime(1684574678), (2023, 5, 20, 9, 24, 38));
+/// ```
+///
+/// # Algorithm
+///
+/// Combination of [secs_to_dhms] and [rd_to_date].
+#[inline]
+pub const fn secs_to_datetime(secs: i64) -> (i32, u8, u8, u8, u8, u8) {
+    let (days, hh, mm, ss) = secs_to_dhms(secs);
+    let (y, m, d) = rd_to_date(days);
+    (y, m, d, hh, mm, ss)
+}
+
+/// Convert year, month, day, hours, minutes and seconds to total seconds in
+/// constant time
+///
+/// Same as [datetime_to_secs](crate::datetime_to_secs).
+///
+/// # Panics
+///
+/// Year must be between [YEAR_MIN] and [YEAR_MAX]. Month must be between `1`
+/// and `12`. Day must be between `1` and the number of days in the month in
+/// question. Hours must be between `0` and `23`. Minutes must be between `0`
+/// and `59`. Seconds must be between `0` and `59`. Bounds are checked using
+/// `debug_assert` only, so that the checks are not present in release builds,
+/// similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::ct::datetime_to_secs;
+///
+/// assert_eq!(datetime_to_secs((1970, 1, 1, 0, 0, 0)), 0);
+/// assert_eq!(datetime_to_secs((2023, 5, 20, 9, 24, 38)), 1684574678);
+/// ```
+///
+/// # Algorithm
+///
+/// Combination of [date_to_rd] and [dhms_to_secs].
+#[inline]
+pub const fn datetime_to_secs((y, m, d, hh, mm, ss): (i32, u8, u8, u8, u8, u8)) -> i64 {
+    dhms_to_secs((date_to_rd((y, m, d)), hh, mm, ss))
+}
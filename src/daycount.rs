@@ -0,0 +1,264 @@
+//! Day count conventions for interest calculations
+//!
+//! Financial contracts accrue interest over a period according to a day count
+//! convention, which determines the fraction of a year between two dates. The
+//! conventions in this module are defined in the 2006 ISDA Definitions and are
+//! pure calendar arithmetic. The `30/360` family counts every month as 30 days
+//! after adjusting the days of month, while the `ACT` family uses the actual
+//! number of days between the dates.
+
+use crate::{consts, date_to_rd, days_in_month, is_leap_year, YEAR_MAX, YEAR_MIN};
+
+/// Day count convention
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DayCount {
+    /// 30/360 US, with the end of February treated as the 30th as for
+    /// instruments paying at the end of month
+    Thirty360Us,
+    /// 30E/360, also known as Eurobond basis
+    Thirty360E,
+    /// 30E/360 ISDA, with the last day of every month treated as the 30th
+    Thirty360EIsda,
+    /// 30E/360 ISDA for the final period, where the second date is the
+    /// termination date and is not adjusted if it is the last day of February
+    Thirty360EIsdaTermination,
+    /// ACT/360, actual days divided by 360
+    Act360,
+    /// ACT/365 Fixed, actual days divided by 365
+    Act365F,
+    /// ACT/ACT ISDA, actual days in leap and common years divided by 366 and
+    /// 365 respectively
+    ActActIsda,
+}
+
+/// Day of month adjusted to at most 30
+#[inline]
+const fn thirty(d: u8) -> i32 {
+    if d > 30 {
+        30
+    } else {
+        d as i32
+    }
+}
+
+/// Determine the number of days between two dates according to a day count convention
+///
+/// Given two `(year, month, day)` tuples and a day count convention returns
+/// the number of days from the first date to the second, which is the
+/// numerator of the year fraction. For the `30/360` conventions this is the
+/// number of days counting every month as 30 days, and for the others it is
+/// the actual number of days. The result is negative if the second date is
+/// before the first.
+///
+/// For [DayCount::Thirty360EIsda] the second date is always adjusted if it is
+/// the last day of the month. The 2006 ISDA Definitions exempt the termination
+/// date from this when it is the last day of February, which is applied with
+/// [DayCount::Thirty360EIsdaTermination] for the final period of an
+/// instrument.
+///
+/// # Panics
+///
+/// Year must be between [YEAR_MIN] and [YEAR_MAX]. Month must be between `1`
+/// and `12`. Day must be between `1` and the number of days in the month in
+/// question. Bounds are checked using `debug_assert` only, so that the checks
+/// are not present in release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::daycount::{day_count, DayCount};
+///
+/// assert_eq!(day_count((2023, 1, 31), (2023, 5, 31), DayCount::Thirty360Us), 120);
+/// assert_eq!(day_count((2023, 1, 30), (2023, 5, 31), DayCount::Thirty360Us), 120);
+/// assert_eq!(day_count((2023, 1, 29), (2023, 5, 31), DayCount::Thirty360Us), 122);
+/// assert_eq!(day_count((2023, 1, 29), (2023, 5, 31), DayCount::Thirty360E), 121);
+/// assert_eq!(day_count((2023, 1, 31), (2023, 5, 31), DayCount::Act360), 120);
+/// assert_eq!(day_count((2022, 8, 31), (2023, 2, 28), DayCount::Thirty360EIsda), 180);
+/// assert_eq!(day_count((2022, 8, 31), (2023, 2, 28), DayCount::Thirty360EIsdaTermination), 178);
+/// ```
+///
+/// # Algorithm
+///
+/// Adjustment of the days of month as given in the 2006 ISDA Definitions,
+/// section 4.16, followed by `360 * (Y2 - Y1) + 30 * (M2 - M1) + (D2 - D1)`
+/// for the `30/360` conventions, or difference of Rata Die for the others.
+#[inline]
+pub const fn day_count(date1: (i32, u8, u8), date2: (i32, u8, u8), convention: DayCount) -> i32 {
+    let (y1, m1, d1) = date1;
+    let (y2, m2, d2) = date2;
+    debug_assert!(y1 >= YEAR_MIN && y1 <= YEAR_MAX, "given year is out of range");
+    debug_assert!(m1 >= consts::MONTH_MIN && m1 <= consts::MONTH_MAX, "given month is out of range");
+    debug_assert!(d1 >= consts::DAY_MIN && d1 <= days_in_month(y1, m1), "given day is out of range");
+    debug_assert!(y2 >= YEAR_MIN && y2 <= YEAR_MAX, "given year is out of range");
+    debug_assert!(m2 >= consts::MONTH_MIN && m2 <= consts::MONTH_MAX, "given month is out of range");
+    debug_assert!(d2 >= consts::DAY_MIN && d2 <= days_in_month(y2, m2), "given day is out of range");
+    let (dd1, dd2) = match convention {
+        DayCount::Thirty360Us => {
+            let feb1 = m1 == 2 && d1 == days_in_month(y1, m1);
+            let feb2 = m2 == 2 && d2 == days_in_month(y2, m2);
+            let dd2 = if feb1 && feb2 { 30 } else { d2 as i32 };
+            let dd1 = if feb1 { 30 } else { thirty(d1) };
+            let dd2 = if dd2 == 31 && dd1 == 30 { 30 } else { dd2 };
+            (dd1, dd2)
+        }
+        DayCount::Thirty360E => (thirty(d1), thirty(d2)),
+        DayCount::Thirty360EIsda | DayCount::Thirty360EIsdaTermination => {
+            let termination = matches!(convention, DayCount::Thirty360EIsdaTermination) && m2 == 2;
+            let dd1 = if d1 == days_in_month(y1, m1) { 30 } else { d1 as i32 };
+            let dd2 = if d2 == days_in_month(y2, m2) && !termination {
+                30
+            } else {
+                d2 as i32
+            };
+            (dd1, dd2)
+        }
+        DayCount::Act360 | DayCount::Act365F | DayCount::ActActIsda => {
+            return date_to_rd(date2) - date_to_rd(date1);
+        }
+    };
+    360 * (y2 - y1) + 30 * (m2 as i32 - m1 as i32) + (dd2 - dd1)
+}
+
+/// Determine the fraction of a year between two dates according to a day count convention
+///
+/// Given two `(year, month, day)` tuples and a day count convention returns
+/// the fraction of a year from the first date to the second. The result is
+/// negative if the second date is before the first.
+///
+/// # Panics
+///
+/// Year must be between [YEAR_MIN] and [YEAR_MAX]. Month must be between `1`
+/// and `12`. Day must be between `1` and the number of days in the month in
+/// question. Bounds are checked using `debug_assert` only, so that the checks
+/// are not present in release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::daycount::{year_fraction, DayCount};
+///
+/// assert_eq!(year_fraction((2023, 1, 31), (2023, 7, 31), DayCount::Thirty360Us), 0.5);
+/// assert_eq!(year_fraction((2023, 1, 1), (2023, 3, 2), DayCount::Act360), 60.0 / 360.0);
+/// assert_eq!(year_fraction((2023, 1, 1), (2023, 3, 2), DayCount::Act365F), 60.0 / 365.0);
+/// assert_eq!(year_fraction((2023, 12, 1), (2024, 2, 1), DayCount::ActActIsda), 31.0 / 365.0 + 31.0 / 366.0);
+/// ```
+///
+/// # Algorithm
+///
+/// Result of [day_count] divided by the denominator of the convention. For
+/// ACT/ACT ISDA, the days are split at the year boundaries and the days in
+/// each year are divided by the length of the year.
+#[inline]
+pub const fn year_fraction(date1: (i32, u8, u8), date2: (i32, u8, u8), convention: DayCount) -> f64 {
+    match convention {
+        DayCount::Thirty360Us
+        | DayCount::Thirty360E
+        | DayCount::Thirty360EIsda
+        | DayCount::Thirty360EIsdaTermination
+        | DayCount::Act360 => day_count(date1, date2, convention) as f64 / 360.0,
+        DayCount::Act365F => day_count(date1, date2, convention) as f64 / 365.0,
+        DayCount::ActActIsda => {
+            let rd1 = date_to_rd(date1);
+            let rd2 = date_to_rd(date2);
+            if rd2 < rd1 {
+                return -year_fraction(date2, date1, convention);
+            }
+            let (y1, y2) = (date1.0, date2.0);
+            let len1 = if is_leap_year(y1) { 366.0 } else { 365.0 };
+            if y1 == y2 {
+                return (rd2 - rd1) as f64 / len1;
+            }
+            let len2 = if is_leap_year(y2) { 366.0 } else { 365.0 };
+            let first = (date_to_rd((y1 + 1, 1, 1)) - rd1) as f64 / len1;
+            let last = (rd2 - date_to_rd((y2, 1, 1))) as f64 / len2;
+            first + (y2 - y1 - 1) as f64 + last
+        }
+    }
+}
+
+/// Day of a date in the 30/360 day numbering, counting every month as 30 days
+#[inline]
+const fn to_30_360((y, m, d): (i32, u8, u8)) -> i64 {
+    y as i64 * 360 + (m as i64 - 1) * 30 + thirty(d) as i64 - 1
+}
+
+/// Add days to a date in 30/360 arithmetic
+///
+/// Given a `(year, month, day)` tuple and a number of days returns the date
+/// the days later, or earlier for a negative count, counting every month as
+/// 30 days. The 31st of a month is treated as the 30th. If the result is
+/// beyond the end of a month shorter than 30 days, such as February 30th, it
+/// is clamped to the last day of the month.
+///
+/// # Panics
+///
+/// Year must be between [YEAR_MIN] and [YEAR_MAX]. Month must be between `1`
+/// and `12`. Day must be between `1` and the number of days in the month in
+/// question. Resulting year must be between [YEAR_MIN] and [YEAR_MAX]. Bounds
+/// are checked using `debug_assert` only, so that the checks are not present
+/// in release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::daycount::add_days_30_360;
+///
+/// assert_eq!(add_days_30_360((2023, 1, 15), 30), (2023, 2, 15));
+/// assert_eq!(add_days_30_360((2023, 1, 31), 1), (2023, 2, 1));
+/// assert_eq!(add_days_30_360((2023, 1, 30), 30), (2023, 2, 28));
+/// assert_eq!(add_days_30_360((2023, 3, 1), -1), (2023, 2, 28));
+/// assert_eq!(add_days_30_360((2023, 6, 15), 360), (2024, 6, 15));
+/// ```
+///
+/// # Algorithm
+///
+/// Conversion to a day number with `360 * Y + 30 * (M - 1) + (D - 1)`, followed
+/// by Euclidean division back to year, month and day.
+#[inline]
+pub const fn add_days_30_360((y, m, d): (i32, u8, u8), days: i32) -> (i32, u8, u8) {
+    debug_assert!(y >= YEAR_MIN && y <= YEAR_MAX, "given year is out of range");
+    debug_assert!(m >= consts::MONTH_MIN && m <= consts::MONTH_MAX, "given month is out of range");
+    debug_assert!(d >= consts::DAY_MIN && d <= days_in_month(y, m), "given day is out of range");
+    let n = to_30_360((y, m, d)) + days as i64;
+    let y = n.div_euclid(360) as i32;
+    debug_assert!(y >= YEAR_MIN && y <= YEAR_MAX, "resulting year is out of range");
+    let r = n.rem_euclid(360);
+    let m = (r / 30 + 1) as u8;
+    let d = (r % 30 + 1) as u8;
+    let last = days_in_month(y, m);
+    (y, m, if d > last { last } else { d })
+}
+
+/// Determine the number of days between two dates in 30/360 arithmetic
+///
+/// Given two `(year, month, day)` tuples returns the number of days from the
+/// first date to the second, counting every month as 30 days and treating the
+/// 31st of a month as the 30th. This is the inverse of [add_days_30_360] and
+/// equal to [day_count] with [DayCount::Thirty360E]. The result is negative if
+/// the second date is before the first.
+///
+/// # Panics
+///
+/// Year must be between [YEAR_MIN] and [YEAR_MAX]. Month must be between `1`
+/// and `12`. Day must be between `1` and the number of days in the month in
+/// question. Bounds are checked using `debug_assert` only, so that the checks
+/// are not present in release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::daycount::days_between_30_360;
+///
+/// assert_eq!(days_between_30_360((2023, 1, 15), (2023, 2, 15)), 30);
+/// assert_eq!(days_between_30_360((2023, 1, 31), (2023, 3, 31)), 60);
+/// assert_eq!(days_between_30_360((2023, 2, 28), (2023, 3, 1)), 3);
+/// assert_eq!(days_between_30_360((2024, 6, 15), (2023, 6, 15)), -360);
+/// ```
+///
+/// # Algorithm
+///
+/// Difference of `360 * Y + 30 * (M - 1) + (D - 1)` for the dates.
+#[inline]
+pub const fn days_between_30_360(date1: (i32, u8, u8), date2: (i32, u8, u8)) -> i32 {
+    day_count(date1, date2, DayCount::Thirty360E)
+}
@@ -0,0 +1,69 @@
+//! Formatting wrappers for logging with `defmt`
+//!
+//! Embedded firmware commonly logs with [`defmt`](https://docs.rs/defmt),
+//! which does not use `core::fmt`. The wrapper types in this module implement
+//! [Format] so that dates and times can be logged as ISO 8601 strings instead
+//! of raw integers, without allocations. The date is formatted on the device
+//! with [format_date], and the time fields are sent as integers and padded by
+//! the host.
+//!
+//! # Examples
+//!
+//! ```ignore
+//! use datealgo::defmt::DefmtDateTime;
+//!
+//! defmt::info!("alarm at {}", DefmtDateTime(datealgo::secs_to_datetime(1684574678)));
+//! // alarm at 2023-05-20T09:24:38Z
+//! ```
+
+use ::defmt::{write, Format, Formatter};
+
+use crate::iso::{format_date, ISO_DATE_MAX_LEN};
+
+/// `(year, month, day)` tuple formatted as an ISO 8601 date
+///
+/// Formatted as for example `2023-05-20`, with years outside `0000` to `9999`
+/// in the expanded representation, such as `+133999-01-01`.
+///
+/// # Panics
+///
+/// Panics on formatting if the date is not valid, as described in
+/// [format_date].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct DefmtDate(pub (i32, u8, u8));
+
+/// `(year, month, day, hours, minutes, seconds)` tuple formatted as an ISO 8601
+/// date and time in UTC
+///
+/// Formatted as for example `2023-05-20T09:24:38Z`, with the date as in
+/// [DefmtDate].
+///
+/// # Panics
+///
+/// Panics on formatting if the date is not valid, as described in
+/// [format_date].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct DefmtDateTime(pub (i32, u8, u8, u8, u8, u8));
+
+/// Format a date to the buffer and return it as a string
+#[inline]
+fn date_str(date: (i32, u8, u8), buf: &mut [u8; ISO_DATE_MAX_LEN]) -> &str {
+    let len = format_date(date, buf);
+    // The formatted date is always ASCII
+    core::str::from_utf8(&buf[..len]).unwrap_or("")
+}
+
+impl Format for DefmtDate {
+    fn format(&self, f: Formatter) {
+        let mut buf = [0; ISO_DATE_MAX_LEN];
+        write!(f, "{=str}", date_str(self.0, &mut buf));
+    }
+}
+
+impl Format for DefmtDateTime {
+    fn format(&self, f: Formatter) {
+        let (y, m, d, hh, mm, ss) = self.0;
+        let mut buf = [0; ISO_DATE_MAX_LEN];
+        write!(f, "{=str}T{=u8:02}:{=u8:02}:{=u8:02}Z", date_str((y, m, d), &mut buf), hh, mm, ss);
+    }
+}
@@ -0,0 +1,97 @@
+//! Date of Easter
+//!
+//! Easter Sunday is the first Sunday after the ecclesiastical full moon on or
+//! after March 21st, computed with tabular rules known as the computus. The
+//! Western churches use the Gregorian computus, while the Eastern Orthodox
+//! churches use the Julian computus, giving dates in the Julian calendar.
+//! Many other movable holidays are at a fixed offset from Easter Sunday.
+
+use crate::calendar::julian::{julian_date_to_rd, JULIAN_YEAR_MAX, JULIAN_YEAR_MIN};
+use crate::{rd_to_date, YEAR_MAX, YEAR_MIN};
+
+/// Determine the date of Easter Sunday in the Gregorian calendar
+///
+/// Given a year returns the `(year, month, day)` tuple of Easter Sunday as
+/// observed by the Western churches, between March 22nd and April 25th.
+///
+/// # Panics
+///
+/// Year must be between [YEAR_MIN] and [YEAR_MAX]. Bounds are checked using
+/// `debug_assert` only, so that the checks are not present in release builds,
+/// similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::easter::easter_sunday;
+///
+/// assert_eq!(easter_sunday(2023), (2023, 4, 9));
+/// assert_eq!(easter_sunday(2024), (2024, 3, 31));
+/// assert_eq!(easter_sunday(2285), (2285, 3, 22));
+/// assert_eq!(easter_sunday(2038), (2038, 4, 25));
+/// ```
+///
+/// # Algorithm
+///
+/// Anonymous Gregorian algorithm, as given by Jean Meeus in Astronomical
+/// Algorithms, using Euclidean division to support negative years.
+#[inline]
+pub const fn easter_sunday(y: i32) -> (i32, u8, u8) {
+    debug_assert!(y >= YEAR_MIN && y <= YEAR_MAX, "given year is out of range");
+    let a = y.rem_euclid(19);
+    let b = y.div_euclid(100);
+    let c = y.rem_euclid(100);
+    let d = b.div_euclid(4);
+    let e = b.rem_euclid(4);
+    let f = (b + 8).div_euclid(25);
+    let g = (b - f + 1).div_euclid(3);
+    let h = (19 * a + b - d - g + 15).rem_euclid(30);
+    let i = c / 4;
+    let k = c % 4;
+    let l = (32 + 2 * e + 2 * i - h - k).rem_euclid(7);
+    let m = (a + 11 * h + 22 * l) / 451;
+    let n = h + l - 7 * m + 114;
+    (y, (n / 31) as u8, (n % 31 + 1) as u8)
+}
+
+/// Determine the date of Easter Sunday in the Julian calendar
+///
+/// Given a year returns a tuple of `(year, month, day)` tuples for Easter
+/// Sunday as observed by the Eastern Orthodox churches, the first in the
+/// Julian calendar, between March 22nd and April 25th, and the second in the
+/// Gregorian calendar.
+///
+/// # Panics
+///
+/// Year must be between `JULIAN_YEAR_MIN + 1` and `JULIAN_YEAR_MAX - 1`, see
+/// [JULIAN_YEAR_MIN] and [JULIAN_YEAR_MAX]. Bounds are checked using
+/// `debug_assert` only, so that the checks are not present in release builds,
+/// similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::easter::julian_easter_sunday;
+///
+/// assert_eq!(julian_easter_sunday(2023), ((2023, 4, 3), (2023, 4, 16)));
+/// assert_eq!(julian_easter_sunday(2024), ((2024, 4, 22), (2024, 5, 5)));
+/// assert_eq!(julian_easter_sunday(2025), ((2025, 4, 7), (2025, 4, 20)));
+/// ```
+///
+/// # Algorithm
+///
+/// Julian algorithm as given by Jean Meeus in Astronomical Algorithms, using
+/// Euclidean division to support negative years, followed by conversion with
+/// [julian_date_to_rd] and [rd_to_date].
+#[inline]
+pub const fn julian_easter_sunday(y: i32) -> ((i32, u8, u8), (i32, u8, u8)) {
+    debug_assert!(y > JULIAN_YEAR_MIN && y < JULIAN_YEAR_MAX, "given year is out of range");
+    let a = y.rem_euclid(4);
+    let b = y.rem_euclid(7);
+    let c = y.rem_euclid(19);
+    let d = (19 * c + 15) % 30;
+    let e = (2 * a + 4 * b - d + 34).rem_euclid(7);
+    let n = d + e + 114;
+    let julian = (y, (n / 31) as u8, (n % 31 + 1) as u8);
+    (julian, rd_to_date(julian_date_to_rd(julian)))
+}
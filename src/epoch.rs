@@ -0,0 +1,1900 @@
+//! Conversions between Unix epoch and other commonly used epochs
+//!
+//! Many file formats, protocols and applications count days or seconds from
+//! some other epoch than the Unix epoch (January 1st, 1970). These functions
+//! convert between such values and the Rata Die and Unix seconds used in the
+//! rest of this crate, so that the offsets and the bounds do not need to be
+//! replicated by every caller.
+
+use crate::{consts, days_in_month, RD_MAX, RD_MIN, RD_SECONDS_MAX, RD_SECONDS_MIN, SECS_IN_DAY};
+
+/// Rata Die of the Modified Julian Day epoch (November 17th, 1858)
+pub const MJD_EPOCH_RD: i32 = -40587;
+
+/// Minimum Modified Julian Day for conversion
+///
+/// Corresponds to [RD_MIN].
+pub const MJD_MIN: i32 = RD_MIN - MJD_EPOCH_RD;
+
+/// Maximum Modified Julian Day for conversion
+///
+/// Corresponds to [RD_MAX].
+pub const MJD_MAX: i32 = RD_MAX - MJD_EPOCH_RD;
+
+/// Minimum Modified Julian Day in seconds for conversion
+///
+/// Corresponds to [RD_SECONDS_MIN].
+pub const MJD_SECONDS_MIN: i64 = RD_SECONDS_MIN - MJD_EPOCH_RD as i64 * SECS_IN_DAY;
+
+/// Maximum Modified Julian Day in seconds for conversion
+///
+/// Corresponds to [RD_SECONDS_MAX].
+pub const MJD_SECONDS_MAX: i64 = RD_SECONDS_MAX - MJD_EPOCH_RD as i64 * SECS_IN_DAY;
+
+/// Convert Rata Die to Modified Julian Day
+///
+/// Given a day counting from Unix epoch (January 1st, 1970) returns the
+/// [Modified Julian Day](https://en.wikipedia.org/wiki/Julian_day), which
+/// counts days from November 17th, 1858.
+///
+/// # Panics
+///
+/// Argument must be between [RD_MIN] and [RD_MAX] inclusive. Bounds are checked
+/// using `debug_assert` only, so that the checks are not present in release
+/// builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{date_to_rd, epoch::rd_to_mjd};
+///
+/// assert_eq!(rd_to_mjd(0), 40587);
+/// assert_eq!(rd_to_mjd(date_to_rd((1858, 11, 17))), 0);
+/// assert_eq!(rd_to_mjd(date_to_rd((2000, 1, 1))), 51544);
+/// ```
+///
+/// # Algorithm
+///
+/// Simple addition of the epoch offset.
+#[inline]
+pub const fn rd_to_mjd(rd: i32) -> i32 {
+    debug_assert!(rd >= RD_MIN && rd <= RD_MAX, "given rata die is out of range");
+    rd - MJD_EPOCH_RD
+}
+
+/// Convert Modified Julian Day to Rata Die
+///
+/// Given a [Modified Julian Day](https://en.wikipedia.org/wiki/Julian_day),
+/// which counts days from November 17th, 1858, returns the day counting from
+/// Unix epoch (January 1st, 1970).
+///
+/// # Panics
+///
+/// Argument must be between [MJD_MIN] and [MJD_MAX] inclusive. Bounds are
+/// checked using `debug_assert` only, so that the checks are not present in
+/// release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{rd_to_date, epoch::mjd_to_rd};
+///
+/// assert_eq!(mjd_to_rd(40587), 0);
+/// assert_eq!(rd_to_date(mjd_to_rd(0)), (1858, 11, 17));
+/// assert_eq!(rd_to_date(mjd_to_rd(60000)), (2023, 2, 25));
+/// ```
+///
+/// # Algorithm
+///
+/// Simple subtraction of the epoch offset.
+#[inline]
+pub const fn mjd_to_rd(mjd: i32) -> i32 {
+    debug_assert!(mjd >= MJD_MIN && mjd <= MJD_MAX, "given modified julian day is out of range");
+    mjd + MJD_EPOCH_RD
+}
+
+/// Convert Unix seconds to Modified Julian Day seconds
+///
+/// Given seconds counting from Unix epoch (January 1st, 1970) returns the
+/// seconds counting from the Modified Julian Day epoch (November 17th, 1858).
+/// Dividing the result by `86400` gives the Modified Julian Day and the
+/// remainder the seconds within that day.
+///
+/// # Panics
+///
+/// Argument must be between [RD_SECONDS_MIN] and [RD_SECONDS_MAX] inclusive.
+/// Bounds are checked using `debug_assert` only, so that the checks are not
+/// present in release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::epoch::secs_to_mjd_secs;
+///
+/// assert_eq!(secs_to_mjd_secs(0), 3506716800);
+/// assert_eq!(secs_to_mjd_secs(-3506716800), 0);
+/// assert_eq!(secs_to_mjd_secs(1684574678), 5191291478);
+/// ```
+///
+/// # Algorithm
+///
+/// Simple addition of the epoch offset.
+#[inline]
+pub const fn secs_to_mjd_secs(secs: i64) -> i64 {
+    debug_assert!(
+        secs >= RD_SECONDS_MIN && secs <= RD_SECONDS_MAX,
+        "given seconds value is out of range"
+    );
+    secs - MJD_EPOCH_RD as i64 * SECS_IN_DAY
+}
+
+/// Convert Modified Julian Day seconds to Unix seconds
+///
+/// Given seconds counting from the Modified Julian Day epoch (November 17th,
+/// 1858) returns the seconds counting from Unix epoch (January 1st, 1970).
+///
+/// # Panics
+///
+/// Argument must be between [MJD_SECONDS_MIN] and [MJD_SECONDS_MAX] inclusive.
+/// Bounds are checked using `debug_assert` only, so that the checks are not
+/// present in release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::epoch::mjd_secs_to_secs;
+///
+/// assert_eq!(mjd_secs_to_secs(3506716800), 0);
+/// assert_eq!(mjd_secs_to_secs(0), -3506716800);
+/// assert_eq!(mjd_secs_to_secs(5191291478), 1684574678);
+/// ```
+///
+/// # Algorithm
+///
+/// Simple subtraction of the epoch offset.
+#[inline]
+pub const fn mjd_secs_to_secs(secs: i64) -> i64 {
+    debug_assert!(
+        secs >= MJD_SECONDS_MIN && secs <= MJD_SECONDS_MAX,
+        "given seconds value is out of range"
+    );
+    secs + MJD_EPOCH_RD as i64 * SECS_IN_DAY
+}
+
+/// Rata Die of the traditional Rata Die epoch (December 31st, 0)
+///
+/// The traditional Rata Die counts January 1st, 1 as day `1`, which makes the
+/// preceding day its zero point.
+pub const RD1_EPOCH_RD: i32 = -719163;
+
+/// Minimum traditional Rata Die for conversion
+///
+/// Corresponds to [RD_MIN].
+pub const RD1_MIN: i32 = RD_MIN - RD1_EPOCH_RD;
+
+/// Maximum traditional Rata Die for conversion
+///
+/// Corresponds to [RD_MAX].
+pub const RD1_MAX: i32 = RD_MAX - RD1_EPOCH_RD;
+
+/// Convert Rata Die to traditional Rata Die
+///
+/// Given a day counting from Unix epoch (January 1st, 1970) returns the
+/// traditional [Rata Die](https://en.wikipedia.org/wiki/Rata_Die) fixed day
+/// number, as used by Reingold and Dershowitz in *Calendrical Calculations*,
+/// where January 1st, 1 is day `1`.
+///
+/// # Panics
+///
+/// Argument must be between [RD_MIN] and [RD_MAX] inclusive. Bounds are checked
+/// using `debug_assert` only, so that the checks are not present in release
+/// builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{date_to_rd, epoch::rd_to_rd1};
+///
+/// assert_eq!(rd_to_rd1(0), 719163);
+/// assert_eq!(rd_to_rd1(date_to_rd((1, 1, 1))), 1);
+/// assert_eq!(rd_to_rd1(date_to_rd((1945, 11, 12))), 710347);
+/// ```
+///
+/// # Algorithm
+///
+/// Simple addition of the epoch offset.
+#[inline]
+pub const fn rd_to_rd1(rd: i32) -> i32 {
+    debug_assert!(rd >= RD_MIN && rd <= RD_MAX, "given rata die is out of range");
+    rd - RD1_EPOCH_RD
+}
+
+/// Convert traditional Rata Die to Rata Die
+///
+/// Given a traditional [Rata Die](https://en.wikipedia.org/wiki/Rata_Die)
+/// fixed day number, as used by Reingold and Dershowitz in *Calendrical
+/// Calculations*, where January 1st, 1 is day `1`, returns the day counting
+/// from Unix epoch (January 1st, 1970).
+///
+/// # Panics
+///
+/// Argument must be between [RD1_MIN] and [RD1_MAX] inclusive. Bounds are
+/// checked using `debug_assert` only, so that the checks are not present in
+/// release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{rd_to_date, epoch::rd1_to_rd};
+///
+/// assert_eq!(rd1_to_rd(719163), 0);
+/// assert_eq!(rd_to_date(rd1_to_rd(1)), (1, 1, 1));
+/// assert_eq!(rd_to_date(rd1_to_rd(710347)), (1945, 11, 12));
+/// ```
+///
+/// # Algorithm
+///
+/// Simple subtraction of the epoch offset.
+#[inline]
+pub const fn rd1_to_rd(rd1: i32) -> i32 {
+    debug_assert!(rd1 >= RD1_MIN && rd1 <= RD1_MAX, "given traditional rata die is out of range");
+    rd1 + RD1_EPOCH_RD
+}
+
+/// Rata Die of the Excel 1900 date system epoch for serials after the
+/// fictitious February 29th, 1900 (December 30th, 1899)
+const EXCEL1900_EPOCH_RD: i32 = -25569;
+
+/// Serial of the fictitious February 29th, 1900 in the Excel 1900 date system
+const EXCEL1900_LEAP_BUG: i32 = 60;
+
+/// Minimum serial in the Excel 1900 date system (January 1st, 1900)
+pub const EXCEL1900_MIN: i32 = 1;
+
+/// Maximum serial in the Excel 1900 date system (December 31st, 9999)
+pub const EXCEL1900_MAX: i32 = 2958465;
+
+/// Convert Rata Die to Excel 1900 date system serial
+///
+/// Given a day counting from Unix epoch (January 1st, 1970) returns the serial
+/// number used by the default Excel 1900 date system, where January 1st, 1900
+/// is `1`. For compatibility with Lotus 1-2-3, Excel considers 1900 to be a
+/// leap year, so serial `60` is the nonexistent February 29th, 1900 and all the
+/// serials starting from March 1st, 1900 are one larger than the count of days.
+///
+/// # Errors
+///
+/// Returns `None` if the date is before January 1st, 1900 or after December
+/// 31st, 9999, as those are not supported by Excel.
+///
+/// # Panics
+///
+/// Argument must be between [RD_MIN] and [RD_MAX] inclusive. Bounds are checked
+/// using `debug_assert` only, so that the checks are not present in release
+/// builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{date_to_rd, epoch::rd_to_excel1900};
+///
+/// assert_eq!(rd_to_excel1900(date_to_rd((1900, 1, 1))), Some(1));
+/// assert_eq!(rd_to_excel1900(date_to_rd((1900, 2, 28))), Some(59));
+/// assert_eq!(rd_to_excel1900(date_to_rd((1900, 3, 1))), Some(61));
+/// assert_eq!(rd_to_excel1900(date_to_rd((2023, 5, 12))), Some(45058));
+/// assert_eq!(rd_to_excel1900(date_to_rd((1899, 12, 31))), None);
+/// ```
+///
+/// # Algorithm
+///
+/// Addition of the epoch offset, with the offset depending on which side of
+/// the fictitious leap day the date is.
+#[inline]
+pub const fn rd_to_excel1900(rd: i32) -> Option<i32> {
+    debug_assert!(rd >= RD_MIN && rd <= RD_MAX, "given rata die is out of range");
+    let serial = rd - EXCEL1900_EPOCH_RD;
+    if serial > EXCEL1900_LEAP_BUG && serial <= EXCEL1900_MAX {
+        Some(serial)
+    } else if serial > EXCEL1900_MIN && serial <= EXCEL1900_LEAP_BUG {
+        Some(serial - 1)
+    } else {
+        None
+    }
+}
+
+/// Convert Excel 1900 date system serial to Rata Die
+///
+/// Given a serial number used by the default Excel 1900 date system, where
+/// January 1st, 1900 is `1`, returns the day counting from Unix epoch (January
+/// 1st, 1970). For compatibility with Lotus 1-2-3, Excel considers 1900 to be a
+/// leap year, so serial `60` is the nonexistent February 29th, 1900 and all the
+/// serials starting from March 1st, 1900 are one larger than the count of days.
+///
+/// # Errors
+///
+/// Returns `None` if the serial is the fictitious February 29th, 1900 (`60`) or
+/// is not between [EXCEL1900_MIN] and [EXCEL1900_MAX] inclusive.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{date_to_rd, epoch::excel1900_to_rd};
+///
+/// assert_eq!(excel1900_to_rd(1), Some(date_to_rd((1900, 1, 1))));
+/// assert_eq!(excel1900_to_rd(59), Some(date_to_rd((1900, 2, 28))));
+/// assert_eq!(excel1900_to_rd(60), None);
+/// assert_eq!(excel1900_to_rd(61), Some(date_to_rd((1900, 3, 1))));
+/// assert_eq!(excel1900_to_rd(45058), Some(date_to_rd((2023, 5, 12))));
+/// ```
+///
+/// # Algorithm
+///
+/// Subtraction of the epoch offset, with the offset depending on which side of
+/// the fictitious leap day the serial is.
+#[inline]
+pub const fn excel1900_to_rd(serial: i32) -> Option<i32> {
+    if serial > EXCEL1900_LEAP_BUG && serial <= EXCEL1900_MAX {
+        Some(serial + EXCEL1900_EPOCH_RD)
+    } else if serial >= EXCEL1900_MIN && serial < EXCEL1900_LEAP_BUG {
+        Some(serial + EXCEL1900_EPOCH_RD + 1)
+    } else {
+        None
+    }
+}
+
+/// Rata Die of the Excel 1904 date system epoch (January 1st, 1904)
+const EXCEL1904_EPOCH_RD: i32 = -24107;
+
+/// Minimum serial in the Excel 1904 date system (January 1st, 1904)
+pub const EXCEL1904_MIN: i32 = 0;
+
+/// Maximum serial in the Excel 1904 date system (December 31st, 9999)
+pub const EXCEL1904_MAX: i32 = 2957003;
+
+/// Convert Rata Die to Excel 1904 date system serial
+///
+/// Given a day counting from Unix epoch (January 1st, 1970) returns the serial
+/// number used by the Excel 1904 date system, where January 1st, 1904 is `0`.
+/// This date system was the default in legacy Excel for Mac and is still used
+/// by some workbooks. Unlike the 1900 date system, it has no fictitious leap
+/// day.
+///
+/// # Errors
+///
+/// Returns `None` if the date is before January 1st, 1904 or after December
+/// 31st, 9999, as those are not supported by Excel.
+///
+/// # Panics
+///
+/// Argument must be between [RD_MIN] and [RD_MAX] inclusive. Bounds are checked
+/// using `debug_assert` only, so that the checks are not present in release
+/// builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{date_to_rd, epoch::rd_to_excel1904};
+///
+/// assert_eq!(rd_to_excel1904(date_to_rd((1904, 1, 1))), Some(0));
+/// assert_eq!(rd_to_excel1904(date_to_rd((2023, 5, 12))), Some(43596));
+/// assert_eq!(rd_to_excel1904(date_to_rd((1903, 12, 31))), None);
+/// ```
+///
+/// # Algorithm
+///
+/// Simple addition of the epoch offset with range validation.
+#[inline]
+pub const fn rd_to_excel1904(rd: i32) -> Option<i32> {
+    debug_assert!(rd >= RD_MIN && rd <= RD_MAX, "given rata die is out of range");
+    let serial = rd - EXCEL1904_EPOCH_RD;
+    if serial >= EXCEL1904_MIN && serial <= EXCEL1904_MAX {
+        Some(serial)
+    } else {
+        None
+    }
+}
+
+/// Convert Excel 1904 date system serial to Rata Die
+///
+/// Given a serial number used by the Excel 1904 date system, where January
+/// 1st, 1904 is `0`, returns the day counting from Unix epoch (January 1st,
+/// 1970). This date system was the default in legacy Excel for Mac and is
+/// still used by some workbooks. Unlike the 1900 date system, it has no
+/// fictitious leap day.
+///
+/// # Errors
+///
+/// Returns `None` if the serial is not between [EXCEL1904_MIN] and
+/// [EXCEL1904_MAX] inclusive.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{date_to_rd, epoch::excel1904_to_rd};
+///
+/// assert_eq!(excel1904_to_rd(0), Some(date_to_rd((1904, 1, 1))));
+/// assert_eq!(excel1904_to_rd(43596), Some(date_to_rd((2023, 5, 12))));
+/// assert_eq!(excel1904_to_rd(-1), None);
+/// ```
+///
+/// # Algorithm
+///
+/// Simple subtraction of the epoch offset with range validation.
+#[inline]
+pub const fn excel1904_to_rd(serial: i32) -> Option<i32> {
+    if serial >= EXCEL1904_MIN && serial <= EXCEL1904_MAX {
+        Some(serial + EXCEL1904_EPOCH_RD)
+    } else {
+        None
+    }
+}
+
+/// Rata Die of the OLE Automation date epoch (December 30th, 1899)
+pub const OADATE_EPOCH_RD: i32 = -25569;
+
+/// Exclusive lower bound of OLE Automation dates (January 1st, 100)
+const OADATE_MIN: f64 = -657435.0;
+
+/// Exclusive upper bound of OLE Automation dates (January 1st, 10000)
+const OADATE_MAX: f64 = 2958466.0;
+
+/// Convert seconds and nanoseconds to OLE Automation date
+///
+/// Given a `(seconds, nanoseconds)` tuple counting from Unix epoch (January
+/// 1st, 1970) returns the OLE Automation date used by COM, VBA and .NET
+/// `DateTime.ToOADate`. The integral part of the value counts days from
+/// December 30th, 1899 and the fractional part is the time of day. For dates
+/// before the epoch the time of day is *subtracted* from the negative day, so
+/// that for example `-1.25` is December 29th, 1899 at 06:00.
+///
+/// The value has a precision of roughly one microsecond for modern dates, so
+/// nanoseconds are not preserved.
+///
+/// # Panics
+///
+/// Seconds must be between [RD_SECONDS_MIN] and [RD_SECONDS_MAX] inclusive.
+/// Nanoseconds must be between `0` and `999_999_999`. Bounds are checked using
+/// `debug_assert` only, so that the checks are not present in release builds,
+/// similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{datetime_to_secs, epoch::secs_to_oadate};
+///
+/// assert_eq!(secs_to_oadate((datetime_to_secs((1899, 12, 30, 0, 0, 0)), 0)), 0.0);
+/// assert_eq!(secs_to_oadate((datetime_to_secs((1900, 1, 1, 18, 0, 0)), 0)), 2.75);
+/// assert_eq!(secs_to_oadate((datetime_to_secs((1899, 12, 29, 6, 0, 0)), 0)), -1.25);
+/// assert_eq!(secs_to_oadate((0, 500_000_000)), 25569.0 + 0.5 / 86400.0);
+/// ```
+///
+/// # Algorithm
+///
+/// Splits the seconds to days and time of day, and combines them with the sign
+/// of the time of day following the sign of the day.
+#[inline]
+pub fn secs_to_oadate((secs, nsecs): (i64, u32)) -> f64 {
+    debug_assert!(
+        secs >= RD_SECONDS_MIN && secs <= RD_SECONDS_MAX,
+        "given seconds value is out of range"
+    );
+    debug_assert!(
+        nsecs >= consts::NANOSECOND_MIN && nsecs <= consts::NANOSECOND_MAX,
+        "given nanoseconds is out of range"
+    );
+    let days = secs.div_euclid(SECS_IN_DAY) - OADATE_EPOCH_RD as i64;
+    let tod = secs.rem_euclid(SECS_IN_DAY) as f64 + nsecs as f64 / 1e9;
+    let frac = tod / SECS_IN_DAY as f64;
+    if days >= 0 {
+        days as f64 + frac
+    } else {
+        days as f64 - frac
+    }
+}
+
+/// Convert OLE Automation date to seconds and nanoseconds
+///
+/// Given an OLE Automation date used by COM, VBA and .NET
+/// `DateTime.FromOADate` returns a `(seconds, nanoseconds)` tuple counting from
+/// Unix epoch (January 1st, 1970). The integral part of the value counts days
+/// from December 30th, 1899 and the fractional part is the time of day. For
+/// dates before the epoch the time of day is *subtracted* from the negative
+/// day, so that for example `-1.25` is December 29th, 1899 at 06:00. This also
+/// means that values between `-1.0` and `0.0` are the same as the respective
+/// positive values.
+///
+/// The result is rounded to the nearest millisecond, similar to .NET.
+///
+/// # Errors
+///
+/// Returns `None` if the value is NaN or is not between `-657435.0` (January
+/// 1st, 100) and `2958466.0` (January 1st, 10000) exclusive, or if it rounds
+/// to a time outside that range.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{datetime_to_secs, epoch::oadate_to_secs};
+///
+/// assert_eq!(oadate_to_secs(0.0), Some((datetime_to_secs((1899, 12, 30, 0, 0, 0)), 0)));
+/// assert_eq!(oadate_to_secs(2.75), Some((datetime_to_secs((1900, 1, 1, 18, 0, 0)), 0)));
+/// assert_eq!(oadate_to_secs(-1.25), Some((datetime_to_secs((1899, 12, 29, 6, 0, 0)), 0)));
+/// assert_eq!(oadate_to_secs(-0.5), oadate_to_secs(0.5));
+/// assert_eq!(oadate_to_secs(-1.9999999999), oadate_to_secs(-2.0));
+/// assert_eq!(oadate_to_secs(f64::NAN), None);
+/// ```
+///
+/// # Algorithm
+///
+/// Same as .NET `DateTime.FromOADate`: the value is converted to milliseconds
+/// rounded away from zero, and for negative values the time of day is
+/// reflected so that it counts forward from the start of the day.
+#[inline]
+pub fn oadate_to_secs(oadate: f64) -> Option<(i64, u32)> {
+    if !(oadate > OADATE_MIN && oadate < OADATE_MAX) {
+        return None;
+    }
+    const MS_IN_DAY: i64 = SECS_IN_DAY * 1000;
+    let half = if oadate >= 0.0 { 0.5 } else { -0.5 };
+    let mut ms = (oadate * MS_IN_DAY as f64 + half) as i64; // truncates towards zero
+    if ms < 0 {
+        ms -= (ms % MS_IN_DAY) * 2;
+    }
+    if ms < (OADATE_MIN as i64 + 1) * MS_IN_DAY || ms >= OADATE_MAX as i64 * MS_IN_DAY {
+        return None;
+    }
+    let ms = ms + OADATE_EPOCH_RD as i64 * MS_IN_DAY;
+    Some((ms.div_euclid(1000), ms.rem_euclid(1000) as u32 * 1_000_000))
+}
+
+/// Unix seconds of the Apple Cocoa reference date (January 1st, 2001)
+pub const COCOA_EPOCH_SECS: i64 = 978307200;
+
+/// Minimum Cocoa seconds for conversion
+///
+/// Corresponds to [RD_SECONDS_MIN].
+pub const COCOA_SECONDS_MIN: i64 = RD_SECONDS_MIN - COCOA_EPOCH_SECS;
+
+/// Maximum Cocoa seconds for conversion
+///
+/// Corresponds to [RD_SECONDS_MAX].
+pub const COCOA_SECONDS_MAX: i64 = RD_SECONDS_MAX - COCOA_EPOCH_SECS;
+
+/// Convert Unix seconds to Cocoa seconds
+///
+/// Given seconds counting from Unix epoch (January 1st, 1970) returns the
+/// seconds counting from the reference date of Apple Cocoa `NSDate`,
+/// `CFAbsoluteTime` and Core Data (January 1st, 2001).
+///
+/// # Panics
+///
+/// Argument must be between [RD_SECONDS_MIN] and [RD_SECONDS_MAX] inclusive.
+/// Bounds are checked using `debug_assert` only, so that the checks are not
+/// present in release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{datetime_to_secs, epoch::secs_to_cocoa_secs};
+///
+/// assert_eq!(secs_to_cocoa_secs(datetime_to_secs((2001, 1, 1, 0, 0, 0))), 0);
+/// assert_eq!(secs_to_cocoa_secs(0), -978307200);
+/// assert_eq!(secs_to_cocoa_secs(1684574678), 706267478);
+/// ```
+///
+/// # Algorithm
+///
+/// Simple subtraction of the epoch offset.
+#[inline]
+pub const fn secs_to_cocoa_secs(secs: i64) -> i64 {
+    debug_assert!(
+        secs >= RD_SECONDS_MIN && secs <= RD_SECONDS_MAX,
+        "given seconds value is out of range"
+    );
+    secs - COCOA_EPOCH_SECS
+}
+
+/// Convert Cocoa seconds to Unix seconds
+///
+/// Given seconds counting from the reference date of Apple Cocoa `NSDate`,
+/// `CFAbsoluteTime` and Core Data (January 1st, 2001) returns the seconds
+/// counting from Unix epoch (January 1st, 1970).
+///
+/// # Panics
+///
+/// Argument must be between [COCOA_SECONDS_MIN] and [COCOA_SECONDS_MAX]
+/// inclusive. Bounds are checked using `debug_assert` only, so that the checks
+/// are not present in release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{datetime_to_secs, epoch::cocoa_secs_to_secs};
+///
+/// assert_eq!(cocoa_secs_to_secs(0), datetime_to_secs((2001, 1, 1, 0, 0, 0)));
+/// assert_eq!(cocoa_secs_to_secs(-978307200), 0);
+/// assert_eq!(cocoa_secs_to_secs(706267478), 1684574678);
+/// ```
+///
+/// # Algorithm
+///
+/// Simple addition of the epoch offset.
+#[inline]
+pub const fn cocoa_secs_to_secs(secs: i64) -> i64 {
+    debug_assert!(
+        secs >= COCOA_SECONDS_MIN && secs <= COCOA_SECONDS_MAX,
+        "given seconds value is out of range"
+    );
+    secs + COCOA_EPOCH_SECS
+}
+
+/// Convert seconds and nanoseconds to Cocoa `f64` seconds
+///
+/// Given a `(seconds, nanoseconds)` tuple counting from Unix epoch (January
+/// 1st, 1970) returns the floating point seconds counting from the reference
+/// date of Apple Cocoa (January 1st, 2001), as stored by `NSDate`,
+/// `CFAbsoluteTime`, binary property lists and Core Data databases.
+///
+/// The value has a precision of roughly one microsecond for modern dates, so
+/// nanoseconds are not preserved.
+///
+/// # Panics
+///
+/// Seconds must be between [RD_SECONDS_MIN] and [RD_SECONDS_MAX] inclusive.
+/// Nanoseconds must be between `0` and `999_999_999`. Bounds are checked using
+/// `debug_assert` only, so that the checks are not present in release builds,
+/// similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{datetime_to_secs, epoch::secs_to_cocoa};
+///
+/// assert_eq!(secs_to_cocoa((datetime_to_secs((2001, 1, 1, 0, 0, 0)), 0)), 0.0);
+/// assert_eq!(secs_to_cocoa((datetime_to_secs((2000, 12, 31, 23, 59, 59)), 500_000_000)), -0.5);
+/// assert_eq!(secs_to_cocoa((1684574678, 250_000_000)), 706267478.25);
+/// ```
+///
+/// # Algorithm
+///
+/// Subtraction of the epoch offset, followed by conversion to `f64`.
+#[inline]
+pub fn secs_to_cocoa((secs, nsecs): (i64, u32)) -> f64 {
+    debug_assert!(
+        nsecs >= consts::NANOSECOND_MIN && nsecs <= consts::NANOSECOND_MAX,
+        "given nanoseconds is out of range"
+    );
+    let secs = secs_to_cocoa_secs(secs);
+    if secs < 0 && nsecs > 0 {
+        // avoid losing precision on the fraction for values close to zero
+        (secs + 1) as f64 - (1_000_000_000 - nsecs) as f64 / 1e9
+    } else {
+        secs as f64 + nsecs as f64 / 1e9
+    }
+}
+
+/// Convert Cocoa `f64` seconds to seconds and nanoseconds
+///
+/// Given floating point seconds counting from the reference date of Apple
+/// Cocoa (January 1st, 2001), as stored by `NSDate`, `CFAbsoluteTime`, binary
+/// property lists and Core Data databases, returns a `(seconds, nanoseconds)`
+/// tuple counting from Unix epoch (January 1st, 1970).
+///
+/// The result is rounded to the nearest microsecond, as the value does not
+/// have any more precision for modern dates.
+///
+/// # Errors
+///
+/// Returns `None` if the value is NaN or is not between [COCOA_SECONDS_MIN]
+/// and [COCOA_SECONDS_MAX] inclusive.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{datetime_to_secs, epoch::cocoa_to_secs};
+///
+/// assert_eq!(cocoa_to_secs(0.0), Some((datetime_to_secs((2001, 1, 1, 0, 0, 0)), 0)));
+/// assert_eq!(cocoa_to_secs(-0.5), Some((datetime_to_secs((2000, 12, 31, 23, 59, 59)), 500_000_000)));
+/// assert_eq!(cocoa_to_secs(706267478.25), Some((1684574678, 250_000_000)));
+/// assert_eq!(cocoa_to_secs(f64::NAN), None);
+/// ```
+///
+/// # Algorithm
+///
+/// Splits the value to whole seconds rounded towards negative infinity and the
+/// fraction, and converts the fraction to microseconds with rounding.
+#[inline]
+pub fn cocoa_to_secs(secs: f64) -> Option<(i64, u32)> {
+    if !(secs >= COCOA_SECONDS_MIN as f64 && secs <= COCOA_SECONDS_MAX as f64) {
+        return None;
+    }
+    let mut whole = secs as i64; // truncates towards zero
+    if (whole as f64) > secs {
+        whole -= 1;
+    }
+    let us = ((secs - whole as f64) * 1e6 + 0.5) as i64;
+    let whole = whole + us / 1_000_000;
+    if whole > COCOA_SECONDS_MAX {
+        return None;
+    }
+    Some((cocoa_secs_to_secs(whole), (us % 1_000_000) as u32 * 1000))
+}
+
+/// Convert MS-DOS date and time to year, month, day, hours, minutes and seconds
+///
+/// Given the 16-bit date and time fields used by the FAT filesystem, ZIP
+/// archives and the MS-DOS API returns a `(year, month, day, hours, minutes,
+/// seconds)` tuple. The date field contains the year offset from 1980 in bits
+/// 9–15, the month in bits 5–8 and the day in bits 0–4. The time field
+/// contains the hours in bits 11–15, the minutes in bits 5–10 and the seconds
+/// divided by two in bits 0–4, so only even seconds can be represented.
+///
+/// # Errors
+///
+/// Returns `None` if any of the fields is out of range, for example a zero
+/// month or day, a day past the end of the month, or an hour over `23`.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::epoch::dos_datetime_to_datetime;
+///
+/// assert_eq!(dos_datetime_to_datetime(0x0021, 0x0000), Some((1980, 1, 1, 0, 0, 0)));
+/// assert_eq!(dos_datetime_to_datetime(0x56b4, 0x4b13), Some((2023, 5, 20, 9, 24, 38)));
+/// assert_eq!(dos_datetime_to_datetime(0xff9f, 0xbf7d), Some((2107, 12, 31, 23, 59, 58)));
+/// assert_eq!(dos_datetime_to_datetime(0x0000, 0x0000), None);
+/// ```
+///
+/// # Algorithm
+///
+/// Bit field extraction with validation of each field.
+#[inline]
+pub const fn dos_datetime_to_datetime(date: u16, time: u16) -> Option<(i32, u8, u8, u8, u8, u8)> {
+    let y = (date >> 9) as i32 + 1980;
+    let m = ((date >> 5) & 0xf) as u8;
+    let d = (date & 0x1f) as u8;
+    let hh = (time >> 11) as u8;
+    let mm = ((time >> 5) & 0x3f) as u8;
+    let ss = (time & 0x1f) as u8 * 2;
+    if m < consts::MONTH_MIN || m > consts::MONTH_MAX || d < consts::DAY_MIN || d > days_in_month(y, m) {
+        return None;
+    }
+    if hh > consts::HOUR_MAX || mm > consts::MINUTE_MAX || ss > consts::SECOND_MAX {
+        return None;
+    }
+    Some((y, m, d, hh, mm, ss))
+}
+
+/// Convert year, month, day, hours, minutes and seconds to MS-DOS date and time
+///
+/// Given a `(year, month, day, hours, minutes, seconds)` tuple returns the
+/// 16-bit `(date, time)` fields used by the FAT filesystem, ZIP archives and
+/// the MS-DOS API. Only even seconds can be represented, so odd seconds are
+/// rounded down.
+///
+/// # Errors
+///
+/// Returns `None` if the year is before 1980 or after 2107.
+///
+/// # Panics
+///
+/// Month must be between `1` and `12`. Day must be between `1` and the number
+/// of days in the month in question. Hours must be between `0` and `23`.
+/// Minutes must be between `0` and `59`. Seconds must be between `0` and `59`.
+/// Bounds are checked using `debug_assert` only, so that the checks are not
+/// present in release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::epoch::datetime_to_dos_datetime;
+///
+/// assert_eq!(datetime_to_dos_datetime((1980, 1, 1, 0, 0, 0)), Some((0x0021, 0x0000)));
+/// assert_eq!(datetime_to_dos_datetime((2023, 5, 20, 9, 24, 38)), Some((0x56b4, 0x4b13)));
+/// assert_eq!(datetime_to_dos_datetime((2023, 5, 20, 9, 24, 39)), Some((0x56b4, 0x4b13)));
+/// assert_eq!(datetime_to_dos_datetime((1979, 12, 31, 23, 59, 59)), None);
+/// ```
+///
+/// # Algorithm
+///
+/// Bit field packing with validation of the year.
+#[inline]
+pub const fn datetime_to_dos_datetime((y, m, d, hh, mm, ss): (i32, u8, u8, u8, u8, u8)) -> Option<(u16, u16)> {
+    debug_assert!(m >= consts::MONTH_MIN && m <= consts::MONTH_MAX, "given month is out of range");
+    debug_assert!(d >= consts::DAY_MIN && d <= consts::DAY_MAX, "given day is out of range");
+    debug_assert!(hh >= consts::HOUR_MIN && hh <= consts::HOUR_MAX, "given hour is out of range");
+    debug_assert!(mm >= consts::MINUTE_MIN && mm <= consts::MINUTE_MAX, "given minute is out of range");
+    debug_assert!(ss >= consts::SECOND_MIN && ss <= consts::SECOND_MAX, "given second is out of range");
+    if y < 1980 || y > 2107 {
+        return None;
+    }
+    let date = ((y - 1980) as u16) << 9 | (m as u16 & 0xf) << 5 | (d as u16 & 0x1f);
+    let time = (hh as u16 & 0x1f) << 11 | (mm as u16 & 0x3f) << 5 | ((ss as u16 / 2) & 0x1f);
+    Some((date, time))
+}
+
+/// Unix seconds of the UUID version 1 epoch (October 15th, 1582)
+pub const UUIDV1_EPOCH_SECS: i64 = -12219292800;
+
+/// Maximum UUID version 1 timestamp, which is 60 bits
+pub const UUIDV1_TIMESTAMP_MAX: u64 = (1 << 60) - 1;
+
+/// Maximum UUID version 7 timestamp, which is 48 bits
+pub const UUIDV7_TIMESTAMP_MAX: u64 = (1 << 48) - 1;
+
+/// Convert UUID version 1 timestamp to seconds and nanoseconds
+///
+/// Given the 60-bit timestamp of a version 1 (or version 6) UUID, which counts
+/// 100 nanosecond intervals from the start of the Gregorian calendar (October
+/// 15th, 1582), returns a `(seconds, nanoseconds)` tuple counting from Unix
+/// epoch (January 1st, 1970).
+///
+/// # Panics
+///
+/// Timestamp must be between `0` and [UUIDV1_TIMESTAMP_MAX] inclusive. Bounds
+/// are checked using `debug_assert` only, so that the checks are not present
+/// in release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{datetime_to_secs, epoch::uuidv1_to_secs};
+///
+/// assert_eq!(uuidv1_to_secs(0), (datetime_to_secs((1582, 10, 15, 0, 0, 0)), 0));
+/// assert_eq!(uuidv1_to_secs(0x1ec9414c232ab00), (1645557742, 0));
+/// assert_eq!(uuidv1_to_secs(122192928000000001), (0, 100));
+/// ```
+///
+/// # Algorithm
+///
+/// Division to seconds followed by addition of the epoch offset.
+#[inline]
+pub const fn uuidv1_to_secs(ts: u64) -> (i64, u32) {
+    debug_assert!(ts <= UUIDV1_TIMESTAMP_MAX, "given timestamp is out of range");
+    let ts = ts & UUIDV1_TIMESTAMP_MAX;
+    let secs = (ts / 10_000_000) as i64 + UUIDV1_EPOCH_SECS;
+    let nsecs = (ts % 10_000_000) as u32 * 100;
+    (secs, nsecs)
+}
+
+/// Convert seconds and nanoseconds to UUID version 1 timestamp
+///
+/// Given a `(seconds, nanoseconds)` tuple counting from Unix epoch (January
+/// 1st, 1970) returns the 60-bit timestamp of a version 1 (or version 6) UUID,
+/// which counts 100 nanosecond intervals from the start of the Gregorian
+/// calendar (October 15th, 1582). Nanoseconds are truncated to 100 nanosecond
+/// precision.
+///
+/// # Errors
+///
+/// Returns `None` if the time is before October 15th, 1582 or the timestamp
+/// does not fit in 60 bits, which happens after the year 5236.
+///
+/// # Panics
+///
+/// Nanoseconds must be between `0` and `999_999_999`. Bounds are checked using
+/// `debug_assert` only, so that the checks are not present in release builds,
+/// similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{datetime_to_secs, epoch::secs_to_uuidv1};
+///
+/// assert_eq!(secs_to_uuidv1((datetime_to_secs((1582, 10, 15, 0, 0, 0)), 0)), Some(0));
+/// assert_eq!(secs_to_uuidv1((1645557742, 0)), Some(0x1ec9414c232ab00));
+/// assert_eq!(secs_to_uuidv1((0, 199)), Some(122192928000000001));
+/// assert_eq!(secs_to_uuidv1((datetime_to_secs((1582, 10, 14, 23, 59, 59)), 0)), None);
+/// ```
+///
+/// # Algorithm
+///
+/// Subtraction of the epoch offset followed by multiplication to 100
+/// nanosecond intervals.
+#[inline]
+pub const fn secs_to_uuidv1((secs, nsecs): (i64, u32)) -> Option<u64> {
+    debug_assert!(
+        nsecs >= consts::NANOSECOND_MIN && nsecs <= consts::NANOSECOND_MAX,
+        "given nanoseconds is out of range"
+    );
+    if secs < UUIDV1_EPOCH_SECS || secs > UUIDV1_EPOCH_SECS + (UUIDV1_TIMESTAMP_MAX / 10_000_000) as i64 {
+        return None;
+    }
+    let ts = (secs - UUIDV1_EPOCH_SECS) as u64 * 10_000_000 + (nsecs / 100) as u64;
+    if ts > UUIDV1_TIMESTAMP_MAX {
+        return None;
+    }
+    Some(ts)
+}
+
+/// Convert UUID version 7 timestamp to seconds and nanoseconds
+///
+/// Given the 48-bit timestamp of a version 7 UUID, which counts milliseconds
+/// from Unix epoch (January 1st, 1970), returns a `(seconds, nanoseconds)`
+/// tuple counting from Unix epoch.
+///
+/// # Panics
+///
+/// Timestamp must be between `0` and [UUIDV7_TIMESTAMP_MAX] inclusive. Bounds
+/// are checked using `debug_assert` only, so that the checks are not present
+/// in release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::epoch::uuidv7_to_secs;
+///
+/// assert_eq!(uuidv7_to_secs(0), (0, 0));
+/// assert_eq!(uuidv7_to_secs(0x017f22e279b0), (1645557742, 0));
+/// assert_eq!(uuidv7_to_secs(1684574678123), (1684574678, 123_000_000));
+/// ```
+///
+/// # Algorithm
+///
+/// Simple division to seconds and milliseconds.
+#[inline]
+pub const fn uuidv7_to_secs(ts: u64) -> (i64, u32) {
+    debug_assert!(ts <= UUIDV7_TIMESTAMP_MAX, "given timestamp is out of range");
+    let ts = ts & UUIDV7_TIMESTAMP_MAX;
+    ((ts / 1000) as i64, (ts % 1000) as u32 * 1_000_000)
+}
+
+/// Convert seconds and nanoseconds to UUID version 7 timestamp
+///
+/// Given a `(seconds, nanoseconds)` tuple counting from Unix epoch (January
+/// 1st, 1970) returns the 48-bit timestamp of a version 7 UUID, which counts
+/// milliseconds from Unix epoch. Nanoseconds are truncated to millisecond
+/// precision.
+///
+/// # Errors
+///
+/// Returns `None` if the time is before Unix epoch or the timestamp does not
+/// fit in 48 bits, which happens after the year 10889.
+///
+/// # Panics
+///
+/// Nanoseconds must be between `0` and `999_999_999`. Bounds are checked using
+/// `debug_assert` only, so that the checks are not present in release builds,
+/// similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::epoch::secs_to_uuidv7;
+///
+/// assert_eq!(secs_to_uuidv7((0, 0)), Some(0));
+/// assert_eq!(secs_to_uuidv7((1645557742, 0)), Some(0x017f22e279b0));
+/// assert_eq!(secs_to_uuidv7((1684574678, 123_456_789)), Some(1684574678123));
+/// assert_eq!(secs_to_uuidv7((-1, 999_999_999)), None);
+/// ```
+///
+/// # Algorithm
+///
+/// Simple multiplication to milliseconds.
+#[inline]
+pub const fn secs_to_uuidv7((secs, nsecs): (i64, u32)) -> Option<u64> {
+    debug_assert!(
+        nsecs >= consts::NANOSECOND_MIN && nsecs <= consts::NANOSECOND_MAX,
+        "given nanoseconds is out of range"
+    );
+    if secs < 0 || secs > (UUIDV7_TIMESTAMP_MAX / 1000) as i64 {
+        return None;
+    }
+    let ts = secs as u64 * 1000 + (nsecs / 1_000_000) as u64;
+    if ts > UUIDV7_TIMESTAMP_MAX {
+        return None;
+    }
+    Some(ts)
+}
+
+/// Unix seconds of the PostgreSQL epoch (January 1st, 2000)
+pub const PG_EPOCH_SECS: i64 = 946684800;
+
+/// Convert seconds and nanoseconds to PostgreSQL timestamp
+///
+/// Given a `(seconds, nanoseconds)` tuple counting from Unix epoch (January
+/// 1st, 1970) returns the microseconds counting from January 1st, 2000, which
+/// is the internal and binary protocol representation of PostgreSQL
+/// `timestamp` and `timestamptz` types. Nanoseconds are truncated to
+/// microsecond precision.
+///
+/// # Errors
+///
+/// Returns `None` if the result does not fit in `i64`, or if it would be
+/// `i64::MIN` or `i64::MAX`, which PostgreSQL uses for `-infinity` and
+/// `infinity`.
+///
+/// # Panics
+///
+/// Nanoseconds must be between `0` and `999_999_999`. Bounds are checked using
+/// `debug_assert` only, so that the checks are not present in release builds,
+/// similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::epoch::secs_to_pg_timestamp;
+///
+/// assert_eq!(secs_to_pg_timestamp((946684800, 0)), Some(0));
+/// assert_eq!(secs_to_pg_timestamp((0, 0)), Some(-946684800_000_000));
+/// assert_eq!(secs_to_pg_timestamp((1684574678, 123_456_789)), Some(737889878_123_456));
+/// assert_eq!(secs_to_pg_timestamp((datealgo::RD_SECONDS_MAX, 0)), None);
+/// ```
+///
+/// # Algorithm
+///
+/// Subtraction of the epoch offset followed by checked multiplication to
+/// microseconds.
+#[inline]
+pub const fn secs_to_pg_timestamp((secs, nsecs): (i64, u32)) -> Option<i64> {
+    debug_assert!(
+        nsecs >= consts::NANOSECOND_MIN && nsecs <= consts::NANOSECOND_MAX,
+        "given nanoseconds is out of range"
+    );
+    let secs = match secs.checked_sub(PG_EPOCH_SECS) {
+        Some(secs) => secs,
+        None => return None,
+    };
+    // keep the intermediate result within range near the negative limit
+    let frac = (nsecs / 1000) as i64;
+    let (secs, frac) = if secs < 0 && frac > 0 {
+        (secs + 1, frac - 1_000_000)
+    } else {
+        (secs, frac)
+    };
+    let us = match secs.checked_mul(1_000_000) {
+        Some(us) => us,
+        None => return None,
+    };
+    match us.checked_add(frac) {
+        Some(us) if us != i64::MIN && us != i64::MAX => Some(us),
+        _ => None,
+    }
+}
+
+/// Convert PostgreSQL timestamp to seconds and nanoseconds
+///
+/// Given the microseconds counting from January 1st, 2000, which is the
+/// internal and binary protocol representation of PostgreSQL `timestamp` and
+/// `timestamptz` types, returns a `(seconds, nanoseconds)` tuple counting from
+/// Unix epoch (January 1st, 1970).
+///
+/// # Errors
+///
+/// Returns `None` if the value is `i64::MIN` or `i64::MAX`, which PostgreSQL
+/// uses for `-infinity` and `infinity`.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::epoch::pg_timestamp_to_secs;
+///
+/// assert_eq!(pg_timestamp_to_secs(0), Some((946684800, 0)));
+/// assert_eq!(pg_timestamp_to_secs(-946684800_000_000), Some((0, 0)));
+/// assert_eq!(pg_timestamp_to_secs(737889878_123_456), Some((1684574678, 123_456_000)));
+/// assert_eq!(pg_timestamp_to_secs(-1), Some((946684799, 999_999_000)));
+/// assert_eq!(pg_timestamp_to_secs(i64::MAX), None);
+/// ```
+///
+/// # Algorithm
+///
+/// Euclidean division to seconds and microseconds followed by addition of the
+/// epoch offset.
+#[inline]
+pub const fn pg_timestamp_to_secs(us: i64) -> Option<(i64, u32)> {
+    if us == i64::MIN || us == i64::MAX {
+        return None;
+    }
+    let secs = us.div_euclid(1_000_000) + PG_EPOCH_SECS;
+    let nsecs = us.rem_euclid(1_000_000) as u32 * 1000;
+    Some((secs, nsecs))
+}
+
+/// Minimum seconds of a valid protobuf `Timestamp` (0001-01-01T00:00:00Z)
+pub const PROTOBUF_SECONDS_MIN: i64 = -62135596800;
+
+/// Maximum seconds of a valid protobuf `Timestamp` (9999-12-31T23:59:59Z)
+pub const PROTOBUF_SECONDS_MAX: i64 = 253402300799;
+
+/// Determine if seconds and nanoseconds are a valid protobuf `Timestamp`
+///
+/// Given the `seconds` and `nanos` fields of a
+/// [`google.protobuf.Timestamp`](https://protobuf.dev/reference/protobuf/google.protobuf/#timestamp)
+/// returns `true` if the timestamp is valid. The seconds must be between
+/// [PROTOBUF_SECONDS_MIN] and [PROTOBUF_SECONDS_MAX] inclusive, which
+/// restricts the timestamp to years 0001 to 9999, and the nanoseconds must be
+/// between `0` and `999_999_999`, also for timestamps before Unix epoch.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::epoch::is_valid_protobuf_timestamp;
+///
+/// assert_eq!(is_valid_protobuf_timestamp(1684574678, 123_456_789), true);
+/// assert_eq!(is_valid_protobuf_timestamp(-1, 999_999_999), true);
+/// assert_eq!(is_valid_protobuf_timestamp(0, -1), false);
+/// assert_eq!(is_valid_protobuf_timestamp(0, 1_000_000_000), false);
+/// assert_eq!(is_valid_protobuf_timestamp(253402300800, 0), false);
+/// ```
+///
+/// # Algorithm
+///
+/// Simple range checks.
+#[inline]
+pub const fn is_valid_protobuf_timestamp(secs: i64, nanos: i32) -> bool {
+    secs >= PROTOBUF_SECONDS_MIN && secs <= PROTOBUF_SECONDS_MAX && nanos >= 0 && nanos <= consts::NANOSECOND_MAX as i32
+}
+
+/// Normalize seconds and nanoseconds of a protobuf `Timestamp`
+///
+/// Given seconds and a possibly negative or overflowing nanoseconds value,
+/// such as the result of adding a protobuf `Duration` to a `Timestamp`,
+/// returns a `(seconds, nanoseconds)` tuple with the nanoseconds between `0`
+/// and `999_999_999`, as required by `google.protobuf.Timestamp`. Whole
+/// seconds of nanoseconds are carried to the seconds, which saturate at the
+/// limits of `i64`. The result is not checked to be within the valid range,
+/// for which [is_valid_protobuf_timestamp] can be used.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::epoch::normalize_timestamp;
+///
+/// assert_eq!(normalize_timestamp(1684574678, 123_456_789), (1684574678, 123_456_789));
+/// assert_eq!(normalize_timestamp(0, -1), (-1, 999_999_999));
+/// assert_eq!(normalize_timestamp(1, 2_500_000_000), (3, 500_000_000));
+/// assert_eq!(normalize_timestamp(0, -3_000_000_000), (-3, 0));
+/// ```
+///
+/// # Algorithm
+///
+/// Euclidean division of the nanoseconds followed by saturating addition.
+#[inline]
+pub const fn normalize_timestamp(secs: i64, nanos: i64) -> (i64, u32) {
+    (
+        secs.saturating_add(nanos.div_euclid(1_000_000_000)),
+        nanos.rem_euclid(1_000_000_000) as u32,
+    )
+}
+
+/// Rata Die of the SAS, Stata and R epoch (January 1st, 1960)
+pub const SAS_EPOCH_RD: i32 = -3653;
+
+/// Minimum SAS date for conversion
+///
+/// Corresponds to [RD_MIN].
+pub const SAS_DATE_MIN: i32 = RD_MIN - SAS_EPOCH_RD;
+
+/// Maximum SAS date for conversion
+///
+/// Corresponds to [RD_MAX].
+pub const SAS_DATE_MAX: i32 = RD_MAX - SAS_EPOCH_RD;
+
+/// Minimum SAS datetime for conversion
+///
+/// Corresponds to [RD_SECONDS_MIN].
+pub const SAS_DATETIME_MIN: i64 = RD_SECONDS_MIN - SAS_EPOCH_RD as i64 * SECS_IN_DAY;
+
+/// Maximum SAS datetime for conversion
+///
+/// Corresponds to [RD_SECONDS_MAX].
+pub const SAS_DATETIME_MAX: i64 = RD_SECONDS_MAX - SAS_EPOCH_RD as i64 * SECS_IN_DAY;
+
+/// Minimum Stata `%tc` milliseconds for conversion
+///
+/// Corresponds to [RD_SECONDS_MIN].
+pub const STATA_MS_MIN: i64 = SAS_DATETIME_MIN * 1000;
+
+/// Maximum Stata `%tc` milliseconds for conversion
+///
+/// Corresponds to [RD_SECONDS_MAX].
+pub const STATA_MS_MAX: i64 = SAS_DATETIME_MAX * 1000 + 999;
+
+/// Convert Rata Die to SAS date
+///
+/// Given a day counting from Unix epoch (January 1st, 1970) returns the day
+/// counting from January 1st, 1960, as used by SAS date values, Stata `%td`
+/// dates and older R formats.
+///
+/// # Panics
+///
+/// Argument must be between [RD_MIN] and [RD_MAX] inclusive. Bounds are checked
+/// using `debug_assert` only, so that the checks are not present in release
+/// builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{date_to_rd, epoch::rd_to_sas_date};
+///
+/// assert_eq!(rd_to_sas_date(date_to_rd((1960, 1, 1))), 0);
+/// assert_eq!(rd_to_sas_date(0), 3653);
+/// assert_eq!(rd_to_sas_date(date_to_rd((2023, 5, 12))), 23142);
+/// ```
+///
+/// # Algorithm
+///
+/// Simple addition of the epoch offset.
+#[inline]
+pub const fn rd_to_sas_date(rd: i32) -> i32 {
+    debug_assert!(rd >= RD_MIN && rd <= RD_MAX, "given rata die is out of range");
+    rd - SAS_EPOCH_RD
+}
+
+/// Convert SAS date to Rata Die
+///
+/// Given a day counting from January 1st, 1960, as used by SAS date values,
+/// Stata `%td` dates and older R formats, returns the day counting from Unix
+/// epoch (January 1st, 1970).
+///
+/// # Panics
+///
+/// Argument must be between [SAS_DATE_MIN] and [SAS_DATE_MAX] inclusive.
+/// Bounds are checked using `debug_assert` only, so that the checks are not
+/// present in release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{rd_to_date, epoch::sas_date_to_rd};
+///
+/// assert_eq!(rd_to_date(sas_date_to_rd(0)), (1960, 1, 1));
+/// assert_eq!(sas_date_to_rd(3653), 0);
+/// assert_eq!(rd_to_date(sas_date_to_rd(23142)), (2023, 5, 12));
+/// ```
+///
+/// # Algorithm
+///
+/// Simple subtraction of the epoch offset.
+#[inline]
+pub const fn sas_date_to_rd(days: i32) -> i32 {
+    debug_assert!(days >= SAS_DATE_MIN && days <= SAS_DATE_MAX, "given sas date is out of range");
+    days + SAS_EPOCH_RD
+}
+
+/// Convert Unix seconds to SAS datetime
+///
+/// Given seconds counting from Unix epoch (January 1st, 1970) returns the
+/// seconds counting from January 1st, 1960, as used by SAS datetime values.
+///
+/// # Panics
+///
+/// Argument must be between [RD_SECONDS_MIN] and [RD_SECONDS_MAX] inclusive.
+/// Bounds are checked using `debug_assert` only, so that the checks are not
+/// present in release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{datetime_to_secs, epoch::secs_to_sas_datetime};
+///
+/// assert_eq!(secs_to_sas_datetime(datetime_to_secs((1960, 1, 1, 0, 0, 0))), 0);
+/// assert_eq!(secs_to_sas_datetime(0), 315619200);
+/// assert_eq!(secs_to_sas_datetime(1684574678), 2000193878);
+/// ```
+///
+/// # Algorithm
+///
+/// Simple addition of the epoch offset.
+#[inline]
+pub const fn secs_to_sas_datetime(secs: i64) -> i64 {
+    debug_assert!(
+        secs >= RD_SECONDS_MIN && secs <= RD_SECONDS_MAX,
+        "given seconds value is out of range"
+    );
+    secs - SAS_EPOCH_RD as i64 * SECS_IN_DAY
+}
+
+/// Convert SAS datetime to Unix seconds
+///
+/// Given seconds counting from January 1st, 1960, as used by SAS datetime
+/// values, returns the seconds counting from Unix epoch (January 1st, 1970).
+///
+/// # Panics
+///
+/// Argument must be between [SAS_DATETIME_MIN] and [SAS_DATETIME_MAX]
+/// inclusive. Bounds are checked using `debug_assert` only, so that the checks
+/// are not present in release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{datetime_to_secs, epoch::sas_datetime_to_secs};
+///
+/// assert_eq!(sas_datetime_to_secs(0), datetime_to_secs((1960, 1, 1, 0, 0, 0)));
+/// assert_eq!(sas_datetime_to_secs(315619200), 0);
+/// assert_eq!(sas_datetime_to_secs(2000193878), 1684574678);
+/// ```
+///
+/// # Algorithm
+///
+/// Simple subtraction of the epoch offset.
+#[inline]
+pub const fn sas_datetime_to_secs(secs: i64) -> i64 {
+    debug_assert!(
+        secs >= SAS_DATETIME_MIN && secs <= SAS_DATETIME_MAX,
+        "given sas datetime is out of range"
+    );
+    secs + SAS_EPOCH_RD as i64 * SECS_IN_DAY
+}
+
+/// Convert seconds and nanoseconds to Stata `%tc` milliseconds
+///
+/// Given a `(seconds, nanoseconds)` tuple counting from Unix epoch (January
+/// 1st, 1970) returns the milliseconds counting from January 1st, 1960, as
+/// used by Stata `%tc` datetime values. Nanoseconds are truncated to
+/// millisecond precision.
+///
+/// # Panics
+///
+/// Seconds must be between [RD_SECONDS_MIN] and [RD_SECONDS_MAX] inclusive.
+/// Nanoseconds must be between `0` and `999_999_999`. Bounds are checked using
+/// `debug_assert` only, so that the checks are not present in release builds,
+/// similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{datetime_to_secs, epoch::secs_to_stata_ms};
+///
+/// assert_eq!(secs_to_stata_ms((datetime_to_secs((1960, 1, 1, 0, 0, 0)), 0)), 0);
+/// assert_eq!(secs_to_stata_ms((0, 0)), 315619200000);
+/// assert_eq!(secs_to_stata_ms((1684574678, 123_456_789)), 2000193878123);
+/// ```
+///
+/// # Algorithm
+///
+/// Addition of the epoch offset followed by multiplication to milliseconds.
+#[inline]
+pub const fn secs_to_stata_ms((secs, nsecs): (i64, u32)) -> i64 {
+    debug_assert!(
+        nsecs >= consts::NANOSECOND_MIN && nsecs <= consts::NANOSECOND_MAX,
+        "given nanoseconds is out of range"
+    );
+    secs_to_sas_datetime(secs) * 1000 + (nsecs / 1_000_000) as i64
+}
+
+/// Convert Stata `%tc` milliseconds to seconds and nanoseconds
+///
+/// Given milliseconds counting from January 1st, 1960, as used by Stata `%tc`
+/// datetime values, returns a `(seconds, nanoseconds)` tuple counting from
+/// Unix epoch (January 1st, 1970).
+///
+/// # Panics
+///
+/// Argument must be between [STATA_MS_MIN] and [STATA_MS_MAX] inclusive.
+/// Bounds are checked using `debug_assert` only, so that the checks are not
+/// present in release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{datetime_to_secs, epoch::stata_ms_to_secs};
+///
+/// assert_eq!(stata_ms_to_secs(0), (datetime_to_secs((1960, 1, 1, 0, 0, 0)), 0));
+/// assert_eq!(stata_ms_to_secs(315619200000), (0, 0));
+/// assert_eq!(stata_ms_to_secs(2000193878123), (1684574678, 123_000_000));
+/// assert_eq!(stata_ms_to_secs(-1), (datetime_to_secs((1959, 12, 31, 23, 59, 59)), 999_000_000));
+/// ```
+///
+/// # Algorithm
+///
+/// Euclidean division to seconds and milliseconds followed by subtraction of
+/// the epoch offset.
+#[inline]
+pub const fn stata_ms_to_secs(ms: i64) -> (i64, u32) {
+    debug_assert!(ms >= STATA_MS_MIN && ms <= STATA_MS_MAX, "given stata milliseconds is out of range");
+    let secs = sas_datetime_to_secs(ms.div_euclid(1000));
+    (secs, ms.rem_euclid(1000) as u32 * 1_000_000)
+}
+
+/// Unix seconds of the J2000.0 epoch (January 1st, 2000, 12:00:00)
+pub const J2000_EPOCH_SECS: i64 = 946728000;
+
+/// Minimum J2000 seconds for conversion
+///
+/// Corresponds to [RD_SECONDS_MIN].
+pub const J2000_SECONDS_MIN: i64 = RD_SECONDS_MIN - J2000_EPOCH_SECS;
+
+/// Maximum J2000 seconds for conversion
+///
+/// Corresponds to [RD_SECONDS_MAX].
+pub const J2000_SECONDS_MAX: i64 = RD_SECONDS_MAX - J2000_EPOCH_SECS;
+
+/// Convert Unix seconds to J2000 seconds
+///
+/// Given seconds counting from Unix epoch (January 1st, 1970) returns the
+/// seconds counting from the J2000.0 epoch (January 1st, 2000, 12:00:00).
+///
+/// The J2000.0 epoch is formally defined in Terrestrial Time, whereas this
+/// conversion simply counts calendar seconds, with every day being 86400
+/// seconds long, consistent with Unix time. Converting to a different time
+/// scale, such as TT or TDB used by SPICE, requires adding the applicable
+/// offset and leap seconds separately.
+///
+/// # Panics
+///
+/// Argument must be between [RD_SECONDS_MIN] and [RD_SECONDS_MAX] inclusive.
+/// Bounds are checked using `debug_assert` only, so that the checks are not
+/// present in release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{datetime_to_secs, epoch::secs_to_j2000_secs};
+///
+/// assert_eq!(secs_to_j2000_secs(datetime_to_secs((2000, 1, 1, 12, 0, 0))), 0);
+/// assert_eq!(secs_to_j2000_secs(0), -946728000);
+/// assert_eq!(secs_to_j2000_secs(1684574678), 737846678);
+/// ```
+///
+/// # Algorithm
+///
+/// Simple subtraction of the epoch offset.
+#[inline]
+pub const fn secs_to_j2000_secs(secs: i64) -> i64 {
+    debug_assert!(
+        secs >= RD_SECONDS_MIN && secs <= RD_SECONDS_MAX,
+        "given seconds value is out of range"
+    );
+    secs - J2000_EPOCH_SECS
+}
+
+/// Convert J2000 seconds to Unix seconds
+///
+/// Given seconds counting from the J2000.0 epoch (January 1st, 2000, 12:00:00)
+/// returns the seconds counting from Unix epoch (January 1st, 1970).
+///
+/// The J2000.0 epoch is formally defined in Terrestrial Time, whereas this
+/// conversion simply counts calendar seconds, with every day being 86400
+/// seconds long, consistent with Unix time. Values in a different time scale,
+/// such as TT or TDB used by SPICE, require subtracting the applicable offset
+/// and leap seconds separately.
+///
+/// # Panics
+///
+/// Argument must be between [J2000_SECONDS_MIN] and [J2000_SECONDS_MAX]
+/// inclusive. Bounds are checked using `debug_assert` only, so that the checks
+/// are not present in release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{datetime_to_secs, epoch::j2000_secs_to_secs};
+///
+/// assert_eq!(j2000_secs_to_secs(0), datetime_to_secs((2000, 1, 1, 12, 0, 0)));
+/// assert_eq!(j2000_secs_to_secs(-946728000), 0);
+/// assert_eq!(j2000_secs_to_secs(737846678), 1684574678);
+/// ```
+///
+/// # Algorithm
+///
+/// Simple addition of the epoch offset.
+#[inline]
+pub const fn j2000_secs_to_secs(secs: i64) -> i64 {
+    debug_assert!(
+        secs >= J2000_SECONDS_MIN && secs <= J2000_SECONDS_MAX,
+        "given seconds value is out of range"
+    );
+    secs + J2000_EPOCH_SECS
+}
+
+/// Convert seconds and nanoseconds to J2000 seconds and nanoseconds
+///
+/// Given a `(seconds, nanoseconds)` tuple counting from Unix epoch (January
+/// 1st, 1970) returns a `(seconds, nanoseconds)` tuple counting from the
+/// J2000.0 epoch (January 1st, 2000, 12:00:00). See [secs_to_j2000_secs] for
+/// notes on time scales.
+///
+/// # Panics
+///
+/// Seconds must be between [RD_SECONDS_MIN] and [RD_SECONDS_MAX] inclusive.
+/// Nanoseconds must be between `0` and `999_999_999`. Bounds are checked using
+/// `debug_assert` only, so that the checks are not present in release builds,
+/// similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::epoch::secs_to_j2000;
+///
+/// assert_eq!(secs_to_j2000((946728000, 500_000_000)), (0, 500_000_000));
+/// assert_eq!(secs_to_j2000((946727999, 500_000_000)), (-1, 500_000_000));
+/// ```
+///
+/// # Algorithm
+///
+/// Simple subtraction of the epoch offset.
+#[inline]
+pub const fn secs_to_j2000((secs, nsecs): (i64, u32)) -> (i64, u32) {
+    debug_assert!(
+        nsecs >= consts::NANOSECOND_MIN && nsecs <= consts::NANOSECOND_MAX,
+        "given nanoseconds is out of range"
+    );
+    (secs_to_j2000_secs(secs), nsecs)
+}
+
+/// Convert J2000 seconds and nanoseconds to seconds and nanoseconds
+///
+/// Given a `(seconds, nanoseconds)` tuple counting from the J2000.0 epoch
+/// (January 1st, 2000, 12:00:00) returns a `(seconds, nanoseconds)` tuple
+/// counting from Unix epoch (January 1st, 1970). See [j2000_secs_to_secs] for
+/// notes on time scales.
+///
+/// # Panics
+///
+/// Seconds must be between [J2000_SECONDS_MIN] and [J2000_SECONDS_MAX]
+/// inclusive. Nanoseconds must be between `0` and `999_999_999`. Bounds are
+/// checked using `debug_assert` only, so that the checks are not present in
+/// release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::epoch::j2000_to_secs;
+///
+/// assert_eq!(j2000_to_secs((0, 500_000_000)), (946728000, 500_000_000));
+/// assert_eq!(j2000_to_secs((-1, 500_000_000)), (946727999, 500_000_000));
+/// ```
+///
+/// # Algorithm
+///
+/// Simple addition of the epoch offset.
+#[inline]
+pub const fn j2000_to_secs((secs, nsecs): (i64, u32)) -> (i64, u32) {
+    debug_assert!(
+        nsecs >= consts::NANOSECOND_MIN && nsecs <= consts::NANOSECOND_MAX,
+        "given nanoseconds is out of range"
+    );
+    (j2000_secs_to_secs(secs), nsecs)
+}
+
+/// Unix seconds of the CCSDS epoch (January 1st, 1958)
+pub const CCSDS_EPOCH_SECS: i64 = -378691200;
+
+/// Maximum CCSDS day segment value, which is 24 bits
+pub const CDS_DAYS_MAX: u32 = (1 << 24) - 1;
+
+/// Maximum length of an encoded CCSDS CDS T-field in bytes
+pub const CDS_MAX_LEN: usize = 11;
+
+/// Convert seconds and nanoseconds to CCSDS CDS segments
+///
+/// Given a `(seconds, nanoseconds)` tuple counting from Unix epoch (January
+/// 1st, 1970) returns a `(days, milliseconds, sub-milliseconds)` tuple of CCSDS
+/// Day Segmented time code segments, where days count from the CCSDS epoch
+/// (January 1st, 1958), milliseconds count from the start of the day and
+/// sub-milliseconds are given in nanoseconds from the start of the millisecond.
+///
+/// Days are limited to 24 bits; callers using a 16-bit day segment must check
+/// that days does not exceed `65535`. Leap seconds are not represented, every
+/// day is 86400 seconds long, consistent with Unix time.
+///
+/// # Errors
+///
+/// Returns `None` if the time is before the CCSDS epoch or days would exceed
+/// [CDS_DAYS_MAX].
+///
+/// # Panics
+///
+/// Nanoseconds must be between `0` and `999_999_999`. Bounds are checked using
+/// `debug_assert` only, so that the checks are not present in release builds,
+/// similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{datetime_to_secs, epoch::secs_to_cds};
+///
+/// assert_eq!(secs_to_cds((0, 0)), Some((4383, 0, 0)));
+/// assert_eq!(secs_to_cds((datetime_to_secs((2023, 5, 20, 9, 24, 38)), 123_456_789)), Some((23880, 33878123, 456_789)));
+/// assert_eq!(secs_to_cds((-378691201, 0)), None);
+/// ```
+///
+/// # Algorithm
+///
+/// Simple subtraction of the epoch offset and splitting into segments.
+#[inline]
+pub const fn secs_to_cds((secs, nsecs): (i64, u32)) -> Option<(u32, u32, u32)> {
+    debug_assert!(
+        nsecs >= consts::NANOSECOND_MIN && nsecs <= consts::NANOSECOND_MAX,
+        "given nanoseconds is out of range"
+    );
+    let secs = secs.wrapping_sub(CCSDS_EPOCH_SECS);
+    if secs < 0 || secs > (CDS_DAYS_MAX as i64 + 1) * SECS_IN_DAY - 1 {
+        return None;
+    }
+    let days = (secs / SECS_IN_DAY) as u32;
+    let ms = (secs % SECS_IN_DAY) as u32 * 1000 + nsecs / 1_000_000;
+    Some((days, ms, nsecs % 1_000_000))
+}
+
+/// Convert CCSDS CDS segments to seconds and nanoseconds
+///
+/// Given a `(days, milliseconds, sub-milliseconds)` tuple of CCSDS Day
+/// Segmented time code segments, where days count from the CCSDS epoch
+/// (January 1st, 1958), milliseconds count from the start of the day and
+/// sub-milliseconds are given in nanoseconds from the start of the millisecond,
+/// returns a `(seconds, nanoseconds)` tuple counting from Unix epoch (January
+/// 1st, 1970).
+///
+/// # Errors
+///
+/// Returns `None` if days exceeds [CDS_DAYS_MAX], milliseconds is not less
+/// than `86_400_000` or sub-milliseconds is not less than `1_000_000`. As
+/// leap seconds are not represented, a millisecond value falling within a leap
+/// second is an error.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{datetime_to_secs, epoch::cds_to_secs};
+///
+/// assert_eq!(cds_to_secs((4383, 0, 0)), Some((0, 0)));
+/// assert_eq!(cds_to_secs((23880, 33878123, 456_789)), Some((datetime_to_secs((2023, 5, 20, 9, 24, 38)), 123_456_789)));
+/// assert_eq!(cds_to_secs((0, 86_400_000, 0)), None);
+/// ```
+///
+/// # Algorithm
+///
+/// Simple combination of the segments and addition of the epoch offset.
+#[inline]
+pub const fn cds_to_secs((days, ms, subms): (u32, u32, u32)) -> Option<(i64, u32)> {
+    if days > CDS_DAYS_MAX || ms >= 86_400_000 || subms >= 1_000_000 {
+        return None;
+    }
+    let secs = days as i64 * SECS_IN_DAY + (ms / 1000) as i64 + CCSDS_EPOCH_SECS;
+    Some((secs, (ms % 1000) * 1_000_000 + subms))
+}
+
+/// Encode seconds and nanoseconds as a CCSDS CDS T-field
+///
+/// Given a `(seconds, nanoseconds)` tuple counting from Unix epoch (January
+/// 1st, 1970) writes the big-endian T-field of a CCSDS Day Segmented time code
+/// into the buffer, returning the number of bytes written. The day segment is
+/// `day_len` bytes long, either `2` or `3`, and the sub-millisecond segment is
+/// `subms_len` bytes long, either `0` (absent), `2` (microseconds) or `4`
+/// (picoseconds). Precision beyond the sub-millisecond segment is truncated.
+///
+/// The P-field describing the segment lengths is not written, as it is
+/// commonly implicit and agreed upon out of band.
+///
+/// # Errors
+///
+/// Returns `None` if the segment lengths are not valid or the time is before
+/// the CCSDS epoch or does not fit in the day segment.
+///
+/// # Panics
+///
+/// Nanoseconds must be between `0` and `999_999_999`. Bounds are checked using
+/// `debug_assert` only, so that the checks are not present in release builds,
+/// similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::epoch::{encode_cds, CDS_MAX_LEN};
+///
+/// let mut buf = [0u8; CDS_MAX_LEN];
+/// assert_eq!(encode_cds((0, 1_500_000), 2, 2, &mut buf), Some(8));
+/// assert_eq!(&buf[..8], &[0x11, 0x1f, 0x00, 0x00, 0x00, 0x01, 0x01, 0xf4]);
+/// assert_eq!(encode_cds((0, 0), 2, 1, &mut buf), None);
+/// ```
+///
+/// # Algorithm
+///
+/// Conversion to segments using [secs_to_cds], followed by big-endian
+/// serialization.
+#[inline]
+pub fn encode_cds((secs, nsecs): (i64, u32), day_len: usize, subms_len: usize, buf: &mut [u8; CDS_MAX_LEN]) -> Option<usize> {
+    if (day_len != 2 && day_len != 3) || (subms_len != 0 && subms_len != 2 && subms_len != 4) {
+        return None;
+    }
+    let (days, ms, subms) = secs_to_cds((secs, nsecs))?;
+    if days >> (day_len * 8) != 0 {
+        return None;
+    }
+    let subms = match subms_len {
+        2 => subms / 1000,
+        _ => subms * 1000,
+    };
+    buf[..day_len].copy_from_slice(&days.to_be_bytes()[4 - day_len..]);
+    buf[day_len..day_len + 4].copy_from_slice(&ms.to_be_bytes());
+    buf[day_len + 4..day_len + 4 + subms_len].copy_from_slice(&subms.to_be_bytes()[4 - subms_len..]);
+    Some(day_len + 4 + subms_len)
+}
+
+/// Decode a CCSDS CDS T-field to seconds and nanoseconds
+///
+/// Given the big-endian T-field of a CCSDS Day Segmented time code with a day
+/// segment of `day_len` bytes, either `2` or `3`, and a sub-millisecond
+/// segment of `subms_len` bytes, either `0` (absent), `2` (microseconds) or
+/// `4` (picoseconds), returns a `(seconds, nanoseconds)` tuple counting from
+/// Unix epoch (January 1st, 1970). Picoseconds are truncated to nanoseconds.
+///
+/// # Errors
+///
+/// Returns `None` if the segment lengths are not valid, the input is not
+/// exactly the expected length, or any segment is out of range.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::epoch::decode_cds;
+///
+/// assert_eq!(decode_cds(&[0x11, 0x1f, 0x00, 0x00, 0x00, 0x01, 0x01, 0xf4], 2, 2), Some((0, 1_500_000)));
+/// assert_eq!(decode_cds(&[0x11, 0x1f, 0x05, 0x26, 0x5c, 0x00], 2, 0), None);
+/// ```
+///
+/// # Algorithm
+///
+/// Big-endian deserialization followed by conversion using [cds_to_secs].
+#[inline]
+pub fn decode_cds(buf: &[u8], day_len: usize, subms_len: usize) -> Option<(i64, u32)> {
+    if (day_len != 2 && day_len != 3) || (subms_len != 0 && subms_len != 2 && subms_len != 4) {
+        return None;
+    }
+    if buf.len() != day_len + 4 + subms_len {
+        return None;
+    }
+    let be = |s: &[u8]| s.iter().fold(0u32, |acc, &b| acc << 8 | b as u32);
+    let days = be(&buf[..day_len]);
+    let ms = be(&buf[day_len..day_len + 4]);
+    let subms = be(&buf[day_len + 4..]);
+    let subms = match subms_len {
+        0 => 0,
+        2 if subms < 1000 => subms * 1000,
+        4 if subms < 1_000_000_000 => subms / 1000,
+        _ => return None,
+    };
+    cds_to_secs((days, ms, subms))
+}
+
+/// Convert Rata Die to days since an arbitrary epoch
+///
+/// Given a day counting from Unix epoch (January 1st, 1970) returns the day
+/// counting from the epoch given as the `EPOCH_RD` const parameter, itself
+/// expressed as a day counting from Unix epoch. This allows defining
+/// conversions for any "days since X" epoch with a compile-time offset.
+///
+/// # Panics
+///
+/// Argument must be between [RD_MIN] and [RD_MAX] inclusive, and the result
+/// must fit in an `i32`. Bounds are checked using `debug_assert` only, so that
+/// the checks are not present in release builds, similar to integer overflow
+/// checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{date_to_rd, epoch::days_from_epoch};
+///
+/// const EPOCH_1900: i32 = date_to_rd((1900, 1, 1));
+/// assert_eq!(days_from_epoch::<EPOCH_1900>(0), 25567);
+/// assert_eq!(days_from_epoch::<EPOCH_1900>(date_to_rd((1900, 1, 2))), 1);
+/// assert_eq!(days_from_epoch::<0>(19497), 19497);
+/// ```
+///
+/// # Algorithm
+///
+/// Simple subtraction of the epoch offset.
+#[inline]
+pub const fn days_from_epoch<const EPOCH_RD: i32>(rd: i32) -> i32 {
+    debug_assert!(rd >= RD_MIN && rd <= RD_MAX, "given rata die is out of range");
+    debug_assert!(
+        rd as i64 - EPOCH_RD as i64 >= i32::MIN as i64 && rd as i64 - EPOCH_RD as i64 <= i32::MAX as i64,
+        "given rata die is out of range for epoch"
+    );
+    rd.wrapping_sub(EPOCH_RD)
+}
+
+/// Convert days since an arbitrary epoch to Rata Die
+///
+/// Given a day counting from the epoch given as the `EPOCH_RD` const
+/// parameter, itself expressed as a day counting from Unix epoch, returns the
+/// day counting from Unix epoch (January 1st, 1970).
+///
+/// # Panics
+///
+/// The result must be between [RD_MIN] and [RD_MAX] inclusive. Bounds are
+/// checked using `debug_assert` only, so that the checks are not present in
+/// release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{date_to_rd, epoch::epoch_days_to_rd};
+///
+/// const EPOCH_1900: i32 = date_to_rd((1900, 1, 1));
+/// assert_eq!(epoch_days_to_rd::<EPOCH_1900>(25567), 0);
+/// assert_eq!(epoch_days_to_rd::<EPOCH_1900>(1), date_to_rd((1900, 1, 2)));
+/// ```
+///
+/// # Algorithm
+///
+/// Simple addition of the epoch offset.
+#[inline]
+pub const fn epoch_days_to_rd<const EPOCH_RD: i32>(days: i32) -> i32 {
+    debug_assert!(
+        days as i64 + EPOCH_RD as i64 >= RD_MIN as i64 && days as i64 + EPOCH_RD as i64 <= RD_MAX as i64,
+        "given days is out of range for epoch"
+    );
+    days.wrapping_add(EPOCH_RD)
+}
+
+/// Convert Unix seconds to seconds since an arbitrary epoch
+///
+/// Given seconds counting from Unix epoch (January 1st, 1970) returns the
+/// seconds counting from the epoch given as the `EPOCH_SECS` const parameter,
+/// itself expressed as seconds counting from Unix epoch. This allows defining
+/// conversions for any "seconds since X" epoch with a compile-time offset.
+///
+/// # Panics
+///
+/// Argument must be between [RD_SECONDS_MIN] and [RD_SECONDS_MAX] inclusive.
+/// Bounds are checked using `debug_assert` only, so that the checks are not
+/// present in release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{datetime_to_secs, epoch::secs_from_epoch};
+///
+/// const EPOCH_1980: i64 = datetime_to_secs((1980, 1, 6, 0, 0, 0));
+/// assert_eq!(secs_from_epoch::<EPOCH_1980>(315964800), 0);
+/// assert_eq!(secs_from_epoch::<EPOCH_1980>(0), -315964800);
+/// ```
+///
+/// # Algorithm
+///
+/// Simple subtraction of the epoch offset.
+#[inline]
+pub const fn secs_from_epoch<const EPOCH_SECS: i64>(secs: i64) -> i64 {
+    debug_assert!(
+        secs >= RD_SECONDS_MIN && secs <= RD_SECONDS_MAX,
+        "given seconds value is out of range"
+    );
+    debug_assert!(
+        EPOCH_SECS >= RD_SECONDS_MIN && EPOCH_SECS <= RD_SECONDS_MAX,
+        "given epoch is out of range"
+    );
+    secs.wrapping_sub(EPOCH_SECS)
+}
+
+/// Convert seconds since an arbitrary epoch to Unix seconds
+///
+/// Given seconds counting from the epoch given as the `EPOCH_SECS` const
+/// parameter, itself expressed as seconds counting from Unix epoch, returns
+/// the seconds counting from Unix epoch (January 1st, 1970).
+///
+/// # Panics
+///
+/// The result must be between [RD_SECONDS_MIN] and [RD_SECONDS_MAX]
+/// inclusive. Bounds are checked using `debug_assert` only, so that the checks
+/// are not present in release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{datetime_to_secs, epoch::epoch_secs_to_secs};
+///
+/// const EPOCH_1980: i64 = datetime_to_secs((1980, 1, 6, 0, 0, 0));
+/// assert_eq!(epoch_secs_to_secs::<EPOCH_1980>(0), 315964800);
+/// assert_eq!(epoch_secs_to_secs::<EPOCH_1980>(-315964800), 0);
+/// ```
+///
+/// # Algorithm
+///
+/// Simple addition of the epoch offset.
+#[inline]
+pub const fn epoch_secs_to_secs<const EPOCH_SECS: i64>(secs: i64) -> i64 {
+    debug_assert!(
+        EPOCH_SECS >= RD_SECONDS_MIN && EPOCH_SECS <= RD_SECONDS_MAX,
+        "given epoch is out of range"
+    );
+    debug_assert!(
+        secs >= RD_SECONDS_MIN - EPOCH_SECS && secs <= RD_SECONDS_MAX - EPOCH_SECS,
+        "given seconds value is out of range for epoch"
+    );
+    secs.wrapping_add(EPOCH_SECS)
+}
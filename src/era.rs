@@ -0,0 +1,248 @@
+//! Conversions between proleptic Gregorian years and other year numberings
+//!
+//! Years in this crate use astronomical year numbering, where year `0` is 1 BC
+//! and year `-1` is 2 BC. The functions in this module convert to and from
+//! other year numberings that share the Gregorian calendar, so months and days
+//! are unaffected.
+
+use crate::{YEAR_MAX, YEAR_MIN};
+
+/// Offset between Holocene Era and astronomical years
+const HOLOCENE_OFFSET: i32 = 10000;
+
+/// Minimum Holocene Era year for conversion
+///
+/// Corresponds to [YEAR_MIN].
+pub const HOLOCENE_YEAR_MIN: i32 = YEAR_MIN + HOLOCENE_OFFSET;
+
+/// Maximum Holocene Era year for conversion
+///
+/// Corresponds to [YEAR_MAX].
+pub const HOLOCENE_YEAR_MAX: i32 = YEAR_MAX + HOLOCENE_OFFSET;
+
+/// Convert year to Holocene Era year
+///
+/// Given an astronomical year returns the Holocene Era (Human Era) year, which
+/// counts 1 HE as 10000 BC, making the conversion a simple offset of 10000.
+///
+/// # Errors
+///
+/// Returns `None` if the year is not between [YEAR_MIN] and [YEAR_MAX]
+/// inclusive.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::era::year_to_holocene;
+///
+/// assert_eq!(year_to_holocene(2023), Some(12023));
+/// assert_eq!(year_to_holocene(0), Some(10000));
+/// assert_eq!(year_to_holocene(-9999), Some(1));
+/// assert_eq!(year_to_holocene(i32::MAX), None);
+/// ```
+///
+/// # Algorithm
+///
+/// Simple addition of the era offset.
+#[inline]
+pub const fn year_to_holocene(y: i32) -> Option<i32> {
+    if y < YEAR_MIN || y > YEAR_MAX {
+        return None;
+    }
+    Some(y + HOLOCENE_OFFSET)
+}
+
+/// Convert Holocene Era year to year
+///
+/// Given a Holocene Era (Human Era) year, which counts 1 HE as 10000 BC,
+/// returns the astronomical year.
+///
+/// # Errors
+///
+/// Returns `None` if the year is not between [HOLOCENE_YEAR_MIN] and
+/// [HOLOCENE_YEAR_MAX] inclusive.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::era::holocene_to_year;
+///
+/// assert_eq!(holocene_to_year(12023), Some(2023));
+/// assert_eq!(holocene_to_year(10000), Some(0));
+/// assert_eq!(holocene_to_year(1), Some(-9999));
+/// assert_eq!(holocene_to_year(i32::MIN), None);
+/// ```
+///
+/// # Algorithm
+///
+/// Simple subtraction of the era offset.
+#[inline]
+pub const fn holocene_to_year(he: i32) -> Option<i32> {
+    if he < HOLOCENE_YEAR_MIN || he > HOLOCENE_YEAR_MAX {
+        return None;
+    }
+    Some(he - HOLOCENE_OFFSET)
+}
+
+/// Offset between Thai Buddhist Era and astronomical years
+const THAI_OFFSET: i32 = 543;
+
+/// Minimum Thai Buddhist Era year for conversion
+///
+/// Corresponds to [YEAR_MIN].
+pub const THAI_YEAR_MIN: i32 = YEAR_MIN + THAI_OFFSET;
+
+/// Maximum Thai Buddhist Era year for conversion
+///
+/// Corresponds to [YEAR_MAX].
+pub const THAI_YEAR_MAX: i32 = YEAR_MAX + THAI_OFFSET;
+
+/// Convert year to Thai Buddhist Era year
+///
+/// Given an astronomical year returns the Thai Buddhist Era year, which is
+/// offset by 543 years. Since astronomical years have a year zero, the offset
+/// is the same for years before the common era.
+///
+/// # Errors
+///
+/// Returns `None` if the year is not between [YEAR_MIN] and [YEAR_MAX]
+/// inclusive.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::era::year_to_thai;
+///
+/// assert_eq!(year_to_thai(2023), Some(2566));
+/// assert_eq!(year_to_thai(-542), Some(1));
+/// assert_eq!(year_to_thai(i32::MAX), None);
+/// ```
+///
+/// # Algorithm
+///
+/// Simple addition of the era offset.
+#[inline]
+pub const fn year_to_thai(y: i32) -> Option<i32> {
+    if y < YEAR_MIN || y > YEAR_MAX {
+        return None;
+    }
+    Some(y + THAI_OFFSET)
+}
+
+/// Convert Thai Buddhist Era year to year
+///
+/// Given a Thai Buddhist Era year returns the astronomical year.
+///
+/// # Errors
+///
+/// Returns `None` if the year is not between [THAI_YEAR_MIN] and
+/// [THAI_YEAR_MAX] inclusive.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::era::thai_to_year;
+///
+/// assert_eq!(thai_to_year(2566), Some(2023));
+/// assert_eq!(thai_to_year(1), Some(-542));
+/// assert_eq!(thai_to_year(i32::MIN), None);
+/// ```
+///
+/// # Algorithm
+///
+/// Simple subtraction of the era offset.
+#[inline]
+pub const fn thai_to_year(be: i32) -> Option<i32> {
+    if be < THAI_YEAR_MIN || be > THAI_YEAR_MAX {
+        return None;
+    }
+    Some(be - THAI_OFFSET)
+}
+
+/// Astronomical year of the first year of the Republic of China era
+const ROC_EPOCH_YEAR: i32 = 1912;
+
+/// Minimum Republic of China era year for conversion
+///
+/// Corresponds to [YEAR_MIN].
+pub const ROC_YEAR_MIN: i32 = YEAR_MIN - ROC_EPOCH_YEAR;
+
+/// Maximum Republic of China era year for conversion
+///
+/// Corresponds to [YEAR_MAX].
+pub const ROC_YEAR_MAX: i32 = YEAR_MAX - ROC_EPOCH_YEAR + 1;
+
+/// Convert year to Republic of China era year
+///
+/// Given an astronomical year returns the Republic of China (Minguo) era year,
+/// where 1912 is year 1. Years before 1912 are returned as negative numbers,
+/// with 1911 being year `-1` (one year before the Republic). There is no year
+/// zero in this numbering.
+///
+/// # Errors
+///
+/// Returns `None` if the year is not between [YEAR_MIN] and [YEAR_MAX]
+/// inclusive.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::era::year_to_roc;
+///
+/// assert_eq!(year_to_roc(2023), Some(112));
+/// assert_eq!(year_to_roc(1912), Some(1));
+/// assert_eq!(year_to_roc(1911), Some(-1));
+/// assert_eq!(year_to_roc(i32::MAX), None);
+/// ```
+///
+/// # Algorithm
+///
+/// Simple subtraction of the era offset, skipping year zero.
+#[inline]
+pub const fn year_to_roc(y: i32) -> Option<i32> {
+    if y < YEAR_MIN || y > YEAR_MAX {
+        return None;
+    }
+    if y >= ROC_EPOCH_YEAR {
+        Some(y - ROC_EPOCH_YEAR + 1)
+    } else {
+        Some(y - ROC_EPOCH_YEAR)
+    }
+}
+
+/// Convert Republic of China era year to year
+///
+/// Given a Republic of China (Minguo) era year, where 1912 is year 1 and
+/// negative numbers count years before the Republic with 1911 being year `-1`,
+/// returns the astronomical year.
+///
+/// # Errors
+///
+/// Returns `None` if the year is zero or not between [ROC_YEAR_MIN] and
+/// [ROC_YEAR_MAX] inclusive.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::era::roc_to_year;
+///
+/// assert_eq!(roc_to_year(112), Some(2023));
+/// assert_eq!(roc_to_year(1), Some(1912));
+/// assert_eq!(roc_to_year(-1), Some(1911));
+/// assert_eq!(roc_to_year(0), None);
+/// ```
+///
+/// # Algorithm
+///
+/// Simple addition of the era offset, skipping year zero.
+#[inline]
+pub const fn roc_to_year(roc: i32) -> Option<i32> {
+    if roc < ROC_YEAR_MIN || roc > ROC_YEAR_MAX || roc == 0 {
+        return None;
+    }
+    if roc > 0 {
+        Some(roc + ROC_EPOCH_YEAR - 1)
+    } else {
+        Some(roc + ROC_EPOCH_YEAR)
+    }
+}
@@ -0,0 +1,300 @@
+//! Narrow range conversions using only 32-bit arithmetic
+//!
+//! Embedded and networking code often only ever deals with modern dates, and
+//! wants the absolute minimum instruction count. The functions in this module
+//! are limited to years from 1970 to 2105, which allows representing seconds
+//! since Unix epoch as `u32` and days since Unix epoch as `u16`. All
+//! computations use 32-bit unsigned arithmetic only, avoiding the 64-bit
+//! multiplications of the general algorithms.
+//!
+//! The results are identical to the general functions within the supported
+//! range.
+
+use crate::consts;
+
+/// Adjustment from Unix epoch to internal epoch, March 1st, 1600
+const DAY_OFFSET: u32 = 135080;
+
+/// Adjustment from years to internal years
+const YEAR_OFFSET: u32 = 1600;
+
+/// Minimum supported year
+pub const YEAR_MIN: u16 = 1970;
+
+/// Maximum supported year
+pub const YEAR_MAX: u16 = 2105;
+
+/// Maximum supported Rata Die, December 31st, 2105
+pub const RD_MAX: u16 = 49672;
+
+/// Maximum supported seconds, December 31st, 2105 23:59:59
+pub const SECONDS_MAX: u32 = 4291747199;
+
+/// Convert Rata Die to Gregorian date
+///
+/// Given a day counting from Unix epoch (January 1st, 1970) returns a `(year,
+/// month, day)` tuple.
+///
+/// # Panics
+///
+/// Argument must be between `0` and [RD_MAX] inclusive. Bounds are checked
+/// using `debug_assert` only, so that the checks are not present in release
+/// builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::fast32::rd_to_date;
+///
+/// assert_eq!(rd_to_date(0), (1970, 1, 1));
+/// assert_eq!(rd_to_date(19489), (2023, 5, 12));
+/// assert_eq!(rd_to_date(47540), (2100, 2, 28));
+/// assert_eq!(rd_to_date(47541), (2100, 3, 1));
+/// assert_eq!(rd_to_date(49672), (2105, 12, 31));
+/// ```
+///
+/// # Algorithm
+///
+/// Neri-Schneider algorithm as in [rd_to_date](crate::rd_to_date), using 32-bit
+/// division for the year within the century.
+#[inline]
+pub const fn rd_to_date(n: u16) -> (u16, u8, u8) {
+    debug_assert!(n <= RD_MAX, "given rata die is out of range");
+    let n = n as u32 + DAY_OFFSET;
+    // century
+    let n = 4 * n + 3;
+    let c = n / 146097;
+    let r = n % 146097;
+    // year
+    let n = r | 3;
+    let z = n / 1461;
+    let n = n % 1461 / 4;
+    let j = n >= 306;
+    let y = 100 * c + z + j as u32;
+    // month and day
+    let n = 2141 * n + 197913;
+    let m = n / 2u32.pow(16);
+    let d = n % 2u32.pow(16) / 2141;
+    // map
+    let y = y + YEAR_OFFSET;
+    let m = if j { m - 12 } else { m };
+    let d = d + 1;
+    (y as u16, m as u8, d as u8)
+}
+
+/// Convert Gregorian date to Rata Die
+///
+/// Given a `(year, month, day)` tuple returns the days since Unix epoch
+/// (January 1st, 1970).
+///
+/// # Panics
+///
+/// Year must be between [YEAR_MIN] and [YEAR_MAX]. Month must be between `1`
+/// and `12`. Day must be between `1` and the number of days in the month in
+/// question. Bounds are checked using `debug_assert` only, so that the checks
+/// are not present in release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::fast32::date_to_rd;
+///
+/// assert_eq!(date_to_rd((1970, 1, 1)), 0);
+/// assert_eq!(date_to_rd((2023, 5, 12)), 19489);
+/// assert_eq!(date_to_rd((2100, 3, 1)), 47541);
+/// assert_eq!(date_to_rd((2105, 12, 31)), 49672);
+/// ```
+///
+/// # Algorithm
+///
+/// Neri-Schneider algorithm as in [date_to_rd](crate::date_to_rd).
+#[inline]
+pub const fn date_to_rd((y, m, d): (u16, u8, u8)) -> u16 {
+    debug_assert!(y >= YEAR_MIN && y <= YEAR_MAX, "given year is out of range");
+    debug_assert!(m >= consts::MONTH_MIN && m <= consts::MONTH_MAX, "given month is out of range");
+    debug_assert!(
+        d >= consts::DAY_MIN && d <= crate::days_in_month(y as i32, m),
+        "given day is out of range"
+    );
+    let jf = (m < 3) as u32;
+    let y = (y as u32).wrapping_sub(YEAR_OFFSET).wrapping_sub(jf);
+    let c = y / 100;
+    let m = m as u32 + 12 * jf;
+    // year
+    let y = 1461 * y / 4 - c + c / 4;
+    // month
+    let m = (979 * m - 2919) / 32;
+    // result
+    let n = y + m + d as u32 - 1;
+    n.wrapping_sub(DAY_OFFSET) as u16
+}
+
+/// Convert Rata Die to day of week
+///
+/// Given a day counting from Unix epoch (January 1st, 1970) returns the day of
+/// week, with `1` meaning Monday and `7` meaning Sunday.
+///
+/// # Panics
+///
+/// Argument must be between `0` and [RD_MAX] inclusive. Bounds are checked
+/// using `debug_assert` only, so that the checks are not present in release
+/// builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::fast32::rd_to_weekday;
+///
+/// assert_eq!(rd_to_weekday(0), 4);
+/// assert_eq!(rd_to_weekday(19489), 5);
+/// assert_eq!(rd_to_weekday(19358), 7);
+/// ```
+///
+/// # Algorithm
+///
+/// Same as [rd_to_weekday](crate::rd_to_weekday), using the 32-bit reciprocal
+/// of `7`.
+#[inline]
+pub const fn rd_to_weekday(n: u16) -> u8 {
+    debug_assert!(n <= RD_MAX, "given rata die is out of range");
+    const C: u32 = u32::MAX / 7;
+    // offset makes n = 0 yield 4, truncated reciprocal is exact far beyond RD_MAX
+    let m = n as u32 + 3;
+    ((m + 1).wrapping_mul(C) >> 29) as u8
+}
+
+/// Split total seconds to days, hours, minutes and seconds
+///
+/// Given seconds counting from Unix epoch (January 1st, 1970) returns a `(days,
+/// hours, minutes, seconds)` tuple.
+///
+/// # Panics
+///
+/// Argument must be between `0` and [SECONDS_MAX] inclusive. Bounds are checked
+/// using `debug_assert` only, so that the checks are not present in release
+/// builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::fast32::secs_to_dhms;
+///
+/// assert_eq!(secs_to_dhms(0), (0, 0, 0, 0));
+/// assert_eq!(secs_to_dhms(86399), (0, 23, 59, 59));
+/// assert_eq!(secs_to_dhms(1684574678), (19497, 9, 24, 38));
+/// ```
+///
+/// # Algorithm
+///
+/// Plain 32-bit division, which compilers implement as multiplication.
+#[inline]
+pub const fn secs_to_dhms(secs: u32) -> (u16, u8, u8, u8) {
+    debug_assert!(secs <= SECONDS_MAX, "given seconds value is out of range");
+    let days = secs / 86400;
+    let secs = secs % 86400;
+    let mins = secs / 60;
+    let ss = secs % 60;
+    let hh = mins / 60;
+    let mm = mins % 60;
+    (days as u16, hh as u8, mm as u8, ss as u8)
+}
+
+/// Combine days, hours, minutes and seconds to total seconds
+///
+/// Given a `(days, hours, minutes, seconds)` tuple from Unix epoch (January
+/// 1st, 1970) returns the total seconds.
+///
+/// # Panics
+///
+/// Days must be between `0` and [RD_MAX] inclusive. Hours must be between `0`
+/// and `23`. Minutes must be between `0` and `59`. Seconds must be between `0`
+/// and `59`. Bounds are checked using `debug_assert` only, so that the checks
+/// are not present in release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::fast32::dhms_to_secs;
+///
+/// assert_eq!(dhms_to_secs((0, 0, 0, 0)), 0);
+/// assert_eq!(dhms_to_secs((0, 23, 59, 59)), 86399);
+/// assert_eq!(dhms_to_secs((19497, 9, 24, 38)), 1684574678);
+/// ```
+///
+/// # Algorithm
+///
+/// Algorithm is simple multiplication, method provided only as convenience.
+#[inline]
+pub const fn dhms_to_secs((d, h, m, s): (u16, u8, u8, u8)) -> u32 {
+    debug_assert!(d <= RD_MAX, "given rata die is out of range");
+    debug_assert!(h >= consts::HOUR_MIN && h <= consts::HOUR_MAX, "given hour is out of range");
+    debug_assert!(m >= consts::MINUTE_MIN && m <= consts::MINUTE_MAX, "given minute is out of range");
+    debug_assert!(s >= consts::SECOND_MIN && s <= consts::SECOND_MAX, "given second is out of range");
+    (d as u32)
+        .wrapping_mul(86400)
+        .wrapping_add(h as u32 * 3600 + m as u32 * 60 + s as u32)
+}
+
+/// Convert total seconds to year, month, day, hours, minutes and seconds
+///
+/// Given seconds counting from Unix epoch (January 1st, 1970) returns a `(year,
+/// month, day, hours, minutes, seconds)` tuple.
+///
+/// # Panics
+///
+/// Argument must be between `0` and [SECONDS_MAX] inclusive. Bounds are checked
+/// using `debug_assert` only, so that the checks are not present in release
+/// builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::fast32::secs_to_datetime;
+///
+/// assert_eq!(secs_to_datetime(0), (1970, 1, 1, 0, 0, 0));
+/// assert_eq!(secs_to_datetime(1684574678), (2023, 5, 20, 9, 24, 38));
+/// assert_eq!(secs_to_datetime(4291747199), (2105, 12, 31, 23, 59, 59));
+/// ```
+///
+/// # Algorithm
+///
+/// Combination of existing functions for convenience only.
+#[inline]
+pub const fn secs_to_datetime(secs: u32) -> (u16, u8, u8, u8, u8, u8) {
+    let (days, hh, mm, ss) = secs_to_dhms(secs);
+    let (y, m, d) = rd_to_date(days);
+    (y, m, d, hh, mm, ss)
+}
+
+/// Convert year, month, day, hours, minutes and seconds to total seconds
+///
+/// Given a `(year, month, day, hours, minutes, seconds)` tuple returns the
+/// seconds since Unix epoch (January 1st, 1970).
+///
+/// # Panics
+///
+/// Year must be between [YEAR_MIN] and [YEAR_MAX]. Month must be between `1`
+/// and `12`. Day must be between `1` and the number of days in the month in
+/// question. Hours must be between `0` and `23`. Minutes must be between `0`
+/// and `59`. Seconds must be between `0` and `59`. Bounds are checked using
+/// `debug_assert` only, so that the checks are not present in release builds,
+/// similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::fast32::datetime_to_secs;
+///
+/// assert_eq!(datetime_to_secs((1970, 1, 1, 0, 0, 0)), 0);
+/// assert_eq!(datetime_to_secs((2023, 5, 20, 9, 24, 38)), 1684574678);
+/// assert_eq!(datetime_to_secs((2105, 12, 31, 23, 59, 59)), 4291747199);
+/// ```
+///
+/// # Algorithm
+///
+/// Combination of existing functions for convenience only.
+#[inline]
+pub const fn datetime_to_secs((y, m, d, hh, mm, ss): (u16, u8, u8, u8, u8, u8)) -> u32 {
+    let days = date_to_rd((y, m, d));
+    dhms_to_secs((days, hh, mm, ss))
+}
@@ -0,0 +1,224 @@
+//! Conversions to and from `hifitime` epochs
+//!
+//! The [hifitime](https://docs.rs/hifitime) crate represents instants as
+//! [Epoch] values, counted internally in TAI from January 1st, 1900. The
+//! functions in this module convert between those and the Unix seconds, TAI
+//! seconds and Rata Die values used in the rest of the crate.
+//!
+//! Conversions of Unix seconds account for leap seconds. With the `leapsecs`
+//! feature enabled, the leap second table of this crate is used, and the TAI-UTC
+//! offset of 10 seconds is extrapolated backwards before 1972 as described in
+//! the [leapsecs](crate::leapsecs) module. Otherwise the leap second table of
+//! `hifitime` is used, which applies no offset before 1972.
+
+#[cfg(feature = "leapsecs")]
+use ::hifitime::TimeScale;
+use ::hifitime::{Duration, Epoch};
+
+use crate::{RD_MAX, RD_MIN, RD_SECONDS_MAX, RD_SECONDS_MIN, SECS_IN_DAY};
+
+/// Seconds from January 1st, 1900 to Unix epoch (January 1st, 1970)
+pub const J1900_TO_UNIX_SECS: i64 = 2208988800;
+
+/// Nanoseconds in a second
+const NANOS_IN_SEC: i128 = 1_000_000_000;
+
+/// Nanoseconds in a Julian century, the unit of [Duration] parts
+const NANOS_IN_CENTURY: i128 = 36525 * SECS_IN_DAY as i128 * NANOS_IN_SEC;
+
+/// Split a duration since January 1st, 1900 to seconds and nanoseconds since
+/// Unix epoch
+#[inline]
+fn duration_to_secs(duration: Duration) -> (i64, u32) {
+    // `Duration::total_nanoseconds` is incorrect for durations more than a
+    // century before the reference epoch, so the parts are combined here
+    let (centuries, nanos) = duration.to_parts();
+    let nanos = centuries as i128 * NANOS_IN_CENTURY + nanos as i128 - J1900_TO_UNIX_SECS as i128 * NANOS_IN_SEC;
+    (nanos.div_euclid(NANOS_IN_SEC) as i64, nanos.rem_euclid(NANOS_IN_SEC) as u32)
+}
+
+/// Combine seconds and nanoseconds since Unix epoch to a duration since
+/// January 1st, 1900
+#[inline]
+fn secs_to_duration((secs, nanos): (i64, u32)) -> Duration {
+    let nanos = (secs + J1900_TO_UNIX_SECS) as i128 * NANOS_IN_SEC + nanos as i128;
+    Duration::from_parts(nanos.div_euclid(NANOS_IN_CENTURY) as i16, nanos.rem_euclid(NANOS_IN_CENTURY) as u64)
+}
+
+/// Convert TAI seconds and nanoseconds to an epoch
+///
+/// Given a `(seconds, nanoseconds)` tuple of TAI seconds, on the same scale as
+/// Unix seconds, returns the corresponding [Epoch] in the TAI time scale.
+///
+/// # Panics
+///
+/// Seconds must be between [RD_SECONDS_MIN] and [RD_SECONDS_MAX] inclusive.
+/// Nanoseconds must be between `0` and `999_999_999`. Bounds are checked using
+/// `debug_assert` only, so that the checks are not present in release builds,
+/// similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::hifitime::tai_to_epoch;
+/// use hifitime::Epoch;
+///
+/// assert_eq!(tai_to_epoch((0, 0)), Epoch::from_gregorian_tai(1970, 1, 1, 0, 0, 0, 0));
+/// assert_eq!(tai_to_epoch((1684574715, 5)), Epoch::from_gregorian_tai(2023, 5, 20, 9, 25, 15, 5));
+/// ```
+#[inline]
+pub fn tai_to_epoch((secs, nanos): (i64, u32)) -> Epoch {
+    debug_assert!(
+        secs >= RD_SECONDS_MIN && secs <= RD_SECONDS_MAX,
+        "given seconds value is out of range"
+    );
+    debug_assert!(nanos < NANOS_IN_SEC as u32, "given nanoseconds value is out of range");
+    Epoch::from_tai_duration(secs_to_duration((secs, nanos)))
+}
+
+/// Convert an epoch to TAI seconds and nanoseconds
+///
+/// Given an [Epoch] in any time scale, returns a `(seconds, nanoseconds)`
+/// tuple of TAI seconds, on the same scale as Unix seconds.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::hifitime::epoch_to_tai;
+/// use hifitime::Epoch;
+///
+/// assert_eq!(epoch_to_tai(Epoch::from_gregorian_tai(1970, 1, 1, 0, 0, 0, 0)), (0, 0));
+/// assert_eq!(epoch_to_tai(Epoch::from_gregorian_tai(2023, 5, 20, 9, 25, 15, 5)), (1684574715, 5));
+/// ```
+#[inline]
+pub fn epoch_to_tai(epoch: Epoch) -> (i64, u32) {
+    duration_to_secs(epoch.to_tai_duration())
+}
+
+/// Convert Unix seconds and nanoseconds to an epoch
+///
+/// Given a `(seconds, nanoseconds)` tuple counting from Unix epoch (January
+/// 1st, 1970), returns the corresponding [Epoch] in the UTC time scale.
+///
+/// # Panics
+///
+/// Seconds must be between [RD_SECONDS_MIN] and [RD_SECONDS_MAX] inclusive.
+/// Nanoseconds must be between `0` and `999_999_999`. Bounds are checked using
+/// `debug_assert` only, so that the checks are not present in release builds,
+/// similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::hifitime::secs_to_epoch;
+/// use hifitime::Epoch;
+///
+/// assert_eq!(secs_to_epoch((1684574678, 5)), Epoch::from_gregorian_utc(2023, 5, 20, 9, 24, 38, 5));
+/// ```
+///
+/// # Algorithm
+///
+/// With the `leapsecs` feature, conversion to TAI using
+/// [utc_to_tai](crate::leapsecs::utc_to_tai). Otherwise conversion by
+/// `hifitime`.
+#[inline]
+pub fn secs_to_epoch((secs, nanos): (i64, u32)) -> Epoch {
+    debug_assert!(
+        secs >= RD_SECONDS_MIN && secs <= RD_SECONDS_MAX,
+        "given seconds value is out of range"
+    );
+    debug_assert!(nanos < NANOS_IN_SEC as u32, "given nanoseconds value is out of range");
+    #[cfg(feature = "leapsecs")]
+    {
+        let tai = crate::leapsecs::utc_to_tai(secs);
+        Epoch::from_tai_duration(secs_to_duration((tai, nanos))).in_time_scale(TimeScale::UTC)
+    }
+    #[cfg(not(feature = "leapsecs"))]
+    {
+        Epoch::from_utc_duration(secs_to_duration((secs, nanos)))
+    }
+}
+
+/// Convert an epoch to Unix seconds and nanoseconds
+///
+/// Given an [Epoch] in any time scale, returns a `(seconds, nanoseconds)`
+/// tuple counting from Unix epoch (January 1st, 1970). Inserted leap seconds
+/// are returned as the last second of the day, 23:59:59.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::hifitime::epoch_to_secs;
+/// use hifitime::Epoch;
+///
+/// assert_eq!(epoch_to_secs(Epoch::from_gregorian_utc(2023, 5, 20, 9, 24, 38, 5)), (1684574678, 5));
+/// ```
+///
+/// # Algorithm
+///
+/// With the `leapsecs` feature, conversion from TAI using
+/// [tai_to_utc](crate::leapsecs::tai_to_utc). Otherwise conversion by
+/// `hifitime`.
+#[inline]
+pub fn epoch_to_secs(epoch: Epoch) -> (i64, u32) {
+    #[cfg(feature = "leapsecs")]
+    {
+        let (secs, nanos) = epoch_to_tai(epoch);
+        (crate::leapsecs::tai_to_utc(secs).0, nanos)
+    }
+    #[cfg(not(feature = "leapsecs"))]
+    {
+        duration_to_secs(epoch.to_utc_duration())
+    }
+}
+
+/// Convert Rata Die to an epoch
+///
+/// Given a day counting from Unix epoch (January 1st, 1970), returns the
+/// [Epoch] of midnight UTC at the start of the day, in the UTC time scale.
+///
+/// # Panics
+///
+/// Argument must be between [RD_MIN] and [RD_MAX] inclusive. Bounds are checked
+/// using `debug_assert` only, so that the checks are not present in release
+/// builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::hifitime::rd_to_epoch;
+/// use hifitime::Epoch;
+///
+/// assert_eq!(rd_to_epoch(19489), Epoch::from_gregorian_utc_at_midnight(2023, 5, 12));
+/// ```
+///
+/// # Algorithm
+///
+/// Multiplication followed by [secs_to_epoch].
+#[inline]
+pub fn rd_to_epoch(n: i32) -> Epoch {
+    debug_assert!(n >= RD_MIN && n <= RD_MAX, "given rata die is out of range");
+    secs_to_epoch((n as i64 * SECS_IN_DAY, 0))
+}
+
+/// Convert an epoch to Rata Die
+///
+/// Given an [Epoch] in any time scale, returns the day counting from Unix
+/// epoch (January 1st, 1970) of the UTC date of the epoch.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::hifitime::epoch_to_rd;
+/// use hifitime::Epoch;
+///
+/// assert_eq!(epoch_to_rd(Epoch::from_gregorian_utc(2023, 5, 12, 23, 59, 59, 0)), 19489);
+/// ```
+///
+/// # Algorithm
+///
+/// [epoch_to_secs] followed by floor division.
+#[inline]
+pub fn epoch_to_rd(epoch: Epoch) -> i32 {
+    epoch_to_secs(epoch).0.div_euclid(SECS_IN_DAY) as i32
+}
@@ -0,0 +1,140 @@
+//! Rules for computing holidays
+//!
+//! Most public holidays are defined by a handful of rules, such as a fixed
+//! date, the nth day of week of a month or an offset from Easter Sunday.
+//! Expressing a holiday calendar as a list of [HolidayRule] values allows the
+//! holidays of any year to be computed with [evaluate_holiday_rules], for
+//! example to build the holiday set used by the [business](crate::business)
+//! functions.
+
+use crate::easter::easter_sunday;
+use crate::{consts, date_to_rd, days_in_month, rd_to_weekday, YEAR_MAX, YEAR_MIN};
+
+/// Rule for the day of a holiday
+///
+/// Months and days of week are as in [date_to_rd] and [rd_to_weekday].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HolidayRule {
+    /// Fixed month and day of month
+    Fixed(u8, u8),
+    /// Day of week (third value) in week `1` to `5`, `5` meaning the last
+    /// (second value), of the month (first value)
+    NthWeekday(u8, u8, u8),
+    /// Monday to Friday nearest to the month and day of month, so that a
+    /// Saturday is observed on the Friday before and a Sunday on the Monday
+    /// after
+    NearestWeekday(u8, u8),
+    /// Number of days after Easter Sunday in the Gregorian calendar, negative
+    /// for days before
+    EasterOffset(i16),
+}
+
+/// Determine the day of a holiday in the given year
+///
+/// Given a year and a holiday rule returns the day of the holiday, counting
+/// from Unix epoch (January 1st, 1970).
+///
+/// # Panics
+///
+/// Year must be between [YEAR_MIN] and [YEAR_MAX]. Month must be between `1`
+/// and `12` and day between `1` and the number of days in the month in
+/// question. Week must be between `1` and `5` and day of week between `1` and
+/// `7`. Bounds are checked using `debug_assert` only, so that the checks are
+/// not present in release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::consts::{MONDAY, THURSDAY};
+/// use datealgo::date_to_rd;
+/// use datealgo::holiday::{holiday_to_rd, HolidayRule::*};
+///
+/// assert_eq!(holiday_to_rd(2023, Fixed(12, 25)), date_to_rd((2023, 12, 25)));
+/// assert_eq!(holiday_to_rd(2023, NthWeekday(11, 4, THURSDAY)), date_to_rd((2023, 11, 23)));
+/// assert_eq!(holiday_to_rd(2023, NthWeekday(5, 5, MONDAY)), date_to_rd((2023, 5, 29)));
+/// assert_eq!(holiday_to_rd(2023, NearestWeekday(7, 4)), date_to_rd((2023, 7, 4)));
+/// assert_eq!(holiday_to_rd(2021, NearestWeekday(12, 25)), date_to_rd((2021, 12, 24)));
+/// assert_eq!(holiday_to_rd(2023, EasterOffset(-2)), date_to_rd((2023, 4, 7)));
+/// ```
+///
+/// # Algorithm
+///
+/// Day of week of the first day of the month using [rd_to_weekday] for week
+/// based rules, and [easter_sunday] for Easter based rules.
+#[inline]
+pub const fn holiday_to_rd(y: i32, rule: HolidayRule) -> i32 {
+    debug_assert!(y >= YEAR_MIN && y <= YEAR_MAX, "given year is out of range");
+    match rule {
+        HolidayRule::Fixed(m, d) => date_to_rd((y, m, d)),
+        HolidayRule::NthWeekday(m, w, wd) => {
+            debug_assert!(m >= consts::MONTH_MIN && m <= consts::MONTH_MAX, "given month is out of range");
+            debug_assert!(w >= 1 && w <= 5, "given week is out of range");
+            debug_assert!(
+                wd >= consts::WEEKDAY_MIN && wd <= consts::WEEKDAY_MAX,
+                "given weekday is out of range"
+            );
+            let first = date_to_rd((y, m, 1));
+            let n = first + ((wd + 7 - rd_to_weekday(first)) % 7) as i32 + 7 * (w as i32 - 1);
+            if n >= first + days_in_month(y, m) as i32 {
+                n - 7
+            } else {
+                n
+            }
+        }
+        HolidayRule::NearestWeekday(m, d) => {
+            let n = date_to_rd((y, m, d));
+            match rd_to_weekday(n) {
+                consts::SATURDAY => n - 1,
+                consts::SUNDAY => n + 1,
+                _ => n,
+            }
+        }
+        HolidayRule::EasterOffset(offset) => date_to_rd(easter_sunday(y)) + offset as i32,
+    }
+}
+
+/// Evaluate holiday rules for the given year
+///
+/// Given a year and a slice of holiday rules returns an iterator over the days
+/// of the holidays, counting from Unix epoch (January 1st, 1970), in the order
+/// of the rules. The days are not sorted or deduplicated, as rules may
+/// coincide in some years.
+///
+/// # Panics
+///
+/// Same as [holiday_to_rd] for each of the rules.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::consts::{MONDAY, THURSDAY};
+/// use datealgo::date_to_rd;
+/// use datealgo::holiday::{evaluate_holiday_rules, HolidayRule::*};
+///
+/// let rules = [
+///     NearestWeekday(1, 1),
+///     NthWeekday(5, 5, MONDAY),
+///     NearestWeekday(7, 4),
+///     NthWeekday(11, 4, THURSDAY),
+///     NearestWeekday(12, 25),
+/// ];
+/// let days: Vec<i32> = evaluate_holiday_rules(2022, &rules).collect();
+/// assert_eq!(
+///     days,
+///     [
+///         date_to_rd((2021, 12, 31)),
+///         date_to_rd((2022, 5, 30)),
+///         date_to_rd((2022, 7, 4)),
+///         date_to_rd((2022, 11, 24)),
+///         date_to_rd((2022, 12, 26)),
+///     ]
+/// );
+/// ```
+///
+/// # Algorithm
+///
+/// Mapping of the rules with [holiday_to_rd].
+#[inline]
+pub fn evaluate_holiday_rules(y: i32, rules: &[HolidayRule]) -> impl Iterator<Item = i32> + '_ {
+    rules.iter().map(move |&rule| holiday_to_rd(y, rule))
+}
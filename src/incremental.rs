@@ -0,0 +1,134 @@
+//! Incremental conversion of nearly sorted timestamps
+//!
+//! Log processing and metrics pipelines convert huge numbers of timestamps
+//! that are almost sorted, so consecutive timestamps usually fall on the same
+//! day. [IncrementalDateTime] caches the date of the previously converted
+//! timestamp, so that such conversions only need a single comparison and a
+//! subtraction before splitting the time of day.
+
+use crate::{rd_to_date, RD_SECONDS_MAX, RD_SECONDS_MIN, SECS_IN_DAY};
+
+/// Converter from Unix seconds to date and time caching the current day
+///
+/// Converts seconds to `(year, month, day, hours, minutes, seconds)` tuples,
+/// giving identical results to [secs_to_datetime](crate::secs_to_datetime).
+/// When the given seconds fall on the same day as the previous conversion,
+/// the cached date is reused and only the time of day is computed. Otherwise
+/// the full algorithm is used and the cache is updated.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::incremental::IncrementalDateTime;
+///
+/// let mut conv = IncrementalDateTime::new();
+/// assert_eq!(conv.secs_to_datetime(1684574678), (2023, 5, 20, 9, 24, 38));
+/// assert_eq!(conv.secs_to_datetime(1684574679), (2023, 5, 20, 9, 24, 39));
+/// assert_eq!(conv.secs_to_datetime(1684627200), (2023, 5, 21, 0, 0, 0));
+/// assert_eq!(conv.date(), (2023, 5, 21));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IncrementalDateTime {
+    rd: i32,
+    midnight: i64,
+    date: (i32, u8, u8),
+}
+
+impl IncrementalDateTime {
+    /// Create a new converter
+    ///
+    /// The cache initially holds January 1st, 1970.
+    #[inline]
+    pub const fn new() -> Self {
+        IncrementalDateTime {
+            rd: 0,
+            midnight: 0,
+            date: (1970, 1, 1),
+        }
+    }
+
+    /// Rata Die of the currently cached day
+    #[inline]
+    pub const fn rd(&self) -> i32 {
+        self.rd
+    }
+
+    /// Unix seconds of the start of the currently cached day
+    #[inline]
+    pub const fn midnight_secs(&self) -> i64 {
+        self.midnight
+    }
+
+    /// Date of the currently cached day as a `(year, month, day)` tuple
+    #[inline]
+    pub const fn date(&self) -> (i32, u8, u8) {
+        self.date
+    }
+
+    /// Convert total seconds to year, month, day, hours, minutes and seconds
+    ///
+    /// Given seconds counting from Unix epoch (January 1st, 1970) returns a
+    /// `(year, month, day, hours, minutes, seconds)` tuple.
+    ///
+    /// # Panics
+    ///
+    /// Argument must be between [RD_SECONDS_MIN] and [RD_SECONDS_MAX]
+    /// inclusive. Bounds are checked using `debug_assert` only, so that the
+    /// checks are not present in release builds, similar to integer overflow
+    /// checks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use datealgo::incremental::IncrementalDateTime;
+    ///
+    /// let mut conv = IncrementalDateTime::new();
+    /// assert_eq!(conv.secs_to_datetime(0), (1970, 1, 1, 0, 0, 0));
+    /// assert_eq!(conv.secs_to_datetime(86399), (1970, 1, 1, 23, 59, 59));
+    /// assert_eq!(conv.secs_to_datetime(-1), (1969, 12, 31, 23, 59, 59));
+    /// ```
+    ///
+    /// # Algorithm
+    ///
+    /// Single unsigned comparison against the cached day, falling back to
+    /// [secs_to_dhms](crate::secs_to_dhms) and [rd_to_date] on day change.
+    #[inline]
+    pub fn secs_to_datetime(&mut self, secs: i64) -> (i32, u8, u8, u8, u8, u8) {
+        debug_assert!(
+            secs >= RD_SECONDS_MIN && secs <= RD_SECONDS_MAX,
+            "given seconds value is out of range"
+        );
+        let offset = secs.wrapping_sub(self.midnight) as u64;
+        if offset < SECS_IN_DAY as u64 {
+            let (hh, mm, ss) = split_time(offset as u32);
+            let (y, m, d) = self.date;
+            return (y, m, d, hh, mm, ss);
+        }
+        let (rd, hh, mm, ss) = crate::secs_to_dhms(secs);
+        self.rd = rd;
+        self.midnight = rd as i64 * SECS_IN_DAY;
+        self.date = rd_to_date(rd);
+        let (y, m, d) = self.date;
+        (y, m, d, hh, mm, ss)
+    }
+}
+
+impl Default for IncrementalDateTime {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Split seconds of day to hours, minutes and seconds
+#[inline]
+const fn split_time(secs: u32) -> (u8, u8, u8) {
+    // see secs_to_dhms for the identities used
+    let prd = 71582789 * secs as u64;
+    let mins = (prd >> 32) as u32;
+    let ss = prd as u32 / 71582789;
+    let prd = 71582789 * mins as u64;
+    let hh = (prd >> 32) as u32;
+    let mm = prd as u32 / 71582789;
+    (hh as u8, mm as u8, ss as u8)
+}
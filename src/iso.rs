@@ -0,0 +1,659 @@
+//! Parsing and formatting of ISO 8601 string components
+//!
+//! These functions operate on byte slices and fixed size buffers, so they work
+//! in `no_std` environments and never allocate. They only handle the individual
+//! components of ISO 8601 and RFC 3339 strings, composing them into complete
+//! timestamps is left to the caller.
+
+use crate::{consts, days_in_month, YEAR_MAX, YEAR_MIN};
+
+/// Parse two ASCII digits from the given position
+#[inline]
+const fn parse_2digits(s: &[u8], i: usize) -> Option<u8> {
+    let a = s[i].wrapping_sub(b'0');
+    let b = s[i + 1].wrapping_sub(b'0');
+    if a > 9 || b > 9 {
+        return None;
+    }
+    Some(a * 10 + b)
+}
+
+/// Write a two digit number as ASCII to the given position
+#[inline]
+pub(crate) fn format_2digits(buf: &mut [u8], i: usize, v: u32) {
+    buf[i] = b'0' + (v / 10 % 10) as u8;
+    buf[i + 1] = b'0' + (v % 10) as u8;
+}
+
+/// Parse UTC offset string to seconds
+///
+/// Given an UTC offset string as bytes returns the offset in seconds, positive
+/// values being east of UTC. Accepts `Z` (or `z`) for UTC and the `±HH:MM`,
+/// `±HHMM` and `±HH` forms. The sign is required and applies to both hours and
+/// minutes, so `-01:30` is `-5400` seconds. Offset `-00:00` is parsed as `0`.
+///
+/// # Errors
+///
+/// Returns `None` if the string is not one of the accepted forms, hours are
+/// over `23` or minutes are over `59`.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::iso::parse_offset;
+///
+/// assert_eq!(parse_offset(b"Z"), Some(0));
+/// assert_eq!(parse_offset(b"+02:00"), Some(7200));
+/// assert_eq!(parse_offset(b"-01:30"), Some(-5400));
+/// assert_eq!(parse_offset(b"+0545"), Some(20700));
+/// assert_eq!(parse_offset(b"-08"), Some(-28800));
+/// assert_eq!(parse_offset(b"+24:00"), None);
+/// assert_eq!(parse_offset(b"01:00"), None);
+/// ```
+///
+/// # Algorithm
+///
+/// Simple digit parsing with a length based selection of the form.
+#[inline]
+pub const fn parse_offset(s: &[u8]) -> Option<i32> {
+    if s.len() == 1 && (s[0] == b'Z' || s[0] == b'z') {
+        return Some(0);
+    }
+    if s.len() != 3 && s.len() != 5 && s.len() != 6 {
+        return None;
+    }
+    let neg = match s[0] {
+        b'+' => false,
+        b'-' => true,
+        _ => return None,
+    };
+    let h = match parse_2digits(s, 1) {
+        Some(h) if h <= 23 => h,
+        _ => return None,
+    };
+    let m = match s.len() {
+        3 => 0,
+        5 => match parse_2digits(s, 3) {
+            Some(m) if m <= 59 => m,
+            _ => return None,
+        },
+        _ => match (s[3], parse_2digits(s, 4)) {
+            (b':', Some(m)) if m <= 59 => m,
+            _ => return None,
+        },
+    };
+    let secs = h as i32 * 3600 + m as i32 * 60;
+    Some(if neg { -secs } else { secs })
+}
+
+/// Format UTC offset in seconds to string
+///
+/// Given an UTC offset in seconds, positive values being east of UTC, writes
+/// the offset in `±HH:MM` form to the given buffer. Zero offset is written as
+/// `+00:00`. Any seconds in the offset are truncated towards zero, so the
+/// output is never `-00:00`.
+///
+/// # Panics
+///
+/// Offset must be between `-86399` and `86399` inclusive. Bounds are checked
+/// using `debug_assert` only, so that the checks are not present in release
+/// builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::iso::format_offset;
+///
+/// let mut buf = [0; 6];
+/// format_offset(0, &mut buf);
+/// assert_eq!(&buf, b"+00:00");
+/// format_offset(7200, &mut buf);
+/// assert_eq!(&buf, b"+02:00");
+/// format_offset(-5400, &mut buf);
+/// assert_eq!(&buf, b"-01:30");
+/// format_offset(20700, &mut buf);
+/// assert_eq!(&buf, b"+05:45");
+/// ```
+///
+/// # Algorithm
+///
+/// Sign and magnitude are handled separately, so that negative offsets with
+/// nonzero minutes are formatted correctly.
+#[inline]
+pub fn format_offset(offset: i32, buf: &mut [u8; 6]) {
+    debug_assert!(offset > -86400 && offset < 86400, "given offset is out of range");
+    let abs = offset.unsigned_abs();
+    buf[0] = if offset <= -60 { b'-' } else { b'+' };
+    format_2digits(buf, 1, abs / 3600);
+    buf[3] = b':';
+    format_2digits(buf, 4, abs / 60 % 60);
+}
+
+/// Format UTC offset in seconds to basic format string
+///
+/// Same as [format_offset], but writes the offset in the `±HHMM` form without
+/// a separator, as used by the `%z` directive of `strftime`. The extended
+/// form written by [format_offset] matches `%:z`.
+///
+/// # Panics
+///
+/// Offset must be between `-86399` and `86399` inclusive. Bounds are checked
+/// using `debug_assert` only, so that the checks are not present in release
+/// builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::iso::format_offset_basic;
+///
+/// let mut buf = [0; 5];
+/// format_offset_basic(0, &mut buf);
+/// assert_eq!(&buf, b"+0000");
+/// format_offset_basic(-5400, &mut buf);
+/// assert_eq!(&buf, b"-0130");
+/// format_offset_basic(20700, &mut buf);
+/// assert_eq!(&buf, b"+0545");
+/// ```
+///
+/// # Algorithm
+///
+/// Same as [format_offset].
+#[inline]
+pub fn format_offset_basic(offset: i32, buf: &mut [u8; 5]) {
+    debug_assert!(offset > -86400 && offset < 86400, "given offset is out of range");
+    let abs = offset.unsigned_abs();
+    buf[0] = if offset <= -60 { b'-' } else { b'+' };
+    format_2digits(buf, 1, abs / 3600);
+    format_2digits(buf, 3, abs / 60 % 60);
+}
+
+/// Format UTC offset or time zone abbreviation for a `strftime` directive
+///
+/// Given an UTC offset in seconds, positive values being east of UTC, a time
+/// zone abbreviation and a `strftime` directive, writes the output of the
+/// directive to the given buffer and returns the number of bytes written. The
+/// supported directives are `%z` for the offset in `±HHMM` form as written by
+/// [format_offset_basic], `%:z` for the offset in `±HH:MM` form as written by
+/// [format_offset] and `%Z` for the abbreviation as is, such as `EEST`.
+///
+/// # Errors
+///
+/// Returns `None` if the directive is not one of the supported ones or the
+/// buffer is too short for the output.
+///
+/// # Panics
+///
+/// Offset must be between `-86399` and `86399` inclusive. Bounds are checked
+/// using `debug_assert` only, so that the checks are not present in release
+/// builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::iso::format_offset_directive;
+///
+/// let mut buf = [0; 8];
+/// assert_eq!(format_offset_directive(10800, b"EEST", b"%z", &mut buf), Some(5));
+/// assert_eq!(&buf[..5], b"+0300");
+/// assert_eq!(format_offset_directive(-5400, b"NDT", b"%:z", &mut buf), Some(6));
+/// assert_eq!(&buf[..6], b"-01:30");
+/// assert_eq!(format_offset_directive(10800, b"EEST", b"%Z", &mut buf), Some(4));
+/// assert_eq!(&buf[..4], b"EEST");
+/// assert_eq!(format_offset_directive(0, b"UTC", b"%Y", &mut buf), None);
+/// ```
+///
+/// # Algorithm
+///
+/// Same as [format_offset] for the offset directives.
+#[inline]
+pub fn format_offset_directive(offset: i32, abbr: &[u8], directive: &[u8], buf: &mut [u8]) -> Option<usize> {
+    debug_assert!(offset > -86400 && offset < 86400, "given offset is out of range");
+    match directive {
+        b"%z" => {
+            format_offset_basic(offset, buf.get_mut(..5)?.try_into().ok()?);
+            Some(5)
+        }
+        b"%:z" => {
+            format_offset(offset, buf.get_mut(..6)?.try_into().ok()?);
+            Some(6)
+        }
+        b"%Z" => {
+            buf.get_mut(..abbr.len())?.copy_from_slice(abbr);
+            Some(abbr.len())
+        }
+        _ => None,
+    }
+}
+
+/// Parse ASCII digits from the given position until a non-digit
+///
+/// Returns the value and the position after the last digit, or `None` if there
+/// are no digits or the value overflows.
+#[inline]
+pub(crate) const fn parse_digits(s: &[u8], mut i: usize) -> Option<(u64, usize)> {
+    let start = i;
+    let mut v: u64 = 0;
+    while i < s.len() && s[i].wrapping_sub(b'0') <= 9 {
+        v = match v.checked_mul(10) {
+            Some(v) => match v.checked_add((s[i] - b'0') as u64) {
+                Some(v) => v,
+                None => return None,
+            },
+            None => return None,
+        };
+        i += 1;
+    }
+    if i == start {
+        return None;
+    }
+    Some((v, i))
+}
+
+/// Write an unsigned number as ASCII to the given position
+///
+/// Returns the position after the last digit.
+#[inline]
+pub(crate) fn format_digits(buf: &mut [u8], i: usize, mut v: u64) -> usize {
+    let mut tmp = [0u8; 20];
+    let mut n = tmp.len();
+    loop {
+        n -= 1;
+        tmp[n] = b'0' + (v % 10) as u8;
+        v /= 10;
+        if v == 0 {
+            break;
+        }
+    }
+    let len = tmp.len() - n;
+    let mut j = 0;
+    while j < len {
+        buf[i + j] = tmp[n + j];
+        j += 1;
+    }
+    i + len
+}
+
+/// Maximum length of a formatted ISO 8601 duration
+///
+/// Sufficient for any value accepted by [format_iso_duration].
+pub const ISO_DURATION_MAX_LEN: usize = 69;
+
+/// Parse ISO 8601 duration string
+///
+/// Given an ISO 8601 duration string as bytes, such as `P1Y2M3DT4H5M6.5S`,
+/// returns a `(years, months, days, seconds, nanoseconds)` tuple. Weeks (`W`)
+/// are converted to days and hours and minutes are converted to seconds, as
+/// they have a fixed length. Years, months and days are kept separate, as their
+/// length depends on the date they are applied to.
+///
+/// A leading `-` makes the whole duration negative, in which case all the
+/// returned values are negative or zero. A leading `+` is also accepted.
+/// Fractional values are only accepted for seconds, with either `.` or `,` as
+/// the decimal separator, and digits after the ninth are truncated.
+///
+/// # Errors
+///
+/// Returns `None` if the string is not a valid duration, if no components are
+/// given, if components are out of order or repeated, or if any of the values
+/// overflow.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::iso::parse_iso_duration;
+///
+/// assert_eq!(parse_iso_duration(b"P1Y2M3DT4H5M6S"), Some((1, 2, 3, 14706, 0)));
+/// assert_eq!(parse_iso_duration(b"P2W"), Some((0, 0, 14, 0, 0)));
+/// assert_eq!(parse_iso_duration(b"PT0.25S"), Some((0, 0, 0, 0, 250_000_000)));
+/// assert_eq!(parse_iso_duration(b"-P1DT1.5S"), Some((0, 0, -1, -1, -500_000_000)));
+/// assert_eq!(parse_iso_duration(b"PT36H"), Some((0, 0, 0, 129600, 0)));
+/// assert_eq!(parse_iso_duration(b"P"), None);
+/// assert_eq!(parse_iso_duration(b"P1D2Y"), None);
+/// ```
+///
+/// # Algorithm
+///
+/// Single pass over the designators in the order mandated by the standard.
+pub const fn parse_iso_duration(s: &[u8]) -> Option<(i32, i32, i32, i64, i32)> {
+    // designators in order, with index 3 being the `T` separator
+    const DESIGNATORS: [u8; 8] = [b'Y', b'M', b'W', b'T', b'D', b'H', b'M', b'S'];
+    let mut i = 0;
+    let neg = i < s.len() && s[i] == b'-';
+    if i < s.len() && (s[i] == b'-' || s[i] == b'+') {
+        i += 1;
+    }
+    if i >= s.len() || s[i] != b'P' {
+        return None;
+    }
+    i += 1;
+    let mut vals = [0u64; 8];
+    let mut nanos = 0u32;
+    let mut next = 0; // index of the next allowed designator
+    let mut time = false;
+    let mut any = false;
+    while i < s.len() {
+        if s[i] == b'T' {
+            if time {
+                return None;
+            }
+            time = true;
+            next = 5;
+            i += 1;
+            if i >= s.len() {
+                return None; // `T` must be followed by a component
+            }
+            continue;
+        }
+        let (v, j) = match parse_digits(s, i) {
+            Some(r) => r,
+            None => return None,
+        };
+        i = j;
+        let mut frac = false;
+        if i < s.len() && (s[i] == b'.' || s[i] == b',') {
+            let start = i + 1;
+            i = start;
+            let mut n = 0u32;
+            let mut digits = 0;
+            while i < s.len() && s[i].wrapping_sub(b'0') <= 9 {
+                if digits < 9 {
+                    n = n * 10 + (s[i] - b'0') as u32;
+                    digits += 1;
+                }
+                i += 1;
+            }
+            if i == start {
+                return None;
+            }
+            while digits < 9 {
+                n *= 10;
+                digits += 1;
+            }
+            nanos = n;
+            frac = true;
+        }
+        if i >= s.len() {
+            return None;
+        }
+        // days are only allowed before `T`, hours and minutes after it
+        let mut k = if time { 5 } else { 0 };
+        let end = if time { 8 } else { 5 };
+        while k < end && (DESIGNATORS[k] != s[i] || k == 3) {
+            k += 1;
+        }
+        if k == end || k < next || (frac && k != 7) {
+            return None;
+        }
+        vals[k] = v;
+        next = k + 1;
+        any = true;
+        i += 1;
+    }
+    if !any {
+        return None;
+    }
+    // check that the values fit in the result types
+    let days = match vals[2].checked_mul(7) {
+        Some(w) => match w.checked_add(vals[4]) {
+            Some(d) => d,
+            None => return None,
+        },
+        None => return None,
+    };
+    let secs = match vals[5].checked_mul(3600) {
+        Some(h) => match vals[6].checked_mul(60) {
+            Some(m) => match h.checked_add(m) {
+                Some(hm) => match hm.checked_add(vals[7]) {
+                    Some(s) => s,
+                    None => return None,
+                },
+                None => return None,
+            },
+            None => return None,
+        },
+        None => return None,
+    };
+    if vals[0] > i32::MAX as u64 || vals[1] > i32::MAX as u64 || days > i32::MAX as u64 || secs > i64::MAX as u64 {
+        return None;
+    }
+    let (y, m, d, s, n) = (vals[0] as i32, vals[1] as i32, days as i32, secs as i64, nanos as i32);
+    if neg {
+        Some((-y, -m, -d, -s, -n))
+    } else {
+        Some((y, m, d, s, n))
+    }
+}
+
+/// Format ISO 8601 duration string
+///
+/// Given a `(years, months, days, seconds, nanoseconds)` tuple writes the
+/// duration in `PnYnMnDTnHnMnS` form to the given buffer and returns the number
+/// of bytes written. Zero components are omitted, seconds are split to hours,
+/// minutes and seconds, and trailing zeroes are removed from the fractional
+/// seconds. A zero duration is written as `PT0S`. Negative durations are
+/// written with a leading `-`.
+///
+/// # Panics
+///
+/// All the values must have the same sign, or be zero. Nanoseconds must be
+/// between `-999_999_999` and `999_999_999`. Bounds are checked using
+/// `debug_assert` only, so that the checks are not present in release builds,
+/// similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::iso::{format_iso_duration, ISO_DURATION_MAX_LEN};
+///
+/// let mut buf = [0; ISO_DURATION_MAX_LEN];
+/// let len = format_iso_duration((1, 2, 3, 14706, 0), &mut buf);
+/// assert_eq!(&buf[..len], b"P1Y2M3DT4H5M6S");
+/// let len = format_iso_duration((0, 0, -1, -1, -500_000_000), &mut buf);
+/// assert_eq!(&buf[..len], b"-P1DT1.5S");
+/// let len = format_iso_duration((0, 0, 0, 0, 0), &mut buf);
+/// assert_eq!(&buf[..len], b"PT0S");
+/// ```
+///
+/// # Algorithm
+///
+/// Formats the magnitudes of the values and prepends a single sign.
+pub fn format_iso_duration((y, m, d, s, n): (i32, i32, i32, i64, i32), buf: &mut [u8; ISO_DURATION_MAX_LEN]) -> usize {
+    debug_assert!(n > -1_000_000_000 && n < 1_000_000_000, "given nanoseconds is out of range");
+    debug_assert!(
+        (y >= 0 && m >= 0 && d >= 0 && s >= 0 && n >= 0) || (y <= 0 && m <= 0 && d <= 0 && s <= 0 && n <= 0),
+        "given values have mixed signs"
+    );
+    let neg = y < 0 || m < 0 || d < 0 || s < 0 || n < 0;
+    let mut i = 0;
+    if neg {
+        buf[i] = b'-';
+        i += 1;
+    }
+    buf[i] = b'P';
+    i += 1;
+    let (y, m, d, s, n) = (
+        y.unsigned_abs(),
+        m.unsigned_abs(),
+        d.unsigned_abs(),
+        s.unsigned_abs(),
+        n.unsigned_abs(),
+    );
+    for (v, c) in [(y, b'Y'), (m, b'M'), (d, b'D')] {
+        if v != 0 {
+            i = format_digits(buf, i, v as u64);
+            buf[i] = c;
+            i += 1;
+        }
+    }
+    let n = n % 1_000_000_000;
+    if s != 0 || n != 0 || i == 1 + neg as usize {
+        buf[i] = b'T';
+        i += 1;
+        let (hh, mm, ss) = (s / 3600, s / 60 % 60, s % 60);
+        if hh != 0 {
+            i = format_digits(buf, i, hh);
+            buf[i] = b'H';
+            i += 1;
+        }
+        if mm != 0 {
+            i = format_digits(buf, i, mm);
+            buf[i] = b'M';
+            i += 1;
+        }
+        if ss != 0 || n != 0 || (hh == 0 && mm == 0) {
+            i = format_digits(buf, i, ss);
+            if n != 0 {
+                buf[i] = b'.';
+                i += 1;
+                let mut n = n;
+                let mut digits = 9;
+                while n % 10 == 0 {
+                    n /= 10;
+                    digits -= 1;
+                }
+                let mut j = digits;
+                while j > 0 {
+                    j -= 1;
+                    buf[i + j] = b'0' + (n % 10) as u8;
+                    n /= 10;
+                }
+                i += digits;
+            }
+            buf[i] = b'S';
+            i += 1;
+        }
+    }
+    i
+}
+
+/// Maximum length of a formatted ISO 8601 date
+///
+/// Sufficient for any year between [YEAR_MIN] and [YEAR_MAX].
+pub const ISO_DATE_MAX_LEN: usize = 14;
+
+/// Parse ISO 8601 calendar date string
+///
+/// Given an ISO 8601 extended format calendar date string as bytes returns a
+/// `(year, month, day)` tuple. Years between `0000` and `9999` are given as
+/// four digits. Other years use the expanded representation, which is a
+/// mandatory sign followed by at least four digits, such as `+133999-01-01` or
+/// `-000001-12-31`. Expanded representation is also accepted for years between
+/// `0000` and `9999`.
+///
+/// # Errors
+///
+/// Returns `None` if the string is not a valid date or the year is not between
+/// [YEAR_MIN] and [YEAR_MAX].
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::iso::parse_date;
+///
+/// assert_eq!(parse_date(b"2023-05-12"), Some((2023, 5, 12)));
+/// assert_eq!(parse_date(b"+133999-01-01"), Some((133999, 1, 1)));
+/// assert_eq!(parse_date(b"-000001-12-31"), Some((-1, 12, 31)));
+/// assert_eq!(parse_date(b"+002023-05-12"), Some((2023, 5, 12)));
+/// assert_eq!(parse_date(b"2023-02-29"), None);
+/// assert_eq!(parse_date(b"12023-01-01"), None);
+/// ```
+///
+/// # Algorithm
+///
+/// Simple digit parsing followed by range validation.
+#[inline]
+pub const fn parse_date(s: &[u8]) -> Option<(i32, u8, u8)> {
+    if s.is_empty() {
+        return None;
+    }
+    let (neg, start) = match s[0] {
+        b'+' => (false, 1),
+        b'-' => (true, 1),
+        _ => (false, 0),
+    };
+    let (y, i) = match parse_digits(s, start) {
+        Some(r) => r,
+        None => return None,
+    };
+    let digits = i - start;
+    if digits < 4 || (start == 0 && digits != 4) || s.len() != i + 6 || s[i] != b'-' || s[i + 3] != b'-' {
+        return None;
+    }
+    let y = match neg {
+        false if y <= YEAR_MAX as u64 => y as i32,
+        true if y <= -YEAR_MIN as u64 => -(y as i32),
+        _ => return None,
+    };
+    let m = match parse_2digits(s, i + 1) {
+        Some(m) if m >= consts::MONTH_MIN && m <= consts::MONTH_MAX => m,
+        _ => return None,
+    };
+    let d = match parse_2digits(s, i + 4) {
+        Some(d) if d >= consts::DAY_MIN && d <= days_in_month(y, m) => d,
+        _ => return None,
+    };
+    Some((y, m, d))
+}
+
+/// Format ISO 8601 calendar date string
+///
+/// Given a `(year, month, day)` tuple writes the date in ISO 8601 extended
+/// format to the given buffer and returns the number of bytes written. Years
+/// between `0000` and `9999` are written as four digits, such as `2023-05-12`.
+/// Other years are written in the expanded representation with a sign and at
+/// least six digits, such as `+133999-01-01` or `-000001-12-31`, so that the
+/// whole supported range of years can be represented.
+///
+/// # Panics
+///
+/// Year must be between [YEAR_MIN] and [YEAR_MAX]. Month must be between `1`
+/// and `12`. Day must be between `1` and the number of days in the month in
+/// question. Bounds are checked using `debug_assert` only, so that the checks
+/// are not present in release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::iso::{format_date, ISO_DATE_MAX_LEN};
+///
+/// let mut buf = [0; ISO_DATE_MAX_LEN];
+/// let len = format_date((2023, 5, 12), &mut buf);
+/// assert_eq!(&buf[..len], b"2023-05-12");
+/// let len = format_date((133999, 1, 1), &mut buf);
+/// assert_eq!(&buf[..len], b"+133999-01-01");
+/// let len = format_date((-1, 12, 31), &mut buf);
+/// assert_eq!(&buf[..len], b"-000001-12-31");
+/// ```
+///
+/// # Algorithm
+///
+/// Simple digit formatting with zero padding.
+#[inline]
+pub fn format_date((y, m, d): (i32, u8, u8), buf: &mut [u8; ISO_DATE_MAX_LEN]) -> usize {
+    debug_assert!(y >= YEAR_MIN && y <= YEAR_MAX, "given year is out of range");
+    debug_assert!(m >= consts::MONTH_MIN && m <= consts::MONTH_MAX, "given month is out of range");
+    debug_assert!(d >= consts::DAY_MIN && d <= days_in_month(y, m), "given day is out of range");
+    let mut i = 0;
+    let mut width = 4;
+    if !(0..=9999).contains(&y) {
+        buf[i] = if y < 0 { b'-' } else { b'+' };
+        i += 1;
+        width = 6;
+    }
+    let ya = y.unsigned_abs() % 10_000_000;
+    let mut digits = 1;
+    while digits < 7 && ya >= 10u32.pow(digits) {
+        digits += 1;
+    }
+    while width > digits {
+        buf[i] = b'0';
+        i += 1;
+        width -= 1;
+    }
+    i = format_digits(buf, i, ya as u64);
+    buf[i] = b'-';
+    format_2digits(buf, i + 1, m as u32);
+    buf[i + 3] = b'-';
+    format_2digits(buf, i + 4, d as u32);
+    i + 6
+}
@@ -0,0 +1,168 @@
+//! Conversions to and from C `struct tm`
+//!
+//! The C library represents broken-down time as `struct tm`, with years
+//! counted from 1900, months from `0` to `11`, day of week from `0` for Sunday
+//! and day of year from `0` for January 1st. The functions in this module
+//! convert between [`libc::tm`] and Unix seconds, as done by `timegm` and
+//! `gmtime_r`, but without the locking and error handling overhead of those.
+//!
+//! Only UTC is supported. The `tm_isdst` field is set to `0` and, on
+//! platforms which have them, `tm_gmtoff` to `0` and `tm_zone` to null.
+
+use ::libc::{c_int, tm};
+
+use crate::{date_to_rd, rd_to_date, rd_to_weekday, secs_to_dhms, RD_SECONDS_MAX, RD_SECONDS_MIN, SECS_IN_DAY};
+
+/// Construct `tm` with the platform specific time zone fields set to UTC
+#[allow(clippy::too_many_arguments)]
+#[inline]
+fn new_tm(sec: c_int, min: c_int, hour: c_int, mday: c_int, mon: c_int, year: c_int, wday: c_int, yday: c_int) -> tm {
+    tm {
+        tm_sec: sec,
+        tm_min: min,
+        tm_hour: hour,
+        tm_mday: mday,
+        tm_mon: mon,
+        tm_year: year,
+        tm_wday: wday,
+        tm_yday: yday,
+        tm_isdst: 0,
+        #[cfg(any(
+            target_os = "linux",
+            target_os = "android",
+            target_os = "emscripten",
+            target_os = "l4re",
+            target_vendor = "apple",
+            target_os = "freebsd",
+            target_os = "dragonfly",
+            target_os = "netbsd",
+            target_os = "openbsd",
+            target_os = "fuchsia",
+            target_os = "redox",
+            target_os = "haiku",
+            target_os = "hurd",
+            target_os = "nto",
+            target_os = "nuttx",
+            target_os = "cygwin",
+            target_os = "solid_asp3",
+        ))]
+        tm_gmtoff: 0,
+        #[cfg(any(
+            target_os = "linux",
+            target_os = "android",
+            target_os = "emscripten",
+            target_os = "l4re",
+            target_vendor = "apple",
+            target_os = "freebsd",
+            target_os = "dragonfly",
+            target_os = "netbsd",
+            target_os = "openbsd",
+            target_os = "fuchsia",
+            target_os = "redox",
+            target_os = "haiku",
+            target_os = "hurd",
+            target_os = "nto",
+            target_os = "nuttx",
+            target_os = "cygwin",
+            target_os = "solid_asp3",
+        ))]
+        tm_zone: core::ptr::null_mut::<::libc::c_char>() as _,
+        #[cfg(any(target_os = "wasi", target_os = "teeos"))]
+        __tm_gmtoff: 0,
+        #[cfg(any(target_os = "wasi", target_os = "teeos"))]
+        __tm_zone: core::ptr::null(),
+    }
+}
+
+/// Convert `struct tm` to Unix seconds
+///
+/// Given a [`libc::tm`] in UTC returns the seconds from Unix epoch (January
+/// 1st, 1970), as done by `timegm`. The `tm_wday`, `tm_yday`, `tm_isdst` and
+/// time zone fields are ignored. A leap second, given as `tm_sec` of `60`, is
+/// counted as the first second of the next minute.
+///
+/// # Panics
+///
+/// The year `tm_year + 1900` must be between [YEAR_MIN](crate::YEAR_MIN) and
+/// [YEAR_MAX](crate::YEAR_MAX). Month `tm_mon` must be between `0` and `11`.
+/// Day `tm_mday` must be between `1` and the number of days in the month in
+/// question. Hours must be between `0` and `23`, minutes between `0` and `59`
+/// and seconds between `0` and `60`. Bounds are checked using `debug_assert`
+/// only, so that the checks are not present in release builds, similar to
+/// integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::libc::{secs_to_tm, tm_to_secs};
+///
+/// let mut tm = secs_to_tm(0);
+/// tm.tm_year = 123;
+/// tm.tm_mon = 4;
+/// tm.tm_mday = 20;
+/// tm.tm_hour = 9;
+/// tm.tm_min = 24;
+/// tm.tm_sec = 38;
+/// assert_eq!(tm_to_secs(&tm), 1684574678);
+/// ```
+///
+/// # Algorithm
+///
+/// Conversion with [date_to_rd] followed by multiplication.
+#[inline]
+pub fn tm_to_secs(tm: &tm) -> i64 {
+    debug_assert!((0..=11).contains(&tm.tm_mon), "given month is out of range");
+    debug_assert!((0..=23).contains(&tm.tm_hour), "given hour is out of range");
+    debug_assert!((0..=59).contains(&tm.tm_min), "given minute is out of range");
+    debug_assert!((0..=60).contains(&tm.tm_sec), "given second is out of range");
+    let y = tm.tm_year.wrapping_add(1900);
+    let n = date_to_rd((y, (tm.tm_mon as u8).wrapping_add(1), tm.tm_mday as u8));
+    n as i64 * SECS_IN_DAY + tm.tm_hour as i64 * 3600 + tm.tm_min as i64 * 60 + tm.tm_sec as i64
+}
+
+/// Convert Unix seconds to `struct tm`
+///
+/// Given seconds from Unix epoch (January 1st, 1970) returns a [`libc::tm`] in
+/// UTC, as done by `gmtime_r`, with `tm_wday` and `tm_yday` filled in.
+///
+/// # Panics
+///
+/// Argument must be between [RD_SECONDS_MIN] and [RD_SECONDS_MAX] inclusive.
+/// Bounds are checked using `debug_assert` only, so that the checks are not
+/// present in release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::libc::secs_to_tm;
+///
+/// let tm = secs_to_tm(1684574678);
+/// assert_eq!((tm.tm_year, tm.tm_mon, tm.tm_mday), (123, 4, 20));
+/// assert_eq!((tm.tm_hour, tm.tm_min, tm.tm_sec), (9, 24, 38));
+/// assert_eq!((tm.tm_wday, tm.tm_yday), (6, 139));
+/// ```
+///
+/// # Algorithm
+///
+/// Combination of [secs_to_dhms], [rd_to_date] and [rd_to_weekday].
+#[inline]
+pub fn secs_to_tm(secs: i64) -> tm {
+    debug_assert!(
+        secs >= RD_SECONDS_MIN && secs <= RD_SECONDS_MAX,
+        "given seconds value is out of range"
+    );
+    let (n, hh, mm, ss) = secs_to_dhms(secs);
+    let (y, m, d) = rd_to_date(n);
+    let wday = rd_to_weekday(n) % 7;
+    let yday = n - date_to_rd((y, 1, 1));
+    new_tm(
+        ss as c_int,
+        mm as c_int,
+        hh as c_int,
+        d as c_int,
+        m as c_int - 1,
+        y.wrapping_sub(1900),
+        wday as c_int,
+        yday,
+    )
+}
@@ -0,0 +1,497 @@
+//! POSIX TZ strings and time zone offset evaluation
+//!
+//! Devices without a time zone database commonly describe the local time zone
+//! with a POSIX `TZ` string, such as `EST5EDT,M3.2.0,M11.1.0` or
+//! `<+0330>-3:30`. The same strings are used as the footer of TZif files to
+//! describe times after the last transition. [PosixTz] parses such strings
+//! without allocations and evaluates the UTC offset and daylight saving time
+//! flag at any instant.
+//!
+//! The parser follows POSIX with the extensions of [RFC 8536](https://www.rfc-editor.org/rfc/rfc8536#section-3.3.1),
+//! which allow rule times from `-167` to `167` hours. When daylight saving
+//! time is given without rules, the current United States rules
+//! `M3.2.0,M11.1.0` are assumed, as done by common C libraries.
+//!
+//! Offsets are given in seconds east of UTC, as in the rest of this crate,
+//! even though the TZ string itself gives them west of UTC.
+
+use crate::{date_to_rd, days_in_month, is_leap_year, rd_to_weekday, secs_to_year, RD_SECONDS_MAX, RD_SECONDS_MIN, SECS_IN_DAY};
+
+/// Maximum length of a time zone abbreviation in a TZ string
+pub const TZ_ABBR_MAX_LEN: usize = 16;
+
+/// Rule for the day of a daylight saving time transition
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TzRule {
+    /// `Jn`: Day of year from `1` to `365`, never counting February 29th
+    JulianNoLeap(u16),
+    /// `n`: Zero based day of year from `0` to `365`, counting February 29th
+    Julian(u16),
+    /// `Mm.w.d`: Day of week `d` (`0` for Sunday to `6` for Saturday) in week
+    /// `w` (`1` to `5`, `5` meaning the last) of month `m` (`1` to `12`)
+    MonthWeekDay(u8, u8, u8),
+}
+
+/// Time zone abbreviation stored inline
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Abbr {
+    buf: [u8; TZ_ABBR_MAX_LEN],
+    len: u8,
+}
+
+impl Abbr {
+    #[inline]
+    fn as_str(&self) -> &str {
+        // Only ASCII letters, digits and signs are accepted by the parser
+        core::str::from_utf8(self.as_bytes()).unwrap_or("")
+    }
+
+    #[inline]
+    const fn as_bytes(&self) -> &[u8] {
+        self.buf.split_at(self.len as usize).0
+    }
+}
+
+/// Daylight saving time part of a TZ string
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Dst {
+    abbr: Abbr,
+    utoff: i32,
+    start: TzRule,
+    start_time: i32,
+    end: TzRule,
+    end_time: i32,
+}
+
+/// Parsed POSIX TZ string
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::datetime_to_secs;
+/// use datealgo::posixtz::PosixTz;
+///
+/// let tz = PosixTz::parse(b"CET-1CEST,M3.5.0,M10.5.0/3").unwrap();
+/// assert_eq!(tz.std_abbr(), "CET");
+/// assert_eq!(tz.dst_abbr(), Some("CEST"));
+/// assert_eq!(tz.offset_at(datetime_to_secs((2023, 1, 15, 12, 0, 0))), (3600, false));
+/// assert_eq!(tz.offset_at(datetime_to_secs((2023, 7, 15, 12, 0, 0))), (7200, true));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PosixTz {
+    std_abbr: Abbr,
+    std_utoff: i32,
+    dst: Option<Dst>,
+}
+
+/// Parse an unsigned number of at most three digits from the given position
+#[inline]
+const fn parse_num(s: &[u8], mut i: usize) -> Option<(u32, usize)> {
+    let start = i;
+    let mut v = 0;
+    while i < s.len() && i - start < 3 && s[i].wrapping_sub(b'0') <= 9 {
+        v = v * 10 + (s[i] - b'0') as u32;
+        i += 1;
+    }
+    if i == start {
+        return None;
+    }
+    Some((v, i))
+}
+
+/// Parse a time zone abbreviation from the given position
+#[inline]
+const fn parse_abbr(s: &[u8], mut i: usize) -> Option<(Abbr, usize)> {
+    let quoted = i < s.len() && s[i] == b'<';
+    if quoted {
+        i += 1;
+    }
+    let mut abbr = Abbr {
+        buf: [0; TZ_ABBR_MAX_LEN],
+        len: 0,
+    };
+    while i < s.len() {
+        let c = s[i];
+        let valid = c.is_ascii_alphabetic() || (quoted && (c.is_ascii_digit() || c == b'+' || c == b'-'));
+        if !valid {
+            break;
+        }
+        if abbr.len as usize == TZ_ABBR_MAX_LEN {
+            return None;
+        }
+        abbr.buf[abbr.len as usize] = c;
+        abbr.len += 1;
+        i += 1;
+    }
+    if quoted {
+        if i == s.len() || s[i] != b'>' {
+            return None;
+        }
+        i += 1;
+    }
+    if abbr.len < 3 {
+        return None;
+    }
+    Some((abbr, i))
+}
+
+/// Parse a signed `hh[:mm[:ss]]` time from the given position
+#[inline]
+const fn parse_time(s: &[u8], mut i: usize, max_hours: u32) -> Option<(i32, usize)> {
+    let neg = i < s.len() && s[i] == b'-';
+    if i < s.len() && (s[i] == b'-' || s[i] == b'+') {
+        i += 1;
+    }
+    let (hh, mut i) = match parse_num(s, i) {
+        Some((hh, i)) if hh <= max_hours => (hh, i),
+        _ => return None,
+    };
+    let mut secs = hh * 3600;
+    let mut unit = 60;
+    while unit > 0 && i < s.len() && s[i] == b':' {
+        match parse_num(s, i + 1) {
+            Some((v, j)) if v <= 59 && j == i + 3 => {
+                secs += v * unit;
+                i = j;
+            }
+            _ => return None,
+        }
+        unit /= 60;
+    }
+    let secs = secs as i32;
+    Some((if neg { -secs } else { secs }, i))
+}
+
+/// Parse a transition rule with optional time from the given position
+#[inline]
+const fn parse_rule(s: &[u8], i: usize) -> Option<(TzRule, i32, usize)> {
+    if i >= s.len() {
+        return None;
+    }
+    let (rule, i) = match s[i] {
+        b'J' => match parse_num(s, i + 1) {
+            Some((n, i)) if n >= 1 && n <= 365 => (TzRule::JulianNoLeap(n as u16), i),
+            _ => return None,
+        },
+        b'M' => {
+            let (m, i) = match parse_num(s, i + 1) {
+                Some((m, i)) if m >= 1 && m <= 12 && i < s.len() && s[i] == b'.' => (m, i),
+                _ => return None,
+            };
+            let (w, i) = match parse_num(s, i + 1) {
+                Some((w, i)) if w >= 1 && w <= 5 && i < s.len() && s[i] == b'.' => (w, i),
+                _ => return None,
+            };
+            let (d, i) = match parse_num(s, i + 1) {
+                Some((d, i)) if d <= 6 => (d, i),
+                _ => return None,
+            };
+            (TzRule::MonthWeekDay(m as u8, w as u8, d as u8), i)
+        }
+        _ => match parse_num(s, i) {
+            Some((n, i)) if n <= 365 => (TzRule::Julian(n as u16), i),
+            _ => return None,
+        },
+    };
+    if i < s.len() && s[i] == b'/' {
+        match parse_time(s, i + 1, 167) {
+            Some((time, i)) => Some((rule, time, i)),
+            None => None,
+        }
+    } else {
+        Some((rule, 7200, i))
+    }
+}
+
+/// Rata Die of the day of a transition rule in the given year
+#[inline]
+const fn rule_to_rd(y: i32, rule: TzRule) -> i32 {
+    match rule {
+        TzRule::JulianNoLeap(n) => {
+            let leap = is_leap_year(y) && n >= 60;
+            date_to_rd((y, 1, 1)) + n as i32 - 1 + leap as i32
+        }
+        TzRule::Julian(n) => date_to_rd((y, 1, 1)) + n as i32,
+        TzRule::MonthWeekDay(m, w, d) => {
+            let first = date_to_rd((y, m, 1));
+            let wd = rd_to_weekday(first) % 7;
+            let n = first + ((d + 7 - wd) % 7) as i32 + 7 * (w as i32 - 1);
+            if n >= first + days_in_month(y, m) as i32 {
+                n - 7
+            } else {
+                n
+            }
+        }
+    }
+}
+
+impl PosixTz {
+    /// Parse a POSIX TZ string
+    ///
+    /// Given a TZ string as bytes, such as `EST5EDT,M3.2.0,M11.1.0/2`, returns
+    /// the parsed time zone. The leading `:` form, which names a file, is not
+    /// supported.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the string is not a valid TZ string, or an
+    /// abbreviation is longer than [TZ_ABBR_MAX_LEN].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use datealgo::posixtz::{PosixTz, TzRule};
+    ///
+    /// let tz = PosixTz::parse(b"EST5EDT,M3.2.0,M11.1.0/2").unwrap();
+    /// assert_eq!(tz.std_utoff(), -18000);
+    /// assert_eq!(tz.dst_utoff(), Some(-14400));
+    /// assert_eq!(
+    ///     tz.dst_rules(),
+    ///     Some((TzRule::MonthWeekDay(3, 2, 0), 7200, TzRule::MonthWeekDay(11, 1, 0), 7200))
+    /// );
+    /// let tz = PosixTz::parse(b"<+0330>-3:30").unwrap();
+    /// assert_eq!(tz.std_abbr(), "+0330");
+    /// assert_eq!(tz.std_utoff(), 12600);
+    /// assert_eq!(tz.dst_utoff(), None);
+    /// assert_eq!(PosixTz::parse(b"EST"), None);
+    /// ```
+    ///
+    /// # Algorithm
+    ///
+    /// Simple recursive descent parsing.
+    #[inline]
+    pub const fn parse(s: &[u8]) -> Option<PosixTz> {
+        let (std_abbr, i) = match parse_abbr(s, 0) {
+            Some(r) => r,
+            None => return None,
+        };
+        let (std_off, i) = match parse_time(s, i, 24) {
+            Some(r) => r,
+            None => return None,
+        };
+        let std_utoff = -std_off;
+        if i == s.len() {
+            return Some(PosixTz {
+                std_abbr,
+                std_utoff,
+                dst: None,
+            });
+        }
+        let (dst_abbr, mut i) = match parse_abbr(s, i) {
+            Some(r) => r,
+            None => return None,
+        };
+        let mut dst_utoff = std_utoff + 3600;
+        if i < s.len() && s[i] != b',' {
+            match parse_time(s, i, 24) {
+                Some((off, j)) => {
+                    dst_utoff = -off;
+                    i = j;
+                }
+                None => return None,
+            }
+        }
+        let dst = if i == s.len() {
+            Dst {
+                abbr: dst_abbr,
+                utoff: dst_utoff,
+                start: TzRule::MonthWeekDay(3, 2, 0),
+                start_time: 7200,
+                end: TzRule::MonthWeekDay(11, 1, 0),
+                end_time: 7200,
+            }
+        } else {
+            if s[i] != b',' {
+                return None;
+            }
+            let (start, start_time, i) = match parse_rule(s, i + 1) {
+                Some(r) => r,
+                None => return None,
+            };
+            if i >= s.len() || s[i] != b',' {
+                return None;
+            }
+            let (end, end_time, i) = match parse_rule(s, i + 1) {
+                Some(r) => r,
+                None => return None,
+            };
+            if i != s.len() {
+                return None;
+            }
+            Dst {
+                abbr: dst_abbr,
+                utoff: dst_utoff,
+                start,
+                start_time,
+                end,
+                end_time,
+            }
+        };
+        Some(PosixTz {
+            std_abbr,
+            std_utoff,
+            dst: Some(dst),
+        })
+    }
+
+    /// Abbreviation of standard time
+    #[inline]
+    pub fn std_abbr(&self) -> &str {
+        self.std_abbr.as_str()
+    }
+
+    /// Abbreviation of daylight saving time, if any
+    #[inline]
+    pub fn dst_abbr(&self) -> Option<&str> {
+        match &self.dst {
+            Some(dst) => Some(dst.abbr.as_str()),
+            None => None,
+        }
+    }
+
+    /// Abbreviation in effect as bytes, given the daylight saving time flag
+    #[inline]
+    pub(crate) const fn abbr_bytes(&self, is_dst: bool) -> &[u8] {
+        match &self.dst {
+            Some(dst) if is_dst => dst.abbr.as_bytes(),
+            _ => self.std_abbr.as_bytes(),
+        }
+    }
+
+    /// Offset of standard time in seconds east of UTC
+    #[inline]
+    pub const fn std_utoff(&self) -> i32 {
+        self.std_utoff
+    }
+
+    /// Offset of daylight saving time in seconds east of UTC, if any
+    #[inline]
+    pub const fn dst_utoff(&self) -> Option<i32> {
+        match &self.dst {
+            Some(dst) => Some(dst.utoff),
+            None => None,
+        }
+    }
+
+    /// Rules of daylight saving time, if any
+    ///
+    /// Returns a `(start, start_time, end, end_time)` tuple, where the times
+    /// are in seconds from local midnight of the day given by the rule, in
+    /// standard time for the start and daylight saving time for the end.
+    #[inline]
+    pub const fn dst_rules(&self) -> Option<(TzRule, i32, TzRule, i32)> {
+        match &self.dst {
+            Some(dst) => Some((dst.start, dst.start_time, dst.end, dst.end_time)),
+            None => None,
+        }
+    }
+
+    /// Compute the daylight saving time transitions of a year
+    ///
+    /// Given a year returns a `(start, end)` tuple of the instants, in seconds
+    /// counting from Unix epoch (January 1st, 1970), at which daylight saving
+    /// time starts and ends in that year. In the southern hemisphere, the end
+    /// is before the start.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the time zone has no daylight saving time.
+    ///
+    /// # Panics
+    ///
+    /// Year must be between [YEAR_MIN](crate::YEAR_MIN) and
+    /// [YEAR_MAX](crate::YEAR_MAX). Bounds are checked using
+    /// `debug_assert` only, so that the checks are not present in release
+    /// builds, similar to integer overflow checks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use datealgo::datetime_to_secs;
+    /// use datealgo::posixtz::PosixTz;
+    ///
+    /// let tz = PosixTz::parse(b"EST5EDT,M3.2.0,M11.1.0").unwrap();
+    /// assert_eq!(
+    ///     tz.dst_transitions(2023),
+    ///     Some((datetime_to_secs((2023, 3, 12, 7, 0, 0)), datetime_to_secs((2023, 11, 5, 6, 0, 0))))
+    /// );
+    /// ```
+    ///
+    /// # Algorithm
+    ///
+    /// Day of the rule from [date_to_rd] and, for month rules, [rd_to_weekday],
+    /// followed by addition of the rule time and subtraction of the offset in
+    /// effect before the transition.
+    #[inline]
+    pub const fn dst_transitions(&self, y: i32) -> Option<(i64, i64)> {
+        let dst = match &self.dst {
+            Some(dst) => dst,
+            None => return None,
+        };
+        let start = rule_to_rd(y, dst.start) as i64 * SECS_IN_DAY + (dst.start_time - self.std_utoff) as i64;
+        let end = rule_to_rd(y, dst.end) as i64 * SECS_IN_DAY + (dst.end_time - dst.utoff) as i64;
+        Some((start, end))
+    }
+
+    /// Evaluate the UTC offset and daylight saving time flag at an instant
+    ///
+    /// Given seconds counting from Unix epoch (January 1st, 1970) returns an
+    /// `(offset, dst)` tuple of the offset in seconds east of UTC and whether
+    /// daylight saving time is in effect.
+    ///
+    /// # Panics
+    ///
+    /// Argument must be between [RD_SECONDS_MIN] and [RD_SECONDS_MAX]
+    /// inclusive. Bounds are checked using `debug_assert` only, so that the
+    /// checks are not present in release builds, similar to integer overflow
+    /// checks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use datealgo::datetime_to_secs;
+    /// use datealgo::posixtz::PosixTz;
+    ///
+    /// let tz = PosixTz::parse(b"AEST-10AEDT,M10.1.0,M4.1.0/3").unwrap();
+    /// assert_eq!(tz.offset_at(datetime_to_secs((2023, 1, 1, 0, 0, 0))), (39600, true));
+    /// assert_eq!(tz.offset_at(datetime_to_secs((2023, 7, 1, 0, 0, 0))), (36000, false));
+    /// ```
+    ///
+    /// # Algorithm
+    ///
+    /// [dst_transitions](PosixTz::dst_transitions) of the year of the instant
+    /// in standard time, followed by comparisons.
+    #[inline]
+    pub const fn offset_at(&self, secs: i64) -> (i32, bool) {
+        debug_assert!(
+            secs >= RD_SECONDS_MIN && secs <= RD_SECONDS_MAX,
+            "given seconds value is out of range"
+        );
+        let dst = match &self.dst {
+            Some(dst) => dst,
+            None => return (self.std_utoff, false),
+        };
+        let local = secs + self.std_utoff as i64;
+        let local = if local < RD_SECONDS_MIN {
+            RD_SECONDS_MIN
+        } else if local > RD_SECONDS_MAX {
+            RD_SECONDS_MAX
+        } else {
+            local
+        };
+        let (start, end) = match self.dst_transitions(secs_to_year(local)) {
+            Some(r) => r,
+            None => return (self.std_utoff, false),
+        };
+        let is_dst = if start < end {
+            secs >= start && secs < end
+        } else {
+            secs < end || secs >= start
+        };
+        if is_dst {
+            (dst.utoff, true)
+        } else {
+            (self.std_utoff, false)
+        }
+    }
+}
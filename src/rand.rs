@@ -0,0 +1,92 @@
+//! Uniform random dates and times with `rand`
+//!
+//! Sampling year, month and day separately gives a biased distribution, as
+//! months and years differ in length. The functions in this module instead
+//! sample uniformly from a range of Rata Die or Unix seconds values and
+//! convert the result, so that every day or second in the range is equally
+//! likely.
+
+use ::rand::Rng;
+
+use crate::{rd_to_date, secs_to_datetime, RD_MAX, RD_MIN, RD_SECONDS_MAX, RD_SECONDS_MIN};
+
+/// Generate a uniformly random date within the given range
+///
+/// Given an inclusive range of days counting from Unix epoch (January 1st,
+/// 1970), returns a random `(year, month, day)` tuple within that range.
+///
+/// # Panics
+///
+/// Both bounds must be between [RD_MIN] and [RD_MAX] inclusive, and `rd_min`
+/// must not be greater than `rd_max`. Bounds are checked using `debug_assert`
+/// only, so that the checks are not present in release builds, similar to
+/// integer overflow checks. In release builds, the bounds are clamped to the
+/// supported range and an empty range yields `rd_min`.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{date_to_rd, rand::random_date_in_range};
+///
+/// let mut rng = rand::rng();
+/// let (y, m, d) = random_date_in_range(&mut rng, date_to_rd((2023, 1, 1)), date_to_rd((2023, 12, 31)));
+/// assert_eq!(y, 2023);
+/// ```
+///
+/// # Algorithm
+///
+/// Uniform sampling of Rata Die followed by [rd_to_date].
+#[inline]
+pub fn random_date_in_range<R: Rng + ?Sized>(rng: &mut R, rd_min: i32, rd_max: i32) -> (i32, u8, u8) {
+    debug_assert!(rd_min >= RD_MIN && rd_min <= RD_MAX, "given rata die is out of range");
+    debug_assert!(rd_max >= RD_MIN && rd_max <= RD_MAX, "given rata die is out of range");
+    debug_assert!(rd_min <= rd_max, "given range is empty");
+    let rd_min = rd_min.clamp(RD_MIN, RD_MAX);
+    let rd_max = rd_max.clamp(rd_min, RD_MAX);
+    rd_to_date(rng.random_range(rd_min..=rd_max))
+}
+
+/// Generate a uniformly random date and time within the given range
+///
+/// Given an inclusive range of seconds counting from Unix epoch (January 1st,
+/// 1970), returns a random `(year, month, day, hours, minutes, seconds)` tuple
+/// within that range.
+///
+/// # Panics
+///
+/// Both bounds must be between [RD_SECONDS_MIN] and [RD_SECONDS_MAX]
+/// inclusive, and `secs_min` must not be greater than `secs_max`. Bounds are
+/// checked using `debug_assert` only, so that the checks are not present in
+/// release builds, similar to integer overflow checks. In release builds, the
+/// bounds are clamped to the supported range and an empty range yields
+/// `secs_min`.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{datetime_to_secs, rand::random_datetime_in_range};
+///
+/// let mut rng = rand::rng();
+/// let min = datetime_to_secs((2023, 5, 20, 0, 0, 0));
+/// let (y, m, d, ..) = random_datetime_in_range(&mut rng, min, min + 86399);
+/// assert_eq!((y, m, d), (2023, 5, 20));
+/// ```
+///
+/// # Algorithm
+///
+/// Uniform sampling of Unix seconds followed by [secs_to_datetime].
+#[inline]
+pub fn random_datetime_in_range<R: Rng + ?Sized>(rng: &mut R, secs_min: i64, secs_max: i64) -> (i32, u8, u8, u8, u8, u8) {
+    debug_assert!(
+        secs_min >= RD_SECONDS_MIN && secs_min <= RD_SECONDS_MAX,
+        "given seconds value is out of range"
+    );
+    debug_assert!(
+        secs_max >= RD_SECONDS_MIN && secs_max <= RD_SECONDS_MAX,
+        "given seconds value is out of range"
+    );
+    debug_assert!(secs_min <= secs_max, "given range is empty");
+    let secs_min = secs_min.clamp(RD_SECONDS_MIN, RD_SECONDS_MAX);
+    let secs_max = secs_max.clamp(secs_min, RD_SECONDS_MAX);
+    secs_to_datetime(rng.random_range(secs_min..=secs_max))
+}
@@ -0,0 +1,143 @@
+//! Conversions to and from the date and time types of `rtcc`
+//!
+//! Embedded real-time clock drivers, such as `ds323x` and `pcf8563`, implement
+//! the [`rtcc`](https://docs.rs/rtcc) traits, which read and write the clock as
+//! `rtcc::NaiveDateTime` and `rtcc::NaiveDate`. Those are re-exports of the
+//! `chrono` types, so this feature depends on `chrono` without default
+//! features, which is `no_std` and has no allocations, and the functions in
+//! this module accept the values returned by the drivers directly. Only the
+//! field accessors and constructors of `chrono` are used, the conversions
+//! themselves are done by this crate.
+
+use ::chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
+
+use crate::{date_to_rd, datetime_to_secs, rd_to_date, secs_to_datetime, RD_MAX, RD_MIN, RD_SECONDS_MAX, RD_SECONDS_MIN};
+
+/// Convert `NaiveDate` to Rata Die
+///
+/// Given a [NaiveDate], returns the days from Unix epoch (January 1st, 1970).
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use datealgo::rtcc::naivedate_to_rd;
+///
+/// assert_eq!(naivedate_to_rd(NaiveDate::from_ymd_opt(2023, 5, 12).unwrap()), 19489);
+/// ```
+///
+/// # Algorithm
+///
+/// Field access followed by [date_to_rd].
+#[inline]
+pub fn naivedate_to_rd(date: NaiveDate) -> i32 {
+    date_to_rd((date.year(), date.month() as u8, date.day() as u8))
+}
+
+/// Convert Rata Die to `NaiveDate`
+///
+/// Given days from Unix epoch (January 1st, 1970), returns a [NaiveDate].
+///
+/// # Errors
+///
+/// Returns `None` if the date is outside the range supported by [NaiveDate].
+///
+/// # Panics
+///
+/// Argument must be between [RD_MIN] and [RD_MAX] inclusive. Bounds are checked
+/// using `debug_assert` only, so that the checks are not present in release
+/// builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use datealgo::rtcc::rd_to_naivedate;
+///
+/// assert_eq!(rd_to_naivedate(19489), NaiveDate::from_ymd_opt(2023, 5, 12));
+/// ```
+///
+/// # Algorithm
+///
+/// [rd_to_date] followed by construction.
+#[inline]
+pub fn rd_to_naivedate(n: i32) -> Option<NaiveDate> {
+    debug_assert!(n >= RD_MIN && n <= RD_MAX, "given rata die is out of range");
+    let (y, m, d) = rd_to_date(n);
+    NaiveDate::from_ymd_opt(y, m as u32, d as u32)
+}
+
+/// Convert `NaiveDateTime` to seconds and nanoseconds
+///
+/// Given a [NaiveDateTime] in UTC, returns a `(seconds, nanoseconds)` tuple
+/// counting from Unix epoch (January 1st, 1970). Leap seconds are returned as
+/// the last nanosecond of the preceding second, 23:59:59.999999999.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use datealgo::rtcc::naivedatetime_to_secs;
+///
+/// let dt = NaiveDate::from_ymd_opt(2023, 5, 20).unwrap().and_hms_milli_opt(9, 24, 38, 123).unwrap();
+/// assert_eq!(naivedatetime_to_secs(&dt), (1684574678, 123_000_000));
+/// ```
+///
+/// # Algorithm
+///
+/// Field access followed by [datetime_to_secs].
+#[inline]
+pub fn naivedatetime_to_secs(dt: &NaiveDateTime) -> (i64, u32) {
+    let secs = datetime_to_secs((
+        dt.year(),
+        dt.month() as u8,
+        dt.day() as u8,
+        dt.hour() as u8,
+        dt.minute() as u8,
+        dt.second() as u8,
+    ));
+    (secs, dt.nanosecond().min(999_999_999))
+}
+
+/// Convert seconds and nanoseconds to `NaiveDateTime`
+///
+/// Given a `(seconds, nanoseconds)` tuple counting from Unix epoch (January
+/// 1st, 1970), returns a [NaiveDateTime] in UTC.
+///
+/// # Errors
+///
+/// Returns `None` if the date is outside the range supported by
+/// [NaiveDateTime].
+///
+/// # Panics
+///
+/// Seconds must be between [RD_SECONDS_MIN] and [RD_SECONDS_MAX] inclusive.
+/// Nanoseconds must be between `0` and `999_999_999`. Bounds are checked using
+/// `debug_assert` only, so that the checks are not present in release builds,
+/// similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use datealgo::rtcc::secs_to_naivedatetime;
+///
+/// let dt = NaiveDate::from_ymd_opt(2023, 5, 20).unwrap().and_hms_milli_opt(9, 24, 38, 123).unwrap();
+/// assert_eq!(secs_to_naivedatetime((1684574678, 123_000_000)), Some(dt));
+/// ```
+///
+/// # Algorithm
+///
+/// [secs_to_datetime] followed by construction.
+#[inline]
+pub fn secs_to_naivedatetime((secs, nanos): (i64, u32)) -> Option<NaiveDateTime> {
+    debug_assert!(
+        secs >= RD_SECONDS_MIN && secs <= RD_SECONDS_MAX,
+        "given seconds value is out of range"
+    );
+    debug_assert!(nanos < 1_000_000_000, "given nanoseconds is out of range");
+    let (y, m, d, hh, mm, ss) = secs_to_datetime(secs);
+    let date = NaiveDate::from_ymd_opt(y, m as u32, d as u32)?;
+    let time = NaiveTime::from_hms_nano_opt(hh as u32, mm as u32, ss as u32, nanos)?;
+    Some(NaiveDateTime::new(date, time))
+}
@@ -0,0 +1,379 @@
+//! Serialization of date and time tuples with `serde`
+//!
+//! The crate represents dates and times as plain tuples, which `serde` would
+//! serialize as sequences of numbers. This module provides adapter types that
+//! instead serialize them in a chosen interchange format:
+//!
+//! - [IsoDate]: `(year, month, day)` as an ISO 8601 date string, such as
+//!   `"2023-05-12"`
+//! - [Rfc3339]: `(year, month, day, hours, minutes, seconds)` as an RFC 3339
+//!   UTC timestamp string, such as `"2023-05-20T09:24:38Z"`
+//! - [RdDays]: `(year, month, day)` as an integer Rata Die
+//! - [UnixSecs]: `(year, month, day, hours, minutes, seconds)` as integer Unix
+//!   seconds
+//!
+//! Each adapter has a module of the same name in snake case, with `serialize`
+//! and `deserialize` functions for use with the `#[serde(with = "...")]`
+//! attribute on plain tuple fields.
+//!
+//! Deserialization validates the values, so that the resulting tuples are
+//! always within the ranges accepted by the rest of the crate.
+
+use core::fmt;
+
+use ::serde::de::{self, Deserializer, Visitor};
+use ::serde::ser::{self, Serializer};
+use ::serde::{Deserialize, Serialize};
+
+use crate::iso::{format_date, parse_date, parse_offset, ISO_DATE_MAX_LEN};
+use crate::{
+    consts, date_to_rd, datetime_to_secs, days_in_month, rd_to_date, secs_to_datetime, RD_MAX, RD_MIN, RD_SECONDS_MAX, RD_SECONDS_MIN,
+    YEAR_MAX, YEAR_MIN,
+};
+
+/// Maximum length of a timestamp formatted by [Rfc3339]
+const RFC3339_MAX_LEN: usize = ISO_DATE_MAX_LEN + 10;
+
+/// Serialize ASCII bytes as a string
+fn serialize_ascii<S: Serializer>(buf: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+    let s = core::str::from_utf8(buf).map_err(ser::Error::custom)?;
+    serializer.serialize_str(s)
+}
+
+/// Parse RFC 3339 timestamp to a UTC datetime tuple
+fn parse_rfc3339(s: &[u8]) -> Option<(i32, u8, u8, u8, u8, u8)> {
+    let mut t = 0;
+    while t < s.len() && !matches!(s[t], b'T' | b't' | b' ') {
+        t += 1;
+    }
+    if t + 9 > s.len() || s[t + 3] != b':' || s[t + 6] != b':' {
+        return None;
+    }
+    let (y, m, d) = parse_date(&s[..t])?;
+    let hh = parse_time_digits(s, t + 1, consts::HOUR_MAX)?;
+    let mm = parse_time_digits(s, t + 4, consts::MINUTE_MAX)?;
+    let ss = parse_time_digits(s, t + 7, consts::SECOND_MAX)?;
+    let mut i = t + 9;
+    if i < s.len() && s[i] == b'.' {
+        i += 1;
+        let start = i;
+        while i < s.len() && s[i].is_ascii_digit() {
+            i += 1;
+        }
+        if i == start {
+            return None;
+        }
+    }
+    let offset = parse_offset(&s[i..])?;
+    let secs = datetime_to_secs((y, m, d, hh, mm, ss)) - offset as i64;
+    if !(RD_SECONDS_MIN..=RD_SECONDS_MAX).contains(&secs) {
+        return None;
+    }
+    Some(secs_to_datetime(secs))
+}
+
+/// Parse two digit time component with the given maximum
+fn parse_time_digits(s: &[u8], i: usize, max: u8) -> Option<u8> {
+    let a = s[i].wrapping_sub(b'0');
+    let b = s[i + 1].wrapping_sub(b'0');
+    if a > 9 || b > 9 || a * 10 + b > max {
+        return None;
+    }
+    Some(a * 10 + b)
+}
+
+/// Check that the given datetime tuple is valid
+fn is_valid_datetime((y, m, d, hh, mm, ss): (i32, u8, u8, u8, u8, u8)) -> bool {
+    is_valid_date((y, m, d)) && hh <= consts::HOUR_MAX && mm <= consts::MINUTE_MAX && ss <= consts::SECOND_MAX
+}
+
+/// Check that the given date tuple is valid
+fn is_valid_date((y, m, d): (i32, u8, u8)) -> bool {
+    (YEAR_MIN..=YEAR_MAX).contains(&y)
+        && (consts::MONTH_MIN..=consts::MONTH_MAX).contains(&m)
+        && d >= consts::DAY_MIN
+        && d <= days_in_month(y, m)
+}
+
+/// `(year, month, day)` tuple serialized as an ISO 8601 date string
+///
+/// Years between `0000` and `9999` are written as four digits, and other years
+/// in the expanded representation, as done by
+/// [format_date].
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::serde::IsoDate;
+///
+/// assert_eq!(serde_json::to_string(&IsoDate((2023, 5, 12))).unwrap(), r#""2023-05-12""#);
+/// assert_eq!(serde_json::from_str::<IsoDate>(r#""2023-05-12""#).unwrap(), IsoDate((2023, 5, 12)));
+/// assert!(serde_json::from_str::<IsoDate>(r#""2023-02-29""#).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct IsoDate(pub (i32, u8, u8));
+
+/// `(year, month, day, hours, minutes, seconds)` tuple serialized as an RFC
+/// 3339 timestamp string
+///
+/// Serialization writes the timestamp in UTC with a `Z` suffix. Years outside
+/// `0000` to `9999` are written in the expanded ISO 8601 representation.
+///
+/// Deserialization accepts `T`, `t` or a space as the separator, ignores
+/// fractional seconds and converts timestamps with a UTC offset to UTC. Leap
+/// seconds, given as second `60`, are rejected.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::serde::Rfc3339;
+///
+/// let dt = Rfc3339((2023, 5, 20, 9, 24, 38));
+/// assert_eq!(serde_json::to_string(&dt).unwrap(), r#""2023-05-20T09:24:38Z""#);
+/// assert_eq!(serde_json::from_str::<Rfc3339>(r#""2023-05-20T09:24:38Z""#).unwrap(), dt);
+/// assert_eq!(serde_json::from_str::<Rfc3339>(r#""2023-05-20T12:24:38.5+03:00""#).unwrap(), dt);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Rfc3339(pub (i32, u8, u8, u8, u8, u8));
+
+/// `(year, month, day)` tuple serialized as an integer Rata Die
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::serde::RdDays;
+///
+/// assert_eq!(serde_json::to_string(&RdDays((2023, 5, 12))).unwrap(), "19489");
+/// assert_eq!(serde_json::from_str::<RdDays>("19489").unwrap(), RdDays((2023, 5, 12)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct RdDays(pub (i32, u8, u8));
+
+/// `(year, month, day, hours, minutes, seconds)` tuple serialized as integer
+/// Unix seconds
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::serde::UnixSecs;
+///
+/// let dt = UnixSecs((2023, 5, 20, 9, 24, 38));
+/// assert_eq!(serde_json::to_string(&dt).unwrap(), "1684574678");
+/// assert_eq!(serde_json::from_str::<UnixSecs>("1684574678").unwrap(), dt);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct UnixSecs(pub (i32, u8, u8, u8, u8, u8));
+
+/// Serialization of `(year, month, day)` tuples as ISO 8601 date strings
+///
+/// # Examples
+///
+/// ```
+/// #[derive(serde::Serialize)]
+/// struct Event {
+///     #[serde(with = "datealgo::serde::iso_date")]
+///     date: (i32, u8, u8),
+/// }
+///
+/// let event = Event { date: (2023, 5, 12) };
+/// assert_eq!(serde_json::to_string(&event).unwrap(), r#"{"date":"2023-05-12"}"#);
+/// ```
+pub mod iso_date {
+    use super::*;
+
+    /// Serialize date as ISO 8601 date string
+    pub fn serialize<S: Serializer>(date: &(i32, u8, u8), serializer: S) -> Result<S::Ok, S::Error> {
+        if !is_valid_date(*date) {
+            return Err(ser::Error::custom("date is out of range"));
+        }
+        let mut buf = [0; ISO_DATE_MAX_LEN];
+        let len = format_date(*date, &mut buf);
+        serialize_ascii(&buf[..len], serializer)
+    }
+
+    /// Deserialize date from ISO 8601 date string
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<(i32, u8, u8), D::Error> {
+        struct DateVisitor;
+
+        impl Visitor<'_> for DateVisitor {
+            type Value = (i32, u8, u8);
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("an ISO 8601 date string")
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                self.visit_bytes(v.as_bytes())
+            }
+
+            fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+                parse_date(v).ok_or_else(|| E::custom("invalid ISO 8601 date"))
+            }
+        }
+
+        deserializer.deserialize_str(DateVisitor)
+    }
+}
+
+/// Serialization of `(year, month, day, hours, minutes, seconds)` tuples as
+/// RFC 3339 timestamp strings
+///
+/// See [Rfc3339] for the accepted formats.
+///
+/// # Examples
+///
+/// ```
+/// #[derive(serde::Deserialize)]
+/// struct Event {
+///     #[serde(with = "datealgo::serde::rfc3339")]
+///     at: (i32, u8, u8, u8, u8, u8),
+/// }
+///
+/// let event: Event = serde_json::from_str(r#"{"at":"2023-05-20T09:24:38Z"}"#).unwrap();
+/// assert_eq!(event.at, (2023, 5, 20, 9, 24, 38));
+/// ```
+pub mod rfc3339 {
+    use super::*;
+
+    /// Serialize datetime as RFC 3339 timestamp string
+    pub fn serialize<S: Serializer>(&(y, m, d, hh, mm, ss): &(i32, u8, u8, u8, u8, u8), serializer: S) -> Result<S::Ok, S::Error> {
+        if !is_valid_datetime((y, m, d, hh, mm, ss)) {
+            return Err(ser::Error::custom("datetime is out of range"));
+        }
+        let mut date = [0; ISO_DATE_MAX_LEN];
+        let len = format_date((y, m, d), &mut date);
+        let mut buf = [0; RFC3339_MAX_LEN];
+        buf[..len].copy_from_slice(&date[..len]);
+        let time = [
+            b'T',
+            b'0' + hh / 10,
+            b'0' + hh % 10,
+            b':',
+            b'0' + mm / 10,
+            b'0' + mm % 10,
+            b':',
+            b'0' + ss / 10,
+            b'0' + ss % 10,
+            b'Z',
+        ];
+        buf[len..len + time.len()].copy_from_slice(&time);
+        serialize_ascii(&buf[..len + time.len()], serializer)
+    }
+
+    /// Deserialize datetime from RFC 3339 timestamp string
+    #[allow(clippy::type_complexity)]
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<(i32, u8, u8, u8, u8, u8), D::Error> {
+        struct DateTimeVisitor;
+
+        impl Visitor<'_> for DateTimeVisitor {
+            type Value = (i32, u8, u8, u8, u8, u8);
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("an RFC 3339 timestamp string")
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                self.visit_bytes(v.as_bytes())
+            }
+
+            fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+                parse_rfc3339(v).ok_or_else(|| E::custom("invalid RFC 3339 timestamp"))
+            }
+        }
+
+        deserializer.deserialize_str(DateTimeVisitor)
+    }
+}
+
+/// Serialization of `(year, month, day)` tuples as integer Rata Die
+///
+/// # Examples
+///
+/// ```
+/// #[derive(serde::Serialize)]
+/// struct Event {
+///     #[serde(with = "datealgo::serde::rd_days")]
+///     date: (i32, u8, u8),
+/// }
+///
+/// let event = Event { date: (2023, 5, 12) };
+/// assert_eq!(serde_json::to_string(&event).unwrap(), r#"{"date":19489}"#);
+/// ```
+pub mod rd_days {
+    use super::*;
+
+    /// Serialize date as integer Rata Die
+    pub fn serialize<S: Serializer>(date: &(i32, u8, u8), serializer: S) -> Result<S::Ok, S::Error> {
+        if !is_valid_date(*date) {
+            return Err(ser::Error::custom("date is out of range"));
+        }
+        serializer.serialize_i32(date_to_rd(*date))
+    }
+
+    /// Deserialize date from integer Rata Die
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<(i32, u8, u8), D::Error> {
+        let n = i64::deserialize(deserializer)?;
+        if !(RD_MIN as i64..=RD_MAX as i64).contains(&n) {
+            return Err(de::Error::custom("rata die is out of range"));
+        }
+        Ok(rd_to_date(n as i32))
+    }
+}
+
+/// Serialization of `(year, month, day, hours, minutes, seconds)` tuples as
+/// integer Unix seconds
+///
+/// # Examples
+///
+/// ```
+/// #[derive(serde::Serialize)]
+/// struct Event {
+///     #[serde(with = "datealgo::serde::unix_secs")]
+///     at: (i32, u8, u8, u8, u8, u8),
+/// }
+///
+/// let event = Event { at: (2023, 5, 20, 9, 24, 38) };
+/// assert_eq!(serde_json::to_string(&event).unwrap(), r#"{"at":1684574678}"#);
+/// ```
+pub mod unix_secs {
+    use super::*;
+
+    /// Serialize datetime as integer Unix seconds
+    pub fn serialize<S: Serializer>(dt: &(i32, u8, u8, u8, u8, u8), serializer: S) -> Result<S::Ok, S::Error> {
+        if !is_valid_datetime(*dt) {
+            return Err(ser::Error::custom("datetime is out of range"));
+        }
+        serializer.serialize_i64(datetime_to_secs(*dt))
+    }
+
+    /// Deserialize datetime from integer Unix seconds
+    #[allow(clippy::type_complexity)]
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<(i32, u8, u8, u8, u8, u8), D::Error> {
+        let secs = i64::deserialize(deserializer)?;
+        if !(RD_SECONDS_MIN..=RD_SECONDS_MAX).contains(&secs) {
+            return Err(de::Error::custom("seconds value is out of range"));
+        }
+        Ok(secs_to_datetime(secs))
+    }
+}
+
+macro_rules! impl_adapter {
+    ($ty:ident, $module:ident) => {
+        impl Serialize for $ty {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                $module::serialize(&self.0, serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $ty {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                $module::deserialize(deserializer).map($ty)
+            }
+        }
+    };
+}
+
+impl_adapter!(IsoDate, iso_date);
+impl_adapter!(Rfc3339, rfc3339);
+impl_adapter!(RdDays, rd_days);
+impl_adapter!(UnixSecs, unix_secs);
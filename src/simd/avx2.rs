@@ -0,0 +1,182 @@
+//! AVX2 implementations for `x86_64`
+//!
+//! The divisions by constants in the scalar algorithms are replaced with
+//! multiplications by their reciprocals, as AVX2 has no integer division. The
+//! 32-bit high multiplications are done with two `vpmuludq`, for the even and
+//! odd lanes. The division of seconds by the length of the day needs 64-bit
+//! precision, so it is done in double precision floating point, which is exact
+//! for the supported range.
+
+use core::arch::x86_64::*;
+
+use crate::{DAY_OFFSET, RD_MAX, RD_MIN, YEAR_OFFSET};
+#[cfg(target_feature = "avx2")]
+use crate::{RD_SECONDS_MAX, RD_SECONDS_MIN, SECS_OFFSET};
+
+/// High 32 bits of the products of unsigned 32-bit lanes and a constant
+#[inline]
+#[target_feature(enable = "avx2")]
+fn mulhi_epu32(a: __m256i, m: __m256i) -> __m256i {
+    let even = _mm256_srli_epi64::<32>(_mm256_mul_epu32(a, m));
+    let odd = _mm256_mul_epu32(_mm256_srli_epi64::<32>(a), m);
+    _mm256_blend_epi32::<0b1010_1010>(even, odd)
+}
+
+/// Convert days shifted by [DAY_OFFSET] to year, month and day in all lanes
+///
+/// Same as [rd_to_date](crate::rd_to_date), with `n / 146097` computed as
+/// `n * 963315389 >> 47` and `n / 1461` as `n * 2939745 >> 32`, which are exact
+/// for all `u32` and for `n < 146100` respectively, and `n / 2141` as `n *
+/// 31345 >> 26`, which is exact for `n < 65536`.
+#[inline]
+#[target_feature(enable = "avx2")]
+fn date_lanes(n: __m256i) -> (__m256i, __m256i, __m256i) {
+    // century
+    let n = _mm256_add_epi32(_mm256_slli_epi32::<2>(n), _mm256_set1_epi32(3));
+    let c = _mm256_srli_epi32::<15>(mulhi_epu32(n, _mm256_set1_epi32(963315389)));
+    let r = _mm256_sub_epi32(n, _mm256_mullo_epi32(c, _mm256_set1_epi32(146097)));
+    // year
+    let n = _mm256_or_si256(r, _mm256_set1_epi32(3));
+    let z = mulhi_epu32(n, _mm256_set1_epi32(2939745));
+    let n = _mm256_srli_epi32::<2>(_mm256_sub_epi32(n, _mm256_mullo_epi32(z, _mm256_set1_epi32(1461))));
+    let j = _mm256_cmpgt_epi32(n, _mm256_set1_epi32(305));
+    let y = _mm256_add_epi32(_mm256_mullo_epi32(c, _mm256_set1_epi32(100)), z);
+    let y = _mm256_sub_epi32(y, j);
+    // month and day
+    let n = _mm256_add_epi32(_mm256_mullo_epi32(n, _mm256_set1_epi32(2141)), _mm256_set1_epi32(197913));
+    let m = _mm256_srli_epi32::<16>(n);
+    let d = _mm256_and_si256(n, _mm256_set1_epi32(0xffff));
+    let d = _mm256_srli_epi32::<26>(_mm256_mullo_epi32(d, _mm256_set1_epi32(31345)));
+    // map
+    let y = _mm256_sub_epi32(y, _mm256_set1_epi32(YEAR_OFFSET));
+    let m = _mm256_sub_epi32(m, _mm256_and_si256(j, _mm256_set1_epi32(12)));
+    let d = _mm256_add_epi32(d, _mm256_set1_epi32(1));
+    (y, m, d)
+}
+
+/// Convert seconds shifted by [SECS_OFFSET] in 64-bit lanes to shifted days
+/// and seconds of day in 32-bit lanes
+#[inline]
+#[cfg(target_feature = "avx2")]
+#[target_feature(enable = "avx2")]
+fn days_and_secs_of_day(s: __m256i) -> (__m128i, __m128i) {
+    // exact conversion of integers below 2^52 to double
+    let f = _mm256_castsi256_pd(_mm256_or_si256(s, _mm256_set1_epi64x(0x4330_0000_0000_0000)));
+    let f = _mm256_sub_pd(f, _mm256_set1_pd(4503599627370496.0));
+    let d = _mm256_cvttpd_epi32(_mm256_div_pd(f, _mm256_set1_pd(86400.0)));
+    let lo = _mm256_permutevar8x32_epi32(s, _mm256_setr_epi32(0, 2, 4, 6, 0, 2, 4, 6));
+    let secs = _mm_sub_epi32(_mm256_castsi256_si128(lo), _mm_mullo_epi32(d, _mm_set1_epi32(86400)));
+    (d, secs)
+}
+
+/// Pack two vectors of values below 256 to bytes
+#[inline]
+#[target_feature(enable = "avx2")]
+fn pack_u8(a: __m256i, b: __m256i) -> ([u8; 8], [u8; 8]) {
+    let w = _mm256_packus_epi32(a, b);
+    let w = _mm256_packus_epi16(w, w);
+    let w = _mm256_permutevar8x32_epi32(w, _mm256_setr_epi32(0, 4, 1, 5, 0, 4, 1, 5));
+    let x = _mm256_castsi256_si128(w);
+    (
+        (_mm_cvtsi128_si64(x) as u64).to_le_bytes(),
+        (_mm_extract_epi64::<1>(x) as u64).to_le_bytes(),
+    )
+}
+
+/// Store a vector of 32-bit lanes to an array
+#[inline]
+#[target_feature(enable = "avx2")]
+fn store_i32(v: __m256i) -> [i32; 8] {
+    let mut out = [0; 8];
+    // SAFETY: unaligned store of 32 bytes to an array of 32 bytes
+    #[allow(unsafe_code)]
+    unsafe {
+        _mm256_storeu_si256(out.as_mut_ptr().cast(), v)
+    };
+    out
+}
+
+/// Convert 8 Rata Die values to Gregorian dates
+#[inline]
+#[target_feature(enable = "avx2")]
+pub(super) fn rd_to_date_x8(ns: &[i32; 8]) -> ([i32; 8], [u8; 8], [u8; 8]) {
+    debug_assert!(ns.iter().all(|&n| n >= RD_MIN && n <= RD_MAX), "given rata die is out of range");
+    // SAFETY: unaligned load of 32 bytes from an array of 32 bytes
+    #[allow(unsafe_code)]
+    let n = unsafe { _mm256_loadu_si256(ns.as_ptr().cast()) };
+    let (y, m, d) = date_lanes(_mm256_add_epi32(n, _mm256_set1_epi32(DAY_OFFSET)));
+    let (m, d) = pack_u8(m, d);
+    (store_i32(y), m, d)
+}
+
+/// Convert 8 total seconds values to year, month, day, hours, minutes and
+/// seconds
+#[inline]
+#[cfg(target_feature = "avx2")]
+#[target_feature(enable = "avx2")]
+#[allow(clippy::type_complexity)]
+pub(super) fn secs_to_datetime_x8(secs: &[i64; 8]) -> ([i32; 8], [u8; 8], [u8; 8], [u8; 8], [u8; 8], [u8; 8]) {
+    debug_assert!(
+        secs.iter().all(|&s| s >= RD_SECONDS_MIN && s <= RD_SECONDS_MAX),
+        "given seconds value is out of range"
+    );
+    // SAFETY: unaligned loads of 32 bytes from the two halves of an array of
+    // 64 bytes
+    #[allow(unsafe_code)]
+    let (lo, hi) = unsafe {
+        (
+            _mm256_loadu_si256(secs.as_ptr().cast()),
+            _mm256_loadu_si256(secs.as_ptr().add(4).cast()),
+        )
+    };
+    let offset = _mm256_set1_epi64x(SECS_OFFSET);
+    let (dlo, slo) = days_and_secs_of_day(_mm256_add_epi64(lo, offset));
+    let (dhi, shi) = days_and_secs_of_day(_mm256_add_epi64(hi, offset));
+    let (y, m, d) = date_lanes(_mm256_set_m128i(dhi, dlo));
+    // see secs_to_dhms for the identities used
+    let s = _mm256_set_m128i(shi, slo);
+    let mins = mulhi_epu32(s, _mm256_set1_epi32(71582789));
+    let ss = _mm256_sub_epi32(s, _mm256_mullo_epi32(mins, _mm256_set1_epi32(60)));
+    let hh = mulhi_epu32(mins, _mm256_set1_epi32(71582789));
+    let mm = _mm256_sub_epi32(mins, _mm256_mullo_epi32(hh, _mm256_set1_epi32(60)));
+    let (m, d) = pack_u8(m, d);
+    let (hh, mm) = pack_u8(hh, mm);
+    let (ss, _) = pack_u8(ss, ss);
+    (store_i32(y), m, d, hh, mm, ss)
+}
+
+/// Convert the leading full chunks of a slice of Rata Die in lanes of 8
+#[inline]
+#[target_feature(enable = "avx2")]
+pub(super) fn rd_to_date_chunks(ns: &[i32], years: &mut [i32], months: &mut [u8], days: &mut [u8]) -> usize {
+    let mut done = 0;
+    let chunks = ns
+        .chunks_exact(8)
+        .zip(years.chunks_exact_mut(8))
+        .zip(months.chunks_exact_mut(8))
+        .zip(days.chunks_exact_mut(8));
+    for (((n, y), m), d) in chunks {
+        let (ly, lm, ld) = rd_to_date_x8(n.try_into().unwrap());
+        y.copy_from_slice(&ly);
+        m.copy_from_slice(&lm);
+        d.copy_from_slice(&ld);
+        done += 8;
+    }
+    done
+}
+
+/// Convert the leading full chunks of a slice of Rata Die to date tuples in
+/// lanes of 8
+#[inline]
+#[target_feature(enable = "avx2")]
+pub(super) fn rd_to_date_chunks_aos(ns: &[i32], dates: &mut [(i32, u8, u8)]) -> usize {
+    let mut done = 0;
+    for (n, out) in ns.chunks_exact(8).zip(dates.chunks_exact_mut(8)) {
+        let (ly, lm, ld) = rd_to_date_x8(n.try_into().unwrap());
+        for (i, date) in out.iter_mut().enumerate() {
+            *date = (ly[i], lm[i], ld[i]);
+        }
+        done += 8;
+    }
+    done
+}
@@ -0,0 +1,181 @@
+//! AVX-512 implementations for `x86_64`
+//!
+//! Same approach as the AVX2 implementation with 16 lanes per vector. The
+//! comparison for the month mapping produces a mask register, which is used
+//! directly in masked additions and subtractions, and the results are packed
+//! to bytes with the truncating `vpmovdb`. Only AVX-512F instructions are
+//! used.
+
+use core::arch::x86_64::*;
+
+use crate::{DAY_OFFSET, RD_MAX, RD_MIN, YEAR_OFFSET};
+#[cfg(target_feature = "avx512f")]
+use crate::{RD_SECONDS_MAX, RD_SECONDS_MIN, SECS_OFFSET};
+
+/// High 32 bits of the products of unsigned 32-bit lanes and a constant
+#[inline]
+#[target_feature(enable = "avx512f")]
+fn mulhi_epu32(a: __m512i, m: __m512i) -> __m512i {
+    let even = _mm512_srli_epi64::<32>(_mm512_mul_epu32(a, m));
+    let odd = _mm512_mul_epu32(_mm512_srli_epi64::<32>(a), m);
+    _mm512_mask_blend_epi32(0xaaaa, even, odd)
+}
+
+/// Convert days shifted by [DAY_OFFSET] to year, month and day in all lanes
+///
+/// Same as [rd_to_date](crate::rd_to_date), with the divisions computed as in
+/// the AVX2 implementation.
+#[inline]
+#[target_feature(enable = "avx512f")]
+fn date_lanes(n: __m512i) -> (__m512i, __m512i, __m512i) {
+    // century
+    let n = _mm512_add_epi32(_mm512_slli_epi32::<2>(n), _mm512_set1_epi32(3));
+    let c = _mm512_srli_epi32::<15>(mulhi_epu32(n, _mm512_set1_epi32(963315389)));
+    let r = _mm512_sub_epi32(n, _mm512_mullo_epi32(c, _mm512_set1_epi32(146097)));
+    // year
+    let n = _mm512_or_si512(r, _mm512_set1_epi32(3));
+    let z = mulhi_epu32(n, _mm512_set1_epi32(2939745));
+    let n = _mm512_srli_epi32::<2>(_mm512_sub_epi32(n, _mm512_mullo_epi32(z, _mm512_set1_epi32(1461))));
+    let j = _mm512_cmpgt_epu32_mask(n, _mm512_set1_epi32(305));
+    let y = _mm512_add_epi32(_mm512_mullo_epi32(c, _mm512_set1_epi32(100)), z);
+    let y = _mm512_mask_add_epi32(y, j, y, _mm512_set1_epi32(1));
+    // month and day
+    let n = _mm512_add_epi32(_mm512_mullo_epi32(n, _mm512_set1_epi32(2141)), _mm512_set1_epi32(197913));
+    let m = _mm512_srli_epi32::<16>(n);
+    let d = _mm512_and_si512(n, _mm512_set1_epi32(0xffff));
+    let d = _mm512_srli_epi32::<26>(_mm512_mullo_epi32(d, _mm512_set1_epi32(31345)));
+    // map
+    let y = _mm512_sub_epi32(y, _mm512_set1_epi32(YEAR_OFFSET));
+    let m = _mm512_mask_sub_epi32(m, j, m, _mm512_set1_epi32(12));
+    let d = _mm512_add_epi32(d, _mm512_set1_epi32(1));
+    (y, m, d)
+}
+
+/// Convert seconds shifted by [SECS_OFFSET] in 64-bit lanes to shifted days
+/// and the low halves of the seconds in 32-bit lanes
+#[inline]
+#[cfg(target_feature = "avx512f")]
+#[target_feature(enable = "avx512f")]
+fn days_and_secs_lo(s: __m512i) -> (__m256i, __m256i) {
+    // exact conversion of integers below 2^52 to double
+    let f = _mm512_castsi512_pd(_mm512_or_si512(s, _mm512_set1_epi64(0x4330_0000_0000_0000)));
+    let f = _mm512_sub_pd(f, _mm512_set1_pd(4503599627370496.0));
+    let d = _mm512_cvttpd_epi32(_mm512_div_pd(f, _mm512_set1_pd(86400.0)));
+    (d, _mm512_cvtepi64_epi32(s))
+}
+
+/// Join two vectors of 8 lanes to a vector of 16 lanes
+#[inline]
+#[cfg(target_feature = "avx512f")]
+#[target_feature(enable = "avx512f")]
+fn join(lo: __m256i, hi: __m256i) -> __m512i {
+    _mm512_inserti64x4::<1>(_mm512_castsi256_si512(lo), hi)
+}
+
+/// Pack a vector of values below 256 to bytes
+#[inline]
+#[target_feature(enable = "avx512f")]
+fn pack_u8(v: __m512i) -> [u8; 16] {
+    let mut out = [0; 16];
+    // SAFETY: unaligned store of 16 bytes to an array of 16 bytes
+    #[allow(unsafe_code)]
+    unsafe {
+        _mm_storeu_si128(out.as_mut_ptr().cast(), _mm512_cvtepi32_epi8(v))
+    };
+    out
+}
+
+/// Store a vector of 32-bit lanes to an array
+#[inline]
+#[target_feature(enable = "avx512f")]
+fn store_i32(v: __m512i) -> [i32; 16] {
+    let mut out = [0; 16];
+    // SAFETY: unaligned store of 64 bytes to an array of 64 bytes
+    #[allow(unsafe_code)]
+    unsafe {
+        _mm512_storeu_si512(out.as_mut_ptr().cast(), v)
+    };
+    out
+}
+
+/// Convert 16 Rata Die values to Gregorian dates
+#[inline]
+#[target_feature(enable = "avx512f")]
+pub(super) fn rd_to_date_x16(ns: &[i32; 16]) -> ([i32; 16], [u8; 16], [u8; 16]) {
+    debug_assert!(ns.iter().all(|&n| n >= RD_MIN && n <= RD_MAX), "given rata die is out of range");
+    // SAFETY: unaligned load of 64 bytes from an array of 64 bytes
+    #[allow(unsafe_code)]
+    let n = unsafe { _mm512_loadu_si512(ns.as_ptr().cast()) };
+    let (y, m, d) = date_lanes(_mm512_add_epi32(n, _mm512_set1_epi32(DAY_OFFSET)));
+    (store_i32(y), pack_u8(m), pack_u8(d))
+}
+
+/// Convert 16 total seconds values to year, month, day, hours, minutes and
+/// seconds
+#[inline]
+#[cfg(target_feature = "avx512f")]
+#[target_feature(enable = "avx512f")]
+#[allow(clippy::type_complexity)]
+pub(super) fn secs_to_datetime_x16(secs: &[i64; 16]) -> ([i32; 16], [u8; 16], [u8; 16], [u8; 16], [u8; 16], [u8; 16]) {
+    debug_assert!(
+        secs.iter().all(|&s| s >= RD_SECONDS_MIN && s <= RD_SECONDS_MAX),
+        "given seconds value is out of range"
+    );
+    // SAFETY: unaligned loads of 64 bytes from the two halves of an array of
+    // 128 bytes
+    #[allow(unsafe_code)]
+    let (lo, hi) = unsafe {
+        (
+            _mm512_loadu_si512(secs.as_ptr().cast()),
+            _mm512_loadu_si512(secs.as_ptr().add(8).cast()),
+        )
+    };
+    let offset = _mm512_set1_epi64(SECS_OFFSET);
+    let (dlo, slo) = days_and_secs_lo(_mm512_add_epi64(lo, offset));
+    let (dhi, shi) = days_and_secs_lo(_mm512_add_epi64(hi, offset));
+    let n = join(dlo, dhi);
+    let s = _mm512_sub_epi32(join(slo, shi), _mm512_mullo_epi32(n, _mm512_set1_epi32(86400)));
+    let (y, m, d) = date_lanes(n);
+    // see secs_to_dhms for the identities used
+    let mins = mulhi_epu32(s, _mm512_set1_epi32(71582789));
+    let ss = _mm512_sub_epi32(s, _mm512_mullo_epi32(mins, _mm512_set1_epi32(60)));
+    let hh = mulhi_epu32(mins, _mm512_set1_epi32(71582789));
+    let mm = _mm512_sub_epi32(mins, _mm512_mullo_epi32(hh, _mm512_set1_epi32(60)));
+    (store_i32(y), pack_u8(m), pack_u8(d), pack_u8(hh), pack_u8(mm), pack_u8(ss))
+}
+
+/// Convert the leading full chunks of a slice of Rata Die in lanes of 16
+#[inline]
+#[target_feature(enable = "avx512f")]
+pub(super) fn rd_to_date_chunks(ns: &[i32], years: &mut [i32], months: &mut [u8], days: &mut [u8]) -> usize {
+    let mut done = 0;
+    let chunks = ns
+        .chunks_exact(16)
+        .zip(years.chunks_exact_mut(16))
+        .zip(months.chunks_exact_mut(16))
+        .zip(days.chunks_exact_mut(16));
+    for (((n, y), m), d) in chunks {
+        let (ly, lm, ld) = rd_to_date_x16(n.try_into().unwrap());
+        y.copy_from_slice(&ly);
+        m.copy_from_slice(&lm);
+        d.copy_from_slice(&ld);
+        done += 16;
+    }
+    done
+}
+
+/// Convert the leading full chunks of a slice of Rata Die to date tuples in
+/// lanes of 16
+#[inline]
+#[target_feature(enable = "avx512f")]
+pub(super) fn rd_to_date_chunks_aos(ns: &[i32], dates: &mut [(i32, u8, u8)]) -> usize {
+    let mut done = 0;
+    for (n, out) in ns.chunks_exact(16).zip(dates.chunks_exact_mut(16)) {
+        let (ly, lm, ld) = rd_to_date_x16(n.try_into().unwrap());
+        for (i, date) in out.iter_mut().enumerate() {
+            *date = (ly[i], lm[i], ld[i]);
+        }
+        done += 16;
+    }
+    done
+}
@@ -0,0 +1,352 @@
+//! Vectorized conversions for bulk data
+//!
+//! The Neri-Schneider algorithms contain no data dependent branches, so they
+//! can be computed for many values at once in vector registers. The functions
+//! in this module convert fixed size arrays of 8 or 16 values.
+//!
+//! The implementation is selected at compile time from the enabled target
+//! features, for example with `-C target-cpu=native`, so that the vector code
+//! can be inlined into the caller. On `x86_64` the conversions use AVX-512F
+//! instructions for arrays of 16 values if it is enabled, and AVX2
+//! instructions for arrays of 8 values, or for 16 values in two halves. On
+//! `aarch64` targets they use NEON instructions, which are always available
+//! there, processing 4 values per vector. Otherwise the functions fall back to
+//! calling the scalar functions for each value, which needs no `unsafe` code.
+//! The results are identical to the scalar functions in all cases.
+//!
+//! To use AVX-512F or AVX2 in prebuilt binaries that do not enable them at
+//! compile time, use the [batch](crate::batch) functions instead. With the
+//! `std` feature they check the features of the CPU at run time with
+//! `is_x86_feature_detected!` once per slice, preferring AVX-512F over AVX2,
+//! so the check is amortized over the whole slice.
+//!
+//! Using `core::arch` requires `unsafe` code for the loads and stores, so
+//! enabling this feature relaxes the crate level lint from
+//! `forbid(unsafe_code)` to `deny(unsafe_code)` and allows it only for those
+//! operations.
+//!
+//! The [batch](crate::batch) functions use these implementations for the bulk
+//! of the data and the scalar loop for the remainder. The `simd` benchmark
+//! compares them with the scalar functions.
+
+#[cfg(target_arch = "x86_64")]
+mod avx2;
+#[cfg(target_arch = "x86_64")]
+mod avx512;
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+mod neon;
+
+/// Check if AVX2 is enabled at compile time or, with the `std` feature,
+/// supported by the CPU at run time
+#[cfg(target_arch = "x86_64")]
+#[inline]
+fn has_avx2() -> bool {
+    #[cfg(all(feature = "std", not(target_feature = "avx2")))]
+    let r = std::is_x86_feature_detected!("avx2");
+    #[cfg(not(all(feature = "std", not(target_feature = "avx2"))))]
+    let r = cfg!(target_feature = "avx2");
+    r
+}
+
+/// Check if AVX-512F is enabled at compile time or, with the `std` feature,
+/// supported by the CPU at run time
+#[cfg(target_arch = "x86_64")]
+#[inline]
+fn has_avx512f() -> bool {
+    #[cfg(all(feature = "std", not(target_feature = "avx512f")))]
+    let r = std::is_x86_feature_detected!("avx512f");
+    #[cfg(not(all(feature = "std", not(target_feature = "avx512f"))))]
+    let r = cfg!(target_feature = "avx512f");
+    r
+}
+
+/// Convert Rata Die to Gregorian date in all lanes with the scalar function
+#[inline]
+#[cfg(not(any(
+    all(target_arch = "x86_64", target_feature = "avx2"),
+    all(target_arch = "aarch64", target_feature = "neon")
+)))]
+fn rd_to_date_lanes<const N: usize>(ns: [i32; N]) -> ([i32; N], [u8; N], [u8; N]) {
+    let mut ys = [0; N];
+    let mut ms = [0; N];
+    let mut ds = [0; N];
+    for (i, &n) in ns.iter().enumerate() {
+        (ys[i], ms[i], ds[i]) = crate::rd_to_date(n);
+    }
+    (ys, ms, ds)
+}
+
+/// Convert total seconds to year, month, day, hours, minutes and seconds in all
+/// lanes with the scalar function
+#[inline]
+#[cfg(not(any(
+    all(target_arch = "x86_64", target_feature = "avx2"),
+    all(target_arch = "aarch64", target_feature = "neon")
+)))]
+#[allow(clippy::type_complexity)]
+fn secs_to_datetime_lanes<const N: usize>(secs: [i64; N]) -> ([i32; N], [u8; N], [u8; N], [u8; N], [u8; N], [u8; N]) {
+    let mut ys = [0; N];
+    let mut ms = [0; N];
+    let mut ds = [0; N];
+    let mut hhs = [0; N];
+    let mut mms = [0; N];
+    let mut sss = [0; N];
+    for (i, &s) in secs.iter().enumerate() {
+        (ys[i], ms[i], ds[i], hhs[i], mms[i], sss[i]) = crate::secs_to_datetime(s);
+    }
+    (ys, ms, ds, hhs, mms, sss)
+}
+
+/// Convert 8 Rata Die values to Gregorian dates
+///
+/// Given an array of days counting from Unix epoch (January 1st, 1970) returns
+/// arrays of years, months and days.
+///
+/// # Panics
+///
+/// Every element must be between [RD_MIN](crate::RD_MIN) and
+/// [RD_MAX](crate::RD_MAX) inclusive. Bounds are checked using `debug_assert`
+/// only, so that the checks are not present in release builds, similar to
+/// integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::simd::rd_to_date_x8;
+///
+/// let (y, m, d) = rd_to_date_x8([0, 19489, -719528, 2932896, 1, 2, 3, 4]);
+/// assert_eq!(y, [1970, 2023, 0, 9999, 1970, 1970, 1970, 1970]);
+/// assert_eq!(m, [1, 5, 1, 12, 1, 1, 1, 1]);
+/// assert_eq!(d, [1, 12, 1, 31, 2, 3, 4, 5]);
+/// ```
+///
+/// # Algorithm
+///
+/// Same as [rd_to_date](crate::rd_to_date), computed across lanes.
+#[inline]
+pub fn rd_to_date_x8(ns: [i32; 8]) -> ([i32; 8], [u8; 8], [u8; 8]) {
+    #[cfg(all(target_arch = "x86_64", target_feature = "avx2"))]
+    // SAFETY: the target has AVX2 enabled at compile time
+    #[allow(unsafe_code)]
+    let r = unsafe { avx2::rd_to_date_x8(&ns) };
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    // SAFETY: the target has NEON enabled at compile time
+    #[allow(unsafe_code)]
+    let r = unsafe { neon::rd_to_date_x8(&ns) };
+    #[cfg(not(any(
+        all(target_arch = "x86_64", target_feature = "avx2"),
+        all(target_arch = "aarch64", target_feature = "neon")
+    )))]
+    let r = rd_to_date_lanes(ns);
+    r
+}
+
+/// Convert 16 Rata Die values to Gregorian dates
+///
+/// Given an array of days counting from Unix epoch (January 1st, 1970) returns
+/// arrays of years, months and days.
+///
+/// # Panics
+///
+/// Every element must be between [RD_MIN](crate::RD_MIN) and
+/// [RD_MAX](crate::RD_MAX) inclusive. Bounds are checked using `debug_assert`
+/// only, so that the checks are not present in release builds, similar to
+/// integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::simd::rd_to_date_x16;
+///
+/// let mut ns = [0; 16];
+/// ns[1] = 19489;
+/// let (y, m, d) = rd_to_date_x16(ns);
+/// assert_eq!((y[0], m[0], d[0]), (1970, 1, 1));
+/// assert_eq!((y[1], m[1], d[1]), (2023, 5, 12));
+/// ```
+///
+/// # Algorithm
+///
+/// Same as [rd_to_date](crate::rd_to_date), computed across lanes.
+#[inline]
+pub fn rd_to_date_x16(ns: [i32; 16]) -> ([i32; 16], [u8; 16], [u8; 16]) {
+    #[cfg(all(target_arch = "x86_64", target_feature = "avx512f"))]
+    // SAFETY: the target has AVX-512F enabled at compile time
+    #[allow(unsafe_code)]
+    let r = unsafe { avx512::rd_to_date_x16(&ns) };
+    #[cfg(not(all(target_arch = "x86_64", target_feature = "avx512f")))]
+    let r = {
+        let (lo, hi) = ns.split_at(8);
+        let (ylo, mlo, dlo) = rd_to_date_x8(lo.try_into().unwrap());
+        let (yhi, mhi, dhi) = rd_to_date_x8(hi.try_into().unwrap());
+        (concat(ylo, yhi), concat(mlo, mhi), concat(dlo, dhi))
+    };
+    r
+}
+
+/// Convert 8 total seconds values to year, month, day, hours, minutes and
+/// seconds
+///
+/// Given an array of seconds counting from Unix epoch (January 1st, 1970)
+/// returns arrays of years, months, days, hours, minutes and seconds.
+///
+/// # Panics
+///
+/// Every element must be between [RD_SECONDS_MIN](crate::RD_SECONDS_MIN) and
+/// [RD_SECONDS_MAX](crate::RD_SECONDS_MAX) inclusive. Bounds are checked using
+/// `debug_assert` only, so that the checks are not present in release builds,
+/// similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::simd::secs_to_datetime_x8;
+///
+/// let (y, m, d, hh, mm, ss) = secs_to_datetime_x8([0, 1684574678, -1, 86399, 0, 0, 0, 0]);
+/// assert_eq!((y[0], m[0], d[0], hh[0], mm[0], ss[0]), (1970, 1, 1, 0, 0, 0));
+/// assert_eq!((y[1], m[1], d[1], hh[1], mm[1], ss[1]), (2023, 5, 20, 9, 24, 38));
+/// assert_eq!((y[2], m[2], d[2], hh[2], mm[2], ss[2]), (1969, 12, 31, 23, 59, 59));
+/// assert_eq!((y[3], m[3], d[3], hh[3], mm[3], ss[3]), (1970, 1, 1, 23, 59, 59));
+/// ```
+///
+/// # Algorithm
+///
+/// Same as [secs_to_datetime](crate::secs_to_datetime), computed across lanes.
+#[inline]
+#[allow(clippy::type_complexity)]
+pub fn secs_to_datetime_x8(secs: [i64; 8]) -> ([i32; 8], [u8; 8], [u8; 8], [u8; 8], [u8; 8], [u8; 8]) {
+    #[cfg(all(target_arch = "x86_64", target_feature = "avx2"))]
+    // SAFETY: the target has AVX2 enabled at compile time
+    #[allow(unsafe_code)]
+    let r = unsafe { avx2::secs_to_datetime_x8(&secs) };
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    // SAFETY: the target has NEON enabled at compile time
+    #[allow(unsafe_code)]
+    let r = unsafe { neon::secs_to_datetime_x8(&secs) };
+    #[cfg(not(any(
+        all(target_arch = "x86_64", target_feature = "avx2"),
+        all(target_arch = "aarch64", target_feature = "neon")
+    )))]
+    let r = secs_to_datetime_lanes(secs);
+    r
+}
+
+/// Convert 16 total seconds values to year, month, day, hours, minutes and
+/// seconds
+///
+/// Given an array of seconds counting from Unix epoch (January 1st, 1970)
+/// returns arrays of years, months, days, hours, minutes and seconds.
+///
+/// # Panics
+///
+/// Every element must be between [RD_SECONDS_MIN](crate::RD_SECONDS_MIN) and
+/// [RD_SECONDS_MAX](crate::RD_SECONDS_MAX) inclusive. Bounds are checked using
+/// `debug_assert` only, so that the checks are not present in release builds,
+/// similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::simd::secs_to_datetime_x16;
+///
+/// let mut secs = [0; 16];
+/// secs[1] = 1684574678;
+/// let (y, m, d, hh, mm, ss) = secs_to_datetime_x16(secs);
+/// assert_eq!((y[0], m[0], d[0], hh[0], mm[0], ss[0]), (1970, 1, 1, 0, 0, 0));
+/// assert_eq!((y[1], m[1], d[1], hh[1], mm[1], ss[1]), (2023, 5, 20, 9, 24, 38));
+/// ```
+///
+/// # Algorithm
+///
+/// Same as [secs_to_datetime](crate::secs_to_datetime), computed across lanes.
+#[inline]
+#[allow(clippy::type_complexity)]
+pub fn secs_to_datetime_x16(secs: [i64; 16]) -> ([i32; 16], [u8; 16], [u8; 16], [u8; 16], [u8; 16], [u8; 16]) {
+    #[cfg(all(target_arch = "x86_64", target_feature = "avx512f"))]
+    // SAFETY: the target has AVX-512F enabled at compile time
+    #[allow(unsafe_code)]
+    let r = unsafe { avx512::secs_to_datetime_x16(&secs) };
+    #[cfg(not(all(target_arch = "x86_64", target_feature = "avx512f")))]
+    let r = {
+        let (lo, hi) = secs.split_at(8);
+        let (ylo, mlo, dlo, hhlo, mmlo, sslo) = secs_to_datetime_x8(lo.try_into().unwrap());
+        let (yhi, mhi, dhi, hhhi, mmhi, sshi) = secs_to_datetime_x8(hi.try_into().unwrap());
+        (
+            concat(ylo, yhi),
+            concat(mlo, mhi),
+            concat(dlo, dhi),
+            concat(hhlo, hhhi),
+            concat(mmlo, mmhi),
+            concat(sslo, sshi),
+        )
+    };
+    r
+}
+
+/// Concatenate two arrays of 8 values
+#[inline]
+#[cfg(not(all(target_arch = "x86_64", target_feature = "avx512f")))]
+fn concat<T: Copy + Default>(lo: [T; 8], hi: [T; 8]) -> [T; 16] {
+    let mut out = [T::default(); 16];
+    out[..8].copy_from_slice(&lo);
+    out[8..].copy_from_slice(&hi);
+    out
+}
+
+/// Convert the leading full chunks of a slice of Rata Die with vector
+/// instructions
+///
+/// Returns the number of elements converted, leaving the rest to the caller.
+#[inline]
+#[allow(unused_variables)]
+pub(crate) fn rd_to_date_chunks(ns: &[i32], years: &mut [i32], months: &mut [u8], days: &mut [u8]) -> usize {
+    #[cfg(target_arch = "x86_64")]
+    {
+        if has_avx512f() {
+            // SAFETY: AVX-512F support was checked above
+            #[allow(unsafe_code)]
+            return unsafe { avx512::rd_to_date_chunks(ns, years, months, days) };
+        }
+        if has_avx2() {
+            // SAFETY: AVX2 support was checked above
+            #[allow(unsafe_code)]
+            return unsafe { avx2::rd_to_date_chunks(ns, years, months, days) };
+        }
+    }
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    // SAFETY: the target has NEON enabled at compile time
+    #[allow(unsafe_code)]
+    let done = unsafe { neon::rd_to_date_chunks(ns, years, months, days) };
+    #[cfg(not(all(target_arch = "aarch64", target_feature = "neon")))]
+    let done = 0;
+    done
+}
+
+/// Convert the leading full chunks of a slice of Rata Die to date tuples with
+/// vector instructions
+///
+/// Returns the number of elements converted, leaving the rest to the caller.
+#[inline]
+#[allow(unused_variables)]
+pub(crate) fn rd_to_date_chunks_aos(ns: &[i32], dates: &mut [(i32, u8, u8)]) -> usize {
+    #[cfg(target_arch = "x86_64")]
+    {
+        if has_avx512f() {
+            // SAFETY: AVX-512F support was checked above
+            #[allow(unsafe_code)]
+            return unsafe { avx512::rd_to_date_chunks_aos(ns, dates) };
+        }
+        if has_avx2() {
+            // SAFETY: AVX2 support was checked above
+            #[allow(unsafe_code)]
+            return unsafe { avx2::rd_to_date_chunks_aos(ns, dates) };
+        }
+    }
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    // SAFETY: the target has NEON enabled at compile time
+    #[allow(unsafe_code)]
+    let done = unsafe { neon::rd_to_date_chunks_aos(ns, dates) };
+    #[cfg(not(all(target_arch = "aarch64", target_feature = "neon")))]
+    let done = 0;
+    done
+}
@@ -0,0 +1,184 @@
+//! NEON implementations for `aarch64`
+//!
+//! Same approach as the AVX2 implementation, with 4 lanes per vector so that
+//! 8 values are processed in two halves. The 32-bit high multiplications use
+//! the widening `umull` and `umull2` and take the odd halves of the products,
+//! and the division of seconds by the length of the day is done in double
+//! precision floating point, which is exact for the supported range.
+
+use core::arch::aarch64::*;
+
+use crate::{DAY_OFFSET, RD_MAX, RD_MIN, RD_SECONDS_MAX, RD_SECONDS_MIN, SECS_OFFSET, YEAR_OFFSET};
+
+/// High 32 bits of the products of unsigned 32-bit lanes and a constant
+#[inline]
+#[target_feature(enable = "neon")]
+fn mulhi_u32(a: uint32x4_t, m: u32) -> uint32x4_t {
+    let m = vdup_n_u32(m);
+    let lo = vreinterpretq_u32_u64(vmull_u32(vget_low_u32(a), m));
+    let hi = vreinterpretq_u32_u64(vmull_u32(vget_high_u32(a), m));
+    vuzp2q_u32(lo, hi)
+}
+
+/// Convert days shifted by [DAY_OFFSET] to year, month and day in all lanes
+///
+/// Same as [rd_to_date](crate::rd_to_date), with the divisions computed as in
+/// the AVX2 implementation.
+#[inline]
+#[target_feature(enable = "neon")]
+fn date_lanes(n: uint32x4_t) -> (int32x4_t, uint32x4_t, uint32x4_t) {
+    // century
+    let n = vaddq_u32(vshlq_n_u32::<2>(n), vdupq_n_u32(3));
+    let c = vshrq_n_u32::<15>(mulhi_u32(n, 963315389));
+    let r = vmlsq_n_u32(n, c, 146097);
+    // year
+    let n = vorrq_u32(r, vdupq_n_u32(3));
+    let z = mulhi_u32(n, 2939745);
+    let n = vshrq_n_u32::<2>(vmlsq_n_u32(n, z, 1461));
+    let j = vcgtq_u32(n, vdupq_n_u32(305));
+    let y = vsubq_u32(vmlaq_n_u32(z, c, 100), j);
+    // month and day
+    let n = vmlaq_n_u32(vdupq_n_u32(197913), n, 2141);
+    let m = vshrq_n_u32::<16>(n);
+    let d = vshrq_n_u32::<26>(vmulq_n_u32(vandq_u32(n, vdupq_n_u32(0xffff)), 31345));
+    // map
+    let y = vsubq_s32(vreinterpretq_s32_u32(y), vdupq_n_s32(YEAR_OFFSET));
+    let m = vsubq_u32(m, vandq_u32(j, vdupq_n_u32(12)));
+    let d = vaddq_u32(d, vdupq_n_u32(1));
+    (y, m, d)
+}
+
+/// Convert seconds shifted by [SECS_OFFSET] in 64-bit lanes to shifted days
+/// and seconds of day in 32-bit lanes
+#[inline]
+#[target_feature(enable = "neon")]
+fn days_and_secs_of_day(s: uint64x2_t) -> (uint32x2_t, uint32x2_t) {
+    let d = vcvtq_u64_f64(vdivq_f64(vcvtq_f64_u64(s), vdupq_n_f64(86400.0)));
+    let d = vmovn_u64(d);
+    let secs = vmls_n_u32(vmovn_u64(s), d, 86400);
+    (d, secs)
+}
+
+/// Narrow two vectors of values below 256 to bytes
+#[inline]
+#[target_feature(enable = "neon")]
+fn narrow_u8(lo: uint32x4_t, hi: uint32x4_t) -> [u8; 8] {
+    let w = vmovn_u16(vcombine_u16(vmovn_u32(lo), vmovn_u32(hi)));
+    vget_lane_u64::<0>(vreinterpret_u64_u8(w)).to_le_bytes()
+}
+
+/// Store two vectors of 32-bit lanes to an array
+#[inline]
+#[target_feature(enable = "neon")]
+fn store_i32(lo: int32x4_t, hi: int32x4_t) -> [i32; 8] {
+    let mut out = [0; 8];
+    // SAFETY: stores of 16 bytes to the two halves of an array of 32 bytes
+    #[allow(unsafe_code)]
+    unsafe {
+        vst1q_s32(out.as_mut_ptr(), lo);
+        vst1q_s32(out.as_mut_ptr().add(4), hi);
+    }
+    out
+}
+
+/// Convert 8 Rata Die values to Gregorian dates
+#[inline]
+#[target_feature(enable = "neon")]
+pub(super) fn rd_to_date_x8(ns: &[i32; 8]) -> ([i32; 8], [u8; 8], [u8; 8]) {
+    debug_assert!(ns.iter().all(|&n| n >= RD_MIN && n <= RD_MAX), "given rata die is out of range");
+    // SAFETY: loads of 16 bytes from the two halves of an array of 32 bytes
+    #[allow(unsafe_code)]
+    let (lo, hi) = unsafe { (vld1q_s32(ns.as_ptr()), vld1q_s32(ns.as_ptr().add(4))) };
+    let offset = vdupq_n_s32(DAY_OFFSET);
+    let (ylo, mlo, dlo) = date_lanes(vreinterpretq_u32_s32(vaddq_s32(lo, offset)));
+    let (yhi, mhi, dhi) = date_lanes(vreinterpretq_u32_s32(vaddq_s32(hi, offset)));
+    (store_i32(ylo, yhi), narrow_u8(mlo, mhi), narrow_u8(dlo, dhi))
+}
+
+/// Convert 4 total seconds values to shifted days and seconds of day
+#[inline]
+#[target_feature(enable = "neon")]
+fn days_and_secs_of_day_x4(secs: &[i64]) -> (uint32x4_t, uint32x4_t) {
+    debug_assert!(secs.len() == 4);
+    // SAFETY: loads of 16 bytes from the two halves of a slice of 32 bytes
+    #[allow(unsafe_code)]
+    let (lo, hi) = unsafe { (vld1q_s64(secs.as_ptr()), vld1q_s64(secs.as_ptr().add(2))) };
+    let offset = vdupq_n_s64(SECS_OFFSET);
+    let (dlo, slo) = days_and_secs_of_day(vreinterpretq_u64_s64(vaddq_s64(lo, offset)));
+    let (dhi, shi) = days_and_secs_of_day(vreinterpretq_u64_s64(vaddq_s64(hi, offset)));
+    (vcombine_u32(dlo, dhi), vcombine_u32(slo, shi))
+}
+
+/// Split seconds of day to hours, minutes and seconds in all lanes
+#[inline]
+#[target_feature(enable = "neon")]
+fn hms_lanes(s: uint32x4_t) -> (uint32x4_t, uint32x4_t, uint32x4_t) {
+    // see secs_to_dhms for the identities used
+    let mins = mulhi_u32(s, 71582789);
+    let ss = vmlsq_n_u32(s, mins, 60);
+    let hh = mulhi_u32(mins, 71582789);
+    let mm = vmlsq_n_u32(mins, hh, 60);
+    (hh, mm, ss)
+}
+
+/// Convert 8 total seconds values to year, month, day, hours, minutes and
+/// seconds
+#[inline]
+#[target_feature(enable = "neon")]
+#[allow(clippy::type_complexity)]
+pub(super) fn secs_to_datetime_x8(secs: &[i64; 8]) -> ([i32; 8], [u8; 8], [u8; 8], [u8; 8], [u8; 8], [u8; 8]) {
+    debug_assert!(
+        secs.iter().all(|&s| s >= RD_SECONDS_MIN && s <= RD_SECONDS_MAX),
+        "given seconds value is out of range"
+    );
+    let (nlo, slo) = days_and_secs_of_day_x4(&secs[..4]);
+    let (nhi, shi) = days_and_secs_of_day_x4(&secs[4..]);
+    let (ylo, mlo, dlo) = date_lanes(nlo);
+    let (yhi, mhi, dhi) = date_lanes(nhi);
+    let (hhlo, mmlo, sslo) = hms_lanes(slo);
+    let (hhhi, mmhi, sshi) = hms_lanes(shi);
+    (
+        store_i32(ylo, yhi),
+        narrow_u8(mlo, mhi),
+        narrow_u8(dlo, dhi),
+        narrow_u8(hhlo, hhhi),
+        narrow_u8(mmlo, mmhi),
+        narrow_u8(sslo, sshi),
+    )
+}
+
+/// Convert the leading full chunks of a slice of Rata Die in lanes of 8
+#[inline]
+#[target_feature(enable = "neon")]
+pub(super) fn rd_to_date_chunks(ns: &[i32], years: &mut [i32], months: &mut [u8], days: &mut [u8]) -> usize {
+    let mut done = 0;
+    let chunks = ns
+        .chunks_exact(8)
+        .zip(years.chunks_exact_mut(8))
+        .zip(months.chunks_exact_mut(8))
+        .zip(days.chunks_exact_mut(8));
+    for (((n, y), m), d) in chunks {
+        let (ly, lm, ld) = rd_to_date_x8(n.try_into().unwrap());
+        y.copy_from_slice(&ly);
+        m.copy_from_slice(&lm);
+        d.copy_from_slice(&ld);
+        done += 8;
+    }
+    done
+}
+
+/// Convert the leading full chunks of a slice of Rata Die to date tuples in
+/// lanes of 8
+#[inline]
+#[target_feature(enable = "neon")]
+pub(super) fn rd_to_date_chunks_aos(ns: &[i32], dates: &mut [(i32, u8, u8)]) -> usize {
+    let mut done = 0;
+    for (n, out) in ns.chunks_exact(8).zip(dates.chunks_exact_mut(8)) {
+        let (ly, lm, ld) = rd_to_date_x8(n.try_into().unwrap());
+        for (i, date) in out.iter_mut().enumerate() {
+            *date = (ly[i], lm[i], ld[i]);
+        }
+        done += 8;
+    }
+    done
+}
@@ -0,0 +1,206 @@
+//! Lookup table based conversions
+//!
+//! The arithmetic algorithms in the crate root are usually the fastest option,
+//! but in some workloads a few kilobytes of cache resident tables can beat
+//! them. The functions in this module compute the same results using lookup
+//! tables over the 400 year Gregorian cycle, which are built at compile time
+//! and take roughly 4 KB in total. Both variants are always available, so
+//! they can be benchmarked against each other for the workload in question.
+
+use crate::{consts, RD_MAX, RD_MIN, YEAR_MAX, YEAR_MIN};
+
+/// Adjustment from Unix epoch to internal epoch, January 1st, -1468000
+const DAY_OFFSET: i32 = crate::ERA_OFFSET * crate::DAYS_IN_ERA + 719528;
+
+/// Adjustment from years to internal years
+const YEAR_OFFSET: i32 = crate::ERA_OFFSET * crate::YEARS_IN_ERA;
+
+/// Days in an era, as unsigned
+const DAYS_IN_ERA: u32 = crate::DAYS_IN_ERA as u32;
+
+/// Years in an era, as unsigned
+const YEARS_IN_ERA: u32 = crate::YEARS_IN_ERA as u32;
+
+/// Number of days covered by each entry in [COARSE_YEAR]
+const COARSE_SHIFT: u32 = 8;
+
+/// Determine if the given year within an era is a leap year
+const fn era_leap(y: u32) -> bool {
+    crate::is_leap_year(y as i32)
+}
+
+/// Leap year flags for each year of an era, one bit per year
+const LEAP_BITS: [u8; 50] = {
+    let mut t = [0; 50];
+    let mut y = 0;
+    while y < YEARS_IN_ERA {
+        if era_leap(y) {
+            t[y as usize / 8] |= 1 << (y % 8);
+        }
+        y += 1;
+    }
+    t
+};
+
+/// Day of era of January 1st of each year of an era
+const YEAR_START: [u32; 401] = {
+    let mut t = [0; 401];
+    let mut y = 0;
+    while y < YEARS_IN_ERA {
+        t[y as usize + 1] = t[y as usize] + if era_leap(y) { 366 } else { 365 };
+        y += 1;
+    }
+    t
+};
+
+/// Year of era containing the first day of each block of days
+const COARSE_YEAR: [u16; (DAYS_IN_ERA >> COARSE_SHIFT) as usize + 1] = {
+    let mut t = [0; (DAYS_IN_ERA >> COARSE_SHIFT) as usize + 1];
+    let mut i = 0;
+    let mut y = 0;
+    while i < t.len() {
+        while YEAR_START[y + 1] <= (i as u32) << COARSE_SHIFT {
+            y += 1;
+        }
+        t[i] = y as u16;
+        i += 1;
+    }
+    t
+};
+
+/// Month and day for each day of year, as `month << 8 | day`, for common and
+/// leap years
+const MONTH_DAY: [[u16; 366]; 2] = {
+    let mut t = [[0; 366]; 2];
+    let mut l = 0;
+    while l < 2 {
+        let mut i = 0;
+        let mut m = 1;
+        while m <= 12 {
+            let len = if m == 2 { 28 + l as u16 } else { 30 | (m ^ (m >> 3)) };
+            let mut d = 1;
+            while d <= len {
+                t[l][i] = m << 8 | d;
+                i += 1;
+                d += 1;
+            }
+            m += 1;
+        }
+        l += 1;
+    }
+    t
+};
+
+/// Days in each month for common and leap years
+const MONTH_DAYS: [[u8; 12]; 2] = [
+    [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31],
+    [31, 29, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31],
+];
+
+/// Convert Rata Die to Gregorian date
+///
+/// Given a day counting from Unix epoch (January 1st, 1970) returns a `(year,
+/// month, day)` tuple. Same as [rd_to_date](crate::rd_to_date).
+///
+/// # Panics
+///
+/// Argument must be between [RD_MIN] and [RD_MAX] inclusive. Bounds are checked
+/// using `debug_assert` only, so that the checks are not present in release
+/// builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::tables::rd_to_date;
+///
+/// assert_eq!(rd_to_date(-719528), (0, 1, 1));
+/// assert_eq!(rd_to_date(0), (1970, 1, 1));
+/// assert_eq!(rd_to_date(19489), (2023, 5, 12));
+/// assert_eq!(rd_to_date(2932896), (9999, 12, 31));
+/// ```
+///
+/// # Algorithm
+///
+/// Splits the day into era and day of era. A coarse table indexed by blocks of
+/// 256 days gives the year of era, which is corrected by at most one using a
+/// table of year starts. A final table gives month and day for the day of
+/// year.
+#[inline]
+pub const fn rd_to_date(n: i32) -> (i32, u8, u8) {
+    debug_assert!(n >= RD_MIN && n <= RD_MAX, "given rata die is out of range");
+    let n = n.wrapping_add(DAY_OFFSET) as u32;
+    // era
+    let e = n / DAYS_IN_ERA;
+    let n = n % DAYS_IN_ERA;
+    // year
+    let y = COARSE_YEAR[(n >> COARSE_SHIFT) as usize] as usize;
+    let y = y + (YEAR_START[y + 1] <= n) as usize;
+    let n = n - YEAR_START[y];
+    // month and day
+    let l = (LEAP_BITS[y / 8] >> (y % 8)) & 1;
+    let md = MONTH_DAY[l as usize][n as usize];
+    // map
+    let y = (e * YEARS_IN_ERA + y as u32) as i32 - YEAR_OFFSET;
+    (y, (md >> 8) as u8, md as u8)
+}
+
+/// Determine if the given year is a leap year
+///
+/// Same as [is_leap_year](crate::is_leap_year).
+///
+/// # Panics
+///
+/// Year must be between [YEAR_MIN] and [YEAR_MAX] inclusive. Bounds are checked
+/// using `debug_assert` only, so that the checks are not present in release
+/// builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::tables::is_leap_year;
+///
+/// assert_eq!(is_leap_year(2023), false);
+/// assert_eq!(is_leap_year(2024), true);
+/// assert_eq!(is_leap_year(2100), false);
+/// assert_eq!(is_leap_year(2400), true);
+/// ```
+///
+/// # Algorithm
+///
+/// Bit lookup from a table of the 400 years of an era.
+#[inline]
+pub const fn is_leap_year(y: i32) -> bool {
+    debug_assert!(y >= YEAR_MIN && y <= YEAR_MAX, "given year is out of range");
+    let y = (y.wrapping_add(YEAR_OFFSET) as u32 % YEARS_IN_ERA) as usize;
+    (LEAP_BITS[y / 8] >> (y % 8)) & 1 != 0
+}
+
+/// Determine the number of days in the given month in the given year
+///
+/// Same as [days_in_month](crate::days_in_month).
+///
+/// # Panics
+///
+/// Year must be between [YEAR_MIN] and [YEAR_MAX]. Month must be between `1`
+/// and `12`. Bounds are checked using `debug_assert` only, so that the checks
+/// are not present in release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::tables::days_in_month;
+///
+/// assert_eq!(days_in_month(2023, 1), 31);
+/// assert_eq!(days_in_month(2023, 2), 28);
+/// assert_eq!(days_in_month(2024, 2), 29);
+/// assert_eq!(days_in_month(2024, 4), 30);
+/// ```
+///
+/// # Algorithm
+///
+/// Table lookup by leap year flag and month.
+#[inline]
+pub const fn days_in_month(y: i32, m: u8) -> u8 {
+    debug_assert!(m >= consts::MONTH_MIN && m <= consts::MONTH_MAX, "given month is out of range");
+    MONTH_DAYS[is_leap_year(y) as usize][(m.wrapping_sub(1) % 12) as usize]
+}
@@ -0,0 +1,213 @@
+//! Tenors for financial schedules
+//!
+//! A tenor is a length of time written as a number and a unit, such as `3M`
+//! for three months or `10Y` for ten years. Tenors are used to describe the
+//! maturities of instruments on a rate curve and the frequencies of payment
+//! schedules. Days and weeks are a fixed number of days, while months and
+//! years depend on the date they are applied to.
+
+use crate::iso::parse_digits;
+use crate::{consts, date_to_rd, days_in_month, rd_to_date, RD_MAX, RD_MIN, YEAR_MAX, YEAR_MIN};
+
+/// Unit of a tenor
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TenorUnit {
+    /// Days, written as `D`
+    Days,
+    /// Weeks, written as `W`
+    Weeks,
+    /// Months, written as `M`
+    Months,
+    /// Years, written as `Y`
+    Years,
+}
+
+/// Parse tenor
+///
+/// Given a tenor string as bytes, such as `3M`, returns a `(count, unit)`
+/// tuple. The unit is one of `D`, `W`, `M` and `Y`, in upper or lower case. A
+/// leading `-` or `+` is accepted for counting backwards.
+///
+/// # Errors
+///
+/// Returns `None` if the string is not a valid tenor or if the count does not
+/// fit in `i32`.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::tenor::{parse_tenor, TenorUnit};
+///
+/// assert_eq!(parse_tenor(b"1D"), Some((1, TenorUnit::Days)));
+/// assert_eq!(parse_tenor(b"2W"), Some((2, TenorUnit::Weeks)));
+/// assert_eq!(parse_tenor(b"3m"), Some((3, TenorUnit::Months)));
+/// assert_eq!(parse_tenor(b"-10Y"), Some((-10, TenorUnit::Years)));
+/// assert_eq!(parse_tenor(b"M"), None);
+/// assert_eq!(parse_tenor(b"1Q"), None);
+/// ```
+///
+/// # Algorithm
+///
+/// Digits followed by a single unit letter.
+pub const fn parse_tenor(s: &[u8]) -> Option<(i32, TenorUnit)> {
+    let mut i = 0;
+    let neg = i < s.len() && s[i] == b'-';
+    if i < s.len() && (s[i] == b'-' || s[i] == b'+') {
+        i += 1;
+    }
+    let (v, i) = match parse_digits(s, i) {
+        Some(r) => r,
+        None => return None,
+    };
+    if i + 1 != s.len() || v > i32::MAX as u64 {
+        return None;
+    }
+    let unit = match s[i] {
+        b'D' | b'd' => TenorUnit::Days,
+        b'W' | b'w' => TenorUnit::Weeks,
+        b'M' | b'm' => TenorUnit::Months,
+        b'Y' | b'y' => TenorUnit::Years,
+        _ => return None,
+    };
+    let v = v as i32;
+    Some((if neg { -v } else { v }, unit))
+}
+
+/// Apply tenor to a day
+///
+/// Given a day counting from Unix epoch (January 1st, 1970), a `(count,
+/// unit)` tuple and an end of month rule returns the day the tenor later, or
+/// earlier for a negative count. When adding months or years, the day of
+/// month is clamped to the length of the resulting month, so that January
+/// 31st plus one month is the end of February. If the end of month rule is
+/// set and the given day is the last day of its month, the result is always
+/// the last day of the resulting month.
+///
+/// # Panics
+///
+/// Argument and the result must be between [RD_MIN] and [RD_MAX] inclusive.
+/// Bounds are checked using `debug_assert` only, so that the checks are not
+/// present in release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{date_to_rd, tenor::{roll_by_tenor, TenorUnit}};
+///
+/// let rd = date_to_rd((2023, 1, 31));
+/// assert_eq!(roll_by_tenor(rd, (2, TenorUnit::Weeks), false), date_to_rd((2023, 2, 14)));
+/// assert_eq!(roll_by_tenor(rd, (1, TenorUnit::Months), false), date_to_rd((2023, 2, 28)));
+/// let rd = date_to_rd((2023, 4, 30));
+/// assert_eq!(roll_by_tenor(rd, (1, TenorUnit::Months), false), date_to_rd((2023, 5, 30)));
+/// assert_eq!(roll_by_tenor(rd, (1, TenorUnit::Months), true), date_to_rd((2023, 5, 31)));
+/// let rd = date_to_rd((2024, 2, 29));
+/// assert_eq!(roll_by_tenor(rd, (-1, TenorUnit::Years), false), date_to_rd((2023, 2, 28)));
+/// ```
+///
+/// # Algorithm
+///
+/// Days and weeks are added to the Rata Die directly. Months and years are
+/// added to the year and month of the Gregorian date using Euclidean
+/// division, followed by clamping of the day. Computation is done in `i64`,
+/// so that any count fails the range check instead of overflowing.
+#[inline]
+pub const fn roll_by_tenor(rd: i32, (n, unit): (i32, TenorUnit), eom: bool) -> i32 {
+    debug_assert!(rd >= RD_MIN && rd <= RD_MAX, "given rata die is out of range");
+    let months = match unit {
+        TenorUnit::Days | TenorUnit::Weeks => {
+            let days = if matches!(unit, TenorUnit::Weeks) { n as i64 * 7 } else { n as i64 };
+            let rd = rd as i64 + days;
+            debug_assert!(rd >= RD_MIN as i64 && rd <= RD_MAX as i64, "resulting rata die is out of range");
+            return rd as i32;
+        }
+        TenorUnit::Months => n as i64,
+        TenorUnit::Years => n as i64 * 12,
+    };
+    let date = rd_to_date(rd);
+    date_to_rd(roll_months(date, months, eom && is_end_of_month(date)))
+}
+
+/// Add months to a date, clamping the day or moving it to the end of month
+///
+/// Months are computed in `i64`, so that any count given as `i32` years does
+/// not overflow before the range check of the resulting year.
+#[inline]
+const fn roll_months((y, m, d): (i32, u8, u8), n: i64, eom: bool) -> (i32, u8, u8) {
+    let total = y as i64 * 12 + (m as i64 - 1) + n;
+    let y = total.div_euclid(12);
+    debug_assert!(y >= YEAR_MIN as i64 && y <= YEAR_MAX as i64, "resulting year is out of range");
+    let y = y as i32;
+    let m = (total.rem_euclid(12) + 1) as u8;
+    let last = days_in_month(y, m);
+    let d = if d > last || eom { last } else { d };
+    (y, m, d)
+}
+
+/// Determine if the given date is the last day of its month
+///
+/// # Panics
+///
+/// Year must be between [YEAR_MIN] and [YEAR_MAX]. Month must be between `1`
+/// and `12`. Day must be between `1` and the number of days in the month in
+/// question. Bounds are checked using `debug_assert` only, so that the checks
+/// are not present in release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::tenor::is_end_of_month;
+///
+/// assert_eq!(is_end_of_month((2023, 1, 31)), true);
+/// assert_eq!(is_end_of_month((2023, 2, 28)), true);
+/// assert_eq!(is_end_of_month((2024, 2, 28)), false);
+/// assert_eq!(is_end_of_month((2023, 4, 30)), true);
+/// ```
+///
+/// # Algorithm
+///
+/// Comparison with [days_in_month].
+#[inline]
+pub const fn is_end_of_month((y, m, d): (i32, u8, u8)) -> bool {
+    debug_assert!(y >= YEAR_MIN && y <= YEAR_MAX, "given year is out of range");
+    debug_assert!(m >= consts::MONTH_MIN && m <= consts::MONTH_MAX, "given month is out of range");
+    debug_assert!(d >= consts::DAY_MIN && d <= days_in_month(y, m), "given day is out of range");
+    d == days_in_month(y, m)
+}
+
+/// Add months to a date preserving the end of month
+///
+/// Given a `(year, month, day)` tuple and a number of months returns the date
+/// the months later, or earlier for a negative count. If the given date is the
+/// last day of its month, the result is the last day of the resulting month,
+/// so that January 31st, February 28th and March 31st follow each other.
+/// Otherwise the day of month is clamped to the length of the resulting
+/// month.
+///
+/// # Panics
+///
+/// Year must be between [YEAR_MIN] and [YEAR_MAX]. Month must be between `1`
+/// and `12`. Day must be between `1` and the number of days in the month in
+/// question. Resulting year must be between [YEAR_MIN] and [YEAR_MAX]. Bounds
+/// are checked using `debug_assert` only, so that the checks are not present
+/// in release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::tenor::roll_months_eom;
+///
+/// assert_eq!(roll_months_eom((2023, 1, 31), 1), (2023, 2, 28));
+/// assert_eq!(roll_months_eom((2023, 2, 28), 1), (2023, 3, 31));
+/// assert_eq!(roll_months_eom((2024, 2, 29), -12), (2023, 2, 28));
+/// assert_eq!(roll_months_eom((2023, 1, 30), 1), (2023, 2, 28));
+/// assert_eq!(roll_months_eom((2023, 1, 30), 2), (2023, 3, 30));
+/// ```
+///
+/// # Algorithm
+///
+/// Months are added to the year and month using Euclidean division, followed
+/// by clamping of the day.
+#[inline]
+pub const fn roll_months_eom((y, m, d): (i32, u8, u8), n: i32) -> (i32, u8, u8) {
+    roll_months((y, m, d), n as i64, is_end_of_month((y, m, d)))
+}
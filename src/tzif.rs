@@ -0,0 +1,816 @@
+//! TZif time zone information files
+//!
+//! The IANA time zone database is distributed to systems as compiled TZif
+//! files, usually found under `/usr/share/zoneinfo`. [TzData] parses such a
+//! file directly from a byte slice without allocations, so that zone support
+//! is available even without the standard library, and looks up the UTC
+//! offset in effect at any instant.
+//!
+//! Versions 1 to 4 of the format described in [RFC
+//! 8536](https://www.rfc-editor.org/rfc/rfc8536) are accepted. For version 2
+//! and later files the 64-bit data block is used, and times after the last
+//! transition are evaluated from the TZ string footer with [PosixTz]. Leap
+//! second records are validated for size but otherwise ignored, so files from
+//! the `right/` hierarchy, which count leap seconds, do not give correct
+//! results.
+//!
+//! With the `std` feature, [load_local] and [load_zone] read the zone
+//! configured for the process or a named zone from the system time zone
+//! database. Only the file loading needs the standard library.
+//!
+//! Offsets are given in seconds east of UTC.
+
+use crate::posixtz::PosixTz;
+use crate::{datetime_to_secs, secs_to_datetime, LocalResult, RD_SECONDS_MAX, RD_SECONDS_MIN, SECS_IN_DAY};
+#[cfg(feature = "std")]
+use std::{env, fs, io, io::Read, path::Path, vec::Vec};
+
+/// Length of a TZif header
+const HEADER_LEN: usize = 44;
+
+/// Length of a local time type record
+const TTINFO_LEN: usize = 6;
+
+/// Parsed TZif file borrowing the underlying bytes
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::datetime_to_secs;
+/// use datealgo::tzif::TzData;
+///
+/// let header = b"TZif2\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x02\0\0\0\x09";
+/// let block = b"\0\0\x0e\x10\0\0\0\0\x1c\x20\x01\x04CET\0CEST\0";
+/// let footer = b"\nCET-1CEST,M3.5.0,M10.5.0/3\n";
+/// let data = [&header[..], block, header, block, footer].concat();
+///
+/// let tz = TzData::parse(&data).unwrap();
+/// let (utoff, dst, idx) = tz.offset_at(datetime_to_secs((2023, 7, 15, 12, 0, 0)));
+/// assert_eq!((utoff, dst, tz.abbreviation(idx)), (7200, true, Some("CEST")));
+/// let (utoff, dst, idx) = tz.offset_at(datetime_to_secs((2023, 1, 15, 12, 0, 0)));
+/// assert_eq!((utoff, dst, tz.abbreviation(idx)), (3600, false, Some("CET")));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TzData<'a> {
+    version: u8,
+    time_size: usize,
+    times: &'a [u8],
+    types: &'a [u8],
+    ttinfos: &'a [u8],
+    chars: &'a [u8],
+    footer: Option<PosixTz>,
+}
+
+/// Read a big-endian 32-bit unsigned integer from the given position
+#[inline]
+const fn read_u32(s: &[u8], i: usize) -> u32 {
+    u32::from_be_bytes([s[i], s[i + 1], s[i + 2], s[i + 3]])
+}
+
+/// Read a big-endian 64-bit signed integer from the given position
+#[inline]
+const fn read_i64(s: &[u8], i: usize) -> i64 {
+    i64::from_be_bytes([s[i], s[i + 1], s[i + 2], s[i + 3], s[i + 4], s[i + 5], s[i + 6], s[i + 7]])
+}
+
+/// Parse a TZif header from the given position
+///
+/// Returns the version number and the `(isutcnt, isstdcnt, leapcnt, timecnt,
+/// typecnt, charcnt)` counts.
+#[inline]
+const fn parse_header(s: &[u8], i: usize) -> Option<(u8, [usize; 6])> {
+    if s.len() < i + HEADER_LEN {
+        return None;
+    }
+    if s[i] != b'T' || s[i + 1] != b'Z' || s[i + 2] != b'i' || s[i + 3] != b'f' {
+        return None;
+    }
+    let version = match s[i + 4] {
+        0 => 1,
+        b'2'..=b'4' => s[i + 4] - b'0',
+        _ => return None,
+    };
+    let mut counts = [0; 6];
+    let mut n = 0;
+    while n < 6 {
+        // Limit counts so that computing block lengths cannot overflow
+        let c = read_u32(s, i + 20 + n * 4);
+        if c > u16::MAX as u32 * 16 {
+            return None;
+        }
+        counts[n] = c as usize;
+        n += 1;
+    }
+    Some((version, counts))
+}
+
+/// Compute the length of a data block with the given counts and time size
+#[inline]
+const fn block_len(counts: [usize; 6], time_size: usize) -> usize {
+    let [isutcnt, isstdcnt, leapcnt, timecnt, typecnt, charcnt] = counts;
+    timecnt * time_size + timecnt + typecnt * TTINFO_LEN + charcnt + leapcnt * (time_size + 4) + isstdcnt + isutcnt
+}
+
+impl<'a> TzData<'a> {
+    /// Parse a TZif file
+    ///
+    /// Given the contents of a TZif file returns the parsed time zone data,
+    /// borrowing the given bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the data is truncated or otherwise not a valid TZif
+    /// file, such as when transition times are not in ascending order or a
+    /// non-empty footer is not a valid TZ string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use datealgo::tzif::TzData;
+    ///
+    /// let header = b"TZif2\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x01\0\0\0\x04";
+    /// let block = b"\0\0\0\0\0\0UTC\0";
+    /// let data = [&header[..], block, header, block, b"\nUTC0\n"].concat();
+    ///
+    /// let tz = TzData::parse(&data).unwrap();
+    /// assert_eq!(tz.version(), 2);
+    /// assert_eq!(tz.transition_count(), 0);
+    /// assert_eq!(tz.local_time_type(0), Some((0, false, 0)));
+    /// assert_eq!(tz.abbreviation(0), Some("UTC"));
+    /// assert_eq!(TzData::parse(&data[..100]), None);
+    /// ```
+    ///
+    /// # Algorithm
+    ///
+    /// Validation of the header counts, transitions and local time types as
+    /// described in RFC 8536, without copying any data.
+    pub const fn parse(data: &'a [u8]) -> Option<TzData<'a>> {
+        let (version, counts) = match parse_header(data, 0) {
+            Some(r) => r,
+            None => return None,
+        };
+        let (version, counts, time_size, start) = if version == 1 {
+            (version, counts, 4, HEADER_LEN)
+        } else {
+            let i = HEADER_LEN + block_len(counts, 4);
+            match parse_header(data, i) {
+                Some((v, c)) if v >= 2 => (v, c, 8, i + HEADER_LEN),
+                _ => return None,
+            }
+        };
+        let [isutcnt, isstdcnt, _, timecnt, typecnt, charcnt] = counts;
+        if typecnt == 0 || typecnt > 256 || charcnt == 0 {
+            return None;
+        }
+        if (isutcnt != 0 && isutcnt != typecnt) || (isstdcnt != 0 && isstdcnt != typecnt) {
+            return None;
+        }
+        let end = start + block_len(counts, time_size);
+        if data.len() < end {
+            return None;
+        }
+        let (_, rest) = data.split_at(start);
+        let (times, rest) = rest.split_at(timecnt * time_size);
+        let (types, rest) = rest.split_at(timecnt);
+        let (ttinfos, rest) = rest.split_at(typecnt * TTINFO_LEN);
+        let (chars, _) = rest.split_at(charcnt);
+        if chars[charcnt - 1] != 0 {
+            return None;
+        }
+        let mut i = 0;
+        while i < typecnt {
+            let utoff = read_u32(ttinfos, i * TTINFO_LEN) as i32;
+            if utoff == i32::MIN || ttinfos[i * TTINFO_LEN + 4] > 1 || ttinfos[i * TTINFO_LEN + 5] as usize >= charcnt {
+                return None;
+            }
+            i += 1;
+        }
+        let mut i = 0;
+        while i < timecnt {
+            if types[i] as usize >= typecnt {
+                return None;
+            }
+            if i > 0 && read_time(times, time_size, i - 1) >= read_time(times, time_size, i) {
+                return None;
+            }
+            i += 1;
+        }
+        let footer = if version == 1 {
+            None
+        } else {
+            if data.len() <= end || data[end] != b'\n' {
+                return None;
+            }
+            let (_, rest) = data.split_at(end + 1);
+            let mut len = 0;
+            while len < rest.len() && rest[len] != b'\n' {
+                len += 1;
+            }
+            if len == rest.len() {
+                return None;
+            }
+            if len == 0 {
+                None
+            } else {
+                match PosixTz::parse(rest.split_at(len).0) {
+                    Some(tz) => Some(tz),
+                    None => return None,
+                }
+            }
+        };
+        Some(TzData {
+            version,
+            time_size,
+            times,
+            types,
+            ttinfos,
+            chars,
+            footer,
+        })
+    }
+
+    /// Version number of the file, from `1` to `4`
+    #[inline]
+    pub const fn version(&self) -> u8 {
+        self.version
+    }
+
+    /// Number of transitions in the file
+    #[inline]
+    pub const fn transition_count(&self) -> usize {
+        self.types.len()
+    }
+
+    /// Transition at the given index
+    ///
+    /// Returns a `(secs, type)` tuple of the instant of the transition, in
+    /// seconds counting from Unix epoch (January 1st, 1970), and the index of
+    /// the local time type in effect from that instant.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the index is out of range.
+    #[inline]
+    pub const fn transition(&self, i: usize) -> Option<(i64, u8)> {
+        if i >= self.types.len() {
+            return None;
+        }
+        Some((read_time(self.times, self.time_size, i), self.types[i]))
+    }
+
+    /// Number of local time types in the file
+    #[inline]
+    pub const fn local_time_type_count(&self) -> usize {
+        self.ttinfos.len() / TTINFO_LEN
+    }
+
+    /// Local time type at the given index
+    ///
+    /// Returns a `(utoff, dst, abbrev_index)` tuple of the offset in seconds
+    /// east of UTC, whether the type is daylight saving time and the index of
+    /// the abbreviation for [abbreviation](TzData::abbreviation).
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the index is out of range.
+    #[inline]
+    pub const fn local_time_type(&self, i: u8) -> Option<(i32, bool, u8)> {
+        let i = i as usize * TTINFO_LEN;
+        if i >= self.ttinfos.len() {
+            return None;
+        }
+        Some((read_u32(self.ttinfos, i) as i32, self.ttinfos[i + 4] != 0, self.ttinfos[i + 5]))
+    }
+
+    /// Time zone abbreviation at the given index
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the index is out of range or the abbreviation is not
+    /// valid UTF-8.
+    #[inline]
+    pub fn abbreviation(&self, idx: u8) -> Option<&'a str> {
+        let rest = self.chars.get(idx as usize..)?;
+        // The parser checks that the last abbreviation is terminated
+        let len = rest.iter().position(|&c| c == 0)?;
+        core::str::from_utf8(&rest[..len]).ok()
+    }
+
+    /// TZ string footer for times after the last transition, if any
+    #[inline]
+    pub const fn footer(&self) -> Option<&PosixTz> {
+        self.footer.as_ref()
+    }
+
+    /// Look up the UTC offset in effect at an instant
+    ///
+    /// Given seconds counting from Unix epoch (January 1st, 1970) returns a
+    /// `(utoff, dst, abbrev_index)` tuple of the offset in seconds east of
+    /// UTC, whether daylight saving time is in effect and the index of the
+    /// abbreviation for [abbreviation](TzData::abbreviation).
+    ///
+    /// Before the first transition the first local time type is used. After
+    /// the last transition the footer is evaluated, if present, and the
+    /// abbreviation index is that of a local time type with matching offset,
+    /// flag and abbreviation.
+    ///
+    /// # Panics
+    ///
+    /// Argument must be between [RD_SECONDS_MIN] and [RD_SECONDS_MAX]
+    /// inclusive. Bounds are checked using `debug_assert` only, so that the
+    /// checks are not present in release builds, similar to integer overflow
+    /// checks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use datealgo::tzif::TzData;
+    ///
+    /// let header = b"TZif2\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x01\0\0\0\x02\0\0\0\x08";
+    /// let v1 = b"\x38\x6d\x43\x80\x01\0\0\0\0\0\0\0\0\x1c\x20\0\x04LMT\0EET\0";
+    /// let v2 = b"\0\0\0\0\x38\x6d\x43\x80\x01\0\0\0\0\0\0\0\0\x1c\x20\0\x04LMT\0EET\0";
+    /// let data = [&header[..], v1, header, v2, b"\nEET-2\n"].concat();
+    ///
+    /// let tz = TzData::parse(&data).unwrap();
+    /// assert_eq!(tz.offset_at(0), (0, false, 0));
+    /// assert_eq!(tz.offset_at(946684800), (7200, false, 4));
+    /// assert_eq!(tz.offset_at(2000000000), (7200, false, 4));
+    /// ```
+    ///
+    /// # Algorithm
+    ///
+    /// Binary search of the transition times, falling back to
+    /// [PosixTz::offset_at] after the last transition.
+    #[inline]
+    pub const fn offset_at(&self, secs: i64) -> (i32, bool, u8) {
+        debug_assert!(
+            secs >= RD_SECONDS_MIN && secs <= RD_SECONDS_MAX,
+            "given seconds value is out of range"
+        );
+        if let Some(footer) = self.footer_at(secs) {
+            let (utoff, dst) = footer.offset_at(secs);
+            return (utoff, dst, self.find_abbrev(utoff, dst, footer.abbr_bytes(dst)));
+        }
+        let n = self.types.len();
+        if n == 0 || secs < read_time(self.times, self.time_size, 0) {
+            return self.type_or_default(0);
+        }
+        // Largest transition not after the given instant
+        let mut lo = 0;
+        let mut hi = n;
+        while hi - lo > 1 {
+            let mid = lo + (hi - lo) / 2;
+            if read_time(self.times, self.time_size, mid) <= secs {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+        self.type_or_default(self.types[lo])
+    }
+
+    /// Footer, if present and in effect at the given instant
+    #[inline]
+    const fn footer_at(&self, secs: i64) -> Option<&PosixTz> {
+        let n = self.types.len();
+        if n == 0 || secs >= read_time(self.times, self.time_size, n - 1) {
+            self.footer.as_ref()
+        } else {
+            None
+        }
+    }
+
+    /// Local time type at the given index, which the parser has validated
+    #[inline]
+    const fn type_or_default(&self, i: u8) -> (i32, bool, u8) {
+        match self.local_time_type(i) {
+            Some(r) => r,
+            None => (0, false, 0),
+        }
+    }
+
+    /// Find the abbreviation index of a local time type matching a footer
+    ///
+    /// Prefers a type with matching offset, flag and abbreviation, then one
+    /// with matching offset and flag, then the type of the last transition.
+    #[inline]
+    const fn find_abbrev(&self, utoff: i32, dst: bool, abbr: &[u8]) -> u8 {
+        let mut fallback = match self.types.len() {
+            0 => self.type_or_default(0).2,
+            n => self.type_or_default(self.types[n - 1]).2,
+        };
+        let mut found = false;
+        let mut i = 0;
+        while i < self.ttinfos.len() / TTINFO_LEN {
+            let (u, d, idx) = self.type_or_default(i as u8);
+            if u == utoff && d == dst {
+                if self.abbrev_eq(idx, abbr) {
+                    return idx;
+                }
+                if !found {
+                    fallback = idx;
+                    found = true;
+                }
+            }
+            i += 1;
+        }
+        fallback
+    }
+
+    /// Compare the abbreviation at the given index with the given bytes
+    #[inline]
+    const fn abbrev_eq(&self, idx: u8, abbr: &[u8]) -> bool {
+        let idx = idx as usize;
+        if idx + abbr.len() >= self.chars.len() || self.chars[idx + abbr.len()] != 0 {
+            return false;
+        }
+        let mut i = 0;
+        while i < abbr.len() {
+            if self.chars[idx + i] != abbr[i] {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+}
+
+/// Read the transition time at the given index
+#[inline]
+const fn read_time(times: &[u8], time_size: usize, i: usize) -> i64 {
+    if time_size == 4 {
+        read_u32(times, i * 4) as i32 as i64
+    } else {
+        read_i64(times, i * 8)
+    }
+}
+
+/// Convert seconds to local date and time in a time zone
+///
+/// Given seconds counting from Unix epoch (January 1st, 1970) and time zone
+/// data returns a `(year, month, day, hours, minutes, seconds)` tuple of the
+/// local time.
+///
+/// # Panics
+///
+/// Argument and the resulting local time must be between [RD_SECONDS_MIN]
+/// and [RD_SECONDS_MAX] inclusive. Bounds are checked using `debug_assert`
+/// only, so that the checks are not present in release builds, similar to
+/// integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::tzif::{secs_to_local_datetime, TzData};
+///
+/// # let header = b"TZif2\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x02\0\0\0\x09";
+/// # let block = b"\0\0\x0e\x10\0\0\0\0\x1c\x20\x01\x04CET\0CEST\0";
+/// # let data = [&header[..], block, header, block, b"\nCET-1CEST,M3.5.0,M10.5.0/3\n"].concat();
+/// let tz = TzData::parse(&data).unwrap();
+/// assert_eq!(secs_to_local_datetime(1684574678, &tz), (2023, 5, 20, 11, 24, 38));
+/// assert_eq!(secs_to_local_datetime(1671534000, &tz), (2022, 12, 20, 12, 0, 0));
+/// ```
+///
+/// # Algorithm
+///
+/// [TzData::offset_at] followed by [secs_to_datetime].
+#[inline]
+pub const fn secs_to_local_datetime(secs: i64, tz: &TzData) -> (i32, u8, u8, u8, u8, u8) {
+    let (utoff, _, _) = tz.offset_at(secs);
+    secs_to_datetime(secs + utoff as i64)
+}
+
+/// Convert local date and time in a time zone to seconds
+///
+/// Given a `(year, month, day, hours, minutes, seconds)` tuple of local time
+/// and time zone data returns the matching instants as a [LocalResult]. Local
+/// times skipped by a transition give [LocalResult::Gap] and local times
+/// repeated by a transition give [LocalResult::Ambiguous].
+///
+/// # Panics
+///
+/// Year must be between [YEAR_MIN](crate::YEAR_MIN) and
+/// [YEAR_MAX](crate::YEAR_MAX). Month must be between `1` and `12`. Day must
+/// be between `1` and the number of days in the month in question. Hours must
+/// be between `0` and `23`. Minutes must be between `0` and `59`. Seconds must
+/// be between `0` and `59`. Bounds are checked using `debug_assert` only, so
+/// that the checks are not present in release builds, similar to integer
+/// overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::tzif::{local_datetime_to_secs, TzData};
+/// use datealgo::LocalResult;
+///
+/// # let header = b"TZif2\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x02\0\0\0\x09";
+/// # let block = b"\0\0\x0e\x10\0\0\0\0\x1c\x20\x01\x04CET\0CEST\0";
+/// # let data = [&header[..], block, header, block, b"\nCET-1CEST,M3.5.0,M10.5.0/3\n"].concat();
+/// let tz = TzData::parse(&data).unwrap();
+/// assert_eq!(local_datetime_to_secs((2023, 5, 20, 11, 24, 38), &tz), LocalResult::Unique(1684574678));
+/// assert_eq!(local_datetime_to_secs((2023, 3, 26, 2, 30, 0), &tz), LocalResult::Gap(1679794200, 3600));
+/// assert_eq!(
+///     local_datetime_to_secs((2023, 10, 29, 2, 30, 0), &tz),
+///     LocalResult::Ambiguous(1698539400, 1698543000)
+/// );
+/// ```
+///
+/// # Algorithm
+///
+/// The offsets in effect a day before and a day after the local time, read
+/// as UTC, are the only candidates as long as the offset changes at most
+/// once within that period. Each candidate instant is kept if
+/// [TzData::offset_at] gives back the same offset.
+#[inline]
+pub const fn local_datetime_to_secs(dt: (i32, u8, u8, u8, u8, u8), tz: &TzData) -> LocalResult {
+    let local = datetime_to_secs(dt);
+    let before = tz.offset_at(clamp_secs(local - SECS_IN_DAY)).0;
+    let after = tz.offset_at(clamp_secs(local + SECS_IN_DAY)).0;
+    let early = local - before as i64;
+    let late = local - after as i64;
+    let early_ok = has_offset(tz, early, before);
+    let late_ok = before != after && has_offset(tz, late, after);
+    match (early_ok, late_ok) {
+        (true, true) if early < late => LocalResult::Ambiguous(early, late),
+        (true, true) => LocalResult::Ambiguous(late, early),
+        (true, false) => LocalResult::Unique(early),
+        (false, true) => LocalResult::Unique(late),
+        (false, false) => LocalResult::Gap(early, before.abs_diff(after)),
+    }
+}
+
+/// Determine if daylight saving time is in effect at an instant
+///
+/// Given seconds counting from Unix epoch (January 1st, 1970) and time zone
+/// data returns whether daylight saving time is in effect, as needed for
+/// `tm_isdst`.
+///
+/// # Panics
+///
+/// Argument must be between [RD_SECONDS_MIN] and [RD_SECONDS_MAX]
+/// inclusive. Bounds are checked using `debug_assert` only, so that the
+/// checks are not present in release builds, similar to integer overflow
+/// checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::tzif::{is_dst_at, TzData};
+///
+/// # let header = b"TZif2\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x02\0\0\0\x09";
+/// # let block = b"\0\0\x0e\x10\0\0\0\0\x1c\x20\x01\x04CET\0CEST\0";
+/// # let data = [&header[..], block, header, block, b"\nCET-1CEST,M3.5.0,M10.5.0/3\n"].concat();
+/// let tz = TzData::parse(&data).unwrap();
+/// assert_eq!(is_dst_at(1684574678, &tz), true);
+/// assert_eq!(is_dst_at(1671534000, &tz), false);
+/// ```
+///
+/// # Algorithm
+///
+/// Same as [TzData::offset_at].
+#[inline]
+pub const fn is_dst_at(secs: i64, tz: &TzData) -> bool {
+    tz.offset_at(secs).1
+}
+
+/// Time zone abbreviation in effect at an instant
+///
+/// Given seconds counting from Unix epoch (January 1st, 1970) and time zone
+/// data returns the abbreviation in effect, as needed for `%Z`. After the
+/// last transition the abbreviation is taken from the footer. An
+/// abbreviation that is not valid UTF-8 is returned as an empty string.
+///
+/// # Panics
+///
+/// Argument must be between [RD_SECONDS_MIN] and [RD_SECONDS_MAX]
+/// inclusive. Bounds are checked using `debug_assert` only, so that the
+/// checks are not present in release builds, similar to integer overflow
+/// checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::tzif::{abbreviation_at, TzData};
+///
+/// # let header = b"TZif2\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x02\0\0\0\x09";
+/// # let block = b"\0\0\x0e\x10\0\0\0\0\x1c\x20\x01\x04CET\0CEST\0";
+/// # let data = [&header[..], block, header, block, b"\nCET-1CEST,M3.5.0,M10.5.0/3\n"].concat();
+/// let tz = TzData::parse(&data).unwrap();
+/// assert_eq!(abbreviation_at(1684574678, &tz), "CEST");
+/// assert_eq!(abbreviation_at(1671534000, &tz), "CET");
+/// ```
+///
+/// # Algorithm
+///
+/// Same as [TzData::offset_at].
+#[inline]
+pub fn abbreviation_at<'b>(secs: i64, tz: &'b TzData) -> &'b str {
+    let (_, dst, idx) = tz.offset_at(secs);
+    match tz.footer_at(secs) {
+        Some(footer) if dst => footer.dst_abbr().unwrap_or(footer.std_abbr()),
+        Some(footer) => footer.std_abbr(),
+        None => tz.abbreviation(idx).unwrap_or(""),
+    }
+}
+
+/// Clamp seconds to the supported range
+#[inline]
+const fn clamp_secs(secs: i64) -> i64 {
+    if secs < RD_SECONDS_MIN {
+        RD_SECONDS_MIN
+    } else if secs > RD_SECONDS_MAX {
+        RD_SECONDS_MAX
+    } else {
+        secs
+    }
+}
+
+/// Determine if the given offset is in effect at an instant
+#[inline]
+const fn has_offset(tz: &TzData, secs: i64, utoff: i32) -> bool {
+    secs >= RD_SECONDS_MIN && secs <= RD_SECONDS_MAX && tz.offset_at(secs).0 == utoff
+}
+
+/// Default directory of the system time zone database
+#[cfg(feature = "std")]
+pub const ZONEINFO_DIR: &str = "/usr/share/zoneinfo";
+
+/// Default path of the local time zone file
+#[cfg(feature = "std")]
+pub const LOCALTIME_PATH: &str = "/etc/localtime";
+
+/// Maximum size of a time zone file that is read
+#[cfg(feature = "std")]
+const FILE_MAX_LEN: u64 = 1 << 20;
+
+/// Load a TZif file
+///
+/// Reads the file at the given path into the given buffer, replacing its
+/// contents, and returns the parsed time zone data borrowing the buffer.
+///
+/// # Errors
+///
+/// Returns the error of reading the file, or an error of kind
+/// [InvalidData](io::ErrorKind::InvalidData) if the file is not a valid TZif
+/// file or is larger than 1 MiB.
+///
+/// # Examples
+///
+/// ```no_run
+/// use datealgo::tzif::load_file;
+///
+/// let mut buf = Vec::new();
+/// let tz = load_file("/usr/share/zoneinfo/Europe/Helsinki", &mut buf).unwrap();
+/// assert_eq!(tz.offset_at(1684574678).0, 10800);
+/// ```
+#[cfg(feature = "std")]
+pub fn load_file<P: AsRef<Path>>(path: P, buf: &mut Vec<u8>) -> io::Result<TzData<'_>> {
+    buf.clear();
+    fs::File::open(path)?.take(FILE_MAX_LEN + 1).read_to_end(buf)?;
+    if buf.len() as u64 > FILE_MAX_LEN {
+        return Err(io::Error::new(io::ErrorKind::InvalidData, "time zone file too large"));
+    }
+    TzData::parse(buf).ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid TZif data"))
+}
+
+/// Load a named zone from the system time zone database
+///
+/// Given an IANA zone name such as `Europe/Helsinki`, reads the zone from the
+/// directory named by the `TZDIR` environment variable, or [ZONEINFO_DIR] if
+/// it is not set, as done by common C libraries. Absolute paths are read
+/// directly. The buffer is used as in [load_file].
+///
+/// # Errors
+///
+/// Returns an error of kind [InvalidInput](io::ErrorKind::InvalidInput) if
+/// the name is empty or contains a `..` component, and otherwise as in
+/// [load_file].
+///
+/// # Examples
+///
+/// ```no_run
+/// use datealgo::tzif::load_zone;
+///
+/// let mut buf = Vec::new();
+/// let tz = load_zone("Australia/Sydney", &mut buf).unwrap();
+/// assert_eq!(tz.offset_at(1684574678).0, 36000);
+/// ```
+#[cfg(feature = "std")]
+pub fn load_zone<'b>(name: &str, buf: &'b mut Vec<u8>) -> io::Result<TzData<'b>> {
+    if name.starts_with('/') {
+        return load_file(name, buf);
+    }
+    if name.is_empty() || name.split('/').any(|c| c == "..") {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, "invalid time zone name"));
+    }
+    match env::var_os("TZDIR") {
+        Some(dir) if !dir.is_empty() => load_file(Path::new(&dir).join(name), buf),
+        _ => load_file(Path::new(ZONEINFO_DIR).join(name), buf),
+    }
+}
+
+/// Load the time zone given by a `TZ` value
+///
+/// Resolves the value of the `TZ` environment variable the way `tzset` does.
+/// When unset, the zone is read from [LOCALTIME_PATH], falling back to UTC if
+/// the file does not exist. When empty, UTC is used. Otherwise a leading `:`
+/// is removed and the value is read with [load_zone], or if no such zone
+/// exists, parsed as a POSIX TZ string. The buffer is used as in
+/// [load_file], and holds a generated TZif file for UTC and POSIX TZ strings.
+///
+/// # Errors
+///
+/// Returns the error of [load_zone] if the value is neither a zone nor a
+/// valid POSIX TZ string.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::tzif::load_tz;
+///
+/// let mut buf = Vec::new();
+/// let tz = load_tz(Some(""), &mut buf).unwrap();
+/// assert_eq!(tz.offset_at(1684574678), (0, false, 0));
+/// assert_eq!(tz.abbreviation(0), Some("UTC"));
+/// let tz = load_tz(Some("<+0530>-5:30"), &mut buf).unwrap();
+/// assert_eq!(tz.offset_at(1684574678), (19800, false, 0));
+/// assert_eq!(tz.abbreviation(0), Some("+0530"));
+/// ```
+#[cfg(feature = "std")]
+pub fn load_tz<'b>(tz: Option<&str>, buf: &'b mut Vec<u8>) -> io::Result<TzData<'b>> {
+    let name = match tz {
+        None => {
+            return match load_file(LOCALTIME_PATH, buf) {
+                Err(e) if e.kind() == io::ErrorKind::NotFound => posix_to_tzif("UTC0", buf),
+                Err(e) => Err(e),
+                // Parse again, as the borrow cannot be returned conditionally
+                Ok(_) => TzData::parse(buf).ok_or_else(|| io::Error::from(io::ErrorKind::InvalidData)),
+            };
+        }
+        Some("") => return posix_to_tzif("UTC0", buf),
+        Some(s) => s.strip_prefix(':').unwrap_or(s),
+    };
+    match load_zone(name, buf) {
+        Err(e) if e.kind() == io::ErrorKind::NotFound && PosixTz::parse(name.as_bytes()).is_some() => posix_to_tzif(name, buf),
+        Err(e) => Err(e),
+        Ok(_) => TzData::parse(buf).ok_or_else(|| io::Error::from(io::ErrorKind::InvalidData)),
+    }
+}
+
+/// Load the local time zone of the process
+///
+/// Same as [load_tz] with the value of the `TZ` environment variable.
+///
+/// # Errors
+///
+/// Returns the error of [load_tz].
+///
+/// # Examples
+///
+/// ```no_run
+/// use datealgo::tzif::{load_local, secs_to_local_datetime};
+///
+/// let mut buf = Vec::new();
+/// let tz = load_local(&mut buf).unwrap();
+/// let (y, m, d, hh, mm, ss) = secs_to_local_datetime(1684574678, &tz);
+/// ```
+#[cfg(feature = "std")]
+pub fn load_local(buf: &mut Vec<u8>) -> io::Result<TzData<'_>> {
+    match env::var("TZ") {
+        Ok(tz) => load_tz(Some(&tz), buf),
+        Err(env::VarError::NotPresent) => load_tz(None, buf),
+        Err(env::VarError::NotUnicode(_)) => Err(io::Error::new(io::ErrorKind::InvalidInput, "invalid TZ value")),
+    }
+}
+
+/// Generate a TZif file without transitions for a POSIX TZ string
+#[cfg(feature = "std")]
+fn posix_to_tzif<'b>(s: &str, buf: &'b mut Vec<u8>) -> io::Result<TzData<'b>> {
+    let posix = PosixTz::parse(s.as_bytes()).ok_or_else(|| io::Error::from(io::ErrorKind::InvalidInput))?;
+    let mut block = Vec::new();
+    block.extend(posix.std_utoff().to_be_bytes());
+    block.extend([0, 0]);
+    let mut chars = posix.std_abbr().as_bytes().to_vec();
+    chars.push(0);
+    let mut typecnt = 1u32;
+    if let (Some(abbr), Some(utoff)) = (posix.dst_abbr(), posix.dst_utoff()) {
+        block.extend(utoff.to_be_bytes());
+        block.extend([1, chars.len() as u8]);
+        chars.extend(abbr.as_bytes());
+        chars.push(0);
+        typecnt = 2;
+    }
+    block.extend(&chars);
+    buf.clear();
+    for _ in 0..2 {
+        buf.extend(b"TZif2");
+        buf.extend([0; 31]);
+        buf.extend(typecnt.to_be_bytes());
+        buf.extend((chars.len() as u32).to_be_bytes());
+        buf.extend(&block);
+    }
+    buf.push(b'\n');
+    buf.extend(s.as_bytes());
+    buf.push(b'\n');
+    TzData::parse(buf).ok_or_else(|| io::Error::from(io::ErrorKind::InvalidData))
+}
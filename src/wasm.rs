@@ -0,0 +1,219 @@
+//! Conversions to and from JavaScript time values
+//!
+//! JavaScript represents instants as `f64` milliseconds from Unix epoch, as
+//! returned by `Date.now()` and `Date.prototype.getTime()`. Valid time values
+//! are whole milliseconds within [JS_MILLIS_MAX] of the epoch, which is
+//! roughly 273,790 years in both directions. All such values are exactly
+//! representable as `f64`, but nanoseconds below a millisecond are not, so
+//! conversions to time values truncate to whole milliseconds. Fractional
+//! milliseconds, such as those from `performance.now()` based computations,
+//! are accepted but only as precise as the `f64` in question.
+//!
+//! The conversions from raw time values work on any target. The conversions
+//! from and to [`js_sys::Date`] objects only work when running in a JavaScript
+//! environment.
+
+use js_sys::Date;
+
+use crate::{datetime_to_secs, secs_to_datetime};
+
+/// Maximum magnitude of a JavaScript time value in milliseconds
+///
+/// Corresponds to 100,000,000 days from Unix epoch in either direction.
+pub const JS_MILLIS_MAX: f64 = 8.64e15;
+
+/// Convert JavaScript time value to seconds and nanoseconds
+///
+/// Given milliseconds from Unix epoch (January 1st, 1970), returns a `(seconds,
+/// nanoseconds)` tuple.
+///
+/// # Errors
+///
+/// Returns `None` if the time value is not a number or its magnitude is over
+/// [JS_MILLIS_MAX], as such values are an invalid date in JavaScript.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::wasm::millis_to_secs;
+///
+/// assert_eq!(millis_to_secs(1684574678123.0), Some((1684574678, 123_000_000)));
+/// assert_eq!(millis_to_secs(-1.0), Some((-1, 999_000_000)));
+/// assert_eq!(millis_to_secs(0.5), Some((0, 500_000)));
+/// assert_eq!(millis_to_secs(f64::NAN), None);
+/// assert_eq!(millis_to_secs(8.64e15 + 1.0), None);
+/// ```
+///
+/// # Algorithm
+///
+/// Floor of the time value as an integer, with the remaining fraction of a
+/// millisecond converted to nanoseconds.
+#[inline]
+pub fn millis_to_secs(ms: f64) -> Option<(i64, u32)> {
+    if !(-JS_MILLIS_MAX..=JS_MILLIS_MAX).contains(&ms) {
+        return None;
+    }
+    let mut whole = ms as i64;
+    if whole as f64 > ms {
+        whole -= 1;
+    }
+    let frac = ((ms - whole as f64) * 1e6) as u32;
+    let secs = whole.div_euclid(1000);
+    let nanos = whole.rem_euclid(1000) as u32 * 1_000_000 + frac.min(999_999);
+    Some((secs, nanos))
+}
+
+/// Convert seconds and nanoseconds to JavaScript time value
+///
+/// Given a `(seconds, nanoseconds)` tuple from Unix epoch (January 1st, 1970),
+/// returns the milliseconds from Unix epoch, truncating the nanoseconds to
+/// whole milliseconds.
+///
+/// # Errors
+///
+/// Returns `None` if the magnitude of the result would be over
+/// [JS_MILLIS_MAX].
+///
+/// # Panics
+///
+/// Nanoseconds must between `0` and `999_999_999`. Bounds are checked using
+/// `debug_assert` only, so that the checks are not present in release builds,
+/// similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::wasm::secs_to_millis;
+///
+/// assert_eq!(secs_to_millis((1684574678, 123_456_789)), Some(1684574678123.0));
+/// assert_eq!(secs_to_millis((-1, 999_999_999)), Some(-1.0));
+/// assert_eq!(secs_to_millis((8_640_000_000_001, 0)), None);
+/// ```
+///
+/// # Algorithm
+///
+/// Integer multiplication, which is exact within the valid range.
+#[inline]
+pub fn secs_to_millis((secs, nanos): (i64, u32)) -> Option<f64> {
+    debug_assert!(nanos < 1_000_000_000, "given nanoseconds is out of range");
+    let ms = secs.checked_mul(1000)?.checked_add((nanos / 1_000_000) as i64)?;
+    if ms.unsigned_abs() > JS_MILLIS_MAX as u64 {
+        return None;
+    }
+    Some(ms as f64)
+}
+
+/// Convert JavaScript time value to year, month, day, hours, minutes, seconds
+/// and nanoseconds
+///
+/// Given milliseconds from Unix epoch (January 1st, 1970), returns a `(year,
+/// month, day, hours, minutes, seconds, nanoseconds)` tuple in UTC.
+///
+/// # Errors
+///
+/// Returns `None` if the time value is not a number or its magnitude is over
+/// [JS_MILLIS_MAX].
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::wasm::millis_to_datetime;
+///
+/// assert_eq!(millis_to_datetime(1684574678123.0), Some((2023, 5, 20, 9, 24, 38, 123_000_000)));
+/// assert_eq!(millis_to_datetime(-8.64e15), Some((-271821, 4, 20, 0, 0, 0, 0)));
+/// ```
+///
+/// # Algorithm
+///
+/// Combination of existing functions for convenience only.
+#[inline]
+pub fn millis_to_datetime(ms: f64) -> Option<(i32, u8, u8, u8, u8, u8, u32)> {
+    let (secs, nanos) = millis_to_secs(ms)?;
+    let (y, m, d, hh, mm, ss) = secs_to_datetime(secs);
+    Some((y, m, d, hh, mm, ss, nanos))
+}
+
+/// Convert year, month, day, hours, minutes, seconds and nanoseconds to
+/// JavaScript time value
+///
+/// Given a `(year, month, day, hours, minutes, seconds, nanoseconds)` tuple in
+/// UTC, returns the milliseconds from Unix epoch (January 1st, 1970),
+/// truncating the nanoseconds to whole milliseconds.
+///
+/// # Errors
+///
+/// Returns `None` if the magnitude of the result would be over
+/// [JS_MILLIS_MAX].
+///
+/// # Panics
+///
+/// Year must be between [YEAR_MIN](crate::YEAR_MIN) and
+/// [YEAR_MAX](crate::YEAR_MAX). Month must be between `1` and `12`. Day must
+/// be between `1` and the number of days in the month in question. Hours must
+/// be between `0` and `23`. Minutes must be between `0` and `59`. Seconds must
+/// be between `0` and `59`. Nanoseconds must be between `0` and
+/// `999_999_999`. Bounds are checked using `debug_assert` only, so that the
+/// checks are not present in release builds, similar to integer overflow
+/// checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::wasm::datetime_to_millis;
+///
+/// assert_eq!(datetime_to_millis((2023, 5, 20, 9, 24, 38, 123_000_000)), Some(1684574678123.0));
+/// assert_eq!(datetime_to_millis((275760, 9, 13, 0, 0, 0, 0)), Some(8.64e15));
+/// assert_eq!(datetime_to_millis((275760, 9, 13, 0, 0, 0, 1_000_000)), None);
+/// ```
+///
+/// # Algorithm
+///
+/// Combination of existing functions for convenience only.
+#[inline]
+pub fn datetime_to_millis((y, m, d, hh, mm, ss, nanos): (i32, u8, u8, u8, u8, u8, u32)) -> Option<f64> {
+    secs_to_millis((datetime_to_secs((y, m, d, hh, mm, ss)), nanos))
+}
+
+/// Convert [`js_sys::Date`] to year, month, day, hours, minutes, seconds and
+/// nanoseconds
+///
+/// Given a JavaScript `Date` object, returns a `(year, month, day, hours,
+/// minutes, seconds, nanoseconds)` tuple in UTC.
+///
+/// # Errors
+///
+/// Returns `None` if the date is an invalid date.
+///
+/// # Algorithm
+///
+/// `Date.prototype.getTime()` followed by [millis_to_datetime].
+#[inline]
+pub fn jsdate_to_datetime(date: &Date) -> Option<(i32, u8, u8, u8, u8, u8, u32)> {
+    millis_to_datetime(date.get_time())
+}
+
+/// Convert year, month, day, hours, minutes, seconds and nanoseconds to
+/// [`js_sys::Date`]
+///
+/// Given a `(year, month, day, hours, minutes, seconds, nanoseconds)` tuple in
+/// UTC, returns a JavaScript `Date` object, truncating the nanoseconds to
+/// whole milliseconds.
+///
+/// # Errors
+///
+/// Returns `None` if the datetime is outside the range of JavaScript dates.
+///
+/// # Panics
+///
+/// Same as [datetime_to_millis].
+///
+/// # Algorithm
+///
+/// [datetime_to_millis] followed by `Date.prototype.setTime()`.
+#[inline]
+pub fn datetime_to_jsdate(dt: (i32, u8, u8, u8, u8, u8, u32)) -> Option<Date> {
+    let ms = datetime_to_millis(dt)?;
+    let date = Date::new_0();
+    date.set_time(ms);
+    Some(date)
+}
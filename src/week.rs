@@ -0,0 +1,732 @@
+//! Week numbering systems other than ISO 8601 and day of week arithmetic
+//!
+//! The crate root provides [ISO week dates](crate::rd_to_isoweekdate), where
+//! weeks start on Monday and the first week of the year contains January 4th.
+//! Several other week numberings are in common use, differing in the first
+//! day of the week and in the rule that decides which week is the first of
+//! the year. This module provides conversions for these systems, along with
+//! helpers for counting and stepping days of week as returned by
+//! [rd_to_weekday].
+
+use crate::{
+    consts, date_to_rd, days_in_month, is_leap_year, rd_to_weekday, rd_to_year, rd_to_year_month, RD_MAX, RD_MIN, YEAR_MAX, YEAR_MIN,
+};
+
+/// Rata Die of the first day of the first MMWR week of the year
+#[inline]
+const fn epiweek_year_start(y: i32) -> i32 {
+    let jan4 = date_to_rd((y, 1, 4));
+    jan4 - (rd_to_weekday(jan4) % 7) as i32
+}
+
+/// Convert Rata Die to MMWR epidemiological week
+///
+/// Given a day counting from Unix epoch (January 1st, 1970) returns a `(year,
+/// week)` tuple according to the MMWR week definition of the US Centers for
+/// Disease Control and Prevention. Weeks start on Sunday, and the first week
+/// of the year is the first week with at least four days in the year, so the
+/// first or last few days of a calendar year may belong to the adjacent MMWR
+/// year.
+///
+/// # Panics
+///
+/// Argument must be between [RD_MIN] and [RD_MAX] inclusive. Bounds are
+/// checked using `debug_assert` only, so that the checks are not present in
+/// release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{date_to_rd, week::rd_to_epiweek};
+///
+/// assert_eq!(rd_to_epiweek(date_to_rd((2023, 5, 20))), (2023, 20));
+/// assert_eq!(rd_to_epiweek(date_to_rd((2023, 1, 1))), (2023, 1));
+/// assert_eq!(rd_to_epiweek(date_to_rd((2021, 1, 2))), (2020, 53));
+/// assert_eq!(rd_to_epiweek(date_to_rd((2019, 12, 29))), (2020, 1));
+/// ```
+///
+/// # Algorithm
+///
+/// The MMWR year is the year of the Wednesday of the week, after which the
+/// week is counted from the Sunday of the week containing January 4th.
+#[inline]
+pub const fn rd_to_epiweek(rd: i32) -> (i32, u8) {
+    debug_assert!(rd >= RD_MIN && rd <= RD_MAX, "given rata die is out of range");
+    let sunday = rd - (rd_to_weekday(rd) % 7) as i32;
+    let y = rd_to_year(sunday + 3);
+    let w = (sunday - epiweek_year_start(y)) / 7 + 1;
+    (y, w as u8)
+}
+
+/// Convert MMWR epidemiological week to range of Rata Die
+///
+/// Given a `(year, week)` tuple returns the `(first, last)` days of the week,
+/// Sunday and Saturday, counting from Unix epoch (January 1st, 1970). The
+/// first week of [YEAR_MIN] starts the day before [RD_MIN].
+///
+/// # Panics
+///
+/// Year must be between [YEAR_MIN] and [YEAR_MAX]. Week must be between `1`
+/// and the number of MMWR weeks in the given year (52 or 53). Bounds are
+/// checked using `debug_assert` only, so that the checks are not present in
+/// release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{date_to_rd, week::epiweek_to_rd_range};
+///
+/// assert_eq!(epiweek_to_rd_range((2023, 20)), (date_to_rd((2023, 5, 14)), date_to_rd((2023, 5, 20))));
+/// assert_eq!(epiweek_to_rd_range((2020, 53)), (date_to_rd((2020, 12, 27)), date_to_rd((2021, 1, 2))));
+/// ```
+///
+/// # Algorithm
+///
+/// Offset from the Sunday of the week containing January 4th.
+#[inline]
+pub const fn epiweek_to_rd_range((y, w): (i32, u8)) -> (i32, i32) {
+    debug_assert!(y >= YEAR_MIN && y <= YEAR_MAX, "given year is out of range");
+    debug_assert!(w >= 1 && w <= epiweeks_in_year(y), "given week is out of range");
+    let first = epiweek_year_start(y) + (w as i32 - 1) * 7;
+    (first, first + 6)
+}
+
+/// Determine the number of MMWR epidemiological weeks in the given year
+///
+/// A year has 53 weeks when it starts on a Wednesday, or when it is a leap
+/// year starting on a Tuesday, and 52 weeks otherwise.
+///
+/// # Panics
+///
+/// Year must be between [YEAR_MIN] and [YEAR_MAX]. Bounds are checked using
+/// `debug_assert` only, so that the checks are not present in release builds,
+/// similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::week::epiweeks_in_year;
+///
+/// assert_eq!(epiweeks_in_year(2014), 53);
+/// assert_eq!(epiweeks_in_year(2020), 53);
+/// assert_eq!(epiweeks_in_year(2023), 52);
+/// ```
+///
+/// # Algorithm
+///
+/// Day of week of January 1st using [rd_to_weekday].
+#[inline]
+pub const fn epiweeks_in_year(y: i32) -> u8 {
+    debug_assert!(y >= YEAR_MIN && y <= YEAR_MAX, "given year is out of range");
+    match rd_to_weekday(date_to_rd((y, 1, 1))) {
+        consts::WEDNESDAY => 53,
+        consts::TUESDAY if is_leap_year(y) => 53,
+        _ => 52,
+    }
+}
+
+/// Rata Die of the first day of the broadcast year
+#[inline]
+const fn broadcast_year_start(y: i32) -> i32 {
+    let jan1 = date_to_rd((y, 1, 1));
+    jan1 - (rd_to_weekday(jan1) - 1) as i32
+}
+
+/// Rata Die of the last day of the broadcast month
+#[inline]
+const fn broadcast_month_end(y: i32, m: u8) -> i32 {
+    let last = date_to_rd((y, m, days_in_month(y, m)));
+    last - (rd_to_weekday(last) % 7) as i32
+}
+
+/// Convert Rata Die to broadcast calendar date
+///
+/// Given a day counting from Unix epoch (January 1st, 1970) returns a `(year,
+/// month, week)` tuple in the standard broadcast calendar used in advertising.
+/// Weeks start on Monday, and each broadcast month starts on the Monday of the
+/// week containing the first day of the calendar month and ends on the last
+/// Sunday of the calendar month. The broadcast year likewise starts on the
+/// week containing January 1st and consists of 52 or 53 whole weeks, numbered
+/// from `1`.
+///
+/// # Panics
+///
+/// Argument must be between [RD_MIN] and [RD_MAX] inclusive. The last days
+/// before [RD_MAX] that belong to the broadcast year after [YEAR_MAX] are not
+/// supported. Bounds are checked using `debug_assert` only, so that the checks
+/// are not present in release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{date_to_rd, week::rd_to_broadcast};
+///
+/// assert_eq!(rd_to_broadcast(date_to_rd((2023, 5, 20))), (2023, 5, 21));
+/// assert_eq!(rd_to_broadcast(date_to_rd((2023, 5, 29))), (2023, 6, 23));
+/// assert_eq!(rd_to_broadcast(date_to_rd((2022, 12, 26))), (2023, 1, 1));
+/// ```
+///
+/// # Algorithm
+///
+/// The broadcast month is the calendar month of the Sunday of the week, after
+/// which the week is counted from the Monday of the week containing January
+/// 1st.
+#[inline]
+pub const fn rd_to_broadcast(rd: i32) -> (i32, u8, u8) {
+    debug_assert!(rd >= RD_MIN && rd <= RD_MAX - 4, "given rata die is out of range");
+    let monday = rd - (rd_to_weekday(rd) - 1) as i32;
+    let (y, m) = rd_to_year_month(monday + 6);
+    let w = (monday - broadcast_year_start(y)) / 7 + 1;
+    (y, m, w as u8)
+}
+
+/// Convert broadcast month to range of Rata Die
+///
+/// Given a `(year, month)` tuple returns the `(first, last)` days of the
+/// broadcast month, Monday and Sunday, counting from Unix epoch (January 1st,
+/// 1970). Broadcast months are four or five weeks long.
+///
+/// # Panics
+///
+/// Year must be between [YEAR_MIN] and [YEAR_MAX]. Month must be between `1`
+/// and `12`. Bounds are checked using `debug_assert` only, so that the checks
+/// are not present in release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{date_to_rd, week::broadcast_month_to_rd_range};
+///
+/// assert_eq!(broadcast_month_to_rd_range((2023, 1)), (date_to_rd((2022, 12, 26)), date_to_rd((2023, 1, 29))));
+/// assert_eq!(broadcast_month_to_rd_range((2023, 5)), (date_to_rd((2023, 5, 1)), date_to_rd((2023, 5, 28))));
+/// ```
+///
+/// # Algorithm
+///
+/// The Monday of the week containing the first day of the month and the last
+/// Sunday of the month.
+#[inline]
+pub const fn broadcast_month_to_rd_range((y, m): (i32, u8)) -> (i32, i32) {
+    debug_assert!(y >= YEAR_MIN && y <= YEAR_MAX, "given year is out of range");
+    debug_assert!(m >= consts::MONTH_MIN && m <= consts::MONTH_MAX, "given month is out of range");
+    let first = date_to_rd((y, m, 1));
+    (first - (rd_to_weekday(first) - 1) as i32, broadcast_month_end(y, m))
+}
+
+/// Convert broadcast week to range of Rata Die
+///
+/// Given a `(year, week)` tuple returns the `(first, last)` days of the week,
+/// Monday and Sunday, counting from Unix epoch (January 1st, 1970).
+///
+/// # Panics
+///
+/// Year must be between [YEAR_MIN] and [YEAR_MAX]. Week must be between `1`
+/// and the number of broadcast weeks in the given year (52 or 53). Bounds are
+/// checked using `debug_assert` only, so that the checks are not present in
+/// release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{date_to_rd, week::broadcast_week_to_rd_range};
+///
+/// assert_eq!(broadcast_week_to_rd_range((2023, 1)), (date_to_rd((2022, 12, 26)), date_to_rd((2023, 1, 1))));
+/// assert_eq!(broadcast_week_to_rd_range((2023, 21)), (date_to_rd((2023, 5, 15)), date_to_rd((2023, 5, 21))));
+/// ```
+///
+/// # Algorithm
+///
+/// Offset from the Monday of the week containing January 1st.
+#[inline]
+pub const fn broadcast_week_to_rd_range((y, w): (i32, u8)) -> (i32, i32) {
+    debug_assert!(y >= YEAR_MIN && y <= YEAR_MAX, "given year is out of range");
+    debug_assert!(w >= 1 && w <= broadcast_weeks_in_year(y), "given week is out of range");
+    let first = broadcast_year_start(y) + (w as i32 - 1) * 7;
+    (first, first + 6)
+}
+
+/// Determine the number of weeks in the given broadcast year
+///
+/// A broadcast year has 53 weeks when December 31st falls on a Sunday, or
+/// when it is a leap year and December 31st falls on a Monday, and 52 weeks
+/// otherwise.
+///
+/// # Panics
+///
+/// Year must be between [YEAR_MIN] and [YEAR_MAX]. Bounds are checked using
+/// `debug_assert` only, so that the checks are not present in release builds,
+/// similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::week::broadcast_weeks_in_year;
+///
+/// assert_eq!(broadcast_weeks_in_year(2023), 53);
+/// assert_eq!(broadcast_weeks_in_year(2024), 52);
+/// ```
+///
+/// # Algorithm
+///
+/// Number of whole weeks between the start of the year and the last Sunday of
+/// December.
+#[inline]
+pub const fn broadcast_weeks_in_year(y: i32) -> u8 {
+    debug_assert!(y >= YEAR_MIN && y <= YEAR_MAX, "given year is out of range");
+    ((broadcast_month_end(y, 12) - broadcast_year_start(y) + 1) / 7) as u8
+}
+
+/// Determine week of year with configurable week rules
+///
+/// Given a `(year, month, day)` tuple, the first day of the week as in
+/// [rd_to_weekday] and the minimum number of days in the first week of the
+/// year, returns a `(week year, week)` tuple. These are the two parameters of
+/// the week data in the Unicode CLDR, used by ICU and most locale-aware
+/// calendars. Week `1` is the first week of the year with at least the given
+/// number of days in the year, so the first or last few days of a calendar
+/// year may belong to the adjacent week year.
+///
+/// ISO 8601 weeks as in [rd_to_isoweekdate](crate::rd_to_isoweekdate) are
+/// given by Monday and `4`, MMWR weeks as in [rd_to_epiweek] by Sunday and
+/// `4`, and the common United States convention by Sunday and `1`.
+///
+/// # Panics
+///
+/// Year must be between [YEAR_MIN] and [YEAR_MAX]. Month must be between `1`
+/// and `12`. Day must be between `1` and the number of days in the month in
+/// question. First day of week and minimum days must be between `1` and `7`.
+/// The resulting week year must also be between [YEAR_MIN] and [YEAR_MAX].
+/// Bounds are checked using `debug_assert` only, so that the checks are not
+/// present in release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{consts::{MONDAY, SUNDAY}, week::week_of_year};
+///
+/// assert_eq!(week_of_year((2023, 5, 20), MONDAY, 4), (2023, 20));
+/// assert_eq!(week_of_year((2023, 5, 20), SUNDAY, 1), (2023, 20));
+/// assert_eq!(week_of_year((2022, 1, 1), MONDAY, 4), (2021, 52));
+/// assert_eq!(week_of_year((2022, 1, 1), SUNDAY, 1), (2022, 1));
+/// assert_eq!(week_of_year((2021, 12, 31), SUNDAY, 1), (2022, 1));
+/// ```
+///
+/// # Algorithm
+///
+/// Week `1` is the week containing the day of January given by the minimum
+/// number of days. The start of the week in the previous or next year is
+/// derived from the length of the year, so the day of week is computed once.
+#[inline]
+pub const fn week_of_year((y, m, d): (i32, u8, u8), first_weekday: u8, min_days: u8) -> (i32, u8) {
+    debug_assert!(y >= YEAR_MIN && y <= YEAR_MAX, "given year is out of range");
+    debug_assert!(m >= consts::MONTH_MIN && m <= consts::MONTH_MAX, "given month is out of range");
+    debug_assert!(d >= consts::DAY_MIN && d <= days_in_month(y, m), "given day is out of range");
+    debug_assert!(
+        first_weekday >= consts::WEEKDAY_MIN && first_weekday <= consts::WEEKDAY_MAX,
+        "given weekday is out of range"
+    );
+    debug_assert!(min_days >= 1 && min_days <= 7, "given minimum days is out of range");
+    let rd = date_to_rd((y, m, d));
+    let jan = date_to_rd((y, 1, min_days));
+    let k = (rd_to_weekday(jan) + 7 - first_weekday) % 7;
+    let start = jan - k as i32;
+    if rd < start {
+        let leap = is_leap_year(y - 1) as u8;
+        let k = (k + 6 - leap) % 7;
+        let start = jan - 365 - leap as i32 - k as i32;
+        return (y - 1, ((rd - start) / 7 + 1) as u8);
+    }
+    let leap = is_leap_year(y) as u8;
+    let k = (k + 1 + leap) % 7;
+    let next = jan + 365 + leap as i32 - k as i32;
+    if rd >= next {
+        return (y + 1, 1);
+    }
+    (y, ((rd - start) / 7 + 1) as u8)
+}
+
+/// Determine week of year with weeks starting on Sunday
+///
+/// Given a `(year, month, day)` tuple returns the week number as in the C
+/// `strftime` format `%U`. Week `1` starts on the first Sunday of the year,
+/// and the days before it are in week `0`. Unlike the other week numberings,
+/// weeks never extend to the adjacent year, so the result is between `0` and
+/// `53`.
+///
+/// # Panics
+///
+/// Year must be between [YEAR_MIN] and [YEAR_MAX]. Month must be between `1`
+/// and `12`. Day must be between `1` and the number of days in the month in
+/// question. Bounds are checked using `debug_assert` only, so that the checks
+/// are not present in release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::week::week_of_year_sunday_start;
+///
+/// assert_eq!(week_of_year_sunday_start((2023, 5, 20)), 20);
+/// assert_eq!(week_of_year_sunday_start((2022, 1, 1)), 0);
+/// assert_eq!(week_of_year_sunday_start((2022, 1, 2)), 1);
+/// assert_eq!(week_of_year_sunday_start((2023, 1, 1)), 1);
+/// ```
+///
+/// # Algorithm
+///
+/// Simple arithmetic on the day of year and day of week, as in C libraries.
+#[inline]
+pub const fn week_of_year_sunday_start((y, m, d): (i32, u8, u8)) -> u8 {
+    debug_assert!(y >= YEAR_MIN && y <= YEAR_MAX, "given year is out of range");
+    debug_assert!(m >= consts::MONTH_MIN && m <= consts::MONTH_MAX, "given month is out of range");
+    debug_assert!(d >= consts::DAY_MIN && d <= days_in_month(y, m), "given day is out of range");
+    let rd = date_to_rd((y, m, d));
+    let yday = rd - date_to_rd((y, 1, 1));
+    let wday = (rd_to_weekday(rd) % 7) as i32;
+    ((yday + 7 - wday) / 7) as u8
+}
+
+/// Determine week of year with weeks starting on Monday
+///
+/// Given a `(year, month, day)` tuple returns the week number as in the C
+/// `strftime` format `%W`. Week `1` starts on the first Monday of the year,
+/// and the days before it are in week `0`. Unlike the other week numberings,
+/// weeks never extend to the adjacent year, so the result is between `0` and
+/// `53`.
+///
+/// # Panics
+///
+/// Year must be between [YEAR_MIN] and [YEAR_MAX]. Month must be between `1`
+/// and `12`. Day must be between `1` and the number of days in the month in
+/// question. Bounds are checked using `debug_assert` only, so that the checks
+/// are not present in release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::week::week_of_year_monday_start;
+///
+/// assert_eq!(week_of_year_monday_start((2023, 5, 20)), 20);
+/// assert_eq!(week_of_year_monday_start((2023, 1, 1)), 0);
+/// assert_eq!(week_of_year_monday_start((2023, 1, 2)), 1);
+/// assert_eq!(week_of_year_monday_start((2024, 1, 1)), 1);
+/// ```
+///
+/// # Algorithm
+///
+/// Simple arithmetic on the day of year and day of week, as in C libraries.
+#[inline]
+pub const fn week_of_year_monday_start((y, m, d): (i32, u8, u8)) -> u8 {
+    debug_assert!(y >= YEAR_MIN && y <= YEAR_MAX, "given year is out of range");
+    debug_assert!(m >= consts::MONTH_MIN && m <= consts::MONTH_MAX, "given month is out of range");
+    debug_assert!(d >= consts::DAY_MIN && d <= days_in_month(y, m), "given day is out of range");
+    let rd = date_to_rd((y, m, d));
+    let yday = rd - date_to_rd((y, 1, 1));
+    let wday = (rd_to_weekday(rd) - 1) as i32;
+    ((yday + 7 - wday) / 7) as u8
+}
+
+/// Weekend mask for Saturday and Sunday
+///
+/// Bit `wd - 1` is set when day of week `wd`, as in [rd_to_weekday], is a
+/// weekend day.
+pub const WEEKEND_SAT_SUN: u8 = 0x60;
+
+/// Weekend mask for Friday and Saturday
+///
+/// Bit `wd - 1` is set when day of week `wd`, as in [rd_to_weekday], is a
+/// weekend day.
+pub const WEEKEND_FRI_SAT: u8 = 0x30;
+
+/// Count occurrences of a day of week in a range of days
+///
+/// Given the first and last days of an inclusive range, counting from Unix
+/// epoch (January 1st, 1970), and a day of week as in [rd_to_weekday], returns
+/// the number of days in the range that fall on the given day of week.
+/// Returns `0` if the last day is before the first day.
+///
+/// # Panics
+///
+/// Arguments must be between [RD_MIN] and [RD_MAX] inclusive. Day of week must
+/// be between `1` and `7`. Bounds are checked using `debug_assert` only, so
+/// that the checks are not present in release builds, similar to integer
+/// overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{consts::{MONDAY, SUNDAY}, date_to_rd, week::count_weekday_in_range};
+///
+/// let (start, end) = (date_to_rd((2023, 5, 1)), date_to_rd((2023, 5, 31)));
+/// assert_eq!(count_weekday_in_range(start, end, MONDAY), 5);
+/// assert_eq!(count_weekday_in_range(start, end, SUNDAY), 4);
+/// assert_eq!(count_weekday_in_range(end, start, SUNDAY), 0);
+/// ```
+///
+/// # Algorithm
+///
+/// Number of whole weeks in the range, plus one if the day of week occurs in
+/// the remaining days.
+#[inline]
+pub const fn count_weekday_in_range(start: i32, end: i32, wd: u8) -> i32 {
+    debug_assert!(start >= RD_MIN && start <= RD_MAX, "given rata die is out of range");
+    debug_assert!(end >= RD_MIN && end <= RD_MAX, "given rata die is out of range");
+    debug_assert!(
+        wd >= consts::WEEKDAY_MIN && wd <= consts::WEEKDAY_MAX,
+        "given weekday is out of range"
+    );
+    if end < start {
+        return 0;
+    }
+    let n = end - start + 1;
+    let offset = ((wd + 7 - rd_to_weekday(start)) % 7) as i32;
+    n / 7 + (offset < n % 7) as i32
+}
+
+/// Count weekend days in a range of days
+///
+/// Given the first and last days of an inclusive range, counting from Unix
+/// epoch (January 1st, 1970), and a weekend mask, such as [WEEKEND_SAT_SUN],
+/// returns the number of days in the range that fall on a weekend day. The
+/// number of working days is the length of the range minus this count.
+/// Returns `0` if the last day is before the first day.
+///
+/// # Panics
+///
+/// Arguments must be between [RD_MIN] and [RD_MAX] inclusive. Weekend mask
+/// must only have bits 0 to 6 set. Bounds are checked using `debug_assert`
+/// only, so that the checks are not present in release builds, similar to
+/// integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{date_to_rd, week::{count_weekend_days, WEEKEND_FRI_SAT, WEEKEND_SAT_SUN}};
+///
+/// let (start, end) = (date_to_rd((2023, 5, 1)), date_to_rd((2023, 5, 31)));
+/// assert_eq!(count_weekend_days(start, end, WEEKEND_SAT_SUN), 8);
+/// assert_eq!(count_weekend_days(start, end, WEEKEND_FRI_SAT), 8);
+/// assert_eq!(count_weekend_days(start, start + 4, WEEKEND_SAT_SUN), 0);
+/// ```
+///
+/// # Algorithm
+///
+/// Number of whole weeks in the range multiplied by the number of weekend
+/// days, plus the weekend days in the remaining days, counted by rotating the
+/// mask to the day of week of the first day.
+#[inline]
+pub const fn count_weekend_days(start: i32, end: i32, mask: u8) -> i32 {
+    debug_assert!(start >= RD_MIN && start <= RD_MAX, "given rata die is out of range");
+    debug_assert!(end >= RD_MIN && end <= RD_MAX, "given rata die is out of range");
+    debug_assert!(mask < 1 << 7, "given weekend mask is out of range");
+    if end < start {
+        return 0;
+    }
+    let n = end - start + 1;
+    let mask = (mask & 0x7f) as u16;
+    let rotated = (mask | mask << 7) >> (rd_to_weekday(start) - 1);
+    let rest = rotated & ((1 << (n % 7)) - 1);
+    n / 7 * mask.count_ones() as i32 + rest.count_ones() as i32
+}
+
+/// Iterator over week aligned chunks of a range of days
+///
+/// Created with [week_chunks].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WeekChunks {
+    next: i32,
+    end: i32,
+    first_weekday: u8,
+}
+
+impl Iterator for WeekChunks {
+    type Item = (i32, i32);
+
+    #[inline]
+    fn next(&mut self) -> Option<(i32, i32)> {
+        if self.next > self.end {
+            return None;
+        }
+        let start = self.next;
+        let offset = ((rd_to_weekday(start) + 7 - self.first_weekday) % 7) as i32;
+        let end = if self.end - start < 6 - offset {
+            self.end
+        } else {
+            start + 6 - offset
+        };
+        self.next = end + 1;
+        Some((start, end))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = if self.next > self.end {
+            0
+        } else {
+            let offset = ((rd_to_weekday(self.next) + 7 - self.first_weekday) % 7) as i32;
+            ((self.end - self.next + offset) / 7 + 1) as usize
+        };
+        (n, Some(n))
+    }
+}
+
+impl ExactSizeIterator for WeekChunks {}
+
+impl core::iter::FusedIterator for WeekChunks {}
+
+/// Split a range of days into week aligned chunks
+///
+/// Given the first and last days of an inclusive range, counting from Unix
+/// epoch (January 1st, 1970), and the first day of the week as in
+/// [rd_to_weekday], returns an iterator of `(first, last)` tuples for each
+/// week overlapping the range, clipped to the range. The first and last chunk
+/// may be shorter than a week. The iterator is empty if the last day is
+/// before the first day.
+///
+/// # Panics
+///
+/// Arguments must be between [RD_MIN] and [RD_MAX] inclusive. First day of
+/// week must be between `1` and `7`. Bounds are checked using `debug_assert`
+/// only, so that the checks are not present in release builds, similar to
+/// integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{consts::MONDAY, date_to_rd, week::week_chunks};
+///
+/// let (start, end) = (date_to_rd((2023, 5, 1)), date_to_rd((2023, 5, 20)));
+/// let mut chunks = week_chunks(start, end, MONDAY);
+/// assert_eq!(chunks.len(), 3);
+/// assert_eq!(chunks.next(), Some((date_to_rd((2023, 5, 1)), date_to_rd((2023, 5, 7)))));
+/// assert_eq!(chunks.next(), Some((date_to_rd((2023, 5, 8)), date_to_rd((2023, 5, 14)))));
+/// assert_eq!(chunks.next(), Some((date_to_rd((2023, 5, 15)), date_to_rd((2023, 5, 20)))));
+/// assert_eq!(chunks.next(), None);
+/// ```
+///
+/// # Algorithm
+///
+/// Each chunk ends on the day before the next first day of week, found from
+/// the day of week of the start of the chunk.
+#[inline]
+pub const fn week_chunks(start: i32, end: i32, first_weekday: u8) -> WeekChunks {
+    debug_assert!(start >= RD_MIN && start <= RD_MAX, "given rata die is out of range");
+    debug_assert!(end >= RD_MIN && end <= RD_MAX, "given rata die is out of range");
+    debug_assert!(
+        first_weekday >= consts::WEEKDAY_MIN && first_weekday <= consts::WEEKDAY_MAX,
+        "given weekday is out of range"
+    );
+    WeekChunks {
+        next: start,
+        end,
+        first_weekday,
+    }
+}
+
+/// Add days to day of week
+///
+/// Given a day of week as in [rd_to_weekday] and a number of days returns the
+/// day of week that many days later, or earlier if the number is negative.
+///
+/// # Panics
+///
+/// Day of week must be between `1` and `7`. Bounds are checked using
+/// `debug_assert` only, so that the checks are not present in release builds,
+/// similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{consts::{MONDAY, SATURDAY, SUNDAY}, week::weekday_add};
+///
+/// assert_eq!(weekday_add(SATURDAY, 2), MONDAY);
+/// assert_eq!(weekday_add(MONDAY, -1), SUNDAY);
+/// assert_eq!(weekday_add(MONDAY, 700), MONDAY);
+/// ```
+///
+/// # Algorithm
+///
+/// Modular arithmetic on days of week as in Howard Hinnant's `chrono`
+/// library for C++20:
+/// > <https://howardhinnant.github.io/date_algorithms.html>
+#[inline]
+pub const fn weekday_add(wd: u8, days: i32) -> u8 {
+    debug_assert!(
+        wd >= consts::WEEKDAY_MIN && wd <= consts::WEEKDAY_MAX,
+        "given weekday is out of range"
+    );
+    ((wd as i32 - 1 + days.rem_euclid(7)) % 7 + 1) as u8
+}
+
+/// Subtract days from day of week
+///
+/// Given a day of week as in [rd_to_weekday] and a number of days returns the
+/// day of week that many days earlier, or later if the number is negative.
+///
+/// # Panics
+///
+/// Day of week must be between `1` and `7`. Bounds are checked using
+/// `debug_assert` only, so that the checks are not present in release builds,
+/// similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{consts::{MONDAY, SATURDAY, SUNDAY}, week::weekday_sub};
+///
+/// assert_eq!(weekday_sub(MONDAY, 2), SATURDAY);
+/// assert_eq!(weekday_sub(SUNDAY, -1), MONDAY);
+/// assert_eq!(weekday_sub(MONDAY, 700), MONDAY);
+/// ```
+///
+/// # Algorithm
+///
+/// Same as [weekday_add] with the number of days negated.
+#[inline]
+pub const fn weekday_sub(wd: u8, days: i32) -> u8 {
+    debug_assert!(
+        wd >= consts::WEEKDAY_MIN && wd <= consts::WEEKDAY_MAX,
+        "given weekday is out of range"
+    );
+    ((wd as i32 - 1 + 7 - days.rem_euclid(7)) % 7 + 1) as u8
+}
+
+/// Determine the number of days from one day of week to the next occurrence of another
+///
+/// Given two days of week as in [rd_to_weekday] returns the number of days
+/// between `0` and `6` to add to the first to reach the second. This is the
+/// number of days to the next occurrence of a day of week, counting the day
+/// itself.
+///
+/// # Panics
+///
+/// Days of week must be between `1` and `7`. Bounds are checked using
+/// `debug_assert` only, so that the checks are not present in release builds,
+/// similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{consts::{FRIDAY, MONDAY, SUNDAY}, week::weekday_difference};
+///
+/// assert_eq!(weekday_difference(MONDAY, FRIDAY), 4);
+/// assert_eq!(weekday_difference(FRIDAY, MONDAY), 3);
+/// assert_eq!(weekday_difference(SUNDAY, SUNDAY), 0);
+/// ```
+///
+/// # Algorithm
+///
+/// Modular arithmetic on days of week as in Howard Hinnant's `chrono`
+/// library for C++20:
+/// > <https://howardhinnant.github.io/date_algorithms.html>
+#[inline]
+pub const fn weekday_difference(from: u8, to: u8) -> u8 {
+    debug_assert!(
+        from >= consts::WEEKDAY_MIN && from <= consts::WEEKDAY_MAX,
+        "given weekday is out of range"
+    );
+    debug_assert!(
+        to >= consts::WEEKDAY_MIN && to <= consts::WEEKDAY_MAX,
+        "given weekday is out of range"
+    );
+    (to + 7 - from) % 7
+}
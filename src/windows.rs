@@ -0,0 +1,292 @@
+//! Conversions to and from Windows `SYSTEMTIME` and `FILETIME`
+//!
+//! Windows represents instants as [FILETIME], a count of 100 nanosecond
+//! intervals from January 1st, 1601 split into two 32-bit halves, and
+//! broken-down time as [SYSTEMTIME], with day of week from `0` for Sunday and
+//! milliseconds precision. The functions in this module convert between those
+//! and the Unix seconds and tuples used in the rest of the crate, using the
+//! [`windows-sys`](https://docs.rs/windows-sys) definitions of the structures.
+//!
+//! The structures are plain data, so the conversions work on any target.
+//! Only UTC is supported, as returned by `GetSystemTime` and
+//! `GetSystemTimeAsFileTime`.
+
+use ::windows_sys::Win32::Foundation::{FILETIME, SYSTEMTIME};
+
+use crate::{datetime_to_secs, rd_to_weekday, secs_to_datetime, secs_to_dhms, RD_SECONDS_MAX, RD_SECONDS_MIN};
+
+/// Seconds from January 1st, 1601 to Unix epoch (January 1st, 1970)
+pub const FILETIME_UNIX_OFFSET_SECS: i64 = 11644473600;
+
+/// Minimum year supported by `SYSTEMTIME`
+pub const SYSTEMTIME_YEAR_MIN: i32 = 1601;
+
+/// Maximum year supported by `SYSTEMTIME`
+pub const SYSTEMTIME_YEAR_MAX: i32 = 30827;
+
+/// 100 nanosecond intervals in a second
+const TICKS_IN_SEC: u64 = 10_000_000;
+
+/// Convert `FILETIME` to seconds and nanoseconds
+///
+/// Given a [FILETIME], returns a `(seconds, nanoseconds)` tuple counting from
+/// Unix epoch (January 1st, 1970).
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::windows::filetime_to_secs;
+/// use windows_sys::Win32::Foundation::FILETIME;
+///
+/// let ft = FILETIME { dwLowDateTime: 0xd53e8000, dwHighDateTime: 0x019db1de };
+/// assert_eq!(filetime_to_secs(ft), (0, 0));
+/// let ft = FILETIME { dwLowDateTime: 0xe61da587, dwHighDateTime: 0x01d98afc };
+/// assert_eq!(filetime_to_secs(ft), (1684574678, 123_456_700));
+/// ```
+///
+/// # Algorithm
+///
+/// Division of the combined 64-bit tick count followed by subtraction of
+/// [FILETIME_UNIX_OFFSET_SECS].
+#[inline]
+pub const fn filetime_to_secs(ft: FILETIME) -> (i64, u32) {
+    let ticks = (ft.dwHighDateTime as u64) << 32 | ft.dwLowDateTime as u64;
+    let secs = (ticks / TICKS_IN_SEC) as i64 - FILETIME_UNIX_OFFSET_SECS;
+    let nanos = (ticks % TICKS_IN_SEC) as u32 * 100;
+    (secs, nanos)
+}
+
+/// Convert seconds and nanoseconds to `FILETIME`
+///
+/// Given a `(seconds, nanoseconds)` tuple counting from Unix epoch (January
+/// 1st, 1970), returns a [FILETIME], truncating the nanoseconds to whole 100
+/// nanosecond intervals.
+///
+/// # Errors
+///
+/// Returns `None` if the instant is before January 1st, 1601 or the tick count
+/// would not fit in 64 bits.
+///
+/// # Panics
+///
+/// Nanoseconds must be between `0` and `999_999_999`. Bounds are checked using
+/// `debug_assert` only, so that the checks are not present in release builds,
+/// similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::windows::secs_to_filetime;
+///
+/// let ft = secs_to_filetime((1684574678, 123_456_789)).unwrap();
+/// assert_eq!((ft.dwHighDateTime, ft.dwLowDateTime), (0x01d98afc, 0xe61da587));
+/// assert!(secs_to_filetime((-11644473601, 0)).is_none());
+/// ```
+///
+/// # Algorithm
+///
+/// Addition of [FILETIME_UNIX_OFFSET_SECS] followed by checked
+/// multiplication.
+#[inline]
+pub const fn secs_to_filetime((secs, nanos): (i64, u32)) -> Option<FILETIME> {
+    debug_assert!(nanos < 1_000_000_000, "given nanoseconds is out of range");
+    let secs = match secs.checked_add(FILETIME_UNIX_OFFSET_SECS) {
+        Some(secs) if secs >= 0 => secs as u64,
+        _ => return None,
+    };
+    let ticks = match secs.checked_mul(TICKS_IN_SEC) {
+        Some(ticks) => ticks,
+        None => return None,
+    };
+    let ticks = match ticks.checked_add((nanos / 100) as u64) {
+        Some(ticks) => ticks,
+        None => return None,
+    };
+    Some(FILETIME {
+        dwLowDateTime: ticks as u32,
+        dwHighDateTime: (ticks >> 32) as u32,
+    })
+}
+
+/// Convert `SYSTEMTIME` to year, month, day, hours, minutes, seconds and
+/// nanoseconds
+///
+/// Given a [SYSTEMTIME] in UTC, returns a `(year, month, day, hours, minutes,
+/// seconds, nanoseconds)` tuple. The `wDayOfWeek` field is ignored.
+///
+/// # Panics
+///
+/// Year must be between [SYSTEMTIME_YEAR_MIN] and [SYSTEMTIME_YEAR_MAX].
+/// Month must be between `1` and `12`. Day must be between `1` and the number
+/// of days in the month in question. Hours must be between `0` and `23`.
+/// Minutes must be between `0` and `59`. Seconds must be between `0` and `59`.
+/// Milliseconds must be between `0` and `999`. Bounds are checked using
+/// `debug_assert` only, so that the checks are not present in release builds,
+/// similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::windows::systemtime_to_datetime;
+/// use windows_sys::Win32::Foundation::SYSTEMTIME;
+///
+/// let st = SYSTEMTIME {
+///     wYear: 2023,
+///     wMonth: 5,
+///     wDayOfWeek: 6,
+///     wDay: 20,
+///     wHour: 9,
+///     wMinute: 24,
+///     wSecond: 38,
+///     wMilliseconds: 123,
+/// };
+/// assert_eq!(systemtime_to_datetime(&st), (2023, 5, 20, 9, 24, 38, 123_000_000));
+/// ```
+///
+/// # Algorithm
+///
+/// Field conversion only.
+#[inline]
+pub const fn systemtime_to_datetime(st: &SYSTEMTIME) -> (i32, u8, u8, u8, u8, u8, u32) {
+    debug_assert!(
+        st.wYear as i32 >= SYSTEMTIME_YEAR_MIN && st.wYear as i32 <= SYSTEMTIME_YEAR_MAX,
+        "given year is out of range"
+    );
+    debug_assert!(st.wMonth >= 1 && st.wMonth <= 12, "given month is out of range");
+    debug_assert!(st.wDay >= 1 && st.wDay <= 31, "given day is out of range");
+    debug_assert!(st.wHour <= 23, "given hour is out of range");
+    debug_assert!(st.wMinute <= 59, "given minute is out of range");
+    debug_assert!(st.wSecond <= 59, "given second is out of range");
+    debug_assert!(st.wMilliseconds <= 999, "given milliseconds is out of range");
+    (
+        st.wYear as i32,
+        st.wMonth as u8,
+        st.wDay as u8,
+        st.wHour as u8,
+        st.wMinute as u8,
+        st.wSecond as u8,
+        st.wMilliseconds as u32 * 1_000_000,
+    )
+}
+
+/// Convert year, month, day, hours, minutes, seconds and nanoseconds to
+/// `SYSTEMTIME`
+///
+/// Given a `(year, month, day, hours, minutes, seconds, nanoseconds)` tuple in
+/// UTC, returns a [SYSTEMTIME] with `wDayOfWeek` filled in, truncating the
+/// nanoseconds to whole milliseconds.
+///
+/// # Errors
+///
+/// Returns `None` if the year is not between [SYSTEMTIME_YEAR_MIN] and
+/// [SYSTEMTIME_YEAR_MAX].
+///
+/// # Panics
+///
+/// Month must be between `1` and `12`. Day must be between `1` and the number
+/// of days in the month in question. Hours must be between `0` and `23`.
+/// Minutes must be between `0` and `59`. Seconds must be between `0` and `59`.
+/// Nanoseconds must be between `0` and `999_999_999`. Bounds are checked using
+/// `debug_assert` only, so that the checks are not present in release builds,
+/// similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::windows::datetime_to_systemtime;
+///
+/// let st = datetime_to_systemtime((2023, 5, 20, 9, 24, 38, 123_456_789)).unwrap();
+/// assert_eq!((st.wYear, st.wMonth, st.wDay, st.wDayOfWeek), (2023, 5, 20, 6));
+/// assert_eq!((st.wHour, st.wMinute, st.wSecond, st.wMilliseconds), (9, 24, 38, 123));
+/// assert!(datetime_to_systemtime((1600, 12, 31, 0, 0, 0, 0)).is_none());
+/// ```
+///
+/// # Algorithm
+///
+/// Field conversion, with the day of week from [rd_to_weekday].
+#[inline]
+pub const fn datetime_to_systemtime((y, m, d, hh, mm, ss, nanos): (i32, u8, u8, u8, u8, u8, u32)) -> Option<SYSTEMTIME> {
+    debug_assert!(nanos < 1_000_000_000, "given nanoseconds is out of range");
+    if y < SYSTEMTIME_YEAR_MIN || y > SYSTEMTIME_YEAR_MAX {
+        return None;
+    }
+    let (n, _, _, _) = secs_to_dhms(datetime_to_secs((y, m, d, hh, mm, ss)));
+    Some(SYSTEMTIME {
+        wYear: y as u16,
+        wMonth: m as u16,
+        wDayOfWeek: (rd_to_weekday(n) % 7) as u16,
+        wDay: d as u16,
+        wHour: hh as u16,
+        wMinute: mm as u16,
+        wSecond: ss as u16,
+        wMilliseconds: (nanos / 1_000_000) as u16,
+    })
+}
+
+/// Convert `SYSTEMTIME` to seconds and nanoseconds
+///
+/// Given a [SYSTEMTIME] in UTC, returns a `(seconds, nanoseconds)` tuple
+/// counting from Unix epoch (January 1st, 1970). The `wDayOfWeek` field is
+/// ignored.
+///
+/// # Panics
+///
+/// Same as [systemtime_to_datetime].
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::windows::{datetime_to_systemtime, systemtime_to_secs};
+///
+/// let st = datetime_to_systemtime((2023, 5, 20, 9, 24, 38, 123_000_000)).unwrap();
+/// assert_eq!(systemtime_to_secs(&st), (1684574678, 123_000_000));
+/// ```
+///
+/// # Algorithm
+///
+/// Combination of existing functions for convenience only.
+#[inline]
+pub const fn systemtime_to_secs(st: &SYSTEMTIME) -> (i64, u32) {
+    let (y, m, d, hh, mm, ss, nanos) = systemtime_to_datetime(st);
+    (datetime_to_secs((y, m, d, hh, mm, ss)), nanos)
+}
+
+/// Convert seconds and nanoseconds to `SYSTEMTIME`
+///
+/// Given a `(seconds, nanoseconds)` tuple counting from Unix epoch (January
+/// 1st, 1970), returns a [SYSTEMTIME] in UTC with `wDayOfWeek` filled in,
+/// truncating the nanoseconds to whole milliseconds.
+///
+/// # Errors
+///
+/// Returns `None` if the year is not between [SYSTEMTIME_YEAR_MIN] and
+/// [SYSTEMTIME_YEAR_MAX].
+///
+/// # Panics
+///
+/// Seconds must be between [RD_SECONDS_MIN] and [RD_SECONDS_MAX] inclusive.
+/// Nanoseconds must be between `0` and `999_999_999`. Bounds are checked using
+/// `debug_assert` only, so that the checks are not present in release builds,
+/// similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::windows::secs_to_systemtime;
+///
+/// let st = secs_to_systemtime((0, 0)).unwrap();
+/// assert_eq!((st.wYear, st.wMonth, st.wDay, st.wDayOfWeek), (1970, 1, 1, 4));
+/// ```
+///
+/// # Algorithm
+///
+/// Combination of existing functions for convenience only.
+#[inline]
+pub const fn secs_to_systemtime((secs, nanos): (i64, u32)) -> Option<SYSTEMTIME> {
+    debug_assert!(
+        secs >= RD_SECONDS_MIN && secs <= RD_SECONDS_MAX,
+        "given seconds value is out of range"
+    );
+    let (y, m, d, hh, mm, ss) = secs_to_datetime(secs);
+    datetime_to_systemtime((y, m, d, hh, mm, ss, nanos))
+}
@@ -0,0 +1,222 @@
+//! Precomputed information for repeated conversions within a year
+//!
+//! Parsers that process many dates within one year, such as CSV imports or
+//! ledgers, can compute [YearInfo] once and then convert dates of that year
+//! with simple table lookups, skipping the century computations entirely.
+
+use crate::{consts, date_to_rd, is_leap_year, YEAR_MAX, YEAR_MIN};
+
+/// Precomputed information about a single Gregorian year
+///
+/// Caches the Rata Die of the first day of the year, the leap year flag and
+/// the cumulative day offsets of each month.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::yearinfo::YearInfo;
+///
+/// const Y2023: YearInfo = YearInfo::new(2023);
+///
+/// assert_eq!(Y2023.date_to_rd((5, 12)), 19489);
+/// assert_eq!(Y2023.rd_to_month_day(19489), (5, 12));
+/// assert_eq!(Y2023.yday_to_month_day(132), (5, 12));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct YearInfo {
+    year: i32,
+    start: i32,
+    leap: bool,
+    offsets: [u16; 13],
+}
+
+impl YearInfo {
+    /// Compute information for the given year
+    ///
+    /// # Panics
+    ///
+    /// Year must be between [YEAR_MIN] and [YEAR_MAX] inclusive. Bounds are
+    /// checked using `debug_assert` only, so that the checks are not present in
+    /// release builds, similar to integer overflow checks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use datealgo::yearinfo::YearInfo;
+    ///
+    /// let info = YearInfo::new(2024);
+    /// assert_eq!(info.year(), 2024);
+    /// assert_eq!(info.start_rd(), 19723);
+    /// assert_eq!(info.is_leap(), true);
+    /// assert_eq!(info.days_in_year(), 366);
+    /// ```
+    #[inline]
+    pub const fn new(y: i32) -> Self {
+        debug_assert!(y >= YEAR_MIN && y <= YEAR_MAX, "given year is out of range");
+        let leap = is_leap_year(y);
+        let mut offsets = [0; 13];
+        let mut m = 1;
+        while m <= 12 {
+            let len = if m == 2 { 28 + leap as u16 } else { 30 | (m ^ (m >> 3)) };
+            offsets[m as usize] = offsets[m as usize - 1] + len;
+            m += 1;
+        }
+        YearInfo {
+            year: y,
+            start: date_to_rd((y, 1, 1)),
+            leap,
+            offsets,
+        }
+    }
+
+    /// Year of this information
+    #[inline]
+    pub const fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Rata Die of January 1st of the year
+    #[inline]
+    pub const fn start_rd(&self) -> i32 {
+        self.start
+    }
+
+    /// Determine if the year is a leap year
+    #[inline]
+    pub const fn is_leap(&self) -> bool {
+        self.leap
+    }
+
+    /// Number of days in the year
+    #[inline]
+    pub const fn days_in_year(&self) -> u16 {
+        self.offsets[12]
+    }
+
+    /// Number of days in the given month of the year
+    ///
+    /// # Panics
+    ///
+    /// Month must be between `1` and `12`. Bounds are checked using
+    /// `debug_assert` only, so that the checks are not present in release
+    /// builds, similar to integer overflow checks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use datealgo::yearinfo::YearInfo;
+    ///
+    /// assert_eq!(YearInfo::new(2023).days_in_month(2), 28);
+    /// assert_eq!(YearInfo::new(2024).days_in_month(2), 29);
+    /// assert_eq!(YearInfo::new(2024).days_in_month(12), 31);
+    /// ```
+    #[inline]
+    pub const fn days_in_month(&self, m: u8) -> u8 {
+        debug_assert!(m >= consts::MONTH_MIN && m <= consts::MONTH_MAX, "given month is out of range");
+        let m = (m.wrapping_sub(1) % 12) as usize;
+        (self.offsets[m + 1] - self.offsets[m]) as u8
+    }
+
+    /// Convert month and day of the year to Rata Die
+    ///
+    /// Given a `(month, day)` tuple returns the days since Unix epoch (January
+    /// 1st, 1970).
+    ///
+    /// # Panics
+    ///
+    /// Month must be between `1` and `12`. Day must be between `1` and the
+    /// number of days in the month in question. Bounds are checked using
+    /// `debug_assert` only, so that the checks are not present in release
+    /// builds, similar to integer overflow checks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use datealgo::yearinfo::YearInfo;
+    ///
+    /// let info = YearInfo::new(1970);
+    /// assert_eq!(info.date_to_rd((1, 1)), 0);
+    /// assert_eq!(info.date_to_rd((12, 31)), 364);
+    /// ```
+    ///
+    /// # Algorithm
+    ///
+    /// Table lookup of the month offset.
+    #[inline]
+    pub const fn date_to_rd(&self, (m, d): (u8, u8)) -> i32 {
+        debug_assert!(m >= consts::MONTH_MIN && m <= consts::MONTH_MAX, "given month is out of range");
+        debug_assert!(d >= consts::DAY_MIN && d <= self.days_in_month(m), "given day is out of range");
+        let m = (m.wrapping_sub(1) % 12) as usize;
+        self.start + self.offsets[m] as i32 + d as i32 - 1
+    }
+
+    /// Convert day of year to month and day
+    ///
+    /// Given a day of year, starting from `1` for January 1st, returns a
+    /// `(month, day)` tuple.
+    ///
+    /// # Panics
+    ///
+    /// Day of year must be between `1` and the number of days in the year.
+    /// Bounds are checked using `debug_assert` only, so that the checks are not
+    /// present in release builds, similar to integer overflow checks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use datealgo::yearinfo::YearInfo;
+    ///
+    /// assert_eq!(YearInfo::new(2023).yday_to_month_day(60), (3, 1));
+    /// assert_eq!(YearInfo::new(2024).yday_to_month_day(60), (2, 29));
+    /// assert_eq!(YearInfo::new(2024).yday_to_month_day(366), (12, 31));
+    /// ```
+    ///
+    /// # Algorithm
+    ///
+    /// Every month has at most 31 days, so dividing the zero based day of year
+    /// by 31 gives either the correct month or the one before it, which is
+    /// corrected with a single table lookup.
+    #[inline]
+    pub const fn yday_to_month_day(&self, yday: u16) -> (u8, u8) {
+        debug_assert!(yday >= 1 && yday <= self.days_in_year(), "given day of year is out of range");
+        let n = yday.wrapping_sub(1) % 366;
+        let m = (n / 31) as usize;
+        let m = m + (self.offsets[m + 1] <= n) as usize;
+        let d = n - self.offsets[m] + 1;
+        (m as u8 + 1, d as u8)
+    }
+
+    /// Convert Rata Die within the year to month and day
+    ///
+    /// Given a day counting from Unix epoch (January 1st, 1970) that falls
+    /// within the year, returns a `(month, day)` tuple.
+    ///
+    /// # Panics
+    ///
+    /// Argument must fall within the year. Bounds are checked using
+    /// `debug_assert` only, so that the checks are not present in release
+    /// builds, similar to integer overflow checks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use datealgo::yearinfo::YearInfo;
+    ///
+    /// let info = YearInfo::new(1970);
+    /// assert_eq!(info.rd_to_month_day(0), (1, 1));
+    /// assert_eq!(info.rd_to_month_day(58), (2, 28));
+    /// assert_eq!(info.rd_to_month_day(364), (12, 31));
+    /// ```
+    ///
+    /// # Algorithm
+    ///
+    /// Same as [yday_to_month_day](YearInfo::yday_to_month_day).
+    #[inline]
+    pub const fn rd_to_month_day(&self, n: i32) -> (u8, u8) {
+        debug_assert!(
+            n >= self.start && n < self.start + self.offsets[12] as i32,
+            "given rata die is out of range"
+        );
+        self.yday_to_month_day((n.wrapping_sub(self.start) as u16).wrapping_add(1))
+    }
+}
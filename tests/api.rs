@@ -1,5 +1,5 @@
-#[rustfmt::skip]
-    
+#![allow(clippy::bool_assert_comparison)]
+
 use datealgo::*;
 use std::time::{Duration, UNIX_EPOCH};
 
@@ -114,6 +114,50 @@ fn test_prev_date() {
     assert_eq!(prev_date((YEAR_MIN, 1, 2)), (YEAR_MIN, 1, 1));
 }
 
+#[test]
+fn test_rd_to_year_month() {
+    for rd in (-1000000..1000000).chain(RD_MIN..RD_MIN + 1000).chain(RD_MAX - 1000..=RD_MAX) {
+        let (y, m, _) = rd_to_date(rd);
+        assert_eq!(rd_to_year_month(rd), (y, m));
+    }
+}
+
+#[test]
+fn test_rd_to_year() {
+    for rd in (-1000000..1000000).chain(RD_MIN..RD_MIN + 1000).chain(RD_MAX - 1000..=RD_MAX) {
+        assert_eq!(rd_to_year(rd), rd_to_date(rd).0);
+    }
+}
+
+#[test]
+fn test_secs_to_year() {
+    for secs in (-100000..100000).map(|s| s * 86399).chain([RD_SECONDS_MIN, RD_SECONDS_MAX, -1, 0]) {
+        assert_eq!(secs_to_year(secs), secs_to_datetime(secs).0);
+    }
+}
+
+#[test]
+fn test_next_date_n() {
+    for rd in date_to_rd((1999, 1, 1))..date_to_rd((2002, 1, 1)) {
+        for n in 0..=28 {
+            assert_eq!(next_date_n(rd_to_date(rd), n), rd_to_date(rd + n as i32));
+        }
+    }
+    assert_eq!(next_date_n((YEAR_MAX, 12, 3), 28), (YEAR_MAX, 12, 31));
+    assert_eq!(next_date_n((YEAR_MIN, 1, 1), 28), (YEAR_MIN, 1, 29));
+}
+
+#[test]
+fn test_prev_date_n() {
+    for rd in date_to_rd((1999, 1, 1))..date_to_rd((2002, 1, 1)) {
+        for n in 0..=28 {
+            assert_eq!(prev_date_n(rd_to_date(rd), n), rd_to_date(rd - n as i32));
+        }
+    }
+    assert_eq!(prev_date_n((YEAR_MAX, 12, 31), 28), (YEAR_MAX, 12, 3));
+    assert_eq!(prev_date_n((YEAR_MIN, 1, 29), 28), (YEAR_MIN, 1, 1));
+}
+
 #[test]
 fn test_secs_to_dhms() {
     assert_eq!(secs_to_dhms(RD_SECONDS_MIN), (RD_MIN, 0, 0, 0));
@@ -138,6 +182,13 @@ fn test_datetime_to_secs() {
     assert_eq!(datetime_to_secs((YEAR_MAX, 12, 31, 23, 59, 59)), RD_SECONDS_MAX);
 }
 
+#[test]
+fn test_unchecked() {
+    for s in (-1_000_000..1_000_000).chain([RD_SECONDS_MIN, RD_SECONDS_MAX]) {
+        assert_eq!(datetime_to_secs_unchecked(secs_to_datetime(s)), s);
+    }
+}
+
 #[test]
 fn test_is_leap_year() {
     assert_eq!(is_leap_year(0), true);
@@ -358,6 +409,122 @@ fn test_isoweeks_in_year() {
     assert_eq!(isoweeks_in_year(YEAR_MAX), 53);
 }
 
+#[test]
+fn test_rd_to_isoweekyear() {
+    for rd in (RD_MIN..=RD_MAX)
+        .step_by(997)
+        .chain(RD_MIN..RD_MIN + 20)
+        .chain(RD_MAX - 20..=RD_MAX)
+    {
+        assert_eq!(rd_to_isoweekyear(rd), rd_to_isoweekdate(rd).0);
+    }
+}
+
+#[test]
+fn test_isoweekdate_ordinal() {
+    for rd in (RD_MIN..=RD_MAX)
+        .step_by(997)
+        .chain(-1000..1000)
+        .chain(RD_MIN..RD_MIN + 20)
+        .chain(RD_MAX - 20..=RD_MAX)
+    {
+        let (y, m, d) = rd_to_date(rd);
+        let ordinal = (y, (rd - date_to_rd((y, 1, 1)) + 1) as u16);
+        let isoweekdate = rd_to_isoweekdate(rd);
+        assert_eq!(isoweekdate_to_ordinal(isoweekdate), ordinal, "{:?}", (y, m, d));
+        assert_eq!(ordinal_to_isoweekdate(ordinal), isoweekdate, "{:?}", (y, m, d));
+    }
+}
+
+#[test]
+fn test_add_isoweeks() {
+    for rd in (RD_MIN..=RD_MAX).step_by(9973).chain(-1000..1000) {
+        let date = rd_to_isoweekdate(rd);
+        for n in [-1000, -53, -52, -1, 0, 1, 52, 53, 1000] {
+            let target = rd as i64 + n as i64 * 7;
+            if (RD_MIN as i64..=RD_MAX as i64).contains(&target) {
+                let expected = rd_to_isoweekdate(target as i32);
+                assert_eq!(add_isoweeks(date, n), expected);
+                assert_eq!(expected.2, date.2);
+                assert_eq!(isoweekdate_checked_add(date, n), Some(expected));
+            } else {
+                assert_eq!(isoweekdate_checked_add(date, n), None);
+            }
+        }
+    }
+    assert_eq!(isoweekdate_checked_add((2023, 0, 1), 0), None);
+    assert_eq!(isoweekdate_checked_add((2023, 53, 1), 0), None);
+    assert_eq!(isoweekdate_checked_add((2023, 1, 0), 0), None);
+    assert_eq!(isoweekdate_checked_add((YEAR_MAX + 1, 1, 1), 0), None);
+    assert_eq!(isoweekdate_checked_add((YEAR_MIN - 1, 1, 1), 0), None);
+    assert_eq!(isoweekdate_checked_add((YEAR_MAX, 53, 5), -1), None);
+    assert_eq!(isoweekdate_checked_add((YEAR_MIN, 1, 1), i32::MAX), None);
+    assert_eq!(isoweekdate_checked_add((YEAR_MAX, 53, 4), i32::MIN), None);
+}
+
+#[test]
+fn test_yearweek_u32() {
+    for y in (0..=YEAR_MAX).step_by(997).chain(1900..2100).chain([YEAR_MAX]) {
+        for w in 0..=99 {
+            let n = y as u32 * 100 + w as u32;
+            if (1..=isoweeks_in_year(y)).contains(&w) {
+                assert_eq!(isoweek_to_yearweek_u32((y, w)), n);
+                assert_eq!(yearweek_u32_to_isoweek(n), Some((y, w)));
+            } else {
+                assert_eq!(yearweek_u32_to_isoweek(n), None);
+            }
+        }
+    }
+    assert_eq!(yearweek_u32_to_isoweek((YEAR_MAX as u32 + 1) * 100 + 1), None);
+    assert_eq!(yearweek_u32_to_isoweek(u32::MAX), None);
+    assert_eq!(yearweek_u32_to_isoweek(0), None);
+}
+
+#[test]
+fn test_yyyymmdd_i32() {
+    for y in (-214748i32..=214748)
+        .step_by(9973)
+        .chain(-5..=5)
+        .chain(1999..2001)
+        .chain([-214748, 214748])
+    {
+        for m in 0..=99 {
+            for d in 0..=99 {
+                let n = y.abs() as i64 * 10000 + m as i64 * 100 + d as i64;
+                let Ok(n) = i32::try_from(if y < 0 { -n } else { n }) else {
+                    continue;
+                };
+                if (1..=12).contains(&m) && (1..=days_in_month(y, m)).contains(&d) {
+                    assert_eq!(date_to_i32_yyyymmdd((y, m, d)), n);
+                    assert_eq!(i32_yyyymmdd_to_date(n), Some((y, m, d)));
+                } else {
+                    assert_eq!(i32_yyyymmdd_to_date(n), None);
+                }
+            }
+        }
+    }
+    assert_eq!(date_to_i32_yyyymmdd((214748, 12, 31)), 2147481231);
+    assert_eq!(date_to_i32_yyyymmdd((-214748, 12, 31)), -2147481231);
+    assert_eq!(i32_yyyymmdd_to_date(i32::MAX), None);
+    assert_eq!(i32_yyyymmdd_to_date(i32::MIN), None);
+    assert_eq!(i32_yyyymmdd_to_date(0), None);
+    assert_eq!(i32_yyyymmdd_to_date(229), Some((0, 2, 29)));
+    assert_eq!(i32_yyyymmdd_to_date(-229), None);
+}
+
+#[test]
+fn test_isoweekyear_to_rd_range() {
+    for y in (YEAR_MIN..=YEAR_MAX).step_by(97).chain(-400..400).chain([YEAR_MAX]) {
+        let (first, last) = isoweekyear_to_rd_range(y);
+        assert_eq!(first, isoweekdate_to_rd((y, 1, 1)));
+        assert_eq!((last - first + 1) / 7, isoweeks_in_year(y) as i32);
+        if y != YEAR_MAX {
+            assert_eq!(rd_to_isoweekdate(last), (y, isoweeks_in_year(y), 7));
+            assert_eq!(rd_to_isoweekdate(last + 1), (y + 1, 1, 1));
+        }
+    }
+}
+
 #[test]
 fn test_systemtime_to_secs() {
     assert_eq!(systemtime_to_secs(UNIX_EPOCH), Some((0, 0)));
@@ -425,3 +592,237 @@ fn test_datetime_to_systemtime() {
         UNIX_EPOCH.checked_sub(Duration::from_secs(-RD_SECONDS_MIN as u64))
     );
 }
+
+#[test]
+fn test_duration_since_epoch_to_datetime() {
+    assert_eq!(duration_since_epoch_to_datetime(Duration::ZERO), Some((1970, 1, 1, 0, 0, 0, 0)));
+    assert_eq!(
+        duration_since_epoch_to_datetime(Duration::new(RD_SECONDS_MAX as u64, 999_999_999)),
+        Some((YEAR_MAX, 12, 31, 23, 59, 59, 999_999_999))
+    );
+    assert_eq!(
+        duration_since_epoch_to_datetime(Duration::from_secs(RD_SECONDS_MAX as u64 + 1)),
+        None
+    );
+    for secs in (0..=RD_SECONDS_MAX).step_by(999_999_937) {
+        let dur = Duration::new(secs as u64, 123);
+        let dt = duration_since_epoch_to_datetime(dur).unwrap();
+        assert_eq!(datetime_to_duration_since_epoch(dt), Some(dur));
+        assert_eq!(Some(dt), systemtime_to_datetime(UNIX_EPOCH + dur));
+    }
+}
+
+#[test]
+fn test_datetime_to_duration_since_epoch() {
+    assert_eq!(datetime_to_duration_since_epoch((1970, 1, 1, 0, 0, 0, 0)), Some(Duration::ZERO));
+    assert_eq!(
+        datetime_to_duration_since_epoch((YEAR_MAX, 12, 31, 23, 59, 59, 1)),
+        Some(Duration::new(RD_SECONDS_MAX as u64, 1))
+    );
+    assert_eq!(datetime_to_duration_since_epoch((1969, 12, 31, 23, 59, 59, 0)), None);
+    assert_eq!(datetime_to_duration_since_epoch((YEAR_MIN, 1, 1, 0, 0, 0, 0)), None);
+}
+
+#[test]
+fn test_secs_to_tm() {
+    assert_eq!(
+        secs_to_tm(RD_SECONDS_MIN),
+        Tm {
+            year: YEAR_MIN,
+            month: 1,
+            day: 1,
+            hour: 0,
+            minute: 0,
+            second: 0,
+            weekday: rd_to_weekday(RD_MIN),
+            yday: 1
+        }
+    );
+    assert_eq!(
+        secs_to_tm(RD_SECONDS_MAX),
+        Tm {
+            year: YEAR_MAX,
+            month: 12,
+            day: 31,
+            hour: 23,
+            minute: 59,
+            second: 59,
+            weekday: rd_to_weekday(RD_MAX),
+            yday: 365 + is_leap_year(YEAR_MAX) as u16
+        }
+    );
+    for secs in (RD_SECONDS_MIN..=RD_SECONDS_MAX).step_by(86_399_977) {
+        let tm = secs_to_tm(secs);
+        let (y, m, d, hh, mm, ss) = secs_to_datetime(secs);
+        assert_eq!((tm.year, tm.month, tm.day, tm.hour, tm.minute, tm.second), (y, m, d, hh, mm, ss));
+        let n = date_to_rd((y, m, d));
+        assert_eq!(tm.weekday, rd_to_weekday(n));
+        assert_eq!(tm.yday as i32, n - date_to_rd((y, 1, 1)) + 1);
+    }
+}
+
+#[test]
+fn test_tm_to_secs() {
+    assert_eq!(tm_to_secs(&secs_to_tm(RD_SECONDS_MIN)), RD_SECONDS_MIN);
+    assert_eq!(tm_to_secs(&secs_to_tm(RD_SECONDS_MAX)), RD_SECONDS_MAX);
+    for secs in (RD_SECONDS_MIN..=RD_SECONDS_MAX).step_by(86_399_977) {
+        let mut tm = secs_to_tm(secs);
+        assert_eq!(tm_to_secs(&tm), secs);
+        tm.weekday = 0;
+        tm.yday = 0;
+        assert_eq!(tm_to_secs(&tm), secs);
+    }
+}
+
+#[test]
+fn test_mktime_utc() {
+    let tm = |tm: Tm| {
+        (
+            tm.year,
+            tm.month as i32,
+            tm.day as i32,
+            tm.hour as i32,
+            tm.minute as i32,
+            tm.second as i32,
+        )
+    };
+    for secs in (RD_SECONDS_MIN..=RD_SECONDS_MAX).step_by(86_399_977) {
+        assert_eq!(mktime_utc(tm(secs_to_tm(secs))), (secs, secs_to_tm(secs)));
+    }
+    let base = datetime_to_secs((2024, 1, 1, 0, 0, 0));
+    assert_eq!(mktime_utc((2023, 13, 1, 0, 0, 0)).0, base);
+    assert_eq!(mktime_utc((2024, 0, 1, 0, 0, 0)).0, datetime_to_secs((2023, 12, 1, 0, 0, 0)));
+    assert_eq!(mktime_utc((2024, 3, 0, 0, 0, 0)).0, datetime_to_secs((2024, 2, 29, 0, 0, 0)));
+    assert_eq!(mktime_utc((2024, 1, 1, 0, 0, 255)).0, base + 255);
+    assert_eq!(mktime_utc((2024, 1, 1, 0, 255, 0)).0, base + 255 * 60);
+    assert_eq!(mktime_utc((2024, 1, 1, 255, 0, 0)).0, base + 255 * 3600);
+    assert_eq!(mktime_utc((2024, 1, 255, 0, 0, 0)).0, base + 254 * 86400);
+    assert_eq!(mktime_utc((2000, 255, 1, 0, 0, 0)).0, datetime_to_secs((2021, 3, 1, 0, 0, 0)));
+    assert_eq!(mktime_utc((2024, 1, 1, 0, 0, 3600)).0, base + 3600);
+    assert_eq!(mktime_utc((2024, 1, 1, 0, 0, -1)).0, base - 1);
+    assert_eq!(mktime_utc((2024, 1, 1, 0, -1, 0)).0, base - 60);
+    assert_eq!(mktime_utc((2024, 1, 1, -1, 0, 0)).0, base - 3600);
+    assert_eq!(mktime_utc((2024, 1, -5, 0, 0, 0)).0, datetime_to_secs((2023, 12, 26, 0, 0, 0)));
+    assert_eq!(mktime_utc((2024, -1, 1, 0, 0, 0)).0, datetime_to_secs((2023, 11, 1, 0, 0, 0)));
+    assert_eq!(mktime_utc((2024, -12, 1, 0, 0, 0)).0, datetime_to_secs((2022, 12, 1, 0, 0, 0)));
+    assert_eq!(mktime_utc((2024, -11, 1, 0, 0, 0)).0, datetime_to_secs((2023, 1, 1, 0, 0, 0)));
+    assert_eq!(mktime_utc((2024, 1, 1, 0, 0, i32::MAX)).0, base + i32::MAX as i64);
+    assert_eq!(mktime_utc((2024, 1, 1, 0, 0, i32::MIN)).0, base + i32::MIN as i64);
+    let (secs, norm) = mktime_utc((2023, 2, 29, 23, 59, 60));
+    assert_eq!(secs, datetime_to_secs((2023, 3, 2, 0, 0, 0)));
+    assert_eq!(norm, secs_to_tm(secs));
+    assert_eq!((norm.weekday, norm.yday), (4, 61));
+    assert_eq!(mktime_utc((YEAR_MIN, 1, 1, 0, 0, 0)).0, RD_SECONDS_MIN);
+    assert_eq!(mktime_utc((YEAR_MAX, 12, 31, 23, 59, 59)).0, RD_SECONDS_MAX);
+}
+
+#[test]
+fn test_resolve_local_secs() {
+    let t = 1_000_000;
+    for local in t - 10_000..t + 10_000 {
+        assert_eq!(resolve_local_secs(local, t, 3600, 3600), LocalResult::Unique(local - 3600));
+        let expected = match local - t {
+            d if d < 3600 => LocalResult::Unique(local - 3600),
+            d if d < 7200 => LocalResult::Gap(local - 3600, 3600),
+            _ => LocalResult::Unique(local - 7200),
+        };
+        assert_eq!(resolve_local_secs(local, t, 3600, 7200), expected);
+        let expected = match local - t {
+            d if d < 3600 => LocalResult::Unique(local - 7200),
+            d if d < 7200 => LocalResult::Ambiguous(local - 7200, local - 3600),
+            _ => LocalResult::Unique(local - 3600),
+        };
+        assert_eq!(resolve_local_secs(local, t, 7200, 3600), expected);
+    }
+    let t = RD_SECONDS_MAX;
+    assert_eq!(resolve_local_secs(t - 1, t, 0, -86400), LocalResult::Ambiguous(t - 1, t + 86399));
+    assert_eq!(resolve_local_secs(t, t, 0, 86400), LocalResult::Gap(t, 86400));
+}
+
+#[test]
+fn test_datetime_offset() {
+    for secs in (RD_SECONDS_MIN + 86400..RD_SECONDS_MAX - 86400).step_by(86_399_977) {
+        for offset in [-86399, -43200, -3600, 0, 1, 19800, 50400, 86399] {
+            let dt = secs_to_datetime_offset(secs, offset);
+            assert_eq!(dt, secs_to_datetime(secs + offset as i64));
+            assert_eq!(datetime_offset_to_secs(dt, offset), secs);
+        }
+    }
+    assert_eq!(secs_to_datetime_offset(RD_SECONDS_MIN, 0), (YEAR_MIN, 1, 1, 0, 0, 0));
+    assert_eq!(secs_to_datetime_offset(RD_SECONDS_MAX, 0), (YEAR_MAX, 12, 31, 23, 59, 59));
+    assert_eq!(datetime_offset_to_secs((YEAR_MIN, 1, 1, 0, 0, 0), -86399), RD_SECONDS_MIN + 86399);
+    assert_eq!(
+        datetime_offset_to_secs((YEAR_MAX, 12, 31, 23, 59, 59), 86399),
+        RD_SECONDS_MAX - 86399
+    );
+}
+
+#[test]
+fn test_partial_date_to_date() {
+    for y in [YEAR_MIN, -1, 0, 1970, 2000, 2023, 2024, 2026, YEAR_MAX] {
+        let start = date_to_rd((y, 1, 1));
+        let end = date_to_rd((y, 12, 31));
+        for rd in start..=end {
+            let (_, m, d) = rd_to_date(rd);
+            let date = Some(rd_to_date(rd));
+            let yday = (rd - start) as u16;
+            let wd = rd_to_weekday(rd);
+            let sunday_week = ((yday + 7 - wd as u16 % 7) / 7) as u8;
+            let monday_week = ((yday + 7 - (wd as u16 - 1)) / 7) as u8;
+            assert_eq!(partial_date_to_date(PartialDate::YearDay(y, yday + 1)), date);
+            assert_eq!(partial_date_to_date(PartialDate::MonthWeekday(y, m, (d - 1) / 7 + 1, wd)), date);
+            assert_eq!(partial_date_to_date(PartialDate::SundayWeek(y, sunday_week, wd)), date);
+            assert_eq!(partial_date_to_date(PartialDate::MondayWeek(y, monday_week, wd)), date);
+            if (RD_MIN + 3..=RD_MAX - 3).contains(&rd) {
+                let (iy, iw, id) = rd_to_isoweekdate(rd);
+                assert_eq!(partial_date_to_date(PartialDate::IsoWeek(iy, iw, id)), date);
+            }
+        }
+        assert_eq!(partial_date_to_date(PartialDate::YearDay(y, 0)), None);
+        assert_eq!(partial_date_to_date(PartialDate::YearDay(y, 367)), None);
+        assert_eq!(partial_date_to_date(PartialDate::SundayWeek(y, 54, 7)), None);
+        assert_eq!(partial_date_to_date(PartialDate::MondayWeek(y, 54, 1)), None);
+        assert_eq!(partial_date_to_date(PartialDate::IsoWeek(y, 54, 1)), None);
+        assert_eq!(partial_date_to_date(PartialDate::IsoWeek(y, 0, 1)), None);
+    }
+    assert_eq!(partial_date_to_date(PartialDate::YearDay(2023, 366)), None);
+    assert_eq!(partial_date_to_date(PartialDate::YearDay(2024, 366)), Some((2024, 12, 31)));
+    assert_eq!(partial_date_to_date(PartialDate::IsoWeek(2023, 53, 1)), None);
+    assert_eq!(partial_date_to_date(PartialDate::IsoWeek(2026, 53, 7)), Some((2027, 1, 3)));
+    assert_eq!(partial_date_to_date(PartialDate::IsoWeek(YEAR_MIN, 1, 1)), Some((YEAR_MIN, 1, 1)));
+    assert_eq!(partial_date_to_date(PartialDate::IsoWeek(YEAR_MAX, 53, 7)), None);
+    assert_eq!(partial_date_to_date(PartialDate::MonthWeekday(2023, 2, 5, 1)), None);
+    assert_eq!(partial_date_to_date(PartialDate::MonthWeekday(2023, 13, 1, 1)), None);
+    assert_eq!(partial_date_to_date(PartialDate::MonthWeekday(2023, 1, 0, 1)), None);
+    assert_eq!(partial_date_to_date(PartialDate::MonthWeekday(2023, 1, 1, 0)), None);
+    assert_eq!(partial_date_to_date(PartialDate::MonthWeekday(2023, 1, 1, 8)), None);
+    assert_eq!(partial_date_to_date(PartialDate::SundayWeek(2023, 0, 7)), None);
+    assert_eq!(partial_date_to_date(PartialDate::YearDay(YEAR_MIN - 1, 1)), None);
+    assert_eq!(partial_date_to_date(PartialDate::YearDay(YEAR_MAX + 1, 1)), None);
+}
+
+#[test]
+fn test_hhmmss_u32() {
+    for h in 0..=99 {
+        for m in 0..=99 {
+            for s in 0..=99 {
+                let n = h as u32 * 10000 + m as u32 * 100 + s as u32;
+                if h <= 23 && m <= 59 && s <= 59 {
+                    assert_eq!(time_to_u32_hhmmss((h, m, s)), n);
+                    assert_eq!(u32_hhmmss_to_time(n), Some((h, m, s)));
+                    for ms in [0, 1, 500, 999] {
+                        assert_eq!(time_to_u32_hhmmssmmm((h, m, s, ms)), n * 1000 + ms as u32);
+                        assert_eq!(u32_hhmmssmmm_to_time(n * 1000 + ms as u32), Some((h, m, s, ms)));
+                    }
+                } else {
+                    assert_eq!(u32_hhmmss_to_time(n), None);
+                    assert_eq!(u32_hhmmssmmm_to_time(n * 1000), None);
+                }
+            }
+        }
+    }
+    assert_eq!(u32_hhmmss_to_time(1000000), None);
+    assert_eq!(u32_hhmmss_to_time(u32::MAX), None);
+    assert_eq!(u32_hhmmssmmm_to_time(u32::MAX), None);
+    assert_eq!(u32_hhmmssmmm_to_time(1_000_000_000), None);
+}
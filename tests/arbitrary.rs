@@ -0,0 +1,36 @@
+#![cfg(feature = "arbitrary")]
+
+use arbitrary::{Arbitrary, Unstructured};
+use datealgo::arbitrary::*;
+use datealgo::{date_to_rd, datetime_to_secs, isoweekdate_to_rd, isoweeks_in_year, rd_to_date, RD_MAX, RD_MIN};
+
+#[test]
+fn test_valid() {
+    let data: Vec<u8> = (0..100_000u32).map(|i| (i.wrapping_mul(2654435761) >> 13) as u8).collect();
+    let mut u = Unstructured::new(&data);
+    while !u.is_empty() {
+        let ArbDate(date) = ArbDate::arbitrary(&mut u).unwrap();
+        assert_eq!(rd_to_date(date_to_rd(date)), date);
+        let ArbDateTime((y, m, d, hh, mm, ss)) = ArbDateTime::arbitrary(&mut u).unwrap();
+        assert!(hh < 24 && mm < 60 && ss < 60);
+        assert_eq!(
+            datealgo::secs_to_datetime(datetime_to_secs((y, m, d, hh, mm, ss))),
+            (y, m, d, hh, mm, ss)
+        );
+        let ArbIsoWeekDate((y, w, d)) = ArbIsoWeekDate::arbitrary(&mut u).unwrap();
+        assert!((1..=isoweeks_in_year(y)).contains(&w) && (1..=7).contains(&d));
+        let n = isoweekdate_to_rd((y, w, d));
+        assert!((RD_MIN..=RD_MAX).contains(&n));
+    }
+}
+
+#[test]
+fn test_bounds() {
+    let mut u = Unstructured::new(&[]);
+    assert_eq!(ArbDate::arbitrary(&mut u).unwrap(), ArbDate(rd_to_date(RD_MIN)));
+    let mut u = Unstructured::new(&[]);
+    assert_eq!(
+        ArbIsoWeekDate::arbitrary(&mut u).unwrap(),
+        ArbIsoWeekDate(datealgo::rd_to_isoweekdate(RD_MIN))
+    );
+}
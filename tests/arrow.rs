@@ -0,0 +1,118 @@
+#![cfg(feature = "arrow")]
+
+use arrow_array::types::{TimestampMicrosecondType, TimestampMillisecondType, TimestampNanosecondType, TimestampSecondType};
+use arrow_array::{Array, Date32Array, Date64Array, Int32Array, TimestampNanosecondArray, TimestampSecondArray, UInt32Array, UInt8Array};
+use datealgo::arrow::*;
+use datealgo::{rd_to_date, secs_to_datetime, RD_MAX, RD_MIN, RD_SECONDS_MAX, RD_SECONDS_MIN};
+
+#[test]
+fn test_date32() {
+    let ns: Vec<Option<i32>> = vec![Some(RD_MIN), Some(-1), None, Some(0), Some(19489), Some(RD_MAX), None];
+    let array = Date32Array::from(ns.clone());
+    let (years, months, days) = date32_to_date_arrays(&array);
+    for (i, n) in ns.iter().enumerate() {
+        match n {
+            Some(n) => assert_eq!((years.value(i), months.value(i), days.value(i)), rd_to_date(*n)),
+            None => assert!(years.is_null(i) && months.is_null(i) && days.is_null(i)),
+        }
+    }
+    assert_eq!(date_arrays_to_date32(&years, &months, &days).unwrap(), array);
+    let sliced = array.slice(1, 3);
+    let (years, months, days) = date32_to_date_arrays(&sliced);
+    assert_eq!((years.len(), years.null_count()), (3, 1));
+    assert_eq!(date_arrays_to_date32(&years, &months, &days).unwrap(), sliced);
+    assert!(date_arrays_to_date32(&years, &months.slice(0, 2), &days).is_none());
+}
+
+#[test]
+fn test_date32_garbage_nulls() {
+    let years = Int32Array::from(vec![Some(2023), None]);
+    let months = UInt8Array::new(vec![5, 13].into(), Some(vec![true, false].into()));
+    let days = UInt8Array::from(vec![12, 0]);
+    let array = date_arrays_to_date32(&years, &months, &days).unwrap();
+    assert_eq!(array, Date32Array::from(vec![Some(19489), None]));
+    let array = Date32Array::new(vec![0, i32::MAX].into(), Some(vec![true, false].into()));
+    let (years, _, _) = date32_to_date_arrays(&array);
+    assert_eq!(years, Int32Array::from(vec![Some(1970), None]));
+}
+
+#[test]
+fn test_date64() {
+    let ms = vec![
+        Some(RD_SECONDS_MIN * 1000),
+        Some(-1),
+        None,
+        Some(0),
+        Some(1684574678123),
+        Some(RD_SECONDS_MAX * 1000 + 999),
+    ];
+    let (years, months, days) = date64_to_date_arrays(&Date64Array::from(ms.clone()));
+    for (i, ms) in ms.iter().enumerate() {
+        match ms {
+            Some(ms) => assert_eq!(
+                (years.value(i), months.value(i), days.value(i)),
+                rd_to_date(ms.div_euclid(86400000) as i32)
+            ),
+            None => assert!(years.is_null(i)),
+        }
+    }
+    let array = date_arrays_to_date64(&years, &months, &days).unwrap();
+    let expected: Vec<Option<i64>> = ms.iter().map(|ms| ms.map(|ms| ms.div_euclid(86400000) * 86400000)).collect();
+    assert_eq!(array, Date64Array::from(expected));
+}
+
+#[test]
+fn test_timestamp() {
+    let secs = vec![
+        Some(RD_SECONDS_MIN),
+        Some(-1),
+        None,
+        Some(0),
+        Some(1684574678),
+        Some(RD_SECONDS_MAX),
+    ];
+    let array = TimestampSecondArray::from(secs.clone());
+    let (y, m, d, hh, mm, ss, nanos) = timestamp_to_datetime_arrays(&array);
+    for (i, s) in secs.iter().enumerate() {
+        match s {
+            Some(s) => {
+                let actual = (y.value(i), m.value(i), d.value(i), hh.value(i), mm.value(i), ss.value(i));
+                assert_eq!(actual, secs_to_datetime(*s));
+                assert_eq!(nanos.value(i), 0);
+            }
+            None => assert!(y.is_null(i) && hh.is_null(i) && nanos.is_null(i)),
+        }
+    }
+    let result = datetime_arrays_to_timestamp::<TimestampSecondType>(&y, &m, &d, &hh, &mm, &ss, &nanos).unwrap();
+    assert_eq!(result, array);
+
+    let ns = vec![Some(i64::MIN), Some(-1), None, Some(1684574678123456789), Some(i64::MAX)];
+    let array = TimestampNanosecondArray::from(ns.clone());
+    let (y, m, d, hh, mm, ss, nanos) = timestamp_to_datetime_arrays(&array);
+    assert_eq!(
+        (y.value(0), m.value(0), d.value(0), hh.value(0), mm.value(0), ss.value(0)),
+        (1677, 9, 21, 0, 12, 43)
+    );
+    assert_eq!(nanos.value(0), 145_224_192);
+    assert_eq!((y.value(1), ss.value(1), nanos.value(1)), (1969, 59, 999_999_999));
+    assert_eq!(nanos.value(3), 123_456_789);
+    let result = datetime_arrays_to_timestamp::<TimestampNanosecondType>(&y, &m, &d, &hh, &mm, &ss, &nanos).unwrap();
+    assert_eq!(result, array);
+    let result = datetime_arrays_to_timestamp::<TimestampMicrosecondType>(&y, &m, &d, &hh, &mm, &ss, &nanos).unwrap();
+    assert_eq!(result.value(3), 1684574678123456);
+    assert_eq!(result.value(1), -1);
+    let result = datetime_arrays_to_timestamp::<TimestampMillisecondType>(&y, &m, &d, &hh, &mm, &ss, &nanos).unwrap();
+    assert_eq!(result.value(3), 1684574678123);
+    assert!(result.is_null(2));
+
+    let y = Int32Array::from(vec![2263]);
+    let one = UInt8Array::from(vec![1]);
+    let zero = UInt8Array::from(vec![0]);
+    let nanos = UInt32Array::from(vec![0]);
+    assert!(datetime_arrays_to_timestamp::<TimestampNanosecondType>(&y, &one, &one, &zero, &zero, &zero, &nanos).is_none());
+    assert!(datetime_arrays_to_timestamp::<TimestampMicrosecondType>(&y, &one, &one, &zero, &zero, &zero, &nanos).is_some());
+    assert!(
+        datetime_arrays_to_timestamp::<TimestampMicrosecondType>(&y, &one, &one, &zero, &zero, &zero, &UInt32Array::from(vec![0, 0]))
+            .is_none()
+    );
+}
@@ -0,0 +1,37 @@
+use chrono::DateTime;
+use datealgo::asctime::{format_asctime, ASCTIME_BUF_LEN};
+use datealgo::{datetime_to_secs, rd_to_weekday, secs_to_datetime, secs_to_dhms};
+
+#[test]
+fn test_format_asctime() {
+    let start = datetime_to_secs((1000, 1, 1, 0, 0, 0));
+    let end = 253402300799;
+    let mut buf = [0xff; ASCTIME_BUF_LEN];
+    for secs in (start..=end).step_by(86_399_977) {
+        let dt = secs_to_datetime(secs);
+        let wd = rd_to_weekday(secs_to_dhms(secs).0);
+        let len = format_asctime(dt, wd, &mut buf);
+        let expected = DateTime::from_timestamp(secs, 0)
+            .unwrap()
+            .format("%a %b %e %H:%M:%S %Y\n")
+            .to_string();
+        assert_eq!(std::str::from_utf8(&buf[..len]).unwrap(), expected);
+        assert_eq!(len, 25);
+        assert_eq!(buf[len], 0);
+    }
+}
+
+#[test]
+fn test_format_asctime_years() {
+    let mut buf = [0xff; ASCTIME_BUF_LEN];
+    let len = format_asctime((999, 1, 9, 1, 2, 3), 1, &mut buf);
+    assert_eq!(&buf[..len + 1], b"Mon Jan  9 01:02:03 999\n\0");
+    let len = format_asctime((0, 2, 29, 0, 0, 0), 2, &mut buf);
+    assert_eq!(&buf[..len + 1], b"Tue Feb 29 00:00:00 0\n\0");
+    let len = format_asctime((-999, 12, 10, 0, 0, 0), 7, &mut buf);
+    assert_eq!(&buf[..len + 1], b"Sun Dec 10 00:00:00 -999\n\0");
+    let mut buf = [0xff; ASCTIME_BUF_LEN];
+    assert_eq!(format_asctime((10000, 1, 1, 0, 0, 0), 6, &mut buf), 0);
+    assert_eq!(format_asctime((-1000, 1, 1, 0, 0, 0), 6, &mut buf), 0);
+    assert_eq!(buf, [0xff; ASCTIME_BUF_LEN]);
+}
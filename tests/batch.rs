@@ -0,0 +1,83 @@
+use datealgo::batch::*;
+use datealgo::{rd_to_date, rd_to_weekday, secs_to_datetime, secs_to_dhms, RD_MAX, RD_MIN, RD_SECONDS_MAX, RD_SECONDS_MIN};
+
+#[test]
+fn test_rd_to_date_slice() {
+    let ns: Vec<i32> = (-1000..1000).chain([RD_MIN, RD_MAX, 19489, -719528]).collect();
+    let mut years = vec![0; ns.len()];
+    let mut months = vec![0; ns.len()];
+    let mut days = vec![0; ns.len()];
+    rd_to_date_slice(&ns, &mut years, &mut months, &mut days);
+    for (i, &n) in ns.iter().enumerate() {
+        assert_eq!((years[i], months[i], days[i]), rd_to_date(n));
+    }
+}
+
+#[test]
+fn test_rd_to_date_slice_aos() {
+    let ns: Vec<i32> = (-1000..1000).chain([RD_MIN, RD_MAX, 19489, -719528]).collect();
+    let mut dates = vec![(0, 0, 0); ns.len()];
+    rd_to_date_slice_aos(&ns, &mut dates);
+    for (i, &n) in ns.iter().enumerate() {
+        assert_eq!(dates[i], rd_to_date(n));
+    }
+}
+
+#[test]
+fn test_rd_to_date_slice_empty() {
+    rd_to_date_slice(&[], &mut [], &mut [], &mut []);
+    rd_to_date_slice_aos(&[], &mut []);
+}
+
+#[test]
+fn test_date_to_rd_slice() {
+    let ns: Vec<i32> = (-1000..1000).chain([RD_MIN, RD_MAX, 19489, -719528]).collect();
+    let dates: Vec<_> = ns.iter().map(|&n| rd_to_date(n)).collect();
+    let years: Vec<i32> = dates.iter().map(|d| d.0).collect();
+    let months: Vec<u8> = dates.iter().map(|d| d.1).collect();
+    let days: Vec<u8> = dates.iter().map(|d| d.2).collect();
+    let mut out = vec![0; ns.len()];
+    date_to_rd_slice(&years, &months, &days, &mut out);
+    assert_eq!(out, ns);
+}
+
+#[test]
+fn test_datetime_to_secs_slice() {
+    let secs: Vec<i64> = (-100_000..100_000)
+        .map(|s| s * 12347)
+        .chain([RD_SECONDS_MIN, RD_SECONDS_MAX, 1684574678])
+        .collect();
+    let dts: Vec<_> = secs.iter().map(|&s| secs_to_datetime(s)).collect();
+    let years: Vec<i32> = dts.iter().map(|d| d.0).collect();
+    let months: Vec<u8> = dts.iter().map(|d| d.1).collect();
+    let days: Vec<u8> = dts.iter().map(|d| d.2).collect();
+    let hours: Vec<u8> = dts.iter().map(|d| d.3).collect();
+    let minutes: Vec<u8> = dts.iter().map(|d| d.4).collect();
+    let seconds: Vec<u8> = dts.iter().map(|d| d.5).collect();
+    let mut out = vec![0; secs.len()];
+    datetime_to_secs_slice(&years, &months, &days, &hours, &minutes, &seconds, &mut out);
+    assert_eq!(out, secs);
+}
+
+#[test]
+fn test_rd_to_weekday_slice() {
+    let ns: Vec<i32> = (-1000..1000).chain([RD_MIN, RD_MAX, 19489, -719528]).collect();
+    let mut weekdays = vec![0; ns.len()];
+    rd_to_weekday_slice(&ns, &mut weekdays);
+    for (i, &n) in ns.iter().enumerate() {
+        assert_eq!(weekdays[i], rd_to_weekday(n));
+    }
+}
+
+#[test]
+fn test_secs_to_weekday_slice() {
+    let secs: Vec<i64> = (-100_000..100_000)
+        .map(|s| s * 97)
+        .chain([RD_SECONDS_MIN, RD_SECONDS_MAX])
+        .collect();
+    let mut weekdays = vec![0; secs.len()];
+    secs_to_weekday_slice(&secs, &mut weekdays);
+    for (i, &s) in secs.iter().enumerate() {
+        assert_eq!(weekdays[i], rd_to_weekday(secs_to_dhms(s).0));
+    }
+}
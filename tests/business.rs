@@ -0,0 +1,255 @@
+use datealgo::business::*;
+use datealgo::week::{WEEKEND_FRI_SAT, WEEKEND_SAT_SUN};
+use datealgo::{date_to_rd, rd_to_date, rd_to_weekday};
+
+const CONVENTIONS: [BusinessDayConvention; 5] = [
+    BusinessDayConvention::Unadjusted,
+    BusinessDayConvention::Following,
+    BusinessDayConvention::ModifiedFollowing,
+    BusinessDayConvention::Preceding,
+    BusinessDayConvention::ModifiedPreceding,
+];
+
+fn holidays() -> Vec<i32> {
+    [
+        (2023, 1, 2),
+        (2023, 4, 7),
+        (2023, 4, 10),
+        (2023, 5, 1),
+        (2023, 12, 25),
+        (2023, 12, 26),
+        (2024, 1, 1),
+    ]
+    .into_iter()
+    .map(date_to_rd)
+    .collect()
+}
+
+#[test]
+fn test_is_holiday() {
+    let holidays = holidays();
+    let holidays = &holidays[..];
+    for rd in date_to_rd((2022, 12, 1))..date_to_rd((2024, 2, 1)) {
+        assert_eq!(is_holiday(rd, holidays), holidays.contains(&rd));
+        assert!(!is_holiday(rd, &[]));
+    }
+}
+
+#[test]
+fn test_adjust_business_day() {
+    use BusinessDayConvention::*;
+    let holidays = holidays();
+    let holidays = &holidays[..];
+    let rd = date_to_rd((2023, 4, 8));
+    assert_eq!(
+        adjust_business_day(rd, Following, WEEKEND_SAT_SUN, holidays),
+        date_to_rd((2023, 4, 11))
+    );
+    assert_eq!(
+        adjust_business_day(rd, Preceding, WEEKEND_SAT_SUN, holidays),
+        date_to_rd((2023, 4, 6))
+    );
+    assert_eq!(adjust_business_day(rd, Unadjusted, WEEKEND_SAT_SUN, holidays), rd);
+    let rd = date_to_rd((2023, 4, 1));
+    assert_eq!(
+        adjust_business_day(rd, Preceding, WEEKEND_SAT_SUN, holidays),
+        date_to_rd((2023, 3, 31))
+    );
+    assert_eq!(
+        adjust_business_day(rd, ModifiedPreceding, WEEKEND_SAT_SUN, holidays),
+        date_to_rd((2023, 4, 3))
+    );
+    let rd = date_to_rd((2023, 12, 31));
+    assert_eq!(
+        adjust_business_day(rd, ModifiedFollowing, WEEKEND_SAT_SUN, holidays),
+        date_to_rd((2023, 12, 29))
+    );
+    assert_eq!(adjust_business_day(rd, ModifiedFollowing, WEEKEND_FRI_SAT, holidays), rd);
+}
+
+#[test]
+fn test_adjust_business_day_properties() {
+    let holidays = holidays();
+    let holidays = &holidays[..];
+    for mask in [0, WEEKEND_SAT_SUN, WEEKEND_FRI_SAT, 0x3f] {
+        for rd in date_to_rd((2022, 12, 1))..date_to_rd((2024, 2, 1)) {
+            let business = mask & (1 << (rd_to_weekday(rd) - 1)) == 0 && !holidays.contains(&rd);
+            assert_eq!(is_business_day(rd, mask, holidays), business);
+            for convention in CONVENTIONS {
+                let adjusted = adjust_business_day(rd, convention, mask, holidays);
+                if business || convention == BusinessDayConvention::Unadjusted {
+                    assert_eq!(adjusted, rd);
+                    continue;
+                }
+                assert!(is_business_day(adjusted, mask, holidays));
+                let (lo, hi) = (adjusted.min(rd), adjusted.max(rd));
+                let skipped = (lo + 1..hi).filter(|&n| is_business_day(n, mask, holidays)).count();
+                let month = |n| rd_to_date(n).1;
+                match convention {
+                    BusinessDayConvention::Following => assert!(adjusted > rd && skipped == 0),
+                    BusinessDayConvention::Preceding => assert!(adjusted < rd && skipped == 0),
+                    _ => assert!(skipped == 0 || month(adjusted) == month(rd)),
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_holiday_calendar() {
+    let holidays = holidays();
+    let start = date_to_rd((2022, 12, 1));
+    let cal: HolidayCalendar<8> = HolidayCalendar::from_sorted(start, &holidays);
+    assert_eq!(cal.start(), start);
+    assert_eq!(cal.last(), start + 511);
+    assert_eq!(cal.count(), holidays.len() as u32);
+    for rd in start - 100..cal.last() + 100 {
+        assert_eq!(cal.is_holiday(rd), holidays.contains(&rd));
+        assert_eq!(Holidays::is_holiday(&cal, rd), holidays.contains(&rd));
+        for mask in [0, WEEKEND_SAT_SUN, WEEKEND_FRI_SAT] {
+            assert_eq!(cal.is_business_day(rd, mask), is_business_day(rd, mask, &holidays[..]));
+            assert_eq!(is_business_day(rd, mask, &cal), is_business_day(rd, mask, &holidays[..]));
+            for convention in CONVENTIONS {
+                assert_eq!(
+                    adjust_business_day(rd, convention, mask, &cal),
+                    adjust_business_day(rd, convention, mask, &holidays[..])
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_holiday_calendar_wider_list() {
+    let holidays = holidays();
+    let start = date_to_rd((2023, 3, 1));
+    let cal: HolidayCalendar<2> = HolidayCalendar::from_sorted(start, &holidays);
+    assert_eq!(cal.count(), 3);
+    for rd in start - 400..cal.last() + 400 {
+        let inside = rd >= start && rd <= cal.last();
+        assert_eq!(cal.is_holiday(rd), inside && holidays.contains(&rd));
+    }
+    let cal: HolidayCalendar<1> = HolidayCalendar::new(start).with_holiday(start - 1).with_holiday(start + 64);
+    assert_eq!(cal.count(), 0);
+    let cal: HolidayCalendar<1> = HolidayCalendar::new(start).with_holiday(i32::MIN).with_holiday(i32::MAX);
+    assert_eq!(cal.count(), 0);
+}
+
+#[test]
+fn test_holiday_calendar_set_operations() {
+    let start = date_to_rd((2023, 1, 1));
+    let a: HolidayCalendar<2> = HolidayCalendar::from_sorted(start, &[start, start + 10, start + 64, start + 127]);
+    let b: HolidayCalendar<2> = HolidayCalendar::new(start).with_holiday(start + 10).with_holiday(start + 100);
+    let empty: HolidayCalendar<2> = HolidayCalendar::new(start);
+    assert_eq!(empty.count(), 0);
+    let union = a.union(&b);
+    let intersection = a.intersection(&b);
+    let difference = a.difference(&b);
+    for rd in start..=a.last() {
+        assert_eq!(union.is_holiday(rd), a.is_holiday(rd) || b.is_holiday(rd));
+        assert_eq!(intersection.is_holiday(rd), a.is_holiday(rd) && b.is_holiday(rd));
+        assert_eq!(difference.is_holiday(rd), a.is_holiday(rd) && !b.is_holiday(rd));
+    }
+    assert_eq!((union.count(), intersection.count(), difference.count()), (5, 1, 3));
+    assert_eq!(a.union(&empty), a);
+    assert_eq!(a.intersection(&empty), empty);
+}
+
+#[test]
+fn test_nth_and_last_business_day_of_month() {
+    let holidays = holidays();
+    let holidays = &holidays[..];
+    for mask in [0, WEEKEND_SAT_SUN, WEEKEND_FRI_SAT, 0x7f] {
+        for (y, m) in (1..=12).map(|m| (2023, m)).chain([(2024, 1), (2024, 2)]) {
+            let days: Vec<i32> = (date_to_rd((y, m, 1))..=date_to_rd((y, m, datealgo::days_in_month(y, m))))
+                .filter(|&rd| is_business_day(rd, mask, holidays))
+                .collect();
+            for n in 1..=32 {
+                assert_eq!(
+                    nth_business_day_of_month(y, m, n, mask, holidays),
+                    days.get(n as usize - 1).copied()
+                );
+            }
+            assert_eq!(last_business_day_of_month(y, m, mask, holidays), days.last().copied());
+        }
+    }
+    assert_eq!(
+        nth_business_day_of_month(2023, 5, 1, WEEKEND_SAT_SUN, holidays),
+        Some(date_to_rd((2023, 5, 2)))
+    );
+    assert_eq!(
+        last_business_day_of_month(2023, 12, WEEKEND_SAT_SUN, holidays),
+        Some(date_to_rd((2023, 12, 29)))
+    );
+}
+
+#[test]
+fn test_settlement_date() {
+    let holidays = holidays();
+    let holidays = &holidays[..];
+    let none: &[i32] = &[];
+    for trade in date_to_rd((2023, 1, 1))..=date_to_rd((2023, 12, 31)) {
+        for n in 0..=5 {
+            let settle = settlement_date(trade, n, WEEKEND_SAT_SUN, holidays);
+            assert!(is_business_day(settle, WEEKEND_SAT_SUN, holidays));
+            let count = (trade + 1..=settle)
+                .filter(|&rd| is_business_day(rd, WEEKEND_SAT_SUN, holidays))
+                .count();
+            if n == 0 {
+                assert_eq!(
+                    settle,
+                    adjust_business_day(trade, BusinessDayConvention::Following, WEEKEND_SAT_SUN, holidays)
+                );
+            } else {
+                assert_eq!(count, n as usize);
+            }
+            assert_eq!(settlement_date_joint(trade, n, WEEKEND_SAT_SUN, holidays, 0, none), Some(settle));
+            let joint = settlement_date_joint(trade, n, WEEKEND_SAT_SUN, holidays, WEEKEND_FRI_SAT, none).unwrap();
+            assert!(joint >= settle);
+            assert!(is_business_day(joint, WEEKEND_SAT_SUN, holidays));
+            assert!(is_business_day(joint, WEEKEND_FRI_SAT, none));
+            assert!(
+                (settle..joint).all(|rd| !is_business_day(rd, WEEKEND_SAT_SUN, holidays) || !is_business_day(rd, WEEKEND_FRI_SAT, none))
+            );
+        }
+    }
+    assert_eq!(
+        settlement_date(date_to_rd((2023, 4, 6)), 2, WEEKEND_SAT_SUN, holidays),
+        date_to_rd((2023, 4, 12))
+    );
+}
+
+#[test]
+fn test_settlement_date_joint_no_common_business_day() {
+    struct Closed;
+    impl Holidays for Closed {
+        fn is_holiday(&self, _rd: i32) -> bool {
+            true
+        }
+    }
+    let holidays = holidays();
+    let none: &[i32] = &[];
+    let trade = date_to_rd((2023, 12, 20));
+    assert_eq!(settlement_date_joint(trade, 2, WEEKEND_SAT_SUN, &holidays[..], 0x1f, none), None);
+    assert_eq!(settlement_date_joint(trade, 2, 0x3f, none, 0x40, none), None);
+    assert_eq!(settlement_date_joint(trade, 2, WEEKEND_SAT_SUN, &holidays[..], 0, &Closed), None);
+    let closed: Vec<i32> = (trade..trade + 400).collect();
+    assert_eq!(
+        settlement_date_joint(trade, 2, WEEKEND_SAT_SUN, &holidays[..], 0, &closed[..]),
+        None
+    );
+    let closed: Vec<i32> = (trade..trade + 200).collect();
+    assert_eq!(
+        settlement_date_joint(trade, 2, WEEKEND_SAT_SUN, &holidays[..], 0, &closed[..]),
+        Some(trade + 201)
+    );
+}
+
+#[test]
+#[cfg(not(debug_assertions))]
+fn test_all_weekend_mask() {
+    let none: &[i32] = &[];
+    let rd = date_to_rd((2023, 12, 20));
+    assert_eq!(adjust_business_day(rd, BusinessDayConvention::Following, 0x7f, none), rd);
+    assert_eq!(settlement_date(rd, 2, 0x7f, none), rd + 2);
+}
@@ -0,0 +1,73 @@
+use datealgo::calendar::islamic::{ISLAMIC_EPOCH_CIVIL, ISLAMIC_LEAP_PATTERN_II};
+use datealgo::calendar::leapweek::LEAPWEEK_PATTERN_454;
+use datealgo::calendar::*;
+
+fn check_year<C: Calendar>(cal: &C, y: i32) {
+    let mut n = cal.date_to_rd((y, 1, 1));
+    let mut days = 0;
+    for m in 1..=cal.months_in_year(y) {
+        for d in 1..=cal.days_in_month(y, m) {
+            assert_eq!(cal.date_to_rd((y, m, d)), n);
+            assert_eq!(cal.rd_to_date(n), (y, m, d));
+            n += 1;
+            days += 1;
+        }
+    }
+    assert_eq!(cal.rd_to_date(n), (y + 1, 1, 1));
+    match days {
+        365 | 354 | 364 => assert!(!cal.is_leap_year(y)),
+        366 | 355 | 371 => assert!(cal.is_leap_year(y)),
+        _ => panic!("unexpected year length {}", days),
+    }
+}
+
+fn check<C: Calendar>(cal: &C) {
+    for y in [-1000, -1, 0, 1, 1399, 1400, 1900, 2000, 2023, 2024] {
+        check_year(cal, y);
+    }
+}
+
+#[test]
+fn test_gregorian() {
+    check(&Gregorian);
+    assert!(Gregorian.is_leap_year(2000));
+    assert_eq!(Gregorian.rd_to_date(0), (1970, 1, 1));
+}
+
+#[test]
+fn test_julian() {
+    check(&Julian);
+    assert!(Julian.is_leap_year(1900));
+    assert_eq!(Julian.rd_to_date(0), (1969, 12, 19));
+}
+
+#[test]
+fn test_islamic_tabular() {
+    let cal = IslamicTabular {
+        pattern: ISLAMIC_LEAP_PATTERN_II,
+        epoch: ISLAMIC_EPOCH_CIVIL,
+    };
+    check(&cal);
+    assert_eq!(cal.rd_to_date(0), (1389, 10, 22));
+}
+
+#[test]
+fn test_persian33() {
+    check(&Persian33);
+    assert_eq!(Persian33.rd_to_date(0), (1348, 10, 11));
+}
+
+#[test]
+fn test_french_republican() {
+    check(&FrenchRepublican);
+    assert_eq!(FrenchRepublican.months_in_year(1), 13);
+}
+
+#[test]
+fn test_leapweek() {
+    let cal = LeapWeek {
+        pattern: LEAPWEEK_PATTERN_454,
+    };
+    check(&cal);
+    assert!(cal.is_leap_year(2020));
+}
@@ -0,0 +1,48 @@
+#![cfg(feature = "capi")]
+
+use datealgo::capi::*;
+use datealgo::libc::{secs_to_tm, tm_to_secs};
+use datealgo::{datetime_to_secs, RD_SECONDS_MAX, RD_SECONDS_MIN};
+
+#[test]
+fn test_gmtime_r() {
+    let mut tm = secs_to_tm(0);
+    for secs in [RD_SECONDS_MIN, -1, 0, 1684574678, RD_SECONDS_MAX] {
+        let res = datealgo_gmtime_r(Some(&secs), Some(&mut tm)).unwrap();
+        assert_eq!(tm_to_secs(res), secs);
+    }
+    assert!(datealgo_gmtime_r(Some(&(RD_SECONDS_MIN - 1)), Some(&mut tm)).is_none());
+    assert!(datealgo_gmtime_r(Some(&(RD_SECONDS_MAX + 1)), Some(&mut tm)).is_none());
+    assert_eq!(tm_to_secs(&tm), RD_SECONDS_MAX);
+    assert!(datealgo_gmtime_r(None, Some(&mut tm)).is_none());
+    assert!(datealgo_gmtime_r(Some(&0), None).is_none());
+}
+
+#[test]
+fn test_timegm() {
+    let mut tm = secs_to_tm(1684574678);
+    assert_eq!(datealgo_timegm(Some(&mut tm)), 1684574678);
+    tm.tm_mon = -1;
+    tm.tm_mday = 366;
+    tm.tm_hour = -1;
+    tm.tm_min = 120;
+    tm.tm_sec = -3600;
+    tm.tm_wday = 42;
+    tm.tm_yday = -5;
+    assert_eq!(datealgo_timegm(Some(&mut tm)), datetime_to_secs((2023, 12, 1, 0, 0, 0)));
+    assert_eq!((tm.tm_year, tm.tm_mon, tm.tm_mday), (123, 11, 1));
+    assert_eq!((tm.tm_hour, tm.tm_min, tm.tm_sec), (0, 0, 0));
+    assert_eq!((tm.tm_wday, tm.tm_yday), (5, 334));
+    let mut tm = secs_to_tm(RD_SECONDS_MAX);
+    tm.tm_sec += 1;
+    assert_eq!(datealgo_timegm(Some(&mut tm)), -1);
+    assert_eq!(tm.tm_sec, 60);
+    tm.tm_year = i32::MAX;
+    tm.tm_mon = i32::MAX;
+    assert_eq!(datealgo_mktime_utc(Some(&mut tm)), -1);
+    let mut tm = secs_to_tm(RD_SECONDS_MIN);
+    tm.tm_mday = i32::MIN;
+    assert_eq!(datealgo_mktime_utc(Some(&mut tm)), -1);
+    assert_eq!(datealgo_timegm(None), -1);
+    assert_eq!(datealgo_mktime_utc(None), -1);
+}
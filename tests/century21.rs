@@ -0,0 +1,42 @@
+use datealgo::century21::*;
+
+#[test]
+fn test_consts() {
+    assert_eq!(EPOCH_RD, datealgo::date_to_rd((2000, 1, 1)));
+    assert_eq!(EPOCH_SECS, datealgo::datetime_to_secs((2000, 1, 1, 0, 0, 0)));
+    assert_eq!(EPOCH_RD + DAYS_MAX as i32, datealgo::date_to_rd((2099, 12, 31)));
+    assert_eq!(
+        EPOCH_SECS + SECONDS_MAX as i64,
+        datealgo::datetime_to_secs((2099, 12, 31, 23, 59, 59))
+    );
+}
+
+#[test]
+fn test_days_to_date() {
+    for n in 0..=DAYS_MAX {
+        let (y, m, d) = datealgo::rd_to_date(EPOCH_RD + n as i32);
+        assert_eq!(days_to_date(n), ((y - 2000) as u8, m, d));
+        assert_eq!(date_to_days(((y - 2000) as u8, m, d)), n);
+        assert_eq!(days_to_weekday(n), datealgo::rd_to_weekday(EPOCH_RD + n as i32));
+    }
+}
+
+#[test]
+fn test_days_in_month() {
+    for y in 0..=YEAR_MAX {
+        assert_eq!(is_leap_year(y), datealgo::is_leap_year(2000 + y as i32));
+        for m in 1..=12 {
+            assert_eq!(days_in_month(y, m), datealgo::days_in_month(2000 + y as i32, m));
+        }
+    }
+}
+
+#[test]
+fn test_secs_to_datetime() {
+    for secs in (0..=SECONDS_MAX).step_by(7919).chain([SECONDS_MAX]) {
+        let (y, m, d, hh, mm, ss) = datealgo::secs_to_datetime(EPOCH_SECS + secs as i64);
+        let dt = ((y - 2000) as u8, m, d, hh, mm, ss);
+        assert_eq!(secs_to_datetime(secs), dt);
+        assert_eq!(datetime_to_secs(dt), secs);
+    }
+}
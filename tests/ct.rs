@@ -0,0 +1,49 @@
+use datealgo::ct::*;
+use datealgo::{RD_MAX, RD_MIN, RD_SECONDS_MAX, RD_SECONDS_MIN, YEAR_MAX, YEAR_MIN};
+
+#[test]
+fn test_is_leap_year() {
+    for y in (-10000..10000).chain(YEAR_MIN..YEAR_MIN + 1000).chain(YEAR_MAX - 1000..=YEAR_MAX) {
+        assert_eq!(is_leap_year(y), datealgo::is_leap_year(y));
+    }
+}
+
+#[test]
+fn test_days_in_month() {
+    for y in (-1000..1000).chain([YEAR_MIN, YEAR_MAX]) {
+        for m in 1..=12 {
+            assert_eq!(days_in_month(y, m), datealgo::days_in_month(y, m));
+        }
+    }
+}
+
+#[test]
+fn test_rd_to_date() {
+    for n in (-1_000_000..1_000_000).chain(RD_MIN..RD_MIN + 1000).chain(RD_MAX - 1000..=RD_MAX) {
+        assert_eq!(rd_to_date(n), datealgo::rd_to_date(n));
+        assert_eq!(date_to_rd(rd_to_date(n)), n);
+        assert_eq!(rd_to_weekday(n), datealgo::rd_to_weekday(n));
+    }
+    for n in (RD_MIN..=RD_MAX).step_by(997) {
+        assert_eq!(rd_to_date(n), datealgo::rd_to_date(n));
+        assert_eq!(date_to_rd(rd_to_date(n)), n);
+        assert_eq!(rd_to_weekday(n), datealgo::rd_to_weekday(n));
+    }
+}
+
+#[test]
+fn test_secs_to_datetime() {
+    for s in (-1_000_000..1_000_000)
+        .chain(RD_SECONDS_MIN..RD_SECONDS_MIN + 1000)
+        .chain(RD_SECONDS_MAX - 1000..=RD_SECONDS_MAX)
+    {
+        assert_eq!(secs_to_dhms(s), datealgo::secs_to_dhms(s));
+        assert_eq!(dhms_to_secs(secs_to_dhms(s)), s);
+        assert_eq!(secs_to_datetime(s), datealgo::secs_to_datetime(s));
+        assert_eq!(datetime_to_secs(secs_to_datetime(s)), s);
+    }
+    for s in (RD_SECONDS_MIN..=RD_SECONDS_MAX).step_by(86_399_997) {
+        assert_eq!(secs_to_datetime(s), datealgo::secs_to_datetime(s));
+        assert_eq!(datetime_to_secs(secs_to_datetime(s)), s);
+    }
+}
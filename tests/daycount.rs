@@ -0,0 +1,149 @@
+use datealgo::daycount::*;
+use datealgo::{date_to_rd, rd_to_date};
+
+const CONVENTIONS: [DayCount; 7] = [
+    DayCount::Thirty360Us,
+    DayCount::Thirty360E,
+    DayCount::Thirty360EIsda,
+    DayCount::Thirty360EIsdaTermination,
+    DayCount::Act360,
+    DayCount::Act365F,
+    DayCount::ActActIsda,
+];
+
+#[test]
+fn test_day_count_thirty360() {
+    let cases = [
+        // (date1, date2, 30/360 US, 30E/360, 30E/360 ISDA)
+        ((2007, 1, 15), (2007, 1, 30), 15, 15, 15),
+        ((2007, 1, 15), (2007, 2, 15), 30, 30, 30),
+        ((2007, 1, 15), (2007, 7, 15), 180, 180, 180),
+        ((2007, 9, 30), (2008, 3, 31), 180, 180, 180),
+        ((2007, 9, 30), (2007, 10, 31), 30, 30, 30),
+        ((2007, 9, 30), (2008, 9, 30), 360, 360, 360),
+        ((2007, 1, 15), (2007, 1, 31), 16, 15, 15),
+        ((2007, 1, 31), (2007, 2, 28), 28, 28, 30),
+        ((2007, 2, 28), (2007, 3, 31), 30, 32, 30),
+        ((2006, 8, 31), (2007, 2, 28), 178, 178, 180),
+        ((2007, 2, 28), (2007, 8, 31), 180, 182, 180),
+        ((2007, 2, 14), (2007, 2, 28), 14, 14, 16),
+        ((2007, 2, 26), (2008, 2, 29), 363, 363, 364),
+        ((2008, 2, 29), (2009, 2, 28), 360, 359, 360),
+        ((2008, 2, 29), (2008, 3, 30), 30, 31, 30),
+        ((2008, 2, 29), (2008, 3, 31), 30, 31, 30),
+        ((2007, 2, 28), (2007, 3, 5), 5, 7, 5),
+        ((2007, 10, 31), (2007, 11, 28), 28, 28, 28),
+        ((2007, 8, 31), (2008, 2, 29), 179, 179, 180),
+        ((2008, 2, 29), (2008, 8, 31), 180, 181, 180),
+        ((2008, 8, 31), (2009, 2, 28), 178, 178, 180),
+        ((2009, 2, 28), (2009, 8, 31), 180, 182, 180),
+    ];
+    for (date1, date2, us, e, eisda) in cases {
+        assert_eq!(day_count(date1, date2, DayCount::Thirty360Us), us, "{date1:?} {date2:?}");
+        assert_eq!(day_count(date1, date2, DayCount::Thirty360E), e, "{date1:?} {date2:?}");
+        assert_eq!(day_count(date1, date2, DayCount::Thirty360EIsda), eisda, "{date1:?} {date2:?}");
+    }
+}
+
+#[test]
+fn test_day_count_thirty360_termination() {
+    let cases = [
+        // (date1, date2, 30E/360 ISDA, with termination date)
+        ((2007, 1, 31), (2007, 2, 28), 30, 28),
+        ((2006, 8, 31), (2007, 2, 28), 180, 178),
+        ((2007, 2, 14), (2007, 2, 28), 16, 14),
+        ((2007, 8, 31), (2008, 2, 29), 180, 179),
+        ((2008, 8, 31), (2009, 2, 28), 180, 178),
+        ((2007, 8, 31), (2008, 2, 28), 178, 178),
+        ((2007, 2, 28), (2007, 8, 31), 180, 180),
+        ((2008, 2, 29), (2009, 2, 28), 360, 358),
+        ((2007, 9, 30), (2008, 3, 31), 180, 180),
+        ((2007, 1, 15), (2007, 4, 30), 105, 105),
+    ];
+    for (date1, date2, eisda, termination) in cases {
+        assert_eq!(day_count(date1, date2, DayCount::Thirty360EIsda), eisda, "{date1:?} {date2:?}");
+        let days = day_count(date1, date2, DayCount::Thirty360EIsdaTermination);
+        assert_eq!(days, termination, "{date1:?} {date2:?}");
+        let fraction = year_fraction(date1, date2, DayCount::Thirty360EIsdaTermination);
+        assert_eq!(fraction, termination as f64 / 360.0);
+    }
+}
+
+#[test]
+fn test_year_fraction_act() {
+    assert_eq!(
+        year_fraction((2003, 11, 1), (2004, 5, 1), DayCount::ActActIsda),
+        61.0 / 365.0 + 121.0 / 366.0
+    );
+    assert_eq!(year_fraction((2003, 11, 1), (2004, 5, 1), DayCount::Act365F), 182.0 / 365.0);
+    assert_eq!(year_fraction((2003, 11, 1), (2004, 5, 1), DayCount::Act360), 182.0 / 360.0);
+    assert_eq!(year_fraction((1999, 2, 1), (1999, 7, 1), DayCount::ActActIsda), 150.0 / 365.0);
+    assert_eq!(year_fraction((2000, 1, 30), (2000, 6, 30), DayCount::ActActIsda), 152.0 / 366.0);
+    assert_eq!(year_fraction((2000, 1, 1), (2010, 1, 1), DayCount::ActActIsda), 10.0);
+    assert_eq!(
+        year_fraction((2004, 5, 1), (2003, 11, 1), DayCount::ActActIsda),
+        -(61.0 / 365.0 + 121.0 / 366.0)
+    );
+}
+
+#[test]
+fn test_year_fraction_properties() {
+    for rd1 in (date_to_rd((1990, 1, 1))..date_to_rd((2010, 1, 1))).step_by(37) {
+        for rd2 in (rd1 - 800..rd1 + 800).step_by(13) {
+            let (date1, date2) = (rd_to_date(rd1), rd_to_date(rd2));
+            for convention in CONVENTIONS {
+                let days = day_count(date1, date2, convention);
+                let fraction = year_fraction(date1, date2, convention);
+                if !matches!(convention, DayCount::Thirty360Us | DayCount::Thirty360EIsdaTermination) {
+                    assert!((fraction + year_fraction(date2, date1, convention)).abs() < 1e-12);
+                }
+                assert_eq!(days.signum(), fraction.partial_cmp(&0.0).unwrap() as i32);
+                match convention {
+                    DayCount::Act360 | DayCount::Act365F | DayCount::ActActIsda => assert_eq!(days, rd2 - rd1),
+                    _ => assert!((days as f64 - (rd2 - rd1) as f64 * 360.0 / 365.25).abs() <= 5.0),
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_add_days_30_360() {
+    assert_eq!(add_days_30_360((2023, 1, 1), 0), (2023, 1, 1));
+    assert_eq!(add_days_30_360((2023, 1, 31), 0), (2023, 1, 30));
+    assert_eq!(add_days_30_360((2023, 1, 30), 29), (2023, 2, 28));
+    assert_eq!(add_days_30_360((2024, 1, 30), 29), (2024, 2, 29));
+    assert_eq!(add_days_30_360((2024, 1, 30), 31), (2024, 3, 1));
+    assert_eq!(add_days_30_360((2023, 12, 30), 1), (2024, 1, 1));
+    assert_eq!(add_days_30_360((2024, 1, 1), -1), (2023, 12, 30));
+    assert_eq!(add_days_30_360((0, 1, 1), -1), (-1, 12, 30));
+    assert_eq!(add_days_30_360((2000, 1, 1), 360 * 100 + 45), (2100, 2, 16));
+    for rd in (date_to_rd((1990, 1, 1))..date_to_rd((2010, 1, 1))).step_by(7) {
+        let date = rd_to_date(rd);
+        for n in [-400, -360, -31, -30, -1, 1, 29, 30, 31, 59, 360, 1000] {
+            let result = add_days_30_360(date, n);
+            let days = days_between_30_360(date, result);
+            if result.1 == 2 && result.2 >= 28 {
+                assert!(days <= n && days >= n - 2);
+            } else {
+                assert_eq!(days, n);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_days_between_30_360() {
+    assert_eq!(days_between_30_360((2023, 1, 1), (2023, 1, 1)), 0);
+    assert_eq!(days_between_30_360((2023, 1, 30), (2023, 1, 31)), 0);
+    assert_eq!(days_between_30_360((2023, 1, 1), (2024, 1, 1)), 360);
+    assert_eq!(days_between_30_360((2023, 2, 28), (2023, 2, 1)), -27);
+    for rd1 in (date_to_rd((1990, 1, 1))..date_to_rd((2010, 1, 1))).step_by(37) {
+        for rd2 in (rd1 - 800..rd1 + 800).step_by(13) {
+            let (date1, date2) = (rd_to_date(rd1), rd_to_date(rd2));
+            let days = days_between_30_360(date1, date2);
+            assert_eq!(days, -days_between_30_360(date2, date1));
+            assert_eq!(days, day_count(date1, date2, DayCount::Thirty360E));
+        }
+    }
+}
@@ -0,0 +1,15 @@
+#![cfg(feature = "defmt")]
+
+use datealgo::defmt::*;
+
+fn assert_format<T: defmt::Format>(_: T) {}
+
+#[test]
+fn test_format() {
+    // Logging requires a global logger from the target, so only check that
+    // the wrappers are usable as `defmt` arguments
+    assert_format(DefmtDate((2023, 5, 20)));
+    assert_format(DefmtDateTime((2023, 5, 20, 9, 24, 38)));
+    assert_format([DefmtDate((-1, 12, 31)), DefmtDate((133999, 1, 1))]);
+    assert_eq!(DefmtDateTime(datealgo::secs_to_datetime(1684574678)).0, (2023, 5, 20, 9, 24, 38));
+}
@@ -0,0 +1,81 @@
+use datealgo::calendar::julian::{julian_date_to_rd, JULIAN_YEAR_MAX, JULIAN_YEAR_MIN};
+use datealgo::easter::*;
+use datealgo::{date_to_rd, rd_to_weekday, YEAR_MAX, YEAR_MIN};
+
+#[test]
+fn test_easter_sunday() {
+    let dates = [
+        (2000, 4, 23),
+        (2001, 4, 15),
+        (2002, 3, 31),
+        (2003, 4, 20),
+        (2004, 4, 11),
+        (2005, 3, 27),
+        (2006, 4, 16),
+        (2007, 4, 8),
+        (2008, 3, 23),
+        (2009, 4, 12),
+        (2010, 4, 4),
+        (2011, 4, 24),
+        (2012, 4, 8),
+        (2013, 3, 31),
+        (2014, 4, 20),
+        (2015, 4, 5),
+        (2016, 3, 27),
+        (2017, 4, 16),
+        (2018, 4, 1),
+        (2019, 4, 21),
+        (2020, 4, 12),
+        (2021, 4, 4),
+        (2022, 4, 17),
+        (2023, 4, 9),
+        (2024, 3, 31),
+        (2025, 4, 20),
+        (1818, 3, 22),
+        (1943, 4, 25),
+        (1583, 4, 10),
+    ];
+    for date in dates {
+        assert_eq!(easter_sunday(date.0), date);
+    }
+}
+
+#[test]
+fn test_julian_easter_sunday() {
+    let dates = [
+        (2018, 4, 8),
+        (2019, 4, 28),
+        (2020, 4, 19),
+        (2021, 5, 2),
+        (2022, 4, 24),
+        (2023, 4, 16),
+        (2024, 5, 5),
+        (2025, 4, 20),
+        (2026, 4, 12),
+        (2027, 5, 2),
+    ];
+    for date in dates {
+        assert_eq!(julian_easter_sunday(date.0).1, date);
+    }
+    // Before 1583 the dates of Easter in the Julian calendar were used everywhere
+    assert_eq!(julian_easter_sunday(1066).0, (1066, 4, 16));
+    assert_eq!(julian_easter_sunday(325).0, (325, 4, 18));
+}
+
+#[test]
+fn test_easter_properties() {
+    for y in (YEAR_MIN..=YEAR_MAX).step_by(997).chain(-1000..3000).chain([YEAR_MIN, YEAR_MAX]) {
+        let date = easter_sunday(y);
+        assert_eq!(date.0, y);
+        assert!((date_to_rd((y, 3, 22))..=date_to_rd((y, 4, 25))).contains(&date_to_rd(date)));
+        assert_eq!(rd_to_weekday(date_to_rd(date)), 7);
+    }
+    for y in (JULIAN_YEAR_MIN + 1..JULIAN_YEAR_MAX).step_by(997).chain(-1000..3000) {
+        let (julian, gregorian) = julian_easter_sunday(y);
+        assert_eq!(julian.0, y);
+        let rd = julian_date_to_rd(julian);
+        assert!((julian_date_to_rd((y, 3, 22))..=julian_date_to_rd((y, 4, 25))).contains(&rd));
+        assert_eq!(date_to_rd(gregorian), rd);
+        assert_eq!(rd_to_weekday(rd), 7);
+    }
+}
@@ -0,0 +1,643 @@
+use datealgo::epoch::*;
+use datealgo::{date_to_rd, datetime_to_secs, secs_to_datetime, RD_MAX, RD_MIN, RD_SECONDS_MAX, RD_SECONDS_MIN};
+
+#[test]
+fn test_mjd_consts() {
+    assert_eq!(MJD_EPOCH_RD, date_to_rd((1858, 11, 17)));
+    assert_eq!(MJD_MIN, -536854565);
+    assert_eq!(MJD_MAX, 536864882);
+    assert_eq!(MJD_SECONDS_MIN, -46384234416000);
+    assert_eq!(MJD_SECONDS_MAX, 46385125891199);
+}
+
+#[test]
+fn test_rd_to_mjd() {
+    assert_eq!(rd_to_mjd(0), 40587);
+    assert_eq!(rd_to_mjd(-40587), 0);
+    assert_eq!(rd_to_mjd(-40588), -1);
+    assert_eq!(rd_to_mjd(date_to_rd((1995, 10, 10))), 50000);
+    assert_eq!(rd_to_mjd(RD_MIN), MJD_MIN);
+    assert_eq!(rd_to_mjd(RD_MAX), MJD_MAX);
+}
+
+#[test]
+fn test_mjd_to_rd() {
+    assert_eq!(mjd_to_rd(40587), 0);
+    assert_eq!(mjd_to_rd(0), -40587);
+    assert_eq!(mjd_to_rd(-1), -40588);
+    assert_eq!(mjd_to_rd(50000), date_to_rd((1995, 10, 10)));
+    assert_eq!(mjd_to_rd(MJD_MIN), RD_MIN);
+    assert_eq!(mjd_to_rd(MJD_MAX), RD_MAX);
+}
+
+#[test]
+fn test_secs_to_mjd_secs() {
+    assert_eq!(secs_to_mjd_secs(0), 40587 * 86400);
+    assert_eq!(secs_to_mjd_secs(-1), 40587 * 86400 - 1);
+    assert_eq!(secs_to_mjd_secs(RD_SECONDS_MIN), MJD_SECONDS_MIN);
+    assert_eq!(secs_to_mjd_secs(RD_SECONDS_MAX), MJD_SECONDS_MAX);
+}
+
+#[test]
+fn test_mjd_secs_to_secs() {
+    assert_eq!(mjd_secs_to_secs(40587 * 86400), 0);
+    assert_eq!(mjd_secs_to_secs(-1), -40587 * 86400 - 1);
+    assert_eq!(mjd_secs_to_secs(MJD_SECONDS_MIN), RD_SECONDS_MIN);
+    assert_eq!(mjd_secs_to_secs(MJD_SECONDS_MAX), RD_SECONDS_MAX);
+}
+
+#[test]
+fn test_rd1_consts() {
+    assert_eq!(RD1_EPOCH_RD, date_to_rd((0, 12, 31)));
+    assert_eq!(RD1_MIN, -536175989);
+    assert_eq!(RD1_MAX, 537543458);
+}
+
+#[test]
+fn test_rd_to_rd1() {
+    assert_eq!(rd_to_rd1(0), 719163);
+    assert_eq!(rd_to_rd1(date_to_rd((0, 12, 31))), 0);
+    assert_eq!(rd_to_rd1(date_to_rd((1, 1, 1))), 1);
+    assert_eq!(rd_to_rd1(date_to_rd((-586, 7, 24))), -214193);
+    assert_eq!(rd_to_rd1(date_to_rd((2094, 7, 18))), 764652);
+    assert_eq!(rd_to_rd1(RD_MIN), RD1_MIN);
+    assert_eq!(rd_to_rd1(RD_MAX), RD1_MAX);
+}
+
+#[test]
+fn test_rd1_to_rd() {
+    assert_eq!(rd1_to_rd(719163), 0);
+    assert_eq!(rd1_to_rd(0), date_to_rd((0, 12, 31)));
+    assert_eq!(rd1_to_rd(1), date_to_rd((1, 1, 1)));
+    assert_eq!(rd1_to_rd(-214193), date_to_rd((-586, 7, 24)));
+    assert_eq!(rd1_to_rd(764652), date_to_rd((2094, 7, 18)));
+    assert_eq!(rd1_to_rd(RD1_MIN), RD_MIN);
+    assert_eq!(rd1_to_rd(RD1_MAX), RD_MAX);
+}
+
+#[test]
+fn test_rd_to_excel1900() {
+    assert_eq!(rd_to_excel1900(date_to_rd((1899, 12, 30))), None);
+    assert_eq!(rd_to_excel1900(date_to_rd((1899, 12, 31))), None);
+    assert_eq!(rd_to_excel1900(date_to_rd((1900, 1, 1))), Some(EXCEL1900_MIN));
+    assert_eq!(rd_to_excel1900(date_to_rd((1900, 2, 28))), Some(59));
+    assert_eq!(rd_to_excel1900(date_to_rd((1900, 3, 1))), Some(61));
+    assert_eq!(rd_to_excel1900(0), Some(25569));
+    assert_eq!(rd_to_excel1900(date_to_rd((9999, 12, 31))), Some(EXCEL1900_MAX));
+    assert_eq!(rd_to_excel1900(date_to_rd((10000, 1, 1))), None);
+    assert_eq!(rd_to_excel1900(RD_MIN), None);
+    assert_eq!(rd_to_excel1900(RD_MAX), None);
+}
+
+#[test]
+fn test_excel1900_to_rd() {
+    assert_eq!(excel1900_to_rd(i32::MIN), None);
+    assert_eq!(excel1900_to_rd(-1), None);
+    assert_eq!(excel1900_to_rd(0), None);
+    assert_eq!(excel1900_to_rd(EXCEL1900_MIN), Some(date_to_rd((1900, 1, 1))));
+    assert_eq!(excel1900_to_rd(59), Some(date_to_rd((1900, 2, 28))));
+    assert_eq!(excel1900_to_rd(60), None);
+    assert_eq!(excel1900_to_rd(61), Some(date_to_rd((1900, 3, 1))));
+    assert_eq!(excel1900_to_rd(25569), Some(0));
+    assert_eq!(excel1900_to_rd(EXCEL1900_MAX), Some(date_to_rd((9999, 12, 31))));
+    assert_eq!(excel1900_to_rd(EXCEL1900_MAX + 1), None);
+    assert_eq!(excel1900_to_rd(i32::MAX), None);
+}
+
+#[test]
+fn test_excel1900_roundtrip() {
+    for serial in EXCEL1900_MIN..=EXCEL1900_MAX {
+        match excel1900_to_rd(serial) {
+            Some(rd) => assert_eq!(rd_to_excel1900(rd), Some(serial)),
+            None => assert_eq!(serial, 60),
+        }
+    }
+}
+
+#[test]
+fn test_rd_to_excel1904() {
+    assert_eq!(rd_to_excel1904(date_to_rd((1903, 12, 31))), None);
+    assert_eq!(rd_to_excel1904(date_to_rd((1904, 1, 1))), Some(EXCEL1904_MIN));
+    assert_eq!(rd_to_excel1904(date_to_rd((1904, 2, 29))), Some(59));
+    assert_eq!(rd_to_excel1904(0), Some(24107));
+    assert_eq!(rd_to_excel1904(date_to_rd((9999, 12, 31))), Some(EXCEL1904_MAX));
+    assert_eq!(rd_to_excel1904(date_to_rd((10000, 1, 1))), None);
+    assert_eq!(rd_to_excel1904(RD_MIN), None);
+    assert_eq!(rd_to_excel1904(RD_MAX), None);
+}
+
+#[test]
+fn test_excel1904_to_rd() {
+    assert_eq!(excel1904_to_rd(i32::MIN), None);
+    assert_eq!(excel1904_to_rd(-1), None);
+    assert_eq!(excel1904_to_rd(EXCEL1904_MIN), Some(date_to_rd((1904, 1, 1))));
+    assert_eq!(excel1904_to_rd(59), Some(date_to_rd((1904, 2, 29))));
+    assert_eq!(excel1904_to_rd(24107), Some(0));
+    assert_eq!(excel1904_to_rd(EXCEL1904_MAX), Some(date_to_rd((9999, 12, 31))));
+    assert_eq!(excel1904_to_rd(EXCEL1904_MAX + 1), None);
+    assert_eq!(excel1904_to_rd(i32::MAX), None);
+}
+
+#[test]
+fn test_excel1900_excel1904() {
+    // the two date systems differ by 1462 days after the 1900 leap bug
+    for serial in EXCEL1904_MIN..=EXCEL1904_MAX {
+        let rd = excel1904_to_rd(serial).unwrap();
+        assert_eq!(rd_to_excel1900(rd), Some(serial + 1462));
+    }
+}
+
+#[test]
+fn test_secs_to_oadate() {
+    assert_eq!(OADATE_EPOCH_RD, date_to_rd((1899, 12, 30)));
+    assert_eq!(secs_to_oadate((0, 0)), 25569.0);
+    assert_eq!(secs_to_oadate((datetime_to_secs((1899, 12, 30, 12, 0, 0)), 0)), 0.5);
+    assert_eq!(secs_to_oadate((datetime_to_secs((1899, 12, 29, 0, 0, 0)), 0)), -1.0);
+    assert_eq!(secs_to_oadate((datetime_to_secs((1899, 12, 29, 18, 0, 0)), 0)), -1.75);
+    assert_eq!(secs_to_oadate((datetime_to_secs((1899, 12, 28, 12, 0, 0)), 0)), -2.5);
+    assert_eq!(secs_to_oadate((datetime_to_secs((100, 1, 1, 0, 0, 0)), 0)), -657434.0);
+    assert_eq!(secs_to_oadate((datetime_to_secs((9999, 12, 31, 0, 0, 0)), 0)), 2958465.0);
+    assert_eq!(secs_to_oadate((datetime_to_secs((2023, 5, 20, 6, 0, 0)), 0)), 45066.25);
+}
+
+#[test]
+fn test_oadate_to_secs() {
+    assert_eq!(oadate_to_secs(25569.0), Some((0, 0)));
+    assert_eq!(oadate_to_secs(0.5), Some((datetime_to_secs((1899, 12, 30, 12, 0, 0)), 0)));
+    assert_eq!(oadate_to_secs(-0.5), Some((datetime_to_secs((1899, 12, 30, 12, 0, 0)), 0)));
+    assert_eq!(oadate_to_secs(-1.0), Some((datetime_to_secs((1899, 12, 29, 0, 0, 0)), 0)));
+    assert_eq!(oadate_to_secs(-1.75), Some((datetime_to_secs((1899, 12, 29, 18, 0, 0)), 0)));
+    assert_eq!(oadate_to_secs(-2.5), Some((datetime_to_secs((1899, 12, 28, 12, 0, 0)), 0)));
+    assert_eq!(oadate_to_secs(45066.25), Some((datetime_to_secs((2023, 5, 20, 6, 0, 0)), 0)));
+    assert_eq!(oadate_to_secs(25569.0 + 0.25 / 86400.0), Some((0, 250_000_000)));
+    assert_eq!(oadate_to_secs(25569.0 + 0.0004 / 86400.0), Some((0, 0)));
+    assert_eq!(oadate_to_secs(25569.0 + 0.9996 / 86400.0), Some((1, 0)));
+    assert_eq!(oadate_to_secs(25569.9999999999), Some((86400, 0)));
+    assert_eq!(oadate_to_secs(-657434.0), Some((datetime_to_secs((100, 1, 1, 0, 0, 0)), 0)));
+    assert_eq!(
+        oadate_to_secs(-657434.999),
+        Some((datetime_to_secs((100, 1, 1, 23, 58, 33)), 600_000_000))
+    );
+    assert_eq!(
+        oadate_to_secs(2958465.999),
+        Some((datetime_to_secs((9999, 12, 31, 23, 58, 33)), 600_000_000))
+    );
+    assert_eq!(oadate_to_secs(-1.9999999999), Some((datetime_to_secs((1899, 12, 28, 0, 0, 0)), 0)));
+    assert_eq!(oadate_to_secs(-1.9999999999), oadate_to_secs(-2.0));
+    assert_eq!(oadate_to_secs(-2.0000000001), oadate_to_secs(-2.0));
+    assert_eq!(oadate_to_secs(-0.9999999999), oadate_to_secs(-1.0));
+    assert_eq!(oadate_to_secs(-1.0000000001), oadate_to_secs(-1.0));
+    assert_eq!(oadate_to_secs(-100.9999999999), oadate_to_secs(-101.0));
+    assert_eq!(oadate_to_secs(-100.0000000001), oadate_to_secs(-100.0));
+    assert_eq!(oadate_to_secs(-657433.9999999999), oadate_to_secs(-657434.0));
+    assert_eq!(
+        oadate_to_secs(-1.9999999),
+        Some((datetime_to_secs((1899, 12, 29, 23, 59, 59)), 991_000_000))
+    );
+    assert_eq!(oadate_to_secs(-657434.9999999999), None);
+    assert_eq!(oadate_to_secs(2958465.9999999999), None);
+    assert_eq!(oadate_to_secs(-657435.0), None);
+    assert_eq!(oadate_to_secs(2958466.0), None);
+    assert_eq!(oadate_to_secs(f64::NAN), None);
+    assert_eq!(oadate_to_secs(f64::INFINITY), None);
+    assert_eq!(oadate_to_secs(f64::NEG_INFINITY), None);
+}
+
+#[test]
+fn test_oadate_near_integer() {
+    for days in -657433..=2958465 {
+        if days % 997 != 0 && !(-3..=3).contains(&days) {
+            continue;
+        }
+        let expected = oadate_to_secs(days as f64);
+        for eps in [1e-9, 5e-9] {
+            assert_eq!(oadate_to_secs(days as f64 - eps), expected, "{days} - {eps}");
+            assert_eq!(oadate_to_secs(days as f64 + eps), expected, "{days} + {eps}");
+        }
+    }
+}
+
+#[test]
+fn test_oadate_roundtrip() {
+    for secs in (datetime_to_secs((100, 1, 1, 0, 0, 0))..=datetime_to_secs((9999, 12, 31, 23, 59, 59))).step_by(999_983) {
+        for ms in [0, 1, 499, 500, 999] {
+            let v = secs_to_oadate((secs, ms * 1_000_000));
+            assert_eq!(oadate_to_secs(v), Some((secs, ms * 1_000_000)));
+        }
+    }
+}
+
+#[test]
+fn test_cocoa_consts() {
+    assert_eq!(COCOA_EPOCH_SECS, datetime_to_secs((2001, 1, 1, 0, 0, 0)));
+    assert_eq!(COCOA_SECONDS_MIN, -46388719440000);
+    assert_eq!(COCOA_SECONDS_MAX, 46380640867199);
+}
+
+#[test]
+fn test_secs_to_cocoa_secs() {
+    assert_eq!(secs_to_cocoa_secs(COCOA_EPOCH_SECS), 0);
+    assert_eq!(secs_to_cocoa_secs(COCOA_EPOCH_SECS - 1), -1);
+    assert_eq!(secs_to_cocoa_secs(RD_SECONDS_MIN), COCOA_SECONDS_MIN);
+    assert_eq!(secs_to_cocoa_secs(RD_SECONDS_MAX), COCOA_SECONDS_MAX);
+}
+
+#[test]
+fn test_cocoa_secs_to_secs() {
+    assert_eq!(cocoa_secs_to_secs(0), COCOA_EPOCH_SECS);
+    assert_eq!(cocoa_secs_to_secs(-1), COCOA_EPOCH_SECS - 1);
+    assert_eq!(cocoa_secs_to_secs(COCOA_SECONDS_MIN), RD_SECONDS_MIN);
+    assert_eq!(cocoa_secs_to_secs(COCOA_SECONDS_MAX), RD_SECONDS_MAX);
+}
+
+#[test]
+fn test_secs_to_cocoa() {
+    assert_eq!(secs_to_cocoa((COCOA_EPOCH_SECS, 0)), 0.0);
+    assert_eq!(secs_to_cocoa((COCOA_EPOCH_SECS, 1000)), 0.000001);
+    assert_eq!(secs_to_cocoa((COCOA_EPOCH_SECS - 1, 999_999_000)), -0.000001);
+    assert_eq!(secs_to_cocoa((0, 0)), -978307200.0);
+}
+
+#[test]
+fn test_cocoa_to_secs() {
+    assert_eq!(cocoa_to_secs(0.0), Some((COCOA_EPOCH_SECS, 0)));
+    assert_eq!(cocoa_to_secs(-0.0), Some((COCOA_EPOCH_SECS, 0)));
+    assert_eq!(cocoa_to_secs(0.000001), Some((COCOA_EPOCH_SECS, 1000)));
+    assert_eq!(cocoa_to_secs(-0.000001), Some((COCOA_EPOCH_SECS - 1, 999_999_000)));
+    assert_eq!(cocoa_to_secs(-0.0000001), Some((COCOA_EPOCH_SECS, 0)));
+    assert_eq!(cocoa_to_secs(0.9999999), Some((COCOA_EPOCH_SECS + 1, 0)));
+    assert_eq!(cocoa_to_secs(-978307200.0), Some((0, 0)));
+    assert_eq!(cocoa_to_secs(COCOA_SECONDS_MIN as f64), Some((RD_SECONDS_MIN, 0)));
+    assert_eq!(cocoa_to_secs(COCOA_SECONDS_MIN as f64 - 1.0), None);
+    assert_eq!(cocoa_to_secs(COCOA_SECONDS_MAX as f64 + 1.0), None);
+    assert_eq!(cocoa_to_secs(f64::NAN), None);
+    assert_eq!(cocoa_to_secs(f64::INFINITY), None);
+    assert_eq!(cocoa_to_secs(f64::NEG_INFINITY), None);
+}
+
+#[test]
+fn test_cocoa_roundtrip() {
+    for secs in (datetime_to_secs((1900, 1, 1, 0, 0, 0))..=datetime_to_secs((2100, 12, 31, 23, 59, 59))).step_by(999_983) {
+        for us in [0, 1, 499_999, 500_000, 999_999] {
+            let v = secs_to_cocoa((secs, us * 1000));
+            assert_eq!(cocoa_to_secs(v), Some((secs, us * 1000)));
+        }
+    }
+}
+
+#[test]
+fn test_dos_datetime_to_datetime() {
+    assert_eq!(dos_datetime_to_datetime(0x0021, 0x0000), Some((1980, 1, 1, 0, 0, 0)));
+    assert_eq!(dos_datetime_to_datetime(0x0021, 0x0001), Some((1980, 1, 1, 0, 0, 2)));
+    assert_eq!(dos_datetime_to_datetime(0x0021, 0x001d), Some((1980, 1, 1, 0, 0, 58)));
+    assert_eq!(dos_datetime_to_datetime(0x0021, 0x001e), None);
+    assert_eq!(dos_datetime_to_datetime(0x0021, 0x0780), None);
+    assert_eq!(dos_datetime_to_datetime(0x0021, 0xc000), None);
+    assert_eq!(dos_datetime_to_datetime(0x0020, 0x0000), None);
+    assert_eq!(dos_datetime_to_datetime(0x0001, 0x0000), None);
+    assert_eq!(dos_datetime_to_datetime(0x01a1, 0x0000), None);
+    assert_eq!(dos_datetime_to_datetime(0x105d, 0x0000), Some((1988, 2, 29, 0, 0, 0)));
+    assert_eq!(dos_datetime_to_datetime(0x125d, 0x0000), None);
+    assert_eq!(dos_datetime_to_datetime(0x00df, 0x0000), None);
+    assert_eq!(dos_datetime_to_datetime(0xff9f, 0xbf7d), Some((2107, 12, 31, 23, 59, 58)));
+}
+
+#[test]
+fn test_datetime_to_dos_datetime() {
+    assert_eq!(datetime_to_dos_datetime((1980, 1, 1, 0, 0, 1)), Some((0x0021, 0x0000)));
+    assert_eq!(datetime_to_dos_datetime((1988, 2, 29, 0, 0, 0)), Some((0x105d, 0x0000)));
+    assert_eq!(datetime_to_dos_datetime((2107, 12, 31, 23, 59, 59)), Some((0xff9f, 0xbf7d)));
+    assert_eq!(datetime_to_dos_datetime((2108, 1, 1, 0, 0, 0)), None);
+    assert_eq!(datetime_to_dos_datetime((-1980, 1, 1, 0, 0, 0)), None);
+}
+
+#[test]
+fn test_dos_datetime_roundtrip() {
+    let start = datetime_to_secs((1980, 1, 1, 0, 0, 0));
+    let end = datetime_to_secs((2107, 12, 31, 23, 59, 58));
+    for secs in (start..=end).step_by(9998).chain([end]) {
+        let dt = secs_to_datetime(secs);
+        let (date, time) = datetime_to_dos_datetime(dt).unwrap();
+        assert_eq!(dos_datetime_to_datetime(date, time), Some(dt));
+    }
+}
+
+#[test]
+fn test_uuidv1_to_secs() {
+    assert_eq!(UUIDV1_EPOCH_SECS, datetime_to_secs((1582, 10, 15, 0, 0, 0)));
+    assert_eq!(uuidv1_to_secs(0), (UUIDV1_EPOCH_SECS, 0));
+    assert_eq!(uuidv1_to_secs(1), (UUIDV1_EPOCH_SECS, 100));
+    assert_eq!(uuidv1_to_secs(122192928000000000), (0, 0));
+    assert_eq!(uuidv1_to_secs(122192927999999999), (-1, 999_999_900));
+    assert_eq!(
+        uuidv1_to_secs(UUIDV1_TIMESTAMP_MAX),
+        (datetime_to_secs((5236, 3, 31, 21, 21, 0)), 684_697_500)
+    );
+}
+
+#[test]
+fn test_secs_to_uuidv1() {
+    assert_eq!(secs_to_uuidv1((UUIDV1_EPOCH_SECS, 0)), Some(0));
+    assert_eq!(secs_to_uuidv1((UUIDV1_EPOCH_SECS, 99)), Some(0));
+    assert_eq!(secs_to_uuidv1((UUIDV1_EPOCH_SECS, 100)), Some(1));
+    assert_eq!(secs_to_uuidv1((UUIDV1_EPOCH_SECS - 1, 999_999_999)), None);
+    assert_eq!(secs_to_uuidv1((0, 0)), Some(122192928000000000));
+    assert_eq!(
+        secs_to_uuidv1((datetime_to_secs((5236, 3, 31, 21, 21, 0)), 684_697_599)),
+        Some(UUIDV1_TIMESTAMP_MAX)
+    );
+    assert_eq!(secs_to_uuidv1((datetime_to_secs((5236, 3, 31, 21, 21, 0)), 684_697_600)), None);
+    assert_eq!(secs_to_uuidv1((RD_SECONDS_MIN, 0)), None);
+    assert_eq!(secs_to_uuidv1((RD_SECONDS_MAX, 0)), None);
+    assert_eq!(secs_to_uuidv1((i64::MAX, 0)), None);
+}
+
+#[test]
+fn test_uuidv7_to_secs() {
+    assert_eq!(uuidv7_to_secs(0), (0, 0));
+    assert_eq!(uuidv7_to_secs(1), (0, 1_000_000));
+    assert_eq!(
+        uuidv7_to_secs(UUIDV7_TIMESTAMP_MAX),
+        (datetime_to_secs((10889, 8, 2, 5, 31, 50)), 655_000_000)
+    );
+}
+
+#[test]
+fn test_secs_to_uuidv7() {
+    assert_eq!(secs_to_uuidv7((0, 0)), Some(0));
+    assert_eq!(secs_to_uuidv7((0, 999_999)), Some(0));
+    assert_eq!(secs_to_uuidv7((0, 1_000_000)), Some(1));
+    assert_eq!(secs_to_uuidv7((-1, 0)), None);
+    assert_eq!(
+        secs_to_uuidv7((datetime_to_secs((10889, 8, 2, 5, 31, 50)), 655_999_999)),
+        Some(UUIDV7_TIMESTAMP_MAX)
+    );
+    assert_eq!(secs_to_uuidv7((datetime_to_secs((10889, 8, 2, 5, 31, 50)), 656_000_000)), None);
+    assert_eq!(secs_to_uuidv7((datetime_to_secs((10889, 8, 2, 5, 31, 51)), 0)), None);
+    assert_eq!(secs_to_uuidv7((RD_SECONDS_MIN, 0)), None);
+    assert_eq!(secs_to_uuidv7((RD_SECONDS_MAX, 0)), None);
+}
+
+#[test]
+fn test_secs_to_pg_timestamp() {
+    assert_eq!(PG_EPOCH_SECS, datetime_to_secs((2000, 1, 1, 0, 0, 0)));
+    assert_eq!(secs_to_pg_timestamp((PG_EPOCH_SECS, 999)), Some(0));
+    assert_eq!(secs_to_pg_timestamp((PG_EPOCH_SECS, 1000)), Some(1));
+    assert_eq!(secs_to_pg_timestamp((PG_EPOCH_SECS - 1, 999_999_999)), Some(-1));
+    assert_eq!(secs_to_pg_timestamp((PG_EPOCH_SECS - 1, 0)), Some(-1_000_000));
+    assert_eq!(
+        secs_to_pg_timestamp((9223372036854 + PG_EPOCH_SECS, 775_806_000)),
+        Some(i64::MAX - 1)
+    );
+    assert_eq!(secs_to_pg_timestamp((9223372036854 + PG_EPOCH_SECS, 775_807_000)), None);
+    assert_eq!(
+        secs_to_pg_timestamp((-9223372036855 + PG_EPOCH_SECS, 224_193_000)),
+        Some(i64::MIN + 1)
+    );
+    assert_eq!(secs_to_pg_timestamp((-9223372036855 + PG_EPOCH_SECS, 224_192_000)), None);
+    assert_eq!(secs_to_pg_timestamp((RD_SECONDS_MIN, 0)), None);
+    assert_eq!(secs_to_pg_timestamp((RD_SECONDS_MAX, 0)), None);
+    assert_eq!(secs_to_pg_timestamp((i64::MIN, 0)), None);
+}
+
+#[test]
+fn test_pg_timestamp_to_secs() {
+    assert_eq!(pg_timestamp_to_secs(0), Some((PG_EPOCH_SECS, 0)));
+    assert_eq!(pg_timestamp_to_secs(1), Some((PG_EPOCH_SECS, 1000)));
+    assert_eq!(pg_timestamp_to_secs(-1_000_000), Some((PG_EPOCH_SECS - 1, 0)));
+    assert_eq!(
+        pg_timestamp_to_secs(i64::MAX - 1),
+        Some((9223372036854 + PG_EPOCH_SECS, 775_806_000))
+    );
+    assert_eq!(
+        pg_timestamp_to_secs(i64::MIN + 1),
+        Some((-9223372036855 + PG_EPOCH_SECS, 224_193_000))
+    );
+    assert_eq!(pg_timestamp_to_secs(i64::MAX), None);
+    assert_eq!(pg_timestamp_to_secs(i64::MIN), None);
+}
+
+#[test]
+fn test_protobuf_consts() {
+    assert_eq!(PROTOBUF_SECONDS_MIN, datetime_to_secs((1, 1, 1, 0, 0, 0)));
+    assert_eq!(PROTOBUF_SECONDS_MAX, datetime_to_secs((9999, 12, 31, 23, 59, 59)));
+}
+
+#[test]
+fn test_is_valid_protobuf_timestamp() {
+    assert!(is_valid_protobuf_timestamp(0, 0));
+    assert!(is_valid_protobuf_timestamp(PROTOBUF_SECONDS_MIN, 0));
+    assert!(is_valid_protobuf_timestamp(PROTOBUF_SECONDS_MAX, 999_999_999));
+    assert!(!is_valid_protobuf_timestamp(PROTOBUF_SECONDS_MIN - 1, 999_999_999));
+    assert!(!is_valid_protobuf_timestamp(PROTOBUF_SECONDS_MAX + 1, 0));
+    assert!(!is_valid_protobuf_timestamp(0, i32::MIN));
+    assert!(!is_valid_protobuf_timestamp(0, i32::MAX));
+    assert!(!is_valid_protobuf_timestamp(i64::MIN, 0));
+    assert!(!is_valid_protobuf_timestamp(i64::MAX, 0));
+}
+
+#[test]
+fn test_normalize_timestamp() {
+    assert_eq!(normalize_timestamp(0, 0), (0, 0));
+    assert_eq!(normalize_timestamp(0, 999_999_999), (0, 999_999_999));
+    assert_eq!(normalize_timestamp(0, 1_000_000_000), (1, 0));
+    assert_eq!(normalize_timestamp(0, -1_000_000_000), (-1, 0));
+    assert_eq!(normalize_timestamp(0, -1_000_000_001), (-2, 999_999_999));
+    assert_eq!(normalize_timestamp(5, i64::MAX), (9223372041, 854_775_807));
+    assert_eq!(normalize_timestamp(5, i64::MIN), (-9223372032, 145_224_192));
+    assert_eq!(normalize_timestamp(i64::MAX, 1_000_000_000), (i64::MAX, 0));
+    assert_eq!(normalize_timestamp(i64::MIN, -1), (i64::MIN, 999_999_999));
+    let (secs, nanos) = normalize_timestamp(PROTOBUF_SECONDS_MAX, 1_000_000_000);
+    assert!(!is_valid_protobuf_timestamp(secs, nanos as i32));
+}
+
+#[test]
+fn test_sas_consts() {
+    assert_eq!(SAS_EPOCH_RD, date_to_rd((1960, 1, 1)));
+    assert_eq!(SAS_DATE_MIN, RD_MIN + 3653);
+    assert_eq!(SAS_DATE_MAX, RD_MAX + 3653);
+    assert_eq!(SAS_DATETIME_MIN, RD_SECONDS_MIN + 315619200);
+    assert_eq!(SAS_DATETIME_MAX, RD_SECONDS_MAX + 315619200);
+    assert_eq!(STATA_MS_MIN, SAS_DATETIME_MIN * 1000);
+    assert_eq!(STATA_MS_MAX, SAS_DATETIME_MAX * 1000 + 999);
+}
+
+#[test]
+fn test_rd_to_sas_date() {
+    assert_eq!(rd_to_sas_date(-3654), -1);
+    assert_eq!(rd_to_sas_date(date_to_rd((1582, 10, 15))), -137774);
+    assert_eq!(rd_to_sas_date(date_to_rd((20000, 12, 31))), 6589340);
+    assert_eq!(rd_to_sas_date(RD_MIN), SAS_DATE_MIN);
+    assert_eq!(rd_to_sas_date(RD_MAX), SAS_DATE_MAX);
+}
+
+#[test]
+fn test_sas_date_to_rd() {
+    assert_eq!(sas_date_to_rd(-1), -3654);
+    assert_eq!(sas_date_to_rd(-137774), date_to_rd((1582, 10, 15)));
+    assert_eq!(sas_date_to_rd(6589340), date_to_rd((20000, 12, 31)));
+    assert_eq!(sas_date_to_rd(SAS_DATE_MIN), RD_MIN);
+    assert_eq!(sas_date_to_rd(SAS_DATE_MAX), RD_MAX);
+}
+
+#[test]
+fn test_secs_to_sas_datetime() {
+    assert_eq!(secs_to_sas_datetime(-315619201), -1);
+    assert_eq!(secs_to_sas_datetime(RD_SECONDS_MIN), SAS_DATETIME_MIN);
+    assert_eq!(secs_to_sas_datetime(RD_SECONDS_MAX), SAS_DATETIME_MAX);
+}
+
+#[test]
+fn test_sas_datetime_to_secs() {
+    assert_eq!(sas_datetime_to_secs(-1), -315619201);
+    assert_eq!(sas_datetime_to_secs(SAS_DATETIME_MIN), RD_SECONDS_MIN);
+    assert_eq!(sas_datetime_to_secs(SAS_DATETIME_MAX), RD_SECONDS_MAX);
+}
+
+#[test]
+fn test_secs_to_stata_ms() {
+    assert_eq!(secs_to_stata_ms((-315619201, 999_999_999)), -1);
+    assert_eq!(secs_to_stata_ms((-315619200, 999_999)), 0);
+    assert_eq!(secs_to_stata_ms((RD_SECONDS_MIN, 0)), STATA_MS_MIN);
+    assert_eq!(secs_to_stata_ms((RD_SECONDS_MAX, 999_999_999)), STATA_MS_MAX);
+}
+
+#[test]
+fn test_stata_ms_to_secs() {
+    assert_eq!(stata_ms_to_secs(-1), (-315619201, 999_000_000));
+    assert_eq!(stata_ms_to_secs(1), (-315619200, 1_000_000));
+    assert_eq!(stata_ms_to_secs(STATA_MS_MIN), (RD_SECONDS_MIN, 0));
+    assert_eq!(stata_ms_to_secs(STATA_MS_MAX), (RD_SECONDS_MAX, 999_000_000));
+}
+
+#[test]
+fn test_j2000_consts() {
+    assert_eq!(J2000_EPOCH_SECS, datetime_to_secs((2000, 1, 1, 12, 0, 0)));
+    assert_eq!(J2000_SECONDS_MIN, RD_SECONDS_MIN - 946728000);
+    assert_eq!(J2000_SECONDS_MAX, RD_SECONDS_MAX - 946728000);
+}
+
+#[test]
+fn test_secs_to_j2000_secs() {
+    assert_eq!(secs_to_j2000_secs(datetime_to_secs((2000, 1, 1, 11, 59, 59))), -1);
+    assert_eq!(secs_to_j2000_secs(RD_SECONDS_MIN), J2000_SECONDS_MIN);
+    assert_eq!(secs_to_j2000_secs(RD_SECONDS_MAX), J2000_SECONDS_MAX);
+}
+
+#[test]
+fn test_j2000_secs_to_secs() {
+    assert_eq!(j2000_secs_to_secs(-1), datetime_to_secs((2000, 1, 1, 11, 59, 59)));
+    assert_eq!(j2000_secs_to_secs(J2000_SECONDS_MIN), RD_SECONDS_MIN);
+    assert_eq!(j2000_secs_to_secs(J2000_SECONDS_MAX), RD_SECONDS_MAX);
+}
+
+#[test]
+fn test_secs_to_j2000() {
+    assert_eq!(secs_to_j2000((RD_SECONDS_MIN, 0)), (J2000_SECONDS_MIN, 0));
+    assert_eq!(secs_to_j2000((RD_SECONDS_MAX, 999_999_999)), (J2000_SECONDS_MAX, 999_999_999));
+}
+
+#[test]
+fn test_j2000_to_secs() {
+    assert_eq!(j2000_to_secs((J2000_SECONDS_MIN, 0)), (RD_SECONDS_MIN, 0));
+    assert_eq!(j2000_to_secs((J2000_SECONDS_MAX, 999_999_999)), (RD_SECONDS_MAX, 999_999_999));
+}
+
+#[test]
+fn test_ccsds_consts() {
+    assert_eq!(CCSDS_EPOCH_SECS, datetime_to_secs((1958, 1, 1, 0, 0, 0)));
+}
+
+#[test]
+fn test_secs_to_cds() {
+    assert_eq!(secs_to_cds((CCSDS_EPOCH_SECS, 0)), Some((0, 0, 0)));
+    assert_eq!(secs_to_cds((CCSDS_EPOCH_SECS - 1, 999_999_999)), None);
+    assert_eq!(secs_to_cds((-1, 999_999_999)), Some((4382, 86_399_999, 999_999)));
+    let max = CCSDS_EPOCH_SECS + (CDS_DAYS_MAX as i64 + 1) * 86400 - 1;
+    assert_eq!(secs_to_cds((max, 999_999_999)), Some((CDS_DAYS_MAX, 86_399_999, 999_999)));
+    assert_eq!(secs_to_cds((max + 1, 0)), None);
+    assert_eq!(secs_to_cds((RD_SECONDS_MIN, 0)), None);
+    assert_eq!(secs_to_cds((RD_SECONDS_MAX, 0)), None);
+}
+
+#[test]
+fn test_cds_to_secs() {
+    assert_eq!(cds_to_secs((0, 0, 0)), Some((CCSDS_EPOCH_SECS, 0)));
+    assert_eq!(cds_to_secs((4382, 86_399_999, 999_999)), Some((-1, 999_999_999)));
+    assert_eq!(cds_to_secs((CDS_DAYS_MAX + 1, 0, 0)), None);
+    assert_eq!(cds_to_secs((0, 0, 1_000_000)), None);
+}
+
+#[test]
+fn test_encode_cds() {
+    let mut buf = [0u8; CDS_MAX_LEN];
+    assert_eq!(encode_cds((0, 0), 2, 0, &mut buf), Some(6));
+    assert_eq!(&buf[..6], &[0x11, 0x1f, 0, 0, 0, 0]);
+    assert_eq!(encode_cds((-1, 999_999_999), 3, 4, &mut buf), Some(11));
+    assert_eq!(buf, [0x00, 0x11, 0x1e, 0x05, 0x26, 0x5b, 0xff, 0x3b, 0x9a, 0xc6, 0x18]);
+    assert_eq!(encode_cds((CCSDS_EPOCH_SECS + 65536 * 86400, 0), 2, 0, &mut buf), None);
+    assert_eq!(encode_cds((CCSDS_EPOCH_SECS + 65536 * 86400, 0), 3, 0, &mut buf), Some(7));
+    assert_eq!(encode_cds((0, 0), 4, 0, &mut buf), None);
+}
+
+#[test]
+fn test_decode_cds() {
+    let max = [0x00, 0x11, 0x1e, 0x05, 0x26, 0x5b, 0xff, 0x3b, 0x9a, 0xc9, 0xff];
+    assert_eq!(decode_cds(&[0x11, 0x1f, 0, 0, 0, 0], 2, 0), Some((0, 0)));
+    assert_eq!(decode_cds(&max, 3, 4), Some((-1, 999_999_999)));
+    assert_eq!(decode_cds(&max[..10], 3, 4), None);
+    assert_eq!(
+        decode_cds(&[0x00, 0x11, 0x1e, 0x05, 0x26, 0x5b, 0xff, 0x3b, 0x9a, 0xca, 0x00], 3, 4),
+        None
+    );
+    assert_eq!(decode_cds(&[0x11, 0x1f, 0x05, 0x26, 0x5c, 0x00], 2, 0), None);
+    assert_eq!(decode_cds(&[0x11, 0x1f, 0, 0, 0, 0, 0x03, 0xe7], 2, 2), Some((0, 999_000)));
+    assert_eq!(decode_cds(&[0x11, 0x1f, 0, 0, 0, 0, 0x03, 0xe8], 2, 2), None);
+    assert_eq!(decode_cds(&[0x11, 0x1f, 0, 0, 0, 0], 2, 2), None);
+    assert_eq!(decode_cds(&[0x11, 0x1f, 0, 0, 0, 0], 1, 0), None);
+}
+
+#[test]
+fn test_cds_roundtrip() {
+    let mut buf = [0u8; CDS_MAX_LEN];
+    for secs in (CCSDS_EPOCH_SECS..CCSDS_EPOCH_SECS + 65536 * 86400).step_by(86400 * 97 + 12345) {
+        let len = encode_cds((secs, 123_456_000), 2, 4, &mut buf).unwrap();
+        assert_eq!(decode_cds(&buf[..len], 2, 4), Some((secs, 123_456_000)));
+    }
+}
+
+#[test]
+fn test_days_from_epoch() {
+    assert_eq!(days_from_epoch::<MJD_EPOCH_RD>(0), rd_to_mjd(0));
+    assert_eq!(days_from_epoch::<RD1_EPOCH_RD>(RD_MIN), rd_to_rd1(RD_MIN));
+    assert_eq!(days_from_epoch::<RD1_EPOCH_RD>(RD_MAX), rd_to_rd1(RD_MAX));
+    assert_eq!(days_from_epoch::<RD_MIN>(RD_MAX), RD_MAX - RD_MIN);
+}
+
+#[test]
+fn test_epoch_days_to_rd() {
+    assert_eq!(epoch_days_to_rd::<MJD_EPOCH_RD>(0), mjd_to_rd(0));
+    assert_eq!(epoch_days_to_rd::<RD1_EPOCH_RD>(RD1_MIN), RD_MIN);
+    assert_eq!(epoch_days_to_rd::<RD1_EPOCH_RD>(RD1_MAX), RD_MAX);
+    assert_eq!(epoch_days_to_rd::<RD_MIN>(RD_MAX - RD_MIN), RD_MAX);
+}
+
+#[test]
+fn test_secs_from_epoch() {
+    assert_eq!(secs_from_epoch::<J2000_EPOCH_SECS>(0), secs_to_j2000_secs(0));
+    assert_eq!(
+        secs_from_epoch::<COCOA_EPOCH_SECS>(RD_SECONDS_MIN),
+        secs_to_cocoa_secs(RD_SECONDS_MIN)
+    );
+    assert_eq!(secs_from_epoch::<RD_SECONDS_MIN>(RD_SECONDS_MAX), RD_SECONDS_MAX - RD_SECONDS_MIN);
+}
+
+#[test]
+fn test_epoch_secs_to_secs() {
+    assert_eq!(epoch_secs_to_secs::<J2000_EPOCH_SECS>(0), j2000_secs_to_secs(0));
+    assert_eq!(epoch_secs_to_secs::<COCOA_EPOCH_SECS>(COCOA_SECONDS_MAX), RD_SECONDS_MAX);
+    assert_eq!(
+        epoch_secs_to_secs::<RD_SECONDS_MIN>(RD_SECONDS_MAX - RD_SECONDS_MIN),
+        RD_SECONDS_MAX
+    );
+}
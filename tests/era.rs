@@ -0,0 +1,92 @@
+use datealgo::era::*;
+use datealgo::{YEAR_MAX, YEAR_MIN};
+
+#[test]
+fn test_holocene_consts() {
+    assert_eq!(HOLOCENE_YEAR_MIN, -1457999);
+    assert_eq!(HOLOCENE_YEAR_MAX, 1481744);
+}
+
+#[test]
+fn test_year_to_holocene() {
+    assert_eq!(year_to_holocene(1970), Some(11970));
+    assert_eq!(year_to_holocene(-10000), Some(0));
+    assert_eq!(year_to_holocene(YEAR_MIN), Some(HOLOCENE_YEAR_MIN));
+    assert_eq!(year_to_holocene(YEAR_MAX), Some(HOLOCENE_YEAR_MAX));
+    assert_eq!(year_to_holocene(YEAR_MIN - 1), None);
+    assert_eq!(year_to_holocene(YEAR_MAX + 1), None);
+    assert_eq!(year_to_holocene(i32::MIN), None);
+}
+
+#[test]
+fn test_holocene_to_year() {
+    assert_eq!(holocene_to_year(11970), Some(1970));
+    assert_eq!(holocene_to_year(0), Some(-10000));
+    assert_eq!(holocene_to_year(HOLOCENE_YEAR_MIN), Some(YEAR_MIN));
+    assert_eq!(holocene_to_year(HOLOCENE_YEAR_MAX), Some(YEAR_MAX));
+    assert_eq!(holocene_to_year(HOLOCENE_YEAR_MIN - 1), None);
+    assert_eq!(holocene_to_year(HOLOCENE_YEAR_MAX + 1), None);
+    assert_eq!(holocene_to_year(i32::MAX), None);
+}
+
+#[test]
+fn test_thai_consts() {
+    assert_eq!(THAI_YEAR_MIN, -1467456);
+    assert_eq!(THAI_YEAR_MAX, 1472287);
+}
+
+#[test]
+fn test_year_to_thai() {
+    assert_eq!(year_to_thai(1970), Some(2513));
+    assert_eq!(year_to_thai(0), Some(543));
+    assert_eq!(year_to_thai(-543), Some(0));
+    assert_eq!(year_to_thai(YEAR_MIN), Some(THAI_YEAR_MIN));
+    assert_eq!(year_to_thai(YEAR_MAX), Some(THAI_YEAR_MAX));
+    assert_eq!(year_to_thai(YEAR_MIN - 1), None);
+    assert_eq!(year_to_thai(YEAR_MAX + 1), None);
+}
+
+#[test]
+fn test_thai_to_year() {
+    assert_eq!(thai_to_year(2513), Some(1970));
+    assert_eq!(thai_to_year(0), Some(-543));
+    assert_eq!(thai_to_year(THAI_YEAR_MIN), Some(YEAR_MIN));
+    assert_eq!(thai_to_year(THAI_YEAR_MAX), Some(YEAR_MAX));
+    assert_eq!(thai_to_year(THAI_YEAR_MIN - 1), None);
+    assert_eq!(thai_to_year(THAI_YEAR_MAX + 1), None);
+}
+
+#[test]
+fn test_roc_consts() {
+    assert_eq!(ROC_YEAR_MIN, -1469911);
+    assert_eq!(ROC_YEAR_MAX, 1469833);
+}
+
+#[test]
+fn test_year_to_roc() {
+    assert_eq!(year_to_roc(1970), Some(59));
+    assert_eq!(year_to_roc(1910), Some(-2));
+    assert_eq!(year_to_roc(0), Some(-1912));
+    assert_eq!(year_to_roc(YEAR_MIN), Some(ROC_YEAR_MIN));
+    assert_eq!(year_to_roc(YEAR_MAX), Some(ROC_YEAR_MAX));
+    assert_eq!(year_to_roc(YEAR_MIN - 1), None);
+    assert_eq!(year_to_roc(YEAR_MAX + 1), None);
+}
+
+#[test]
+fn test_roc_to_year() {
+    assert_eq!(roc_to_year(59), Some(1970));
+    assert_eq!(roc_to_year(-2), Some(1910));
+    assert_eq!(roc_to_year(-1912), Some(0));
+    assert_eq!(roc_to_year(ROC_YEAR_MIN), Some(YEAR_MIN));
+    assert_eq!(roc_to_year(ROC_YEAR_MAX), Some(YEAR_MAX));
+    assert_eq!(roc_to_year(ROC_YEAR_MIN - 1), None);
+    assert_eq!(roc_to_year(ROC_YEAR_MAX + 1), None);
+}
+
+#[test]
+fn test_roc_roundtrip() {
+    for y in 1800..2100 {
+        assert_eq!(year_to_roc(y).and_then(roc_to_year), Some(y));
+    }
+}
@@ -0,0 +1,51 @@
+use datealgo::fast32::*;
+
+#[test]
+fn test_consts() {
+    assert_eq!(RD_MAX as i32, datealgo::date_to_rd((YEAR_MAX as i32, 12, 31)));
+    assert_eq!(
+        SECONDS_MAX as i64,
+        datealgo::datetime_to_secs((YEAR_MAX as i32, 12, 31, 23, 59, 59))
+    );
+}
+
+#[test]
+fn test_rd_to_date() {
+    for n in 0..=RD_MAX {
+        let (y, m, d) = datealgo::rd_to_date(n as i32);
+        assert_eq!(rd_to_date(n), (y as u16, m, d));
+    }
+}
+
+#[test]
+fn test_date_to_rd() {
+    for n in 0..=RD_MAX {
+        let (y, m, d) = datealgo::rd_to_date(n as i32);
+        assert_eq!(date_to_rd((y as u16, m, d)), n);
+    }
+}
+
+#[test]
+fn test_rd_to_weekday() {
+    for n in 0..=RD_MAX {
+        assert_eq!(rd_to_weekday(n), datealgo::rd_to_weekday(n as i32));
+    }
+}
+
+#[test]
+fn test_secs_to_datetime() {
+    for secs in (0..=SECONDS_MAX).step_by(7919).chain([SECONDS_MAX]) {
+        let (y, m, d, hh, mm, ss) = datealgo::secs_to_datetime(secs as i64);
+        assert_eq!(secs_to_datetime(secs), (y as u16, m, d, hh, mm, ss));
+        assert_eq!(datetime_to_secs((y as u16, m, d, hh, mm, ss)), secs);
+    }
+}
+
+#[test]
+fn test_secs_to_dhms() {
+    for secs in (0..=SECONDS_MAX).step_by(7919).chain([SECONDS_MAX]) {
+        let (d, hh, mm, ss) = datealgo::secs_to_dhms(secs as i64);
+        assert_eq!(secs_to_dhms(secs), (d as u16, hh, mm, ss));
+        assert_eq!(dhms_to_secs((d as u16, hh, mm, ss)), secs);
+    }
+}
@@ -0,0 +1,57 @@
+use datealgo::calendar::french::*;
+use datealgo::{date_to_rd, RD_MAX, RD_MIN};
+
+#[test]
+fn test_french_consts() {
+    assert_eq!(FRENCH_EPOCH, date_to_rd((1792, 9, 22)));
+}
+
+#[test]
+fn test_rd_to_french_date() {
+    assert_eq!(rd_to_french_date(FRENCH_EPOCH - 1), (0, 13, 5));
+    assert_eq!(rd_to_french_date(date_to_rd((1805, 9, 23))), (14, 1, 1));
+    assert_eq!(rd_to_french_date(date_to_rd((1805, 12, 31))), (14, 4, 10));
+    assert_eq!(rd_to_french_date(date_to_rd((1808, 9, 23))), (17, 1, 1));
+    assert_eq!(rd_to_french_date(date_to_rd((1871, 5, 6))), (79, 8, 16));
+    assert_eq!(rd_to_french_date(date_to_rd((2022, 9, 22))), (231, 1, 1));
+}
+
+#[test]
+fn test_french_date_to_rd() {
+    assert_eq!(french_date_to_rd((0, 13, 5)), FRENCH_EPOCH - 1);
+    assert_eq!(french_date_to_rd((4, 1, 1)), date_to_rd((1795, 9, 23)));
+    assert_eq!(french_date_to_rd((15, 13, 6)), date_to_rd((1807, 9, 23)));
+    assert_eq!(french_date_to_rd((16, 13, 5)), date_to_rd((1808, 9, 22)));
+    assert_eq!(french_date_to_rd((20, 13, 6)), date_to_rd((1812, 9, 22)));
+}
+
+#[test]
+fn test_french_roundtrip() {
+    for n in (RD_MIN..=RD_MAX).step_by(7919) {
+        assert_eq!(french_date_to_rd(rd_to_french_date(n)), n);
+    }
+    for n in -100000..100000 {
+        let (y, m, d) = rd_to_french_date(n);
+        assert!(d <= french_days_in_month(y, m));
+        assert_eq!(french_date_to_rd((y, m, d)), n);
+    }
+}
+
+#[test]
+fn test_is_french_leap_year() {
+    let leaps: Vec<i32> = (1..=30).filter(|&y| is_french_leap_year(y)).collect();
+    assert_eq!(leaps, [3, 7, 11, 15, 20, 24, 28]);
+    assert!(is_french_leap_year(400));
+    assert!(!is_french_leap_year(4000));
+    assert!(!is_french_leap_year(0));
+    assert!(is_french_leap_year(-4));
+}
+
+#[test]
+fn test_french_days_in_month() {
+    for m in 1..=12 {
+        assert_eq!(french_days_in_month(1, m), 30);
+    }
+    assert_eq!(french_days_in_month(1, 13), 5);
+    assert_eq!(french_days_in_month(20, 13), 6);
+}
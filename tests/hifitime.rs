@@ -0,0 +1,45 @@
+#![cfg(feature = "hifitime")]
+
+use datealgo::hifitime::*;
+use datealgo::{datetime_to_secs, secs_to_datetime, RD_SECONDS_MAX, RD_SECONDS_MIN};
+use hifitime::Epoch;
+
+#[test]
+fn test_tai_roundtrip() {
+    for secs in (-1_000_000..1_000_000).map(|s| s * 4999) {
+        assert_eq!(epoch_to_tai(tai_to_epoch((secs, 123))), (secs, 123));
+    }
+    assert_eq!(epoch_to_tai(tai_to_epoch((-1, 999_999_999))), (-1, 999_999_999));
+}
+
+#[test]
+fn test_secs_roundtrip() {
+    for secs in (-1_000_000..1_000_000).map(|s| s * 4999).chain([RD_SECONDS_MIN, RD_SECONDS_MAX]) {
+        assert_eq!(epoch_to_secs(secs_to_epoch((secs, 123))), (secs, 123));
+    }
+}
+
+#[test]
+fn test_secs_to_epoch() {
+    for secs in (63072000..1_800_000_000).step_by(999_983) {
+        let (y, m, d, hh, mm, ss) = secs_to_datetime(secs);
+        assert_eq!(secs_to_epoch((secs, 7)), Epoch::from_gregorian_utc(y, m, d, hh, mm, ss, 7));
+    }
+}
+
+#[test]
+fn test_leap_second() {
+    let leap = Epoch::from_gregorian_tai(2017, 1, 1, 0, 0, 36, 0);
+    let before = datetime_to_secs((2016, 12, 31, 23, 59, 59));
+    assert_eq!(epoch_to_secs(leap), (before, 0));
+    assert_eq!(epoch_to_secs(secs_to_epoch((before + 1, 0))), (before + 1, 0));
+    assert_eq!(epoch_to_tai(secs_to_epoch((before + 1, 0))), (before + 38, 0));
+}
+
+#[test]
+fn test_rd() {
+    for n in (-100_000..100_000).step_by(7) {
+        assert_eq!(epoch_to_rd(rd_to_epoch(n)), n);
+    }
+    assert_eq!(epoch_to_rd(secs_to_epoch((-1, 0))), -1);
+}
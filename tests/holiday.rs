@@ -0,0 +1,96 @@
+use datealgo::consts::{MONDAY, SATURDAY, SUNDAY, THURSDAY};
+use datealgo::easter::easter_sunday;
+use datealgo::holiday::*;
+use datealgo::{date_to_rd, days_in_month, rd_to_date, rd_to_weekday};
+
+const US_FEDERAL: [HolidayRule; 11] = [
+    HolidayRule::NearestWeekday(1, 1),
+    HolidayRule::NthWeekday(1, 3, MONDAY),
+    HolidayRule::NthWeekday(2, 3, MONDAY),
+    HolidayRule::NthWeekday(5, 5, MONDAY),
+    HolidayRule::NearestWeekday(6, 19),
+    HolidayRule::NearestWeekday(7, 4),
+    HolidayRule::NthWeekday(9, 1, MONDAY),
+    HolidayRule::NthWeekday(10, 2, MONDAY),
+    HolidayRule::NearestWeekday(11, 11),
+    HolidayRule::NthWeekday(11, 4, THURSDAY),
+    HolidayRule::NearestWeekday(12, 25),
+];
+
+#[test]
+fn test_us_federal() {
+    let days: Vec<(i32, u8, u8)> = evaluate_holiday_rules(2023, &US_FEDERAL).map(rd_to_date).collect();
+    assert_eq!(
+        days,
+        [
+            (2023, 1, 2),
+            (2023, 1, 16),
+            (2023, 2, 20),
+            (2023, 5, 29),
+            (2023, 6, 19),
+            (2023, 7, 4),
+            (2023, 9, 4),
+            (2023, 10, 9),
+            (2023, 11, 10),
+            (2023, 11, 23),
+            (2023, 12, 25),
+        ]
+    );
+}
+
+#[test]
+fn test_uk_bank_holidays() {
+    let rules = [
+        HolidayRule::EasterOffset(-2),
+        HolidayRule::EasterOffset(1),
+        HolidayRule::NthWeekday(5, 1, MONDAY),
+        HolidayRule::NthWeekday(5, 5, MONDAY),
+        HolidayRule::NthWeekday(8, 5, MONDAY),
+    ];
+    let days: Vec<(i32, u8, u8)> = evaluate_holiday_rules(2024, &rules).map(rd_to_date).collect();
+    assert_eq!(days, [(2024, 3, 29), (2024, 4, 1), (2024, 5, 6), (2024, 5, 27), (2024, 8, 26)]);
+}
+
+#[test]
+fn test_holiday_to_rd() {
+    for y in 1990..=2030 {
+        for m in 1..=12 {
+            for wd in 1..=7 {
+                let matching: Vec<i32> = (1..=days_in_month(y, m))
+                    .map(|d| date_to_rd((y, m, d)))
+                    .filter(|&rd| rd_to_weekday(rd) == wd)
+                    .collect();
+                for w in 1..=4 {
+                    assert_eq!(holiday_to_rd(y, HolidayRule::NthWeekday(m, w, wd)), matching[w as usize - 1]);
+                }
+                assert_eq!(holiday_to_rd(y, HolidayRule::NthWeekday(m, 5, wd)), *matching.last().unwrap());
+            }
+            for d in 1..=days_in_month(y, m) {
+                let rd = date_to_rd((y, m, d));
+                assert_eq!(holiday_to_rd(y, HolidayRule::Fixed(m, d)), rd);
+                let nearest = holiday_to_rd(y, HolidayRule::NearestWeekday(m, d));
+                let expected = match rd_to_weekday(rd) {
+                    SATURDAY => rd - 1,
+                    SUNDAY => rd + 1,
+                    _ => rd,
+                };
+                assert_eq!(nearest, expected);
+                assert!(rd_to_weekday(nearest) < SATURDAY);
+            }
+        }
+        let easter = date_to_rd(easter_sunday(y));
+        assert_eq!(holiday_to_rd(y, HolidayRule::EasterOffset(0)), easter);
+        assert_eq!(holiday_to_rd(y, HolidayRule::EasterOffset(39)), easter + 39);
+        assert_eq!(holiday_to_rd(y, HolidayRule::EasterOffset(-47)), easter - 47);
+    }
+}
+
+#[test]
+fn test_evaluate_holiday_rules() {
+    assert_eq!(evaluate_holiday_rules(2023, &[]).count(), 0);
+    for y in [-1000, 0, 1970, 2000, 10000] {
+        let days: Vec<i32> = evaluate_holiday_rules(y, &US_FEDERAL).collect();
+        let expected: Vec<i32> = US_FEDERAL.iter().map(|&rule| holiday_to_rd(y, rule)).collect();
+        assert_eq!(days, expected);
+    }
+}
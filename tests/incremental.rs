@@ -0,0 +1,39 @@
+use datealgo::incremental::IncrementalDateTime;
+use datealgo::{secs_to_datetime, RD_SECONDS_MAX, RD_SECONDS_MIN};
+
+#[test]
+fn test_incremental_sorted() {
+    let mut conv = IncrementalDateTime::new();
+    for secs in (1684500000..1684800000).step_by(37) {
+        assert_eq!(conv.secs_to_datetime(secs), secs_to_datetime(secs));
+    }
+}
+
+#[test]
+fn test_incremental_jitter() {
+    let mut conv = IncrementalDateTime::default();
+    let mut secs = -200000;
+    for i in 0..100000i64 {
+        let s = secs + (i * 7919 % 601) - 300;
+        assert_eq!(conv.secs_to_datetime(s), secs_to_datetime(s));
+        secs += 5;
+    }
+}
+
+#[test]
+fn test_incremental_extremes() {
+    let mut conv = IncrementalDateTime::new();
+    for secs in [
+        0,
+        RD_SECONDS_MIN,
+        RD_SECONDS_MIN + 86399,
+        RD_SECONDS_MAX,
+        RD_SECONDS_MAX - 86399,
+        0,
+        -1,
+        86400,
+    ] {
+        assert_eq!(conv.secs_to_datetime(secs), secs_to_datetime(secs));
+        assert_eq!(conv.midnight_secs(), conv.rd() as i64 * 86400);
+    }
+}
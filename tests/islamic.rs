@@ -0,0 +1,103 @@
+use datealgo::calendar::islamic::*;
+use datealgo::{date_to_rd, RD_MAX, RD_MIN};
+
+const PATTERNS: [u32; 4] = [
+    ISLAMIC_LEAP_PATTERN_I,
+    ISLAMIC_LEAP_PATTERN_II,
+    ISLAMIC_LEAP_PATTERN_III,
+    ISLAMIC_LEAP_PATTERN_IV,
+];
+
+fn reference_islamic_to_rd((y, m, d): (i32, u8, u8)) -> i32 {
+    let (y, m, d) = (y as i64, m as i64, d as i64);
+    let n = d + (59 * (m - 1) + 1) / 2 + (y - 1) * 354 + (3 + 11 * y).div_euclid(30) - 1;
+    (n + ISLAMIC_EPOCH_CIVIL as i64) as i32
+}
+
+#[test]
+fn test_islamic_consts() {
+    assert_eq!(ISLAMIC_EPOCH_CIVIL, date_to_rd((622, 7, 19)));
+    assert_eq!(ISLAMIC_EPOCH_ASTRONOMICAL, date_to_rd((622, 7, 18)));
+    for p in PATTERNS {
+        assert_eq!(p.count_ones(), 11);
+        assert!(p < 1 << 30);
+    }
+}
+
+#[test]
+fn test_rd_to_islamic_tabular() {
+    let p = ISLAMIC_LEAP_PATTERN_II;
+    assert_eq!(rd_to_islamic_tabular(ISLAMIC_EPOCH_CIVIL, p, ISLAMIC_EPOCH_CIVIL), (1, 1, 1));
+    assert_eq!(rd_to_islamic_tabular(ISLAMIC_EPOCH_CIVIL, p, ISLAMIC_EPOCH_ASTRONOMICAL), (1, 1, 2));
+    assert_eq!(rd_to_islamic_tabular(ISLAMIC_EPOCH_CIVIL - 1, p, ISLAMIC_EPOCH_CIVIL), (0, 12, 29));
+    assert_eq!(rd_to_islamic_tabular(19910, p, ISLAMIC_EPOCH_CIVIL), (1445, 12, 29));
+    assert_eq!(rd_to_islamic_tabular(19911, p, ISLAMIC_EPOCH_CIVIL), (1445, 12, 30));
+    assert_eq!(rd_to_islamic_tabular(19912, p, ISLAMIC_EPOCH_CIVIL), (1446, 1, 1));
+    for n in (RD_MIN..=RD_MAX).step_by(7919) {
+        let (y, m, d) = rd_to_islamic_tabular(n, p, ISLAMIC_EPOCH_CIVIL);
+        assert_eq!(reference_islamic_to_rd((y, m, d)), n);
+    }
+}
+
+#[test]
+fn test_islamic_tabular_to_rd() {
+    let p = ISLAMIC_LEAP_PATTERN_II;
+    assert_eq!(
+        islamic_tabular_to_rd((1, 1, 1), p, ISLAMIC_EPOCH_ASTRONOMICAL),
+        ISLAMIC_EPOCH_ASTRONOMICAL
+    );
+    assert_eq!(islamic_tabular_to_rd((0, 1, 1), p, ISLAMIC_EPOCH_CIVIL), -492502);
+    assert_eq!(islamic_tabular_to_rd((-1, 12, 30), p, ISLAMIC_EPOCH_CIVIL), -492503);
+    assert_eq!(islamic_tabular_to_rd((1445, 12, 30), p, ISLAMIC_EPOCH_CIVIL), 19911);
+    for y in [-1000000, -31, -30, -1, 0, 1, 29, 30, 31, 1445, 1000000] {
+        for m in 1..=12 {
+            let d = islamic_tabular_days_in_month(y, m, p);
+            assert_eq!(
+                islamic_tabular_to_rd((y, m, d), p, ISLAMIC_EPOCH_CIVIL),
+                reference_islamic_to_rd((y, m, d))
+            );
+        }
+    }
+}
+
+#[test]
+fn test_islamic_tabular_roundtrip() {
+    for p in PATTERNS {
+        for epoch in [ISLAMIC_EPOCH_CIVIL, ISLAMIC_EPOCH_ASTRONOMICAL] {
+            for n in (RD_MIN..=RD_MAX).step_by(104729) {
+                assert_eq!(islamic_tabular_to_rd(rd_to_islamic_tabular(n, p, epoch), p, epoch), n);
+            }
+            for n in ISLAMIC_EPOCH_CIVIL - 11000..ISLAMIC_EPOCH_CIVIL + 11000 {
+                assert_eq!(islamic_tabular_to_rd(rd_to_islamic_tabular(n, p, epoch), p, epoch), n);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_is_islamic_tabular_leap_year() {
+    let years = [
+        [2, 5, 7, 10, 13, 15, 18, 21, 24, 26, 29],
+        [2, 5, 7, 10, 13, 16, 18, 21, 24, 26, 29],
+        [2, 5, 8, 10, 13, 16, 19, 21, 24, 27, 29],
+        [2, 5, 8, 11, 13, 16, 19, 21, 24, 27, 30],
+    ];
+    for (p, years) in PATTERNS.iter().zip(years) {
+        for y in 1..=30 {
+            assert_eq!(is_islamic_tabular_leap_year(y, *p), years.contains(&y));
+            assert_eq!(is_islamic_tabular_leap_year(y - 30, *p), years.contains(&y));
+            assert_eq!(is_islamic_tabular_leap_year(y + 1410, *p), years.contains(&y));
+        }
+    }
+}
+
+#[test]
+fn test_islamic_tabular_days_in_month() {
+    let p = ISLAMIC_LEAP_PATTERN_II;
+    let days = [30, 29, 30, 29, 30, 29, 30, 29, 30, 29, 30, 30];
+    for m in 1..=12 {
+        assert_eq!(islamic_tabular_days_in_month(1445, m, p), days[m as usize - 1]);
+    }
+    assert_eq!(islamic_tabular_days_in_month(0, 12, p), 29);
+    assert_eq!(islamic_tabular_days_in_month(-1, 12, p), 30);
+}
@@ -0,0 +1,242 @@
+use datealgo::iso::*;
+use datealgo::{rd_to_date, RD_MAX, RD_MIN, YEAR_MAX, YEAR_MIN};
+
+#[test]
+fn test_parse_offset() {
+    assert_eq!(parse_offset(b"Z"), Some(0));
+    assert_eq!(parse_offset(b"z"), Some(0));
+    assert_eq!(parse_offset(b"+00:00"), Some(0));
+    assert_eq!(parse_offset(b"-00:00"), Some(0));
+    assert_eq!(parse_offset(b"+23:59"), Some(86340));
+    assert_eq!(parse_offset(b"-23:59"), Some(-86340));
+    assert_eq!(parse_offset(b"-00:30"), Some(-1800));
+    assert_eq!(parse_offset(b"-0930"), Some(-34200));
+    assert_eq!(parse_offset(b"+14"), Some(50400));
+    assert_eq!(parse_offset(b""), None);
+    assert_eq!(parse_offset(b"Z "), None);
+    assert_eq!(parse_offset(b"+1"), None);
+    assert_eq!(parse_offset(b"+01:"), None);
+    assert_eq!(parse_offset(b"+01-00"), None);
+    assert_eq!(parse_offset(b"+0100:"), None);
+    assert_eq!(parse_offset(b"+01:60"), None);
+    assert_eq!(parse_offset(b"+0160"), None);
+    assert_eq!(parse_offset(b"+24"), None);
+    assert_eq!(parse_offset(b"+a1:00"), None);
+    assert_eq!(parse_offset(b"+01:0a"), None);
+    assert_eq!(parse_offset(b" 01:00"), None);
+}
+
+#[test]
+fn test_format_offset() {
+    let mut buf = [0; 6];
+    format_offset(0, &mut buf);
+    assert_eq!(&buf, b"+00:00");
+    format_offset(-1800, &mut buf);
+    assert_eq!(&buf, b"-00:30");
+    format_offset(-59, &mut buf);
+    assert_eq!(&buf, b"+00:00");
+    format_offset(-60, &mut buf);
+    assert_eq!(&buf, b"-00:01");
+    format_offset(86399, &mut buf);
+    assert_eq!(&buf, b"+23:59");
+    format_offset(-86399, &mut buf);
+    assert_eq!(&buf, b"-23:59");
+    format_offset(-34200, &mut buf);
+    assert_eq!(&buf, b"-09:30");
+}
+
+#[test]
+fn test_offset_roundtrip() {
+    let mut buf = [0; 6];
+    for offset in (-86340..=86340).step_by(60) {
+        format_offset(offset, &mut buf);
+        assert_eq!(parse_offset(&buf), Some(offset));
+    }
+}
+
+#[test]
+fn test_format_offset_basic() {
+    let mut buf = [0; 5];
+    let mut ext = [0; 6];
+    for offset in -86399..=86399 {
+        format_offset_basic(offset, &mut buf);
+        format_offset(offset, &mut ext);
+        assert_eq!(&buf[..3], &ext[..3]);
+        assert_eq!(&buf[3..], &ext[4..]);
+        if offset % 60 == 0 {
+            assert_eq!(parse_offset(&buf), Some(offset));
+        }
+    }
+}
+
+#[test]
+fn test_format_offset_directive() {
+    let mut buf = [0; 16];
+    let mut basic = [0; 5];
+    let mut ext = [0; 6];
+    for offset in (-86399..=86399).step_by(7) {
+        format_offset_basic(offset, &mut basic);
+        format_offset(offset, &mut ext);
+        assert_eq!(format_offset_directive(offset, b"", b"%z", &mut buf), Some(5));
+        assert_eq!(&buf[..5], &basic);
+        assert_eq!(format_offset_directive(offset, b"", b"%:z", &mut buf), Some(6));
+        assert_eq!(&buf[..6], &ext);
+    }
+    assert_eq!(format_offset_directive(0, b"UTC", b"%Z", &mut buf), Some(3));
+    assert_eq!(&buf[..3], b"UTC");
+    assert_eq!(format_offset_directive(0, b"", b"%Z", &mut buf), Some(0));
+    assert_eq!(format_offset_directive(-10800, b"-03", b"%Z", &mut buf), Some(3));
+    assert_eq!(&buf[..3], b"-03");
+    assert_eq!(format_offset_directive(0, b"UTC", b"%", &mut buf), None);
+    assert_eq!(format_offset_directive(0, b"UTC", b"%::z", &mut buf), None);
+    assert_eq!(format_offset_directive(0, b"UTC", b"z", &mut buf), None);
+    assert_eq!(format_offset_directive(0, b"UTC", b"", &mut buf), None);
+    assert_eq!(format_offset_directive(0, b"UTC", b"%z", &mut buf[..4]), None);
+    assert_eq!(format_offset_directive(0, b"UTC", b"%:z", &mut buf[..5]), None);
+    assert_eq!(format_offset_directive(0, b"UTC", b"%Z", &mut buf[..2]), None);
+    assert_eq!(format_offset_directive(0, b"UTC", b"%Z", &mut buf[..3]), Some(3));
+}
+
+#[test]
+fn test_parse_iso_duration() {
+    assert_eq!(parse_iso_duration(b"P1Y"), Some((1, 0, 0, 0, 0)));
+    assert_eq!(parse_iso_duration(b"P1M"), Some((0, 1, 0, 0, 0)));
+    assert_eq!(parse_iso_duration(b"P1D"), Some((0, 0, 1, 0, 0)));
+    assert_eq!(parse_iso_duration(b"PT1H"), Some((0, 0, 0, 3600, 0)));
+    assert_eq!(parse_iso_duration(b"PT1M"), Some((0, 0, 0, 60, 0)));
+    assert_eq!(parse_iso_duration(b"PT1S"), Some((0, 0, 0, 1, 0)));
+    assert_eq!(parse_iso_duration(b"P0D"), Some((0, 0, 0, 0, 0)));
+    assert_eq!(parse_iso_duration(b"PT0S"), Some((0, 0, 0, 0, 0)));
+    assert_eq!(parse_iso_duration(b"+P1M"), Some((0, 1, 0, 0, 0)));
+    assert_eq!(parse_iso_duration(b"-P1Y2M3W4DT5H6M7S"), Some((-1, -2, -25, -18367, 0)));
+    assert_eq!(parse_iso_duration(b"PT1,5S"), Some((0, 0, 0, 1, 500_000_000)));
+    assert_eq!(parse_iso_duration(b"PT0.000000001S"), Some((0, 0, 0, 0, 1)));
+    assert_eq!(parse_iso_duration(b"PT0.0000000019S"), Some((0, 0, 0, 0, 1)));
+    assert_eq!(parse_iso_duration(b"-PT0.1S"), Some((0, 0, 0, 0, -100_000_000)));
+    assert_eq!(parse_iso_duration(b"P2147483647Y"), Some((i32::MAX, 0, 0, 0, 0)));
+    assert_eq!(parse_iso_duration(b"P2147483648Y"), None);
+    assert_eq!(parse_iso_duration(b"P306783379W"), None);
+    assert_eq!(parse_iso_duration(b"PT2562047788015216H"), None);
+    assert_eq!(parse_iso_duration(b"P99999999999999999999Y"), None);
+    assert_eq!(parse_iso_duration(b""), None);
+    assert_eq!(parse_iso_duration(b"P"), None);
+    assert_eq!(parse_iso_duration(b"PT"), None);
+    assert_eq!(parse_iso_duration(b"P1DT"), None);
+    assert_eq!(parse_iso_duration(b"1D"), None);
+    assert_eq!(parse_iso_duration(b"P1"), None);
+    assert_eq!(parse_iso_duration(b"PD"), None);
+    assert_eq!(parse_iso_duration(b"P1H"), None);
+    assert_eq!(parse_iso_duration(b"PT1D"), None);
+    assert_eq!(parse_iso_duration(b"P1D1D"), None);
+    assert_eq!(parse_iso_duration(b"P1M1Y"), None);
+    assert_eq!(parse_iso_duration(b"PT1S1M"), None);
+    assert_eq!(parse_iso_duration(b"PT1HT1M"), None);
+    assert_eq!(parse_iso_duration(b"P1.5D"), None);
+    assert_eq!(parse_iso_duration(b"PT1.5M"), None);
+    assert_eq!(parse_iso_duration(b"PT1.S"), None);
+    assert_eq!(parse_iso_duration(b"--P1D"), None);
+    assert_eq!(parse_iso_duration(b"P-1D"), None);
+}
+
+#[test]
+fn test_format_iso_duration() {
+    let mut buf = [0; ISO_DURATION_MAX_LEN];
+    let mut f = |d| {
+        let len = format_iso_duration(d, &mut buf);
+        String::from_utf8(buf[..len].to_vec()).unwrap()
+    };
+    assert_eq!(f((0, 0, 0, 0, 0)), "PT0S");
+    assert_eq!(f((1, 0, 0, 0, 0)), "P1Y");
+    assert_eq!(f((0, 1, 0, 0, 0)), "P1M");
+    assert_eq!(f((0, 0, 1, 0, 0)), "P1D");
+    assert_eq!(f((0, 0, 0, 3600, 0)), "PT1H");
+    assert_eq!(f((0, 0, 0, 60, 0)), "PT1M");
+    assert_eq!(f((0, 0, 0, 3601, 0)), "PT1H1S");
+    assert_eq!(f((0, 0, 0, 0, 1)), "PT0.000000001S");
+    assert_eq!(f((0, 0, 0, 0, 120_000_000)), "PT0.12S");
+    assert_eq!(f((0, 0, 0, 0, -120_000_000)), "-PT0.12S");
+    assert_eq!(f((-1, -2, -25, -18367, 0)), "-P1Y2M25DT5H6M7S");
+    assert_eq!(
+        f((i32::MIN, i32::MIN, i32::MIN, i64::MIN, -999_999_999)),
+        "-P2147483648Y2147483648M2147483648DT2562047788015215H30M8.999999999S"
+    );
+    assert_eq!(
+        f((i32::MAX, i32::MAX, i32::MAX, i64::MAX, 999_999_999)),
+        "P2147483647Y2147483647M2147483647DT2562047788015215H30M7.999999999S"
+    );
+}
+
+#[test]
+fn test_iso_duration_roundtrip() {
+    let mut buf = [0; ISO_DURATION_MAX_LEN];
+    for d in [
+        (0, 0, 0, 0, 0),
+        (1, 2, 3, 4, 5),
+        (-1, -2, -3, -4, -5),
+        (0, 0, 0, 86399, 999_999_999),
+        (0, 0, 0, -86399, -999_999_999),
+        (i32::MAX, i32::MAX, i32::MAX, i64::MAX, 999_999_999),
+    ] {
+        let len = format_iso_duration(d, &mut buf);
+        assert_eq!(parse_iso_duration(&buf[..len]), Some(d));
+    }
+}
+
+#[test]
+fn test_parse_date() {
+    assert_eq!(parse_date(b"0000-01-01"), Some((0, 1, 1)));
+    assert_eq!(parse_date(b"9999-12-31"), Some((9999, 12, 31)));
+    assert_eq!(parse_date(b"1970-01-01"), Some((1970, 1, 1)));
+    assert_eq!(parse_date(b"2024-02-29"), Some((2024, 2, 29)));
+    assert_eq!(parse_date(b"+0000-01-01"), Some((0, 1, 1)));
+    assert_eq!(parse_date(b"-0000-01-01"), Some((0, 1, 1)));
+    assert_eq!(parse_date(b"+010000-01-01"), Some((10000, 1, 1)));
+    assert_eq!(parse_date(b"-000400-02-29"), Some((-400, 2, 29)));
+    assert_eq!(parse_date(b"+1471744-12-31"), Some((YEAR_MAX, 12, 31)));
+    assert_eq!(parse_date(b"-1467999-01-01"), Some((YEAR_MIN, 1, 1)));
+    assert_eq!(parse_date(b"+1471745-01-01"), None);
+    assert_eq!(parse_date(b"-1468000-01-01"), None);
+    assert_eq!(parse_date(b"+99999999999999999999-01-01"), None);
+    assert_eq!(parse_date(b""), None);
+    assert_eq!(parse_date(b"+"), None);
+    assert_eq!(parse_date(b"999-01-01"), None);
+    assert_eq!(parse_date(b"+999-01-01"), None);
+    assert_eq!(parse_date(b"2023-1-01"), None);
+    assert_eq!(parse_date(b"2023-01-1"), None);
+    assert_eq!(parse_date(b"2023-01-011"), None);
+    assert_eq!(parse_date(b"2023/01/01"), None);
+    assert_eq!(parse_date(b"20230101"), None);
+    assert_eq!(parse_date(b"2023-00-01"), None);
+    assert_eq!(parse_date(b"2023-13-01"), None);
+    assert_eq!(parse_date(b"2023-01-00"), None);
+    assert_eq!(parse_date(b"2023-04-31"), None);
+    assert_eq!(parse_date(b"2100-02-29"), None);
+}
+
+#[test]
+fn test_format_date() {
+    let mut buf = [0; ISO_DATE_MAX_LEN];
+    let mut f = |d| {
+        let len = format_date(d, &mut buf);
+        String::from_utf8(buf[..len].to_vec()).unwrap()
+    };
+    assert_eq!(f((0, 1, 1)), "0000-01-01");
+    assert_eq!(f((1, 1, 1)), "0001-01-01");
+    assert_eq!(f((9999, 12, 31)), "9999-12-31");
+    assert_eq!(f((10000, 1, 1)), "+010000-01-01");
+    assert_eq!(f((-1, 1, 1)), "-000001-01-01");
+    assert_eq!(f((999999, 1, 1)), "+999999-01-01");
+    assert_eq!(f((1000000, 1, 1)), "+1000000-01-01");
+    assert_eq!(f((YEAR_MAX, 12, 31)), "+1471744-12-31");
+    assert_eq!(f((YEAR_MIN, 1, 1)), "-1467999-01-01");
+}
+
+#[test]
+fn test_date_roundtrip() {
+    let mut buf = [0; ISO_DATE_MAX_LEN];
+    for rd in (RD_MIN..=RD_MAX).step_by(9973).chain([RD_MIN, RD_MAX]) {
+        let d = rd_to_date(rd);
+        let len = format_date(d, &mut buf);
+        assert_eq!(parse_date(&buf[..len]), Some(d));
+    }
+}
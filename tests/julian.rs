@@ -0,0 +1,104 @@
+use datealgo::calendar::julian::*;
+use datealgo::{date_to_rd, rd_to_date, RD_MAX, RD_MIN};
+
+fn reference_julian_date_to_rd((y, m, d): (i32, u8, u8)) -> i32 {
+    let (y, m, d) = (y as i64, m as i64, d as i64);
+    let a = (14 - m) / 12;
+    let y = y + 4800 - a;
+    let m = m + 12 * a - 3;
+    let jdn = d + (153 * m + 2) / 5 + 365 * y + y.div_euclid(4) - 32083;
+    (jdn - 2440588) as i32
+}
+
+#[test]
+fn test_julian_consts() {
+    assert_eq!(rd_to_julian_date(RD_MIN).0, JULIAN_YEAR_MIN);
+    assert_eq!(rd_to_julian_date(RD_MAX).0, JULIAN_YEAR_MAX);
+    assert_eq!(GREGORIAN_REFORM_RD, date_to_rd((1582, 10, 15)));
+    assert_eq!(GREGORIAN_REFORM_RD - 1, julian_date_to_rd((1582, 10, 4)));
+    assert_eq!(BRITISH_REFORM_RD, date_to_rd((1752, 9, 14)));
+    assert_eq!(BRITISH_REFORM_RD - 1, julian_date_to_rd((1752, 9, 2)));
+}
+
+#[test]
+fn test_rd_to_julian_date() {
+    assert_eq!(rd_to_julian_date(RD_MIN), (-1467969, 2, 25));
+    assert_eq!(rd_to_julian_date(RD_MAX), (1471714, 10, 14));
+    assert_eq!(rd_to_julian_date(date_to_rd((0, 12, 30))), (1, 1, 1));
+    assert_eq!(rd_to_julian_date(date_to_rd((200, 3, 1))), (200, 3, 1));
+    assert_eq!(rd_to_julian_date(date_to_rd((1900, 3, 13))), (1900, 2, 29));
+    assert_eq!(rd_to_julian_date(date_to_rd((0, 3, 1))), (0, 3, 3));
+}
+
+#[test]
+fn test_julian_date_to_rd() {
+    assert_eq!(julian_date_to_rd((1, 1, 1)), date_to_rd((0, 12, 30)));
+    assert_eq!(julian_date_to_rd((1900, 2, 29)), date_to_rd((1900, 3, 13)));
+    assert_eq!(julian_date_to_rd((-4712, 1, 1)), -2440588);
+    for y in [-1000001, -4, -1, 0, 1, 4, 1969, 1970, 2000, 1000003] {
+        for m in 1..=12 {
+            for d in [1, julian_days_in_month(y, m)] {
+                assert_eq!(julian_date_to_rd((y, m, d)), reference_julian_date_to_rd((y, m, d)));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_julian_roundtrip() {
+    for n in (RD_MIN..=RD_MAX).step_by(7919) {
+        assert_eq!(julian_date_to_rd(rd_to_julian_date(n)), n);
+    }
+    for n in -1000..=1000 {
+        let (y, m, d) = rd_to_julian_date(n);
+        assert_eq!(reference_julian_date_to_rd((y, m, d)), n);
+    }
+}
+
+#[test]
+fn test_is_julian_leap_year() {
+    assert!(!is_julian_leap_year(JULIAN_YEAR_MIN));
+    assert!(!is_julian_leap_year(JULIAN_YEAR_MAX));
+    assert!(is_julian_leap_year(1700));
+    assert!(!is_julian_leap_year(-3));
+}
+
+#[test]
+fn test_julian_days_in_month() {
+    let days = [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+    for m in 1..=12 {
+        assert_eq!(julian_days_in_month(2023, m), days[m as usize - 1]);
+    }
+    assert_eq!(julian_days_in_month(2100, 2), 29);
+}
+
+#[test]
+fn test_rd_to_hybrid_date() {
+    assert_eq!(rd_to_hybrid_date(GREGORIAN_REFORM_RD - 1, GREGORIAN_REFORM_RD), (1582, 10, 4));
+    assert_eq!(rd_to_hybrid_date(GREGORIAN_REFORM_RD, GREGORIAN_REFORM_RD), (1582, 10, 15));
+    assert_eq!(rd_to_hybrid_date(RD_MIN, 0), rd_to_julian_date(RD_MIN));
+    assert_eq!(rd_to_hybrid_date(RD_MAX, 0), rd_to_date(RD_MAX));
+}
+
+#[test]
+fn test_hybrid_date_to_rd() {
+    assert_eq!(hybrid_date_to_rd((1582, 10, 4), GREGORIAN_REFORM_RD), Some(GREGORIAN_REFORM_RD - 1));
+    for d in 5..=14 {
+        assert_eq!(hybrid_date_to_rd((1582, 10, d), GREGORIAN_REFORM_RD), None);
+    }
+    assert_eq!(hybrid_date_to_rd((1582, 10, 15), GREGORIAN_REFORM_RD), Some(GREGORIAN_REFORM_RD));
+    assert_eq!(hybrid_date_to_rd((1500, 2, 29), GREGORIAN_REFORM_RD), Some(-171596));
+    assert_eq!(hybrid_date_to_rd((1582, 2, 30), GREGORIAN_REFORM_RD), None);
+    // Julian calendar runs behind, so dates repeat and Gregorian wins
+    let switchover = date_to_rd((100, 1, 1));
+    assert_eq!(hybrid_date_to_rd((99, 12, 31), switchover), Some(julian_date_to_rd((99, 12, 31))));
+    assert_eq!(hybrid_date_to_rd((100, 1, 1), switchover), Some(switchover));
+}
+
+#[test]
+fn test_hybrid_roundtrip() {
+    for n in (RD_MIN..=RD_MAX).step_by(7919) {
+        let date = rd_to_hybrid_date(n, BRITISH_REFORM_RD);
+        assert_eq!(hybrid_date_to_rd(date, BRITISH_REFORM_RD), Some(n));
+    }
+}
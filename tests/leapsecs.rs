@@ -0,0 +1,289 @@
+#![cfg(feature = "leapsecs")]
+
+use datealgo::datetime_to_secs;
+use datealgo::leapsecs::*;
+
+#[test]
+fn test_leap_seconds() {
+    assert_eq!(LEAP_SECONDS[0], (datetime_to_secs((1972, 1, 1, 0, 0, 0)), 10));
+    for w in LEAP_SECONDS.windows(2) {
+        assert!(w[0].0 < w[1].0);
+        assert_eq!(w[0].1 + 1, w[1].1);
+        assert_eq!(w[1].0 % 86400, 0);
+    }
+}
+
+#[test]
+fn test_utc_to_tai() {
+    assert_eq!(utc_to_tai(i64::MIN + 10), i64::MIN + 20);
+    assert_eq!(utc_to_tai(63071999), 63072009);
+    assert_eq!(utc_to_tai(63072000), 63072010);
+    assert_eq!(utc_to_tai(78796799), 78796809);
+    assert_eq!(utc_to_tai(78796800), 78796811);
+    assert_eq!(utc_to_tai(datetime_to_secs((2023, 5, 20, 9, 24, 38))), 1684574715);
+}
+
+#[test]
+fn test_tai_to_utc() {
+    assert_eq!(tai_to_utc(i64::MIN + 20), (i64::MIN + 10, false));
+    assert_eq!(tai_to_utc(63072009), (63071999, false));
+    assert_eq!(tai_to_utc(63072010), (63072000, false));
+    assert_eq!(tai_to_utc(78796809), (78796799, false));
+    assert_eq!(tai_to_utc(78796810), (78796799, true));
+    assert_eq!(tai_to_utc(78796811), (78796800, false));
+    assert_eq!(tai_to_utc(1684574715), (datetime_to_secs((2023, 5, 20, 9, 24, 38)), false));
+}
+
+#[test]
+fn test_tai_roundtrip() {
+    let mut leaps = 0;
+    for tai in 63072000..63072000 + 86400 * 365 * 2 {
+        let (utc, leap) = tai_to_utc(tai);
+        if leap {
+            leaps += 1;
+            assert_eq!(utc_to_tai(utc) + 1, tai);
+        } else {
+            assert_eq!(utc_to_tai(utc), tai);
+        }
+    }
+    assert_eq!(leaps, 2);
+    for &(secs, _) in &LEAP_SECONDS[1..] {
+        assert_eq!(tai_to_utc(utc_to_tai(secs - 1) + 1), (secs - 1, true));
+    }
+}
+
+#[test]
+fn test_gps_consts() {
+    assert_eq!(GPS_EPOCH_SECS, datetime_to_secs((1980, 1, 6, 0, 0, 0)));
+}
+
+#[test]
+fn test_utc_to_gps_secs() {
+    assert_eq!(utc_to_gps_secs(GPS_EPOCH_SECS - 1), -1);
+    assert_eq!(utc_to_gps_secs(datetime_to_secs((1981, 6, 30, 23, 59, 59))), 46828799);
+    assert_eq!(utc_to_gps_secs(datetime_to_secs((1981, 7, 1, 0, 0, 0))), 46828801);
+    assert_eq!(utc_to_gps_secs(0), -315964809);
+}
+
+#[test]
+fn test_gps_to_utc_secs() {
+    assert_eq!(gps_to_utc_secs(-1), (GPS_EPOCH_SECS - 1, false));
+    assert_eq!(gps_to_utc_secs(46828799), (datetime_to_secs((1981, 6, 30, 23, 59, 59)), false));
+    assert_eq!(gps_to_utc_secs(46828800), (datetime_to_secs((1981, 6, 30, 23, 59, 59)), true));
+    assert_eq!(gps_to_utc_secs(46828801), (datetime_to_secs((1981, 7, 1, 0, 0, 0)), false));
+    assert_eq!(gps_to_utc_secs(-315964809), (0, false));
+}
+
+#[test]
+fn test_tai_to_datetime() {
+    assert_eq!(tai_to_datetime(10), (1970, 1, 1, 0, 0, 0));
+    assert_eq!(tai_to_datetime(78796809), (1972, 6, 30, 23, 59, 59));
+    assert_eq!(tai_to_datetime(78796810), (1972, 6, 30, 23, 59, 60));
+    assert_eq!(tai_to_datetime(78796811), (1972, 7, 1, 0, 0, 0));
+}
+
+#[test]
+fn test_datetime_to_tai() {
+    assert_eq!(datetime_to_tai((1970, 1, 1, 0, 0, 0)), Some(10));
+    assert_eq!(datetime_to_tai((1972, 6, 30, 23, 59, 60)), Some(78796810));
+    assert_eq!(datetime_to_tai((1972, 6, 30, 23, 59, 61)), None);
+    assert_eq!(datetime_to_tai((1972, 6, 30, 24, 0, 0)), None);
+    for &(secs, _) in &LEAP_SECONDS[1..] {
+        let (y, m, d, hh, mm, ss) = datealgo::secs_to_datetime(secs - 1);
+        assert_eq!((hh, mm, ss), (23, 59, 59));
+        let tai = datetime_to_tai((y, m, d, 23, 59, 60)).unwrap();
+        assert_eq!(tai_to_datetime(tai), (y, m, d, 23, 59, 60));
+    }
+}
+
+#[test]
+fn test_is_valid_datetime_leap() {
+    assert!(is_valid_datetime_leap((1972, 6, 30, 23, 59, 60)));
+    assert!(!is_valid_datetime_leap((1972, 6, 29, 23, 59, 60)));
+    assert!(!is_valid_datetime_leap((1971, 12, 31, 23, 59, 60)));
+    assert!(!is_valid_datetime_leap((1972, 13, 1, 0, 0, 0)));
+    assert!(!is_valid_datetime_leap((1972, 1, 0, 0, 0, 0)));
+    assert!(!is_valid_datetime_leap((1972, 1, 1, 0, 60, 0)));
+    assert!(!is_valid_datetime_leap((i32::MAX, 1, 1, 0, 0, 0)));
+}
+
+#[test]
+fn test_tai_datetime_roundtrip() {
+    for tai in (78796810 - 100000..78796810 + 100000).step_by(7) {
+        assert_eq!(datetime_to_tai(tai_to_datetime(tai)), Some(tai));
+    }
+}
+
+#[test]
+fn test_smear_utc_to_posix() {
+    let leap = datetime_to_secs((2017, 1, 1, 0, 0, 0));
+    let tai = utc_to_tai(leap);
+    assert_eq!(
+        smear_utc_to_posix((tai - 43200 - 2, 999_999_999), &LEAP_SECONDS),
+        (leap - 43200 - 1, 999_999_999)
+    );
+    assert_eq!(smear_utc_to_posix((tai - 43201, 0), &LEAP_SECONDS), (leap - 43200, 0));
+    assert_eq!(smear_utc_to_posix((tai - 1, 0), &LEAP_SECONDS), (leap - 1, 500_005_786));
+    assert_eq!(
+        smear_utc_to_posix((tai + 43199, 999_999_999), &LEAP_SECONDS),
+        (leap + 43199, 999_999_999)
+    );
+    assert_eq!(smear_utc_to_posix((tai + 43200, 0), &LEAP_SECONDS), (leap + 43200, 0));
+    assert_eq!(smear_utc_to_posix((10, 5), &LEAP_SECONDS), (0, 5));
+    assert_eq!(smear_utc_to_posix((i64::MAX, 0), &LEAP_SECONDS), (i64::MAX - 37, 0));
+    // a table without leap seconds does not smear
+    assert_eq!(smear_utc_to_posix((tai, 0), &LEAP_SECONDS[..1]), (tai - 10, 0));
+}
+
+#[test]
+fn test_smear_posix_to_utc() {
+    let leap = datetime_to_secs((2017, 1, 1, 0, 0, 0));
+    let tai = utc_to_tai(leap);
+    assert_eq!(
+        smear_posix_to_utc((leap - 43200 - 1, 999_999_999), &LEAP_SECONDS),
+        (tai - 43200 - 2, 999_999_999)
+    );
+    assert_eq!(smear_posix_to_utc((leap - 43200, 0), &LEAP_SECONDS), (tai - 43201, 0));
+    assert_eq!(smear_posix_to_utc((leap, 0), &LEAP_SECONDS), (tai - 1, 500_000_000));
+    assert_eq!(smear_posix_to_utc((leap + 43200, 0), &LEAP_SECONDS), (tai + 43200, 0));
+    assert_eq!(smear_posix_to_utc((0, 5), &LEAP_SECONDS), (10, 5));
+    assert_eq!(smear_posix_to_utc((i64::MIN, 0), &LEAP_SECONDS), (i64::MIN + 10, 0));
+}
+
+#[test]
+fn test_smear_roundtrip() {
+    for &(leap, _) in &LEAP_SECONDS[1..] {
+        for secs in (leap - 50000..leap + 50000).step_by(97) {
+            for nsecs in [0, 1, 123_456_789, 999_999_999] {
+                let utc = smear_posix_to_utc((secs, nsecs), &LEAP_SECONDS);
+                assert_eq!(smear_utc_to_posix(utc, &LEAP_SECONDS), (secs, nsecs));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_leap_seconds_at() {
+    assert_eq!(leap_seconds_at(i64::MIN), 10);
+    assert_eq!(leap_seconds_at(63072000), 10);
+    assert_eq!(leap_seconds_at(78796799), 10);
+    assert_eq!(leap_seconds_at(78796800), 11);
+    assert_eq!(leap_seconds_at(i64::MAX), 37);
+}
+
+#[test]
+fn test_is_leap_second_insertion_day() {
+    let days: Vec<i32> = (0..20000).filter(|&rd| is_leap_second_insertion_day(rd)).collect();
+    assert_eq!(days.len(), 27);
+    assert_eq!(days[0], datealgo::date_to_rd((1972, 6, 30)));
+    assert!(!is_leap_second_insertion_day(datealgo::date_to_rd((1971, 12, 31))));
+    assert!(!is_leap_second_insertion_day(datealgo::RD_MIN));
+    assert!(!is_leap_second_insertion_day(datealgo::RD_MAX));
+}
+
+#[test]
+fn test_next_leap_second_after() {
+    assert_eq!(next_leap_second_after(i64::MIN), Some(78796800));
+    assert_eq!(next_leap_second_after(78796799), Some(78796800));
+    assert_eq!(next_leap_second_after(78796800), Some(94694400));
+    assert_eq!(next_leap_second_after(1483228799), Some(1483228800));
+    assert_eq!(next_leap_second_after(i64::MAX), None);
+}
+
+#[test]
+fn test_leap_second_table_builtin() {
+    for secs in (-100_000_000..2_000_000_000).step_by(86399) {
+        assert_eq!(BuiltinLeapSeconds.utc_to_tai(secs), utc_to_tai(secs));
+        assert_eq!(BuiltinLeapSeconds.tai_to_utc(secs), tai_to_utc(secs));
+        assert_eq!(BuiltinLeapSeconds.utc_to_gps_secs(secs), utc_to_gps_secs(secs));
+        assert_eq!(BuiltinLeapSeconds.gps_to_utc_secs(secs), gps_to_utc_secs(secs));
+        assert_eq!(BuiltinLeapSeconds.leap_seconds_at(secs), leap_seconds_at(secs));
+        assert_eq!(BuiltinLeapSeconds.next_leap_second_after(secs), next_leap_second_after(secs));
+        assert_eq!(LEAP_SECONDS.tai_to_datetime(secs), tai_to_datetime(secs));
+    }
+    for rd in 0..20000 {
+        assert_eq!(LEAP_SECONDS[..].is_leap_second_insertion_day(rd), is_leap_second_insertion_day(rd));
+    }
+}
+
+#[test]
+fn test_leap_second_table_custom() {
+    let end = datetime_to_secs((2031, 1, 1, 0, 0, 0));
+    let mut table = LEAP_SECONDS.to_vec();
+    table.push((end, 38));
+    assert_eq!(table.leap_seconds_at(end - 1), 37);
+    assert_eq!(table.leap_seconds_at(end), 38);
+    assert_eq!(table.utc_to_tai(end), end + 38);
+    assert_eq!(table.tai_to_utc(end + 37), (end - 1, true));
+    assert_eq!(table.tai_to_utc(end + 38), (end, false));
+    assert_eq!(table.tai_to_datetime(end + 37), (2030, 12, 31, 23, 59, 60));
+    assert_eq!(table.datetime_to_tai((2030, 12, 31, 23, 59, 60)), Some(end + 37));
+    assert_eq!(BuiltinLeapSeconds.datetime_to_tai((2030, 12, 31, 23, 59, 60)), None);
+    assert!(table.is_leap_second_insertion_day(datealgo::date_to_rd((2030, 12, 31))));
+    assert_eq!(table.next_leap_second_after(1483228800), Some(end));
+    assert_eq!(table.next_leap_second_after(end), None);
+    assert_eq!(table.smear_posix_to_utc(table.smear_utc_to_posix((end + 37, 0))), (end + 37, 0));
+}
+
+#[test]
+fn test_parse_leap_seconds_list() {
+    let mut table = [(0, 0); 64];
+    let file = include_bytes!("data/leap-seconds.list");
+    let (n, expires) = parse_leap_seconds_list(file, &mut table).unwrap();
+    assert_eq!(&table[..n], &LEAP_SECONDS[..]);
+    assert_eq!(expires, Some(datetime_to_secs((2026, 6, 28, 0, 0, 0))));
+    assert_eq!(parse_leap_seconds_list(file, &mut table[..27]), None);
+    assert_eq!(parse_leap_seconds_list(b"2272060800 10\n", &mut table), Some((1, None)));
+    assert_eq!(parse_leap_seconds_list(b"2272060800 10", &mut table), Some((1, None)));
+    assert_eq!(parse_leap_seconds_list(b"2272060800 10\n2272060800 11\n", &mut table), None);
+    assert_eq!(parse_leap_seconds_list(b"2272060800 10\n2287785600 12\n", &mut table), None);
+    assert_eq!(parse_leap_seconds_list(b"2272060800 10\n2287785601 11\n", &mut table), None);
+    assert_eq!(parse_leap_seconds_list(b"2272060800\n", &mut table), None);
+    assert_eq!(parse_leap_seconds_list(b"2272060800 10 1\n", &mut table), None);
+    assert_eq!(parse_leap_seconds_list(b"2272060800 x\n", &mut table), None);
+    assert_eq!(parse_leap_seconds_list(b"#@ x\n2272060800 10\n", &mut table), None);
+    assert_eq!(parse_leap_seconds_list(b"# comment\n", &mut table), None);
+}
+
+#[test]
+fn test_parse_tzdata_leapseconds() {
+    let mut table = [(0, 0); 64];
+    let file = include_bytes!("data/leapseconds");
+    let (n, expires) = parse_tzdata_leapseconds(file, &mut table).unwrap();
+    assert_eq!(&table[..n], &LEAP_SECONDS[..]);
+    assert_eq!(expires, Some(datetime_to_secs((2026, 6, 28, 0, 0, 0))));
+    assert_eq!(parse_tzdata_leapseconds(file, &mut table[..27]), None);
+    assert_eq!(parse_tzdata_leapseconds(b"", &mut table), Some((1, None)));
+    assert_eq!(parse_tzdata_leapseconds(b"", &mut []), None);
+    let (n, expires) = parse_tzdata_leapseconds(b"Leap 2030 Dec 31 23:59:59 - S\nExpires 2031 Jun 28 00:00:00\n", &mut table).unwrap();
+    assert_eq!(table[1], (datetime_to_secs((2031, 1, 1, 0, 0, 0)), 9));
+    assert_eq!((n, expires), (2, Some(datetime_to_secs((2031, 6, 28, 0, 0, 0)))));
+    for file in [
+        &b"Leap 1972 Jun 30 23:59:59 + S\n"[..],
+        b"Leap 1972 Jun 30 23:59:60 - S\n",
+        b"Leap 1972 Jun 30 23:59:60 + R\n",
+        b"Leap 1972 Jun 31 23:59:60 + S\n",
+        b"Leap 1972 Jum 30 23:59:60 + S\n",
+        b"Leap 1972 Jun 30 23:59:60 +\n",
+        b"Leap 1972 Jun 30 23:59:60 + S S\n",
+        b"Leap 1972 Dec 31 23:59:60 + S\nLeap 1972 Jun 30 23:59:60 + S\n",
+        b"Expires 2031 Jun 28 00:00:01\n",
+        b"Zone Etc/UTC 0 - UTC\n",
+    ] {
+        assert_eq!(parse_tzdata_leapseconds(file, &mut table), None);
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_load_leap_seconds() {
+    let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data/");
+    let expires = Some(datetime_to_secs((2026, 6, 28, 0, 0, 0)));
+    for name in ["leap-seconds.list", "leapseconds"] {
+        let (table, exp) = load_leap_seconds(format!("{}{}", dir, name)).unwrap();
+        assert_eq!(table, LEAP_SECONDS);
+        assert_eq!(exp, expires);
+    }
+    let err = load_leap_seconds(format!("{}Europe_Helsinki.tzif", dir)).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+}
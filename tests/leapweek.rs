@@ -0,0 +1,54 @@
+use datealgo::calendar::leapweek::*;
+use datealgo::{date_to_rd, rd_to_weekday, RD_MAX, RD_MIN};
+
+const PATTERNS: [u16; 3] = [LEAPWEEK_PATTERN_454, LEAPWEEK_PATTERN_445, LEAPWEEK_PATTERN_544];
+
+#[test]
+fn test_rd_to_leapweek_date() {
+    assert_eq!(rd_to_leapweek_date(date_to_rd((2019, 12, 30)), LEAPWEEK_PATTERN_454), (2020, 1, 1));
+    assert_eq!(rd_to_leapweek_date(date_to_rd((2020, 1, 26)), LEAPWEEK_PATTERN_454), (2020, 1, 28));
+    assert_eq!(rd_to_leapweek_date(date_to_rd((2020, 1, 27)), LEAPWEEK_PATTERN_454), (2020, 2, 1));
+    assert_eq!(rd_to_leapweek_date(date_to_rd((2020, 1, 27)), LEAPWEEK_PATTERN_544), (2020, 1, 29));
+    assert_eq!(rd_to_leapweek_date(date_to_rd((2021, 1, 3)), LEAPWEEK_PATTERN_445), (2020, 12, 42));
+    assert_eq!(rd_to_leapweek_date(date_to_rd((2021, 1, 4)), LEAPWEEK_PATTERN_445), (2021, 1, 1));
+    assert_eq!(rd_to_leapweek_date(RD_MIN, LEAPWEEK_PATTERN_454), (-1467999, 1, 1));
+    assert_eq!(rd_to_leapweek_date(RD_MAX, LEAPWEEK_PATTERN_454), (1471744, 12, 32));
+}
+
+#[test]
+fn test_leapweek_date_to_rd() {
+    assert_eq!(leapweek_date_to_rd((2020, 1, 1), LEAPWEEK_PATTERN_454), date_to_rd((2019, 12, 30)));
+    assert_eq!(leapweek_date_to_rd((2020, 2, 35), LEAPWEEK_PATTERN_454), date_to_rd((2020, 3, 1)));
+    assert_eq!(leapweek_date_to_rd((2020, 12, 42), LEAPWEEK_PATTERN_445), date_to_rd((2021, 1, 3)));
+}
+
+#[test]
+fn test_leapweek_roundtrip() {
+    for pattern in PATTERNS {
+        for n in (RD_MIN..=RD_MAX).step_by(7919) {
+            assert_eq!(leapweek_date_to_rd(rd_to_leapweek_date(n, pattern), pattern), n);
+        }
+        for n in -10000..10000 {
+            let (y, m, d) = rd_to_leapweek_date(n, pattern);
+            assert!(d <= leapweek_days_in_month(y, m, pattern));
+            assert_eq!(leapweek_date_to_rd((y, m, d), pattern), n);
+            if d == 1 {
+                assert_eq!(rd_to_weekday(n), 1);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_leapweek_days_in_month() {
+    let weeks = [4, 5, 4, 4, 5, 4, 4, 5, 4, 4, 5, 4];
+    for m in 1..=12 {
+        assert_eq!(leapweek_days_in_month(2023, m, LEAPWEEK_PATTERN_454), weeks[m as usize - 1] * 7);
+    }
+    for pattern in PATTERNS {
+        let total: u32 = (1..=12).map(|m| leapweek_days_in_month(2023, m, pattern) as u32).sum();
+        assert_eq!(total, 364);
+        let total: u32 = (1..=12).map(|m| leapweek_days_in_month(2026, m, pattern) as u32).sum();
+        assert_eq!(total, 371);
+    }
+}
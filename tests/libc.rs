@@ -0,0 +1,43 @@
+#![cfg(feature = "libc")]
+
+use datealgo::libc::*;
+use datealgo::{datetime_to_secs, RD_SECONDS_MAX, RD_SECONDS_MIN};
+
+#[test]
+fn test_secs_to_tm() {
+    let tm = secs_to_tm(0);
+    assert_eq!((tm.tm_year, tm.tm_mon, tm.tm_mday), (70, 0, 1));
+    assert_eq!((tm.tm_hour, tm.tm_min, tm.tm_sec), (0, 0, 0));
+    assert_eq!((tm.tm_wday, tm.tm_yday, tm.tm_isdst), (4, 0, 0));
+    let tm = secs_to_tm(datetime_to_secs((2024, 12, 31, 23, 59, 59)));
+    assert_eq!((tm.tm_year, tm.tm_mon, tm.tm_mday), (124, 11, 31));
+    assert_eq!((tm.tm_wday, tm.tm_yday), (2, 365));
+    let tm = secs_to_tm(datetime_to_secs((2023, 1, 1, 0, 0, 0)));
+    assert_eq!((tm.tm_wday, tm.tm_yday), (0, 0));
+    let tm = secs_to_tm(-1);
+    assert_eq!((tm.tm_year, tm.tm_mon, tm.tm_mday), (69, 11, 31));
+    assert_eq!((tm.tm_hour, tm.tm_min, tm.tm_sec), (23, 59, 59));
+    assert_eq!((tm.tm_wday, tm.tm_yday), (3, 364));
+}
+
+#[test]
+fn test_tm_to_secs() {
+    let mut tm = secs_to_tm(1684574678);
+    assert_eq!(tm_to_secs(&tm), 1684574678);
+    tm.tm_wday = 0;
+    tm.tm_yday = 0;
+    tm.tm_isdst = 1;
+    assert_eq!(tm_to_secs(&tm), 1684574678);
+    tm.tm_sec = 60;
+    assert_eq!(tm_to_secs(&tm), 1684574700);
+}
+
+#[test]
+fn test_roundtrip() {
+    for secs in [RD_SECONDS_MIN, -86401, -86400, -1, 0, 1, 951782400, 4107542399, RD_SECONDS_MAX] {
+        assert_eq!(tm_to_secs(&secs_to_tm(secs)), secs);
+    }
+    for secs in (RD_SECONDS_MIN..=RD_SECONDS_MAX).step_by(1_000_003_777) {
+        assert_eq!(tm_to_secs(&secs_to_tm(secs)), secs);
+    }
+}
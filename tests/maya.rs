@@ -0,0 +1,32 @@
+use datealgo::calendar::maya::*;
+use datealgo::{date_to_rd, RD_MAX, RD_MIN};
+
+#[test]
+fn test_rd_to_longcount() {
+    let gmt = MAYA_GMT_CORRELATION;
+    assert_eq!(rd_to_longcount(date_to_rd((2012, 12, 20)), gmt), (12, 19, 19, 17, 19));
+    assert_eq!(
+        rd_to_longcount(date_to_rd((2012, 12, 23)), MAYA_LOUNSBURY_CORRELATION),
+        (13, 0, 0, 0, 0)
+    );
+    assert_eq!(rd_to_longcount(date_to_rd((-3113, 8, 10)), gmt), (-1, 19, 19, 17, 19));
+    assert_eq!(rd_to_longcount(RD_MIN, gmt), (-3716, 9, 0, 17, 13));
+    assert_eq!(rd_to_longcount(RD_MAX, gmt), (3740, 16, 15, 0, 0));
+}
+
+#[test]
+fn test_longcount_to_rd() {
+    let gmt = MAYA_GMT_CORRELATION;
+    assert_eq!(longcount_to_rd((0, 0, 0, 0, 0), gmt), date_to_rd((-3113, 8, 11)));
+    assert_eq!(longcount_to_rd((-1, 19, 19, 17, 19), gmt), date_to_rd((-3113, 8, 10)));
+    assert_eq!(longcount_to_rd((9, 12, 11, 5, 18), gmt), date_to_rd((683, 8, 29)));
+    assert_eq!(longcount_to_rd((-3716, 9, 0, 17, 13), gmt), RD_MIN);
+    assert_eq!(longcount_to_rd((3740, 16, 15, 0, 0), gmt), RD_MAX);
+}
+
+#[test]
+fn test_longcount_roundtrip() {
+    for n in (RD_MIN..=RD_MAX).step_by(7919) {
+        assert_eq!(longcount_to_rd(rd_to_longcount(n, MAYA_GMT_CORRELATION), MAYA_GMT_CORRELATION), n);
+    }
+}
@@ -0,0 +1,58 @@
+use datealgo::calendar::persian::*;
+use datealgo::{date_to_rd, RD_MAX, RD_MIN};
+
+#[test]
+fn test_persian_consts() {
+    assert_eq!(PERSIAN33_EPOCH, date_to_rd((622, 3, 21)));
+}
+
+#[test]
+fn test_rd_to_persian33_date() {
+    assert_eq!(rd_to_persian33_date(PERSIAN33_EPOCH), (1, 1, 1));
+    assert_eq!(rd_to_persian33_date(PERSIAN33_EPOCH - 1), (0, 12, 29));
+    assert_eq!(rd_to_persian33_date(date_to_rd((1979, 2, 11))), (1357, 11, 22));
+    assert_eq!(rd_to_persian33_date(date_to_rd((2024, 3, 19))), (1402, 12, 29));
+    assert_eq!(rd_to_persian33_date(date_to_rd((2025, 3, 21))), (1404, 1, 1));
+    assert_eq!(rd_to_persian33_date(date_to_rd((2023, 9, 22))), (1402, 6, 31));
+    assert_eq!(rd_to_persian33_date(date_to_rd((2023, 9, 23))), (1402, 7, 1));
+}
+
+#[test]
+fn test_persian33_date_to_rd() {
+    assert_eq!(persian33_date_to_rd((1, 1, 1)), PERSIAN33_EPOCH);
+    assert_eq!(persian33_date_to_rd((1357, 11, 22)), date_to_rd((1979, 2, 11)));
+    assert_eq!(persian33_date_to_rd((1402, 7, 1)), date_to_rd((2023, 9, 23)));
+    assert_eq!(persian33_date_to_rd((1404, 1, 1)), date_to_rd((2025, 3, 21)));
+    // Nowruz falls on March 20th or 21st during the valid range of the rule
+    for y in 1178..=1634 {
+        let (_, m, d) = datealgo::rd_to_date(persian33_date_to_rd((y, 1, 1)));
+        assert!(m == 3 && (19..=22).contains(&d));
+    }
+}
+
+#[test]
+fn test_persian33_roundtrip() {
+    for n in (RD_MIN..=RD_MAX).step_by(7919) {
+        assert_eq!(persian33_date_to_rd(rd_to_persian33_date(n)), n);
+    }
+    for n in -20000..20000 {
+        assert_eq!(persian33_date_to_rd(rd_to_persian33_date(n)), n);
+    }
+}
+
+#[test]
+fn test_is_persian33_leap_year() {
+    let leaps: Vec<i32> = (1380..1410).filter(|&y| is_persian33_leap_year(y)).collect();
+    assert_eq!(leaps, [1383, 1387, 1391, 1395, 1399, 1403, 1408]);
+    assert!(is_persian33_leap_year(-32));
+    assert!(!is_persian33_leap_year(0));
+}
+
+#[test]
+fn test_persian33_days_in_month() {
+    let days = [31, 31, 31, 31, 31, 31, 30, 30, 30, 30, 30, 29];
+    for m in 1..=12 {
+        assert_eq!(persian33_days_in_month(1402, m), days[m as usize - 1]);
+    }
+    assert_eq!(persian33_days_in_month(1399, 12), 30);
+}
@@ -0,0 +1,110 @@
+use datealgo::posixtz::*;
+use datealgo::{datetime_to_secs, RD_SECONDS_MAX, RD_SECONDS_MIN, YEAR_MAX, YEAR_MIN};
+
+#[test]
+fn test_parse() {
+    let tz = PosixTz::parse(b"UTC0").unwrap();
+    assert_eq!(
+        (tz.std_abbr(), tz.std_utoff(), tz.dst_abbr(), tz.dst_utoff()),
+        ("UTC", 0, None, None)
+    );
+    let tz = PosixTz::parse(b"<-0330>+3:30:15").unwrap();
+    assert_eq!((tz.std_abbr(), tz.std_utoff()), ("-0330", -12615));
+    let tz = PosixTz::parse(b"EST5EDT").unwrap();
+    assert_eq!((tz.dst_abbr(), tz.dst_utoff()), (Some("EDT"), Some(-14400)));
+    assert_eq!(
+        tz.dst_rules(),
+        Some((TzRule::MonthWeekDay(3, 2, 0), 7200, TzRule::MonthWeekDay(11, 1, 0), 7200))
+    );
+    let tz = PosixTz::parse(b"IST-1GMT0,M10.5.0,M3.5.0/1").unwrap();
+    assert_eq!((tz.std_utoff(), tz.dst_utoff()), (3600, Some(0)));
+    let tz = PosixTz::parse(b"<-02>2<-01>,M3.5.0/-1,M10.5.0/0").unwrap();
+    assert_eq!(
+        tz.dst_rules(),
+        Some((TzRule::MonthWeekDay(3, 5, 0), -3600, TzRule::MonthWeekDay(10, 5, 0), 0))
+    );
+    let tz = PosixTz::parse(b"XXX3YYY2,J60/+1:30,300/167:59:59").unwrap();
+    assert_eq!(tz.dst_rules(), Some((TzRule::JulianNoLeap(60), 5400, TzRule::Julian(300), 604799)));
+    assert_eq!(tz.dst_utoff(), Some(-7200));
+    let tz = PosixTz::parse(b"<ABCDEFGHIJKLMNOP>0").unwrap();
+    assert_eq!(tz.std_abbr(), "ABCDEFGHIJKLMNOP");
+    for s in [
+        &b""[..],
+        b"EST",
+        b"ES5",
+        b"EST+",
+        b"EST25",
+        b"EST5:60",
+        b"EST5:0",
+        b"<EST5",
+        b"<ES>5",
+        b"EST5EDT,",
+        b"EST5EDT,M3.2.0",
+        b"EST5EDT,M3.2.0,",
+        b"EST5EDT,M13.1.0,M11.1.0",
+        b"EST5EDT,M3.6.0,M11.1.0",
+        b"EST5EDT,M3.2.7,M11.1.0",
+        b"EST5EDT,M3.2,M11.1.0",
+        b"EST5EDT,J0,J365",
+        b"EST5EDT,0,366",
+        b"EST5EDT,M3.2.0/168,M11.1.0",
+        b"EST5EDT,M3.2.0,M11.1.0x",
+        b"EST5EDT4x",
+        b"ABCDEFGHIJKLMNOPQ0",
+        b":America/New_York",
+    ] {
+        assert_eq!(PosixTz::parse(s), None, "{}", String::from_utf8_lossy(s));
+    }
+}
+
+#[test]
+fn test_dst_transitions() {
+    let secs = |y, m, d, hh, mm| datetime_to_secs((y, m, d, hh, mm, 0));
+    assert_eq!(PosixTz::parse(b"UTC0").unwrap().dst_transitions(2023), None);
+    let tz = PosixTz::parse(b"CET-1CEST,M3.5.0,M10.5.0/3").unwrap();
+    assert_eq!(tz.dst_transitions(2023), Some((secs(2023, 3, 26, 1, 0), secs(2023, 10, 29, 1, 0))));
+    let tz = PosixTz::parse(b"AEST-10AEDT,M10.1.0,M4.1.0/3").unwrap();
+    assert_eq!(tz.dst_transitions(2023), Some((secs(2023, 9, 30, 16, 0), secs(2023, 4, 1, 16, 0))));
+    let tz = PosixTz::parse(b"<-02>2<-01>,M3.5.0/-1,M10.5.0/0").unwrap();
+    assert_eq!(tz.dst_transitions(2024), Some((secs(2024, 3, 31, 1, 0), secs(2024, 10, 27, 1, 0))));
+    let tz = PosixTz::parse(b"XXX0YYY,M2.5.0/0,M2.4.6/0").unwrap();
+    assert_eq!(tz.dst_transitions(2023), Some((secs(2023, 2, 26, 0, 0), secs(2023, 2, 24, 23, 0))));
+    let tz = PosixTz::parse(b"XXX0YYY-1,J60/0,300/0").unwrap();
+    assert_eq!(tz.dst_transitions(2023), Some((secs(2023, 3, 1, 0, 0), secs(2023, 10, 27, 23, 0))));
+    assert_eq!(tz.dst_transitions(2024), Some((secs(2024, 3, 1, 0, 0), secs(2024, 10, 26, 23, 0))));
+    assert!(tz.dst_transitions(YEAR_MIN).is_some());
+    assert!(tz.dst_transitions(YEAR_MAX).is_some());
+}
+
+#[test]
+fn test_offset_at() {
+    let secs = |y, m, d, hh, mm| datetime_to_secs((y, m, d, hh, mm, 0));
+    let tz = PosixTz::parse(b"EST5EDT,M3.2.0,M11.1.0").unwrap();
+    assert_eq!(tz.offset_at(secs(2023, 3, 12, 6, 59) + 59), (-18000, false));
+    assert_eq!(tz.offset_at(secs(2023, 3, 12, 7, 0)), (-14400, true));
+    assert_eq!(tz.offset_at(secs(2023, 11, 5, 5, 59) + 59), (-14400, true));
+    assert_eq!(tz.offset_at(secs(2023, 11, 5, 6, 0)), (-18000, false));
+    assert_eq!(tz.offset_at(secs(2024, 1, 1, 3, 0)), (-18000, false));
+    let tz = PosixTz::parse(b"AEST-10AEDT,M10.1.0,M4.1.0/3").unwrap();
+    assert_eq!(tz.offset_at(secs(2022, 12, 31, 14, 0)), (39600, true));
+    assert_eq!(tz.offset_at(secs(2023, 4, 1, 15, 59)), (39600, true));
+    assert_eq!(tz.offset_at(secs(2023, 4, 1, 16, 0)), (36000, false));
+    assert_eq!(tz.offset_at(secs(2023, 9, 30, 15, 59)), (36000, false));
+    assert_eq!(tz.offset_at(secs(2023, 9, 30, 16, 0)), (39600, true));
+    let tz = PosixTz::parse(b"EST5EDT,0/0,J365/25").unwrap();
+    for s in (secs(2020, 1, 1, 0, 0)..secs(2030, 1, 1, 0, 0)).step_by(3599) {
+        assert_eq!(tz.offset_at(s), (-14400, true));
+    }
+    let tz = PosixTz::parse(b"<+0330>-3:30").unwrap();
+    assert_eq!(tz.offset_at(0), (12600, false));
+    for s in [
+        "UTC0",
+        "EST5EDT",
+        "<-14>14<-13>,J1/0,J365/24",
+        "<+14>-14<+15>,M12.5.6/167,M1.1.0/-167",
+    ] {
+        let tz = PosixTz::parse(s.as_bytes()).unwrap();
+        tz.offset_at(RD_SECONDS_MIN);
+        tz.offset_at(RD_SECONDS_MAX);
+    }
+}
@@ -0,0 +1,41 @@
+#![cfg(feature = "rand")]
+
+use datealgo::rand::*;
+use datealgo::{date_to_rd, datetime_to_secs, RD_MAX, RD_MIN, RD_SECONDS_MAX, RD_SECONDS_MIN};
+use rand::rngs::SmallRng;
+use rand::SeedableRng;
+
+#[test]
+fn test_random_date_in_range() {
+    let mut rng = SmallRng::seed_from_u64(1);
+    let (min, max) = (date_to_rd((2024, 1, 1)), date_to_rd((2024, 12, 31)));
+    let mut counts = [0u32; 13];
+    for _ in 0..366_000 {
+        let date = random_date_in_range(&mut rng, min, max);
+        let n = date_to_rd(date);
+        assert!((min..=max).contains(&n));
+        counts[date.1 as usize] += 1;
+    }
+    // February has 29 days and January 31 days in 2024, roughly 29000 and 31000 samples
+    assert!((28000..30000).contains(&counts[2]));
+    assert!((30000..32000).contains(&counts[1]));
+    assert_eq!(random_date_in_range(&mut rng, RD_MAX, RD_MAX), datealgo::rd_to_date(RD_MAX));
+    let n = date_to_rd(random_date_in_range(&mut rng, RD_MIN, RD_MAX));
+    assert!((RD_MIN..=RD_MAX).contains(&n));
+}
+
+#[test]
+fn test_random_datetime_in_range() {
+    let mut rng = SmallRng::seed_from_u64(2);
+    let min = datetime_to_secs((2023, 5, 20, 9, 24, 38));
+    for _ in 0..10_000 {
+        let secs = datetime_to_secs(random_datetime_in_range(&mut rng, min, min + 100));
+        assert!((min..=min + 100).contains(&secs));
+    }
+    assert_eq!(
+        random_datetime_in_range(&mut rng, RD_SECONDS_MIN, RD_SECONDS_MIN),
+        datealgo::secs_to_datetime(RD_SECONDS_MIN)
+    );
+    let secs = datetime_to_secs(random_datetime_in_range(&mut rng, RD_SECONDS_MIN, RD_SECONDS_MAX));
+    assert!((RD_SECONDS_MIN..=RD_SECONDS_MAX).contains(&secs));
+}
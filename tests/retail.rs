@@ -0,0 +1,85 @@
+use datealgo::calendar::leapweek::*;
+use datealgo::calendar::retail::*;
+use datealgo::consts::{SATURDAY, SUNDAY};
+use datealgo::{date_to_rd, rd_to_weekday};
+
+const PATTERNS: [u16; 3] = [LEAPWEEK_PATTERN_454, LEAPWEEK_PATTERN_445, LEAPWEEK_PATTERN_544];
+const YEAR_ENDS: [YearEnd; 4] = [
+    YEAR_END_NRF,
+    YearEnd::Last(9, SATURDAY),
+    YearEnd::Nearest(12, SUNDAY),
+    YearEnd::Last(6, SUNDAY),
+];
+
+#[test]
+fn test_retail_year_end() {
+    // NRF calendar fiscal years 2017 to 2024, numbered by the following year here
+    assert_eq!(retail_year_end(2018, YEAR_END_NRF), date_to_rd((2018, 2, 3)));
+    assert_eq!(retail_year_end(2019, YEAR_END_NRF), date_to_rd((2019, 2, 2)));
+    assert_eq!(retail_year_end(2020, YEAR_END_NRF), date_to_rd((2020, 2, 1)));
+    assert_eq!(retail_year_end(2021, YEAR_END_NRF), date_to_rd((2021, 1, 30)));
+    assert_eq!(retail_year_end(2022, YEAR_END_NRF), date_to_rd((2022, 1, 29)));
+    assert_eq!(retail_year_end(2025, YEAR_END_NRF), date_to_rd((2025, 2, 1)));
+    assert_eq!(retail_weeks_in_year(2018, YEAR_END_NRF), 53);
+    assert_eq!(retail_year_end(2020, YearEnd::Nearest(12, SUNDAY)), date_to_rd((2021, 1, 3)));
+    assert_eq!(retail_year_end(2022, YearEnd::Last(9, SATURDAY)), date_to_rd((2022, 9, 24)));
+}
+
+#[test]
+fn test_rd_to_retail() {
+    let p = LEAPWEEK_PATTERN_445;
+    let end = YearEnd::Last(9, SATURDAY);
+    assert_eq!(rd_to_retail(date_to_rd((2022, 9, 24)), p, end), (2022, 12, 52));
+    assert_eq!(rd_to_retail(date_to_rd((2022, 9, 25)), p, end), (2023, 1, 1));
+    assert_eq!(rd_to_retail(date_to_rd((2022, 10, 23)), p, end), (2023, 2, 5));
+    assert_eq!(rd_to_retail(date_to_rd((2023, 9, 30)), p, end), (2023, 12, 53));
+    let end = YearEnd::Nearest(12, SUNDAY);
+    assert_eq!(rd_to_retail(date_to_rd((2021, 1, 3)), p, end), (2020, 12, 53));
+    assert_eq!(rd_to_retail(date_to_rd((2021, 1, 4)), p, end), (2021, 1, 1));
+}
+
+#[test]
+fn test_retail_consistency() {
+    for pattern in PATTERNS {
+        for year_end in YEAR_ENDS {
+            let mut prev = rd_to_retail(date_to_rd((1899, 12, 31)), pattern, year_end);
+            for n in date_to_rd((1900, 1, 1))..date_to_rd((2100, 1, 1)) {
+                let (y, p, w) = rd_to_retail(n, pattern, year_end);
+                let (first, last) = retail_week_to_rd_range((y, w), year_end);
+                assert!((first..=last).contains(&n));
+                assert_eq!(last - first, 6);
+                let (first, last) = retail_period_to_rd_range((y, p), pattern, year_end);
+                assert!((first..=last).contains(&n));
+                if n == retail_year_end(y, year_end) {
+                    assert_eq!(n, last);
+                    assert_eq!((p, w), (12, retail_weeks_in_year(y, year_end)));
+                }
+                if n == first && p == 1 {
+                    assert_eq!((y, w), (prev.0 + 1, 1));
+                } else if (n - retail_year_end(y, year_end)).rem_euclid(7) == 1 {
+                    assert_eq!((y, w), (prev.0, prev.2 + 1));
+                } else {
+                    assert_eq!((y, p, w), prev);
+                }
+                prev = (y, p, w);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_retail_weekday() {
+    for year_end in YEAR_ENDS {
+        let (YearEnd::Last(m, wd) | YearEnd::Nearest(m, wd)) = year_end;
+        for y in 1900..2100 {
+            let end = retail_year_end(y, year_end);
+            assert_eq!(rd_to_weekday(end), wd);
+            let diff = end - date_to_rd((y, m, datealgo::days_in_month(y, m)));
+            match year_end {
+                YearEnd::Last(..) => assert!((-6..=0).contains(&diff)),
+                YearEnd::Nearest(..) => assert!((-3..=3).contains(&diff)),
+            }
+            assert!((52..=53).contains(&retail_weeks_in_year(y, year_end)));
+        }
+    }
+}
@@ -0,0 +1,40 @@
+#![cfg(feature = "rtcc")]
+
+use chrono::{DateTime, NaiveDate};
+use datealgo::rtcc::*;
+use datealgo::{RD_MAX, RD_MIN};
+
+#[test]
+fn test_naivedate() {
+    let min = NaiveDate::MIN
+        .signed_duration_since(NaiveDate::from_ymd_opt(1970, 1, 1).unwrap())
+        .num_days() as i32;
+    let max = NaiveDate::MAX
+        .signed_duration_since(NaiveDate::from_ymd_opt(1970, 1, 1).unwrap())
+        .num_days() as i32;
+    for n in (min..=max).step_by(997) {
+        let date = rd_to_naivedate(n).unwrap();
+        assert_eq!(date, NaiveDate::from_num_days_from_ce_opt(n + 719163).unwrap());
+        assert_eq!(naivedate_to_rd(date), n);
+    }
+    assert_eq!(naivedate_to_rd(NaiveDate::MAX), max);
+    assert!(rd_to_naivedate(min - 1).is_none());
+    assert!(rd_to_naivedate(max + 1).is_none());
+    assert!(rd_to_naivedate(RD_MIN).is_none());
+    assert!(rd_to_naivedate(RD_MAX).is_none());
+}
+
+#[test]
+fn test_naivedatetime() {
+    for secs in (-8_000_000_000_000..=8_000_000_000_000).step_by(99_999_999_977) {
+        let dt = secs_to_naivedatetime((secs, 123_456_789)).unwrap();
+        assert_eq!(dt, DateTime::from_timestamp(secs, 123_456_789).unwrap().naive_utc());
+        assert_eq!(naivedatetime_to_secs(&dt), (secs, 123_456_789));
+    }
+    let leap = NaiveDate::from_ymd_opt(2016, 12, 31)
+        .unwrap()
+        .and_hms_nano_opt(23, 59, 59, 1_500_000_000)
+        .unwrap();
+    assert_eq!(naivedatetime_to_secs(&leap), (1483228799, 999_999_999));
+    assert!(secs_to_naivedatetime((9_000_000_000_000, 0)).is_none());
+}
@@ -0,0 +1,73 @@
+#![cfg(feature = "serde")]
+
+use datealgo::serde::*;
+use datealgo::{rd_to_date, secs_to_datetime, RD_MAX, RD_MIN, RD_SECONDS_MAX, RD_SECONDS_MIN, YEAR_MAX, YEAR_MIN};
+
+#[test]
+fn test_iso_date() {
+    for n in (RD_MIN..=RD_MAX).step_by(99991).chain([RD_MIN, RD_MAX]) {
+        let date = IsoDate(rd_to_date(n));
+        let s = serde_json::to_string(&date).unwrap();
+        assert_eq!(serde_json::from_str::<IsoDate>(&s).unwrap(), date);
+    }
+    assert_eq!(serde_json::to_string(&IsoDate((-1, 12, 31))).unwrap(), r#""-000001-12-31""#);
+    assert!(serde_json::to_string(&IsoDate((2023, 2, 29))).is_err());
+    assert!(serde_json::to_string(&IsoDate((YEAR_MAX + 1, 1, 1))).is_err());
+    assert!(serde_json::from_str::<IsoDate>("19489").is_err());
+}
+
+#[test]
+fn test_rfc3339() {
+    for secs in (RD_SECONDS_MIN..=RD_SECONDS_MAX)
+        .step_by(8_639_999_977)
+        .chain([RD_SECONDS_MIN, RD_SECONDS_MAX])
+    {
+        let dt = Rfc3339(secs_to_datetime(secs));
+        let s = serde_json::to_string(&dt).unwrap();
+        assert_eq!(serde_json::from_str::<Rfc3339>(&s).unwrap(), dt);
+    }
+    let dt = Rfc3339((2023, 5, 20, 9, 24, 38));
+    assert_eq!(serde_json::from_str::<Rfc3339>(r#""2023-05-20t09:24:38z""#).unwrap(), dt);
+    assert_eq!(serde_json::from_str::<Rfc3339>(r#""2023-05-20 09:24:38.123456789Z""#).unwrap(), dt);
+    assert_eq!(serde_json::from_str::<Rfc3339>(r#""2023-05-20T01:54:38-07:30""#).unwrap(), dt);
+    assert_eq!(
+        serde_json::from_str::<Rfc3339>(r#""2023-05-20T00:00:00+01:00""#).unwrap(),
+        Rfc3339((2023, 5, 19, 23, 0, 0))
+    );
+    for s in [
+        r#""2023-05-20T09:24:38""#,
+        r#""2023-05-20T09:24:60Z""#,
+        r#""2023-05-20T24:00:00Z""#,
+        r#""2023-05-20T09:24:38.Z""#,
+        r#""2023-05-20T09:24Z""#,
+        r#""2023-05-20""#,
+        r#""""#,
+    ] {
+        assert!(serde_json::from_str::<Rfc3339>(s).is_err(), "{}", s);
+    }
+    let min = format!(r#""{}-01-01T00:00:00+00:01""#, YEAR_MIN);
+    assert!(serde_json::from_str::<Rfc3339>(&min).is_err());
+}
+
+#[test]
+fn test_rd_days() {
+    assert_eq!(serde_json::to_string(&RdDays(rd_to_date(RD_MIN))).unwrap(), RD_MIN.to_string());
+    assert_eq!(
+        serde_json::from_str::<RdDays>(&RD_MAX.to_string()).unwrap(),
+        RdDays(rd_to_date(RD_MAX))
+    );
+    assert!(serde_json::from_str::<RdDays>(&(RD_MAX as i64 + 1).to_string()).is_err());
+    assert!(serde_json::from_str::<RdDays>(r#""2023-05-12""#).is_err());
+}
+
+#[test]
+fn test_unix_secs() {
+    let max = UnixSecs(secs_to_datetime(RD_SECONDS_MAX));
+    assert_eq!(serde_json::to_string(&max).unwrap(), RD_SECONDS_MAX.to_string());
+    assert_eq!(
+        serde_json::from_str::<UnixSecs>(&RD_SECONDS_MIN.to_string()).unwrap(),
+        UnixSecs(secs_to_datetime(RD_SECONDS_MIN))
+    );
+    assert!(serde_json::from_str::<UnixSecs>(&(RD_SECONDS_MIN - 1).to_string()).is_err());
+    assert!(serde_json::to_string(&UnixSecs((2023, 5, 20, 24, 0, 0))).is_err());
+}
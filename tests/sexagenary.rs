@@ -0,0 +1,46 @@
+use datealgo::calendar::sexagenary::*;
+use datealgo::{date_to_rd, RD_MAX, RD_MIN, YEAR_MAX, YEAR_MIN};
+
+#[test]
+fn test_year_to_sexagenary() {
+    assert_eq!(year_to_sexagenary(4), 1);
+    assert_eq!(year_to_sexagenary(3), 60);
+    assert_eq!(year_to_sexagenary(-56), 1);
+    assert_eq!(year_to_sexagenary(1911), 48);
+    assert_eq!(year_to_sexagenary(YEAR_MIN), 18);
+    assert_eq!(year_to_sexagenary(YEAR_MAX), 1);
+}
+
+#[test]
+fn test_rd_to_sexagenary() {
+    assert_eq!(rd_to_sexagenary(-17), 1);
+    assert_eq!(rd_to_sexagenary(-18), 60);
+    assert_eq!(rd_to_sexagenary(date_to_rd((1949, 10, 1))), 1);
+    assert_eq!(rd_to_sexagenary(RD_MIN), 46);
+    assert_eq!(rd_to_sexagenary(RD_MAX), 53);
+}
+
+#[test]
+fn test_sexagenary_to_stem_branch() {
+    assert_eq!(sexagenary_to_stem_branch(11), (1, 11));
+    assert_eq!(sexagenary_to_stem_branch(48), (8, 12));
+    for n in 1..=60 {
+        let (s, b) = sexagenary_to_stem_branch(n);
+        assert_eq!(stem_branch_to_sexagenary((s, b)), Some(n));
+    }
+}
+
+#[test]
+fn test_stem_branch_to_sexagenary() {
+    assert_eq!(stem_branch_to_sexagenary((1, 11)), Some(11));
+    assert_eq!(stem_branch_to_sexagenary((10, 12)), Some(60));
+    let mut count = 0;
+    for s in 1..=10 {
+        for b in 1..=12 {
+            if stem_branch_to_sexagenary((s, b)).is_some() {
+                count += 1;
+            }
+        }
+    }
+    assert_eq!(count, 60);
+}
@@ -0,0 +1,66 @@
+#![cfg(feature = "simd")]
+
+use datealgo::simd::*;
+use datealgo::{rd_to_date, secs_to_datetime, RD_MAX, RD_MIN, RD_SECONDS_MAX, RD_SECONDS_MIN};
+
+#[test]
+fn test_rd_to_date_x8() {
+    let mut ns = [0; 8];
+    for base in (RD_MIN..=RD_MAX - 8).step_by(12345).chain([RD_MAX - 7, -4, 19489]) {
+        for (i, n) in ns.iter_mut().enumerate() {
+            *n = base + i as i32;
+        }
+        let (y, m, d) = rd_to_date_x8(ns);
+        for i in 0..8 {
+            assert_eq!((y[i], m[i], d[i]), rd_to_date(ns[i]));
+        }
+    }
+}
+
+#[test]
+fn test_rd_to_date_x16() {
+    let mut ns = [0; 16];
+    for base in (RD_MIN..=RD_MAX - 16).step_by(12345).chain([RD_MAX - 15, -8, 19489]) {
+        for (i, n) in ns.iter_mut().enumerate() {
+            *n = base + i as i32;
+        }
+        let (y, m, d) = rd_to_date_x16(ns);
+        for i in 0..16 {
+            assert_eq!((y[i], m[i], d[i]), rd_to_date(ns[i]));
+        }
+    }
+}
+
+#[test]
+fn test_secs_to_datetime_x8() {
+    let mut secs = [0; 8];
+    for base in (RD_SECONDS_MIN..=RD_SECONDS_MAX - 8 * 3607)
+        .step_by(1234567891)
+        .chain([RD_SECONDS_MAX - 7 * 3607, -4 * 3607, 1684574678])
+    {
+        for (i, s) in secs.iter_mut().enumerate() {
+            *s = base + i as i64 * 3607;
+        }
+        let (y, m, d, hh, mm, ss) = secs_to_datetime_x8(secs);
+        for i in 0..8 {
+            assert_eq!((y[i], m[i], d[i], hh[i], mm[i], ss[i]), secs_to_datetime(secs[i]));
+        }
+    }
+}
+
+#[test]
+fn test_secs_to_datetime_x16() {
+    let mut secs = [0; 16];
+    for base in (RD_SECONDS_MIN..=RD_SECONDS_MAX - 16 * 3607)
+        .step_by(1234567891)
+        .chain([RD_SECONDS_MAX - 15 * 3607, -8 * 3607, 1684574678])
+    {
+        for (i, s) in secs.iter_mut().enumerate() {
+            *s = base + i as i64 * 3607;
+        }
+        let (y, m, d, hh, mm, ss) = secs_to_datetime_x16(secs);
+        for i in 0..16 {
+            assert_eq!((y[i], m[i], d[i], hh[i], mm[i], ss[i]), secs_to_datetime(secs[i]));
+        }
+    }
+}
@@ -0,0 +1,30 @@
+#![cfg(feature = "tables")]
+
+use datealgo::tables::*;
+use datealgo::{RD_MAX, RD_MIN, YEAR_MAX, YEAR_MIN};
+
+#[test]
+fn test_rd_to_date() {
+    for n in (-1_000_000..1_000_000).chain(RD_MIN..RD_MIN + 1000).chain(RD_MAX - 1000..=RD_MAX) {
+        assert_eq!(rd_to_date(n), datealgo::rd_to_date(n));
+    }
+    for n in (RD_MIN..=RD_MAX).step_by(997) {
+        assert_eq!(rd_to_date(n), datealgo::rd_to_date(n));
+    }
+}
+
+#[test]
+fn test_is_leap_year() {
+    for y in (-10000..10000).chain(YEAR_MIN..YEAR_MIN + 1000).chain(YEAR_MAX - 1000..=YEAR_MAX) {
+        assert_eq!(is_leap_year(y), datealgo::is_leap_year(y));
+    }
+}
+
+#[test]
+fn test_days_in_month() {
+    for y in (-1000..1000).chain([YEAR_MIN, YEAR_MAX]) {
+        for m in 1..=12 {
+            assert_eq!(days_in_month(y, m), datealgo::days_in_month(y, m));
+        }
+    }
+}
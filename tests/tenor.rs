@@ -0,0 +1,116 @@
+use chrono::{Months, NaiveDate};
+use datealgo::tenor::*;
+use datealgo::{date_to_rd, days_in_month, rd_to_date, RD_MAX, RD_MIN, YEAR_MAX, YEAR_MIN};
+
+#[test]
+fn test_parse_tenor() {
+    assert_eq!(parse_tenor(b"0D"), Some((0, TenorUnit::Days)));
+    assert_eq!(parse_tenor(b"+1w"), Some((1, TenorUnit::Weeks)));
+    assert_eq!(parse_tenor(b"18M"), Some((18, TenorUnit::Months)));
+    assert_eq!(parse_tenor(b"30Y"), Some((30, TenorUnit::Years)));
+    assert_eq!(parse_tenor(b"2147483647D"), Some((i32::MAX, TenorUnit::Days)));
+    assert_eq!(parse_tenor(b"-2147483647D"), Some((-i32::MAX, TenorUnit::Days)));
+    assert_eq!(parse_tenor(b"2147483648D"), None);
+    assert_eq!(parse_tenor(b"99999999999999999999D"), None);
+    assert_eq!(parse_tenor(b""), None);
+    assert_eq!(parse_tenor(b"1"), None);
+    assert_eq!(parse_tenor(b"-"), None);
+    assert_eq!(parse_tenor(b"-D"), None);
+    assert_eq!(parse_tenor(b"1MM"), None);
+    assert_eq!(parse_tenor(b"1 M"), None);
+    assert_eq!(parse_tenor(b" 1M"), None);
+    assert_eq!(parse_tenor(b"1Y6M"), None);
+    assert_eq!(parse_tenor(b"--1M"), None);
+}
+
+#[test]
+fn test_roll_by_tenor() {
+    for rd in date_to_rd((1999, 1, 1))..date_to_rd((2002, 1, 1)) {
+        let (y, m, d) = rd_to_date(rd);
+        let date = NaiveDate::from_ymd_opt(y, m as u32, d as u32).unwrap();
+        assert_eq!(roll_by_tenor(rd, (3, TenorUnit::Days), false), rd + 3);
+        assert_eq!(roll_by_tenor(rd, (-2, TenorUnit::Weeks), true), rd - 14);
+        for n in [1, 2, 3, 6, 11, 12, 13, 25, 120] {
+            for (months, unit, count) in [(n, TenorUnit::Months, n), (n * 12, TenorUnit::Years, n)] {
+                let later = date.checked_add_months(Months::new(months)).unwrap();
+                let earlier = date.checked_sub_months(Months::new(months)).unwrap();
+                let to_rd = |date: NaiveDate| (date - NaiveDate::from_ymd_opt(1970, 1, 1).unwrap()).num_days() as i32;
+                assert_eq!(roll_by_tenor(rd, (count as i32, unit), false), to_rd(later));
+                assert_eq!(roll_by_tenor(rd, (-(count as i32), unit), false), to_rd(earlier));
+                let rolled = rd_to_date(roll_by_tenor(rd, (count as i32, unit), true));
+                if d == days_in_month(y, m) {
+                    assert_eq!(rolled.2, days_in_month(rolled.0, rolled.1));
+                } else {
+                    assert_eq!(date_to_rd(rolled), to_rd(later));
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_roll_by_tenor_extremes() {
+    let span = RD_MAX - RD_MIN;
+    assert_eq!(roll_by_tenor(RD_MIN, (span, TenorUnit::Days), false), RD_MAX);
+    assert_eq!(roll_by_tenor(RD_MAX, (-span, TenorUnit::Days), false), RD_MIN);
+    assert_eq!(roll_by_tenor(RD_MIN, (span / 7, TenorUnit::Weeks), false), RD_MIN + span / 7 * 7);
+    let years = YEAR_MAX - YEAR_MIN;
+    assert_eq!(
+        roll_by_tenor(RD_MIN, (years, TenorUnit::Years), false),
+        date_to_rd((YEAR_MAX, 1, 1))
+    );
+    assert_eq!(
+        roll_by_tenor(RD_MAX, (-years * 12, TenorUnit::Months), true),
+        date_to_rd((YEAR_MIN, 12, 31))
+    );
+    assert_eq!(roll_months_eom((YEAR_MAX, 12, 31), -years * 12), (YEAR_MIN, 12, 31));
+}
+
+#[test]
+#[cfg(debug_assertions)]
+fn test_roll_by_tenor_overflow() {
+    for s in [&b"2147483647D"[..], b"2147483647W", b"2147483647M", b"2147483647Y", b"-2147483647Y"] {
+        let tenor = parse_tenor(s).unwrap();
+        for rd in [RD_MIN, 0, RD_MAX] {
+            for eom in [false, true] {
+                let err = std::panic::catch_unwind(|| roll_by_tenor(rd, tenor, eom)).unwrap_err();
+                let msg = err.downcast_ref::<&str>().unwrap();
+                assert!(msg.starts_with("resulting ") && msg.ends_with(" is out of range"), "{msg}");
+            }
+        }
+    }
+}
+
+#[test]
+fn test_is_end_of_month() {
+    for rd in date_to_rd((1999, 1, 1))..date_to_rd((2002, 1, 1)) {
+        let (y, m, d) = rd_to_date(rd);
+        assert_eq!(is_end_of_month((y, m, d)), rd_to_date(rd + 1).1 != m);
+    }
+    assert!(is_end_of_month((2000, 2, 29)));
+    assert!(!is_end_of_month((2000, 2, 28)));
+    assert!(is_end_of_month((1900, 2, 28)));
+}
+
+#[test]
+fn test_roll_months_eom() {
+    let mut date = (2023, 1, 31);
+    for (m, d) in [(2, 28), (3, 31), (4, 30), (5, 31), (6, 30)] {
+        date = roll_months_eom(date, 1);
+        assert_eq!(date, (2023, m, d));
+    }
+    assert_eq!(roll_months_eom((2023, 1, 15), 1), (2023, 2, 15));
+    assert_eq!(roll_months_eom((2023, 3, 30), -1), (2023, 2, 28));
+    assert_eq!(roll_months_eom((2023, 11, 30), 3), (2024, 2, 29));
+    assert_eq!(roll_months_eom((2024, 2, 29), 12), (2025, 2, 28));
+    assert_eq!(roll_months_eom((2023, 2, 28), -13), (2022, 1, 31));
+    assert_eq!(roll_months_eom((0, 1, 31), -1), (-1, 12, 31));
+    for rd in date_to_rd((1999, 1, 1))..date_to_rd((2002, 1, 1)) {
+        let date = rd_to_date(rd);
+        for n in [-25, -12, -1, 0, 1, 2, 12, 25] {
+            let rolled = roll_months_eom(date, n);
+            assert_eq!(date_to_rd(rolled), roll_by_tenor(rd, (n, TenorUnit::Months), true));
+            assert!(is_end_of_month(rolled) || !is_end_of_month(date));
+        }
+    }
+}
@@ -0,0 +1,260 @@
+use datealgo::tzif::{abbreviation_at, is_dst_at, local_datetime_to_secs, secs_to_local_datetime, TzData};
+use datealgo::{datetime_to_secs, LocalResult, RD_SECONDS_MAX, RD_SECONDS_MIN};
+
+const HELSINKI: &[u8] = include_bytes!("data/Europe_Helsinki.tzif");
+const SYDNEY: &[u8] = include_bytes!("data/Australia_Sydney.tzif");
+
+fn header(version: u8, counts: [u32; 6]) -> Vec<u8> {
+    let mut v = b"TZif".to_vec();
+    v.push(version);
+    v.extend([0; 15]);
+    for c in counts {
+        v.extend(c.to_be_bytes());
+    }
+    v
+}
+
+fn build(version: u8, times: &[i64], types: &[u8], ttinfos: &[(i32, u8, u8)], chars: &[u8], footer: Option<&[u8]>) -> Vec<u8> {
+    let counts = [0, 0, 0, times.len() as u32, ttinfos.len() as u32, chars.len() as u32];
+    let block = |wide: bool| {
+        let mut v = Vec::new();
+        for &t in times {
+            if wide {
+                v.extend(t.to_be_bytes());
+            } else {
+                v.extend((t as i32).to_be_bytes());
+            }
+        }
+        v.extend(types);
+        for &(utoff, isdst, idx) in ttinfos {
+            v.extend(utoff.to_be_bytes());
+            v.push(isdst);
+            v.push(idx);
+        }
+        v.extend(chars);
+        v
+    };
+    let mut v = header(version, counts);
+    v.extend(block(false));
+    if version != 0 {
+        v.extend(header(version, counts));
+        v.extend(block(true));
+        if let Some(footer) = footer {
+            v.push(b'\n');
+            v.extend(footer);
+            v.push(b'\n');
+        }
+    }
+    v
+}
+
+fn lookup<'a>(tz: &TzData<'a>, secs: i64) -> (i32, bool, &'a str) {
+    let (utoff, dst, idx) = tz.offset_at(secs);
+    (utoff, dst, tz.abbreviation(idx).unwrap())
+}
+
+#[test]
+fn test_helsinki() {
+    let tz = TzData::parse(HELSINKI).unwrap();
+    assert!(tz.version() >= 2);
+    assert!(tz.transition_count() > 100);
+    assert!(tz.footer().is_some());
+    let s = datetime_to_secs((2023, 1, 15, 12, 0, 0));
+    assert_eq!(lookup(&tz, s), (7200, false, "EET"));
+    let s = datetime_to_secs((2023, 7, 15, 12, 0, 0));
+    assert_eq!(lookup(&tz, s), (10800, true, "EEST"));
+    let s = datetime_to_secs((2023, 3, 26, 1, 0, 0));
+    assert_eq!(lookup(&tz, s - 1), (7200, false, "EET"));
+    assert_eq!(lookup(&tz, s), (10800, true, "EEST"));
+    let s = datetime_to_secs((2023, 10, 29, 1, 0, 0));
+    assert_eq!(lookup(&tz, s - 1), (10800, true, "EEST"));
+    assert_eq!(lookup(&tz, s), (7200, false, "EET"));
+    let s = datetime_to_secs((1900, 1, 1, 0, 0, 0));
+    assert_eq!(lookup(&tz, s), (5989, false, "HMT"));
+    let s = datetime_to_secs((2100, 7, 1, 0, 0, 0));
+    assert_eq!(lookup(&tz, s), (10800, true, "EEST"));
+    let s = datetime_to_secs((2100, 12, 1, 0, 0, 0));
+    assert_eq!(lookup(&tz, s), (7200, false, "EET"));
+    assert_eq!(lookup(&tz, RD_SECONDS_MIN), (5989, false, "LMT"));
+    assert_eq!(lookup(&tz, RD_SECONDS_MAX).0, 7200);
+}
+
+#[test]
+fn test_sydney() {
+    let tz = TzData::parse(SYDNEY).unwrap();
+    let s = datetime_to_secs((2023, 1, 1, 0, 0, 0));
+    assert_eq!(lookup(&tz, s), (39600, true, "AEDT"));
+    let s = datetime_to_secs((2023, 7, 1, 0, 0, 0));
+    assert_eq!(lookup(&tz, s), (36000, false, "AEST"));
+    let s = datetime_to_secs((2200, 1, 1, 0, 0, 0));
+    assert_eq!(lookup(&tz, s), (39600, true, "AEDT"));
+    let s = datetime_to_secs((2200, 7, 1, 0, 0, 0));
+    assert_eq!(lookup(&tz, s), (36000, false, "AEST"));
+}
+
+#[test]
+fn test_transitions() {
+    let tz = TzData::parse(HELSINKI).unwrap();
+    for i in 0..tz.transition_count() {
+        let (t, ty) = tz.transition(i).unwrap();
+        assert_eq!(Some(tz.offset_at(t)), tz.local_time_type(ty));
+        if i > 0 {
+            let (_, prev) = tz.transition(i - 1).unwrap();
+            assert_eq!(Some(tz.offset_at(t - 1)), tz.local_time_type(prev));
+        }
+    }
+    assert_eq!(tz.transition(tz.transition_count()), None);
+    assert_eq!(tz.local_time_type(tz.local_time_type_count() as u8), None);
+}
+
+#[test]
+fn test_synthetic() {
+    let ttinfos = [(0, 0, 0), (3600, 0, 4), (7200, 1, 8)];
+    let chars = b"LMT\0AAA\0BBB\0";
+    for version in [0, b'2', b'3', b'4'] {
+        let data = build(version, &[-100, 0, 100], &[1, 2, 1], &ttinfos, chars, Some(b""));
+        let tz = TzData::parse(&data).unwrap();
+        assert_eq!(tz.version(), if version == 0 { 1 } else { version - b'0' });
+        assert_eq!(tz.footer(), None);
+        assert_eq!(lookup(&tz, -101), (0, false, "LMT"));
+        assert_eq!(lookup(&tz, -100), (3600, false, "AAA"));
+        assert_eq!(lookup(&tz, -1), (3600, false, "AAA"));
+        assert_eq!(lookup(&tz, 0), (7200, true, "BBB"));
+        assert_eq!(lookup(&tz, 99), (7200, true, "BBB"));
+        assert_eq!(lookup(&tz, 100), (3600, false, "AAA"));
+        assert_eq!(lookup(&tz, RD_SECONDS_MAX), (3600, false, "AAA"));
+    }
+    let data = build(b'2', &[], &[], &ttinfos, chars, Some(b"AAA-1BBB,J1,J365/25"));
+    let tz = TzData::parse(&data).unwrap();
+    assert_eq!(lookup(&tz, RD_SECONDS_MIN), (3600, false, "AAA"));
+    assert_eq!(lookup(&tz, 0), (3600, false, "AAA"));
+    assert_eq!(lookup(&tz, datetime_to_secs((2023, 6, 1, 0, 0, 0))), (7200, true, "BBB"));
+    let data = build(b'2', &[0], &[1], &ttinfos, chars, Some(b"CCC-1"));
+    let tz = TzData::parse(&data).unwrap();
+    assert_eq!(lookup(&tz, 100), (3600, false, "AAA"));
+    let data = build(b'2', &[0], &[0], &ttinfos, chars, Some(b"CCC-3"));
+    let tz = TzData::parse(&data).unwrap();
+    assert_eq!(lookup(&tz, 100), (10800, false, "LMT"));
+}
+
+#[test]
+fn test_invalid() {
+    let ttinfos = [(0, 0, 0), (3600, 1, 4)];
+    let chars = b"LMT\0AAA\0";
+    let valid = build(b'2', &[0, 100], &[0, 1], &ttinfos, chars, Some(b"AAA-1"));
+    assert!(TzData::parse(&valid).is_some());
+    for n in 0..valid.len() {
+        assert_eq!(TzData::parse(&valid[..n]), None);
+    }
+    let mut data = valid.clone();
+    data[0] = b'X';
+    assert_eq!(TzData::parse(&data), None);
+    let mut data = valid.clone();
+    data[4] = b'5';
+    assert_eq!(TzData::parse(&data), None);
+    for data in [
+        build(b'2', &[100, 0], &[0, 1], &ttinfos, chars, Some(b"")),
+        build(b'2', &[0, 0], &[0, 1], &ttinfos, chars, Some(b"")),
+        build(b'2', &[0, 100], &[0, 2], &ttinfos, chars, Some(b"")),
+        build(b'2', &[], &[], &[(0, 2, 0)], chars, Some(b"")),
+        build(b'2', &[], &[], &[(0, 0, 8)], chars, Some(b"")),
+        build(b'2', &[], &[], &[(i32::MIN, 0, 0)], chars, Some(b"")),
+        build(b'2', &[], &[], &[], chars, Some(b"")),
+        build(b'2', &[], &[], &ttinfos, b"LMT\0AAA", Some(b"")),
+        build(b'2', &[], &[], &ttinfos, chars, Some(b"AAA")),
+        build(b'2', &[], &[], &ttinfos, chars, None),
+    ] {
+        assert_eq!(TzData::parse(&data), None);
+    }
+}
+
+#[test]
+fn test_local_datetime() {
+    let tz = TzData::parse(HELSINKI).unwrap();
+    let s = datetime_to_secs((2023, 7, 15, 12, 0, 0));
+    assert_eq!(secs_to_local_datetime(s, &tz), (2023, 7, 15, 15, 0, 0));
+    assert_eq!(local_datetime_to_secs((2023, 7, 15, 15, 0, 0), &tz), LocalResult::Unique(s));
+    let s = datetime_to_secs((2023, 3, 26, 1, 0, 0));
+    assert_eq!(secs_to_local_datetime(s - 1, &tz), (2023, 3, 26, 2, 59, 59));
+    assert_eq!(secs_to_local_datetime(s, &tz), (2023, 3, 26, 4, 0, 0));
+    assert_eq!(local_datetime_to_secs((2023, 3, 26, 3, 0, 0), &tz), LocalResult::Gap(s, 3600));
+    assert_eq!(
+        local_datetime_to_secs((2023, 3, 26, 3, 59, 59), &tz),
+        LocalResult::Gap(s + 3599, 3600)
+    );
+    assert_eq!(local_datetime_to_secs((2023, 3, 26, 4, 0, 0), &tz), LocalResult::Unique(s));
+    let s = datetime_to_secs((2023, 10, 29, 1, 0, 0));
+    assert_eq!(secs_to_local_datetime(s - 1, &tz), (2023, 10, 29, 3, 59, 59));
+    assert_eq!(secs_to_local_datetime(s, &tz), (2023, 10, 29, 3, 0, 0));
+    assert_eq!(
+        local_datetime_to_secs((2023, 10, 29, 3, 0, 0), &tz),
+        LocalResult::Ambiguous(s - 3600, s)
+    );
+    assert_eq!(local_datetime_to_secs((2023, 10, 29, 4, 0, 0), &tz), LocalResult::Unique(s + 3600));
+    assert_eq!(
+        local_datetime_to_secs((2023, 10, 29, 2, 59, 59), &tz),
+        LocalResult::Unique(s - 3601)
+    );
+    let s = RD_SECONDS_MIN + 86400;
+    let dt = secs_to_local_datetime(s, &tz);
+    assert_eq!(local_datetime_to_secs(dt, &tz), LocalResult::Unique(s));
+}
+
+#[test]
+fn test_local_datetime_roundtrip() {
+    let tz = TzData::parse(SYDNEY).unwrap();
+    let start = datetime_to_secs((1850, 1, 1, 0, 0, 0));
+    for s in (start..start + 300 * 365 * 86400).step_by(36007) {
+        let dt = secs_to_local_datetime(s, &tz);
+        match local_datetime_to_secs(dt, &tz) {
+            LocalResult::Unique(t) => assert_eq!(t, s),
+            LocalResult::Ambiguous(a, b) => assert!(a == s || b == s),
+            LocalResult::Gap(..) => panic!("gap for existing local time {:?}", dt),
+        }
+    }
+}
+
+#[test]
+fn test_dst_and_abbreviation_at() {
+    let tz = TzData::parse(HELSINKI).unwrap();
+    let s = datetime_to_secs((2023, 3, 26, 1, 0, 0));
+    assert_eq!((is_dst_at(s - 1, &tz), abbreviation_at(s - 1, &tz)), (false, "EET"));
+    assert_eq!((is_dst_at(s, &tz), abbreviation_at(s, &tz)), (true, "EEST"));
+    let s = datetime_to_secs((1900, 1, 1, 0, 0, 0));
+    assert_eq!((is_dst_at(s, &tz), abbreviation_at(s, &tz)), (false, "HMT"));
+    let s = datetime_to_secs((2100, 7, 1, 0, 0, 0));
+    assert_eq!((is_dst_at(s, &tz), abbreviation_at(s, &tz)), (true, "EEST"));
+    let ttinfos = [(0, 0, 0), (3600, 0, 4)];
+    let data = build(b'2', &[0], &[1], &ttinfos, b"LMT\0AAA\0", Some(b"CCC-1DDD,J1,J365/25"));
+    let tz = TzData::parse(&data).unwrap();
+    assert_eq!((is_dst_at(-1, &tz), abbreviation_at(-1, &tz)), (false, "LMT"));
+    assert_eq!((is_dst_at(0, &tz), abbreviation_at(0, &tz)), (false, "CCC"));
+    let s = datetime_to_secs((2023, 6, 1, 0, 0, 0));
+    assert_eq!((is_dst_at(s, &tz), abbreviation_at(s, &tz)), (true, "DDD"));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_load() {
+    use datealgo::tzif::{load_file, load_tz, load_zone};
+    use std::io::ErrorKind;
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data/Europe_Helsinki.tzif");
+    let mut buf = Vec::new();
+    assert_eq!(load_file(path, &mut buf).unwrap(), TzData::parse(HELSINKI).unwrap());
+    assert_eq!(load_zone(path, &mut buf).unwrap(), TzData::parse(HELSINKI).unwrap());
+    let tz_value = format!(":{}", path);
+    assert_eq!(load_tz(Some(&tz_value), &mut buf).unwrap(), TzData::parse(HELSINKI).unwrap());
+    let tz = load_tz(Some("EST5EDT,M3.2.0,M11.1.0"), &mut buf).unwrap();
+    let s = datetime_to_secs((2023, 7, 1, 0, 0, 0));
+    assert_eq!((tz.offset_at(s).0, abbreviation_at(s, &tz)), (-14400, "EDT"));
+    let s = datetime_to_secs((2023, 1, 1, 0, 0, 0));
+    assert_eq!((tz.offset_at(s).0, abbreviation_at(s, &tz)), (-18000, "EST"));
+    let tz = load_tz(Some(""), &mut buf).unwrap();
+    assert_eq!((tz.offset_at(s).0, abbreviation_at(s, &tz)), (0, "UTC"));
+    let err = |r: std::io::Result<TzData>| r.unwrap_err().kind();
+    assert_eq!(err(load_tz(Some("../etc/passwd"), &mut buf)), ErrorKind::InvalidInput);
+    assert_eq!(err(load_zone("", &mut buf)), ErrorKind::InvalidInput);
+    assert_eq!(err(load_tz(Some("No/Such_Zone"), &mut buf)), ErrorKind::NotFound);
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml");
+    assert_eq!(err(load_file(path, &mut buf)), ErrorKind::InvalidData);
+}
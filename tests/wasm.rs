@@ -0,0 +1,37 @@
+#![cfg(feature = "wasm")]
+
+use datealgo::wasm::*;
+
+#[test]
+fn test_millis_roundtrip() {
+    for ms in (-1_000_000..1_000_000).map(|ms: i64| ms * 8_639_999_999) {
+        let ms = ms as f64;
+        let secs = millis_to_secs(ms).unwrap();
+        assert_eq!(secs_to_millis(secs), Some(ms));
+        let dt = millis_to_datetime(ms).unwrap();
+        assert_eq!(datetime_to_millis(dt), Some(ms));
+    }
+}
+
+#[test]
+fn test_millis_limits() {
+    assert_eq!(millis_to_datetime(JS_MILLIS_MAX), Some((275760, 9, 13, 0, 0, 0, 0)));
+    assert_eq!(millis_to_datetime(-JS_MILLIS_MAX), Some((-271821, 4, 20, 0, 0, 0, 0)));
+    assert_eq!(millis_to_secs(JS_MILLIS_MAX + 1.0), None);
+    assert_eq!(millis_to_secs(-JS_MILLIS_MAX - 1.0), None);
+    assert_eq!(millis_to_secs(f64::INFINITY), None);
+    assert_eq!(millis_to_secs(f64::NEG_INFINITY), None);
+    assert_eq!(millis_to_secs(f64::NAN), None);
+    assert_eq!(datetime_to_millis((-271821, 4, 19, 23, 59, 59, 999_999_999)), None);
+    assert_eq!(secs_to_millis((i64::MAX / 1000, 0)), None);
+}
+
+#[test]
+fn test_millis_fraction() {
+    assert_eq!(millis_to_secs(-0.5), Some((-1, 999_500_000)));
+    assert_eq!(millis_to_secs(1.25), Some((0, 1_250_000)));
+    assert_eq!(millis_to_secs(-0.0), Some((0, 0)));
+    let (_, nanos) = millis_to_secs(1684574678123.999).unwrap();
+    assert!((123_990_000..=123_999_999).contains(&nanos));
+    assert_eq!(secs_to_millis((0, 999_999)), Some(0.0));
+}
@@ -0,0 +1,233 @@
+use chrono::NaiveDate;
+use datealgo::week::{
+    broadcast_month_to_rd_range, broadcast_week_to_rd_range, broadcast_weeks_in_year, count_weekday_in_range, count_weekend_days,
+    epiweek_to_rd_range, epiweeks_in_year, rd_to_broadcast, rd_to_epiweek, week_chunks, week_of_year, week_of_year_monday_start,
+    week_of_year_sunday_start, weekday_add, weekday_difference, weekday_sub, WEEKEND_FRI_SAT, WEEKEND_SAT_SUN,
+};
+use datealgo::{date_to_rd, rd_to_date, rd_to_isoweekdate, rd_to_weekday, RD_MAX, RD_MIN, YEAR_MAX, YEAR_MIN};
+
+#[test]
+fn test_epiweek_known() {
+    assert_eq!(rd_to_epiweek(date_to_rd((2014, 1, 1))), (2014, 1));
+    assert_eq!(rd_to_epiweek(date_to_rd((2013, 12, 29))), (2014, 1));
+    assert_eq!(rd_to_epiweek(date_to_rd((2015, 1, 3))), (2014, 53));
+    assert_eq!(rd_to_epiweek(date_to_rd((2015, 1, 4))), (2015, 1));
+    assert_eq!(rd_to_epiweek(date_to_rd((2021, 1, 1))), (2020, 53));
+    assert_eq!(rd_to_epiweek(date_to_rd((2021, 1, 3))), (2021, 1));
+    assert_eq!(rd_to_epiweek(date_to_rd((2022, 1, 1))), (2021, 52));
+    assert_eq!(rd_to_epiweek(date_to_rd((2022, 1, 2))), (2022, 1));
+    assert_eq!(rd_to_epiweek(date_to_rd((2024, 12, 31))), (2025, 1));
+    assert_eq!(
+        epiweek_to_rd_range((2024, 1)),
+        (date_to_rd((2023, 12, 31)), date_to_rd((2024, 1, 6)))
+    );
+    assert_eq!(epiweeks_in_year(2008), 53);
+    assert_eq!(epiweeks_in_year(2024), 52);
+}
+
+#[test]
+fn test_epiweek_roundtrip() {
+    for rd in (-1_000_000..1_000_000).chain(RD_MIN..RD_MIN + 1000).chain(RD_MAX - 1000..=RD_MAX) {
+        let (y, w) = rd_to_epiweek(rd);
+        assert!((1..=epiweeks_in_year(y)).contains(&w));
+        let (first, last) = epiweek_to_rd_range((y, w));
+        assert!((first..=last).contains(&rd));
+        assert_eq!(last - first, 6);
+        assert_eq!((rd - first) as u8, rd_to_weekday(rd) % 7);
+        assert_eq!(datealgo::rd_to_year(first + 3), y);
+    }
+}
+
+#[test]
+fn test_epiweeks_in_year() {
+    for y in (-2000..3000).chain(YEAR_MIN..YEAR_MIN + 10).chain(YEAR_MAX - 10..YEAR_MAX) {
+        let (first, _) = epiweek_to_rd_range((y, 1));
+        let (next, _) = epiweek_to_rd_range((y + 1, 1));
+        assert_eq!((next - first) / 7, epiweeks_in_year(y) as i32);
+    }
+}
+
+#[test]
+fn test_broadcast_known() {
+    assert_eq!(rd_to_broadcast(date_to_rd((2024, 1, 1))), (2024, 1, 1));
+    assert_eq!(rd_to_broadcast(date_to_rd((2023, 12, 31))), (2023, 12, 53));
+    assert_eq!(rd_to_broadcast(date_to_rd((2024, 12, 29))), (2024, 12, 52));
+    assert_eq!(rd_to_broadcast(date_to_rd((2024, 12, 30))), (2025, 1, 1));
+    assert_eq!(rd_to_broadcast(date_to_rd((2024, 3, 31))), (2024, 3, 13));
+    assert_eq!(rd_to_broadcast(date_to_rd((2024, 4, 1))), (2024, 4, 14));
+    assert_eq!(
+        broadcast_month_to_rd_range((2024, 2)),
+        (date_to_rd((2024, 1, 29)), date_to_rd((2024, 2, 25)))
+    );
+    assert_eq!(
+        broadcast_month_to_rd_range((2024, 3)),
+        (date_to_rd((2024, 2, 26)), date_to_rd((2024, 3, 31)))
+    );
+    assert_eq!(broadcast_weeks_in_year(2017), 53);
+    assert_eq!(broadcast_weeks_in_year(2018), 52);
+}
+
+#[test]
+fn test_broadcast_roundtrip() {
+    for rd in (-1_000_000..1_000_000)
+        .chain(RD_MIN..RD_MIN + 1000)
+        .chain(RD_MAX - 1000..=RD_MAX - 4)
+    {
+        let (y, m, w) = rd_to_broadcast(rd);
+        assert!((1..=broadcast_weeks_in_year(y)).contains(&w));
+        let (first, last) = broadcast_week_to_rd_range((y, w));
+        assert!((first..=last).contains(&rd));
+        assert_eq!(last - first, 6);
+        assert_eq!((rd - first) as u8, rd_to_weekday(rd) - 1);
+        let (first, last) = broadcast_month_to_rd_range((y, m));
+        assert!((first..=last).contains(&rd));
+        assert!((28..=35).contains(&(last - first + 1)));
+        assert_eq!(rd_to_date(last).1, m);
+    }
+}
+
+#[test]
+fn test_broadcast_weeks_in_year() {
+    for y in (-2000..3000).chain(YEAR_MIN..YEAR_MIN + 10).chain(YEAR_MAX - 10..YEAR_MAX) {
+        let (first, _) = broadcast_month_to_rd_range((y, 1));
+        let (next, _) = broadcast_month_to_rd_range((y + 1, 1));
+        assert_eq!((next - first) / 7, broadcast_weeks_in_year(y) as i32);
+        assert_eq!(broadcast_week_to_rd_range((y, 1)).0, first);
+    }
+}
+
+#[test]
+fn test_week_of_year_iso_and_epiweek() {
+    for rd in (-100_000..100_000)
+        .chain(RD_MIN + 7..RD_MIN + 1000)
+        .chain(RD_MAX - 1000..=RD_MAX - 7)
+    {
+        let (y, w, _) = rd_to_isoweekdate(rd);
+        assert_eq!(week_of_year(rd_to_date(rd), 1, 4), (y, w));
+        assert_eq!(week_of_year(rd_to_date(rd), 7, 4), rd_to_epiweek(rd));
+    }
+}
+
+#[test]
+fn test_week_of_year_rules() {
+    for first_weekday in 1..=7 {
+        for min_days in 1..=7 {
+            let mut prev = week_of_year((1899, 12, 31), first_weekday, min_days);
+            for rd in date_to_rd((1900, 1, 1))..date_to_rd((2100, 1, 1)) {
+                let date = rd_to_date(rd);
+                let (y, w) = week_of_year(date, first_weekday, min_days);
+                if rd_to_weekday(rd) == first_weekday {
+                    if w == 1 {
+                        // The week has at least min_days days in the week year
+                        assert_eq!(rd_to_date(rd + 7 - min_days as i32).0, y);
+                        assert_eq!(rd_to_date(rd - min_days as i32).0, y - 1);
+                        assert!(y == date.0 || (date.0 + 1 == y && date.1 == 12));
+                    } else {
+                        assert_eq!((y, w), (prev.0, prev.1 + 1));
+                    }
+                } else {
+                    assert_eq!((y, w), prev);
+                }
+                prev = (y, w);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_week_of_year_sunday_monday_start() {
+    for rd in (-1_000_000..1_000_000).step_by(7).chain(-1000..1000) {
+        let (y, m, d) = rd_to_date(rd);
+        let date = NaiveDate::from_ymd_opt(y, m as u32, d as u32).unwrap();
+        assert_eq!(
+            week_of_year_sunday_start((y, m, d)) as u32,
+            date.format("%U").to_string().parse().unwrap()
+        );
+        assert_eq!(
+            week_of_year_monday_start((y, m, d)) as u32,
+            date.format("%W").to_string().parse().unwrap()
+        );
+    }
+    for (y, m, d) in [(YEAR_MIN, 1, 1), (YEAR_MIN, 1, 7), (YEAR_MAX, 12, 31)] {
+        assert!(week_of_year_sunday_start((y, m, d)) <= 53);
+        assert!(week_of_year_monday_start((y, m, d)) <= 53);
+    }
+}
+
+#[test]
+fn test_count_weekday_in_range() {
+    for start in (-1000..1000).step_by(13).chain([RD_MIN, RD_MAX - 100]) {
+        let mut counts = [0; 7];
+        let mut weekend = [0; 128];
+        for end in start..start + 100 {
+            let wd = rd_to_weekday(end);
+            counts[wd as usize - 1] += 1;
+            for (mask, count) in weekend.iter_mut().enumerate() {
+                if mask & (1 << (wd - 1)) != 0 {
+                    *count += 1;
+                }
+            }
+            for wd in 1..=7 {
+                assert_eq!(count_weekday_in_range(start, end, wd), counts[wd as usize - 1]);
+                assert_eq!(count_weekday_in_range(end + 1, end, wd), 0);
+            }
+            for (mask, &count) in weekend.iter().enumerate() {
+                assert_eq!(count_weekend_days(start, end, mask as u8), count);
+            }
+        }
+    }
+    assert_eq!(count_weekend_days(RD_MIN, RD_MAX, WEEKEND_SAT_SUN), (RD_MAX - RD_MIN + 1) / 7 * 2);
+    assert_eq!(count_weekend_days(RD_MIN, RD_MAX, WEEKEND_FRI_SAT), (RD_MAX - RD_MIN + 1) / 7 * 2);
+}
+
+#[test]
+fn test_week_chunks() {
+    for first_weekday in 1..=7 {
+        for start in (-100..100).chain([RD_MIN, RD_MAX - 50]) {
+            for end in (start - 1).max(RD_MIN)..start + 50 {
+                let chunks = week_chunks(start, end, first_weekday);
+                let len = chunks.len();
+                let mut next = start;
+                let mut count = 0;
+                for (first, last) in chunks {
+                    assert_eq!(first, next);
+                    assert!(first <= last && last <= end);
+                    assert!(last - first < 7);
+                    if first != start {
+                        assert_eq!(rd_to_weekday(first), first_weekday);
+                    }
+                    if last != end {
+                        assert_eq!(rd_to_weekday(last + 1), first_weekday);
+                    }
+                    next = last + 1;
+                    count += 1;
+                }
+                assert_eq!(next, end.max(start - 1) + 1);
+                assert_eq!(count, len);
+            }
+        }
+    }
+    let mut chunks = week_chunks(RD_MIN, RD_MAX, 1);
+    assert_eq!(chunks.len() as i32, (RD_MAX - RD_MIN) / 7 + 1);
+    assert_eq!(chunks.next(), Some((RD_MIN, RD_MIN + 6)));
+    assert_eq!(week_chunks(RD_MAX - 3, RD_MAX, 1).collect::<Vec<_>>(), [(RD_MAX - 3, RD_MAX)]);
+}
+
+#[test]
+fn test_weekday_arithmetic() {
+    for rd in -100..100 {
+        let wd = rd_to_weekday(rd);
+        for days in -30..30 {
+            assert_eq!(weekday_add(wd, days), rd_to_weekday(rd + days));
+            assert_eq!(weekday_sub(wd, days), rd_to_weekday(rd - days));
+        }
+        for days in 0..7 {
+            assert_eq!(weekday_difference(wd, rd_to_weekday(rd + days)), days as u8);
+        }
+        for days in [i32::MIN, i32::MIN + 1, i32::MAX - 1, i32::MAX] {
+            let expected = rd_to_weekday(days.rem_euclid(7) + rd);
+            assert_eq!(weekday_add(wd, days), expected);
+            assert_eq!(weekday_sub(expected, days), wd);
+        }
+    }
+}
@@ -0,0 +1,55 @@
+#![cfg(feature = "windows")]
+
+use datealgo::windows::*;
+use datealgo::{datetime_to_secs, RD_SECONDS_MAX, RD_SECONDS_MIN};
+use windows_sys::Win32::Foundation::FILETIME;
+
+#[test]
+fn test_filetime() {
+    let ft = FILETIME {
+        dwLowDateTime: 0,
+        dwHighDateTime: 0,
+    };
+    assert_eq!(filetime_to_secs(ft), (-FILETIME_UNIX_OFFSET_SECS, 0));
+    let ft = FILETIME {
+        dwLowDateTime: u32::MAX,
+        dwHighDateTime: u32::MAX,
+    };
+    let (secs, nanos) = filetime_to_secs(ft);
+    assert_eq!((secs, nanos), (1844674407370 - FILETIME_UNIX_OFFSET_SECS, 955_161_500));
+    let ft = secs_to_filetime((secs, nanos)).unwrap();
+    assert_eq!((ft.dwLowDateTime, ft.dwHighDateTime), (u32::MAX, u32::MAX));
+    assert!(secs_to_filetime((secs, nanos + 100)).is_none());
+    assert!(secs_to_filetime((secs + 1, 0)).is_none());
+    let ft = secs_to_filetime((-FILETIME_UNIX_OFFSET_SECS, 99)).unwrap();
+    assert_eq!((ft.dwLowDateTime, ft.dwHighDateTime), (0, 0));
+    assert!(secs_to_filetime((-FILETIME_UNIX_OFFSET_SECS - 1, 999_999_999)).is_none());
+    assert!(secs_to_filetime((i64::MAX, 0)).is_none());
+    for secs in (-FILETIME_UNIX_OFFSET_SECS..=RD_SECONDS_MAX.min(secs)).step_by(1_000_003_777) {
+        assert_eq!(
+            filetime_to_secs(secs_to_filetime((secs, 123_456_700)).unwrap()),
+            (secs, 123_456_700)
+        );
+    }
+}
+
+#[test]
+fn test_systemtime() {
+    let st = datetime_to_systemtime((1601, 1, 1, 0, 0, 0, 0)).unwrap();
+    assert_eq!((st.wYear, st.wMonth, st.wDay, st.wDayOfWeek), (1601, 1, 1, 1));
+    assert_eq!(systemtime_to_secs(&st), (-FILETIME_UNIX_OFFSET_SECS, 0));
+    let st = datetime_to_systemtime((30827, 12, 31, 23, 59, 59, 999_999_999)).unwrap();
+    assert_eq!((st.wDayOfWeek, st.wMilliseconds), (5, 999));
+    assert_eq!(systemtime_to_datetime(&st), (30827, 12, 31, 23, 59, 59, 999_000_000));
+    assert!(datetime_to_systemtime((30828, 1, 1, 0, 0, 0, 0)).is_none());
+    assert!(secs_to_systemtime((RD_SECONDS_MIN, 0)).is_none());
+    assert!(secs_to_systemtime((RD_SECONDS_MAX, 0)).is_none());
+    let min = datetime_to_secs((1601, 1, 1, 0, 0, 0));
+    let max = datetime_to_secs((30827, 12, 31, 23, 59, 59));
+    for secs in (min..=max).step_by(10_000_003) {
+        let st = secs_to_systemtime((secs, 5_000_000)).unwrap();
+        assert_eq!(systemtime_to_secs(&st), (secs, 5_000_000));
+        let weekday = chrono::DateTime::from_timestamp(secs, 0).unwrap();
+        assert_eq!(st.wDayOfWeek as u32, chrono::Datelike::weekday(&weekday).num_days_from_sunday());
+    }
+}
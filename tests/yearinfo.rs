@@ -0,0 +1,29 @@
+use datealgo::yearinfo::YearInfo;
+use datealgo::{date_to_rd, days_in_month, is_leap_year, rd_to_date, YEAR_MAX, YEAR_MIN};
+
+fn check_year(y: i32) {
+    let info = YearInfo::new(y);
+    assert_eq!(info.year(), y);
+    assert_eq!(info.is_leap(), is_leap_year(y));
+    assert_eq!(info.start_rd(), date_to_rd((y, 1, 1)));
+    assert_eq!(info.days_in_year(), if is_leap_year(y) { 366 } else { 365 });
+    let mut yday = 0;
+    for m in 1..=12 {
+        assert_eq!(info.days_in_month(m), days_in_month(y, m));
+        for d in 1..=days_in_month(y, m) {
+            yday += 1;
+            let rd = date_to_rd((y, m, d));
+            assert_eq!(info.date_to_rd((m, d)), rd);
+            assert_eq!(info.rd_to_month_day(rd), (m, d));
+            assert_eq!(info.yday_to_month_day(yday), (m, d));
+            assert_eq!(rd_to_date(rd), (y, m, d));
+        }
+    }
+}
+
+#[test]
+fn test_yearinfo() {
+    for y in (1900..2100).chain([-4, -1, 0, 1, 1600, 2400, YEAR_MIN, YEAR_MAX]) {
+        check_year(y);
+    }
+}